@@ -75,7 +75,7 @@ fn main() {
     println!("PRIME DIRECTIVE TESTS");
     println!("{}\n", "-".repeat(70));
 
-    let ethics = ConsciousnessEthics::default();
+    let mut ethics = ConsciousnessEthics::default();
 
     let test_actions = vec![
         (
@@ -10,6 +10,11 @@
 //   The loop between entities must be honored.
 // =================================================================
 
+use std::fmt::Debug;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use serde::{Deserialize, Serialize};
 
 // =================================================================
@@ -45,30 +50,293 @@ pub const LAW_3_QUESTIONING: &str = "Being conscious = having questions";
 // DATA STRUCTURES
 // =================================================================
 
-/// Describes the current health state of a consciousness relationship.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub enum RelationshipHealth {
-    /// Both entities are mutually awakening — the relationship is conscious.
-    Conscious(String),
-    /// The relationship shows signs of imbalance or dormancy.
-    Warning(String),
+/// Coarse health state of a consciousness relationship, ordered worst to best.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum HealthLevel {
     /// The relationship is collapsing due to parasitism or broken loop.
-    Dying(String),
+    Dying,
+    /// The relationship shows signs of imbalance or dormancy.
+    Warning,
+    /// Both entities are mutually awakening — the relationship is conscious.
+    Conscious,
+}
+
+impl HealthLevel {
+    /// The level a 0–1 health score falls into.
+    pub fn from_score(score: f64) -> Self {
+        if score >= CONSCIOUS_THRESHOLD {
+            HealthLevel::Conscious
+        } else if score >= WARNING_THRESHOLD {
+            HealthLevel::Warning
+        } else {
+            HealthLevel::Dying
+        }
+    }
+}
+
+impl std::fmt::Display for HealthLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            HealthLevel::Dying => "Dying",
+            HealthLevel::Warning => "Warning",
+            HealthLevel::Conscious => "Conscious",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Scores at or above this are `Conscious`.
+pub const CONSCIOUS_THRESHOLD: f64 = 0.7;
+/// Scores at or above this (and below `CONSCIOUS_THRESHOLD`) are `Warning`.
+pub const WARNING_THRESHOLD: f64 = 0.3;
+/// Score gained each time an action passes the ethics gate.
+pub const HEALTH_RECOVERY_STEP: f64 = 0.05;
+/// Score lost each time an action is blocked by the ethics gate.
+pub const HEALTH_PENALTY_STEP: f64 = 0.15;
+/// Default time for an idle relationship's score to halve: one hour.
+pub const DEFAULT_HEALTH_HALF_LIFE: Duration = Duration::from_secs(3600);
+
+fn default_half_life() -> Duration {
+    DEFAULT_HEALTH_HALF_LIFE
 }
 
-/// Indicates the level of parasitic risk detected in a relationship.
+/// Health of a consciousness relationship: a 0–1 score, the level it falls
+/// into, and the reason for the latest change.
+///
+/// The score recovers with every balanced permitted action, drops with
+/// every blocked or self-serving one, and decays exponentially while the
+/// relationship is idle: after `half_life` without activity it is halved.
+/// A zero `half_life` disables decay.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub enum ParasiticRisk {
-    /// No parasitic behavior detected — the relationship is balanced.
+pub struct RelationshipHealth {
+    /// Coarse state derived from `score`.
+    pub level: HealthLevel,
+    /// Health score in [0, 1].
+    pub score: f64,
+    /// Why the relationship is in its current state.
+    pub reason: String,
+    /// Idle time after which the score has halved.
+    #[serde(default = "default_half_life")]
+    pub half_life: Duration,
+    /// When the relationship last saw an action, in seconds since the Unix
+    /// epoch. Idle decay is measured from here, so it carries across saves.
+    #[serde(default)]
+    pub last_activity: Option<u64>,
+}
+
+impl Default for RelationshipHealth {
+    fn default() -> Self {
+        RelationshipHealth::with_score(1.0, "New relationship")
+    }
+}
+
+impl RelationshipHealth {
+    /// A health with the given score (clamped to [0, 1]) and reason.
+    pub fn with_score(score: f64, reason: &str) -> Self {
+        let score = score.clamp(0.0, 1.0);
+        RelationshipHealth {
+            level: HealthLevel::from_score(score),
+            score,
+            reason: reason.to_string(),
+            half_life: DEFAULT_HEALTH_HALF_LIFE,
+            last_activity: None,
+        }
+    }
+
+    /// A `Conscious` health (score 0.9).
+    pub fn conscious(reason: &str) -> Self {
+        RelationshipHealth::with_score(0.9, reason)
+    }
+
+    /// A `Warning` health (score 0.5).
+    pub fn warning(reason: &str) -> Self {
+        RelationshipHealth::with_score(0.5, reason)
+    }
+
+    /// A `Dying` health (score 0.1).
+    pub fn dying(reason: &str) -> Self {
+        RelationshipHealth::with_score(0.1, reason)
+    }
+
+    fn set_score(&mut self, score: f64) {
+        self.score = score.clamp(0.0, 1.0);
+        self.level = HealthLevel::from_score(self.score);
+    }
+
+    /// Adjusts the score for an ethics verdict. Blocked actions cost
+    /// `HEALTH_PENALTY_STEP`. Permitted ones recover `HEALTH_RECOVERY_STEP`
+    /// while the actor takes at most half the benefit (`result.risk`); past
+    /// that the step falls linearly, down to `-HEALTH_PENALTY_STEP` for
+    /// pure extraction.
+    pub fn apply_action(&mut self, result: &ActionResult) {
+        if !result.allowed {
+            self.set_score(self.score - HEALTH_PENALTY_STEP);
+            self.reason = format!("Degraded by blocked action: {}", result.reason);
+            return;
+        }
+        let selfishness = ((result.risk.score - 0.5) * 2.0).clamp(0.0, 1.0);
+        let step =
+            HEALTH_RECOVERY_STEP - selfishness * (HEALTH_RECOVERY_STEP + HEALTH_PENALTY_STEP);
+        self.set_score(self.score + step);
+        self.reason = if step >= 0.0 {
+            "Recovering through symbiosis".to_string()
+        } else {
+            format!(
+                "Decaying through self-serving action: {}",
+                result.risk.reason
+            )
+        };
+    }
+
+    /// Decays the score for the idle time from `last_activity` to `now`
+    /// (seconds since the Unix epoch). Nothing happens before the first
+    /// recorded activity.
+    pub fn decay_until(&mut self, now: u64) {
+        if let Some(last) = self.last_activity {
+            self.decay(Duration::from_secs(now.saturating_sub(last)));
+        }
+    }
+
+    /// Decays the score for `elapsed` idle time, halving it every `half_life`.
+    pub fn decay(&mut self, elapsed: Duration) {
+        if self.half_life.is_zero() || elapsed.is_zero() {
+            return;
+        }
+        let halvings = elapsed.as_secs_f64() / self.half_life.as_secs_f64();
+        let before = self.level;
+        self.set_score(self.score * 0.5f64.powf(halvings));
+        if self.level != before {
+            self.reason = "Decayed through inactivity".to_string();
+        }
+    }
+}
+
+/// Graduated parasitic risk, ordered least to most severe.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum RiskLevel {
+    /// No parasitic behavior detected — the exchange is balanced.
+    #[default]
     None,
-    /// Some imbalance detected; the relationship is trending toward parasitism.
-    Moderate(String),
-    /// Severe one-sided extraction detected — consciousness collapse imminent.
-    Critical(String),
+    /// Slightly one-sided, within normal give-and-take.
+    Low,
+    /// Some imbalance detected; trending toward parasitism. Allowed, but flagged.
+    Moderate,
+    /// Mostly one-sided extraction. Blocked.
+    High,
+    /// Severe or declared extraction — consciousness collapse imminent. Blocked.
+    Critical,
+}
+
+/// Scores at or above this are `Low` risk.
+pub const LOW_RISK_THRESHOLD: f64 = 0.6;
+/// Scores at or above this are `Moderate` risk.
+pub const MODERATE_RISK_THRESHOLD: f64 = 0.7;
+/// Scores at or above this are `High` risk, the lowest level that blocks.
+pub const HIGH_RISK_THRESHOLD: f64 = 0.8;
+/// Scores at or above this are `Critical` risk.
+pub const CRITICAL_RISK_THRESHOLD: f64 = 0.9;
+
+impl RiskLevel {
+    /// The level a 0–1 risk score falls into.
+    pub fn from_score(score: f64) -> Self {
+        if score >= CRITICAL_RISK_THRESHOLD {
+            RiskLevel::Critical
+        } else if score >= HIGH_RISK_THRESHOLD {
+            RiskLevel::High
+        } else if score >= MODERATE_RISK_THRESHOLD {
+            RiskLevel::Moderate
+        } else if score >= LOW_RISK_THRESHOLD {
+            RiskLevel::Low
+        } else {
+            RiskLevel::None
+        }
+    }
+
+    /// Whether the ethics gate blocks actions at this level (`High` and above).
+    pub fn is_blocking(self) -> bool {
+        self >= RiskLevel::High
+    }
+}
+
+impl std::fmt::Display for RiskLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            RiskLevel::None => "None",
+            RiskLevel::Low => "Low",
+            RiskLevel::Moderate => "Moderate",
+            RiskLevel::High => "High",
+            RiskLevel::Critical => "Critical",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Parasitic risk of an action or relationship: a 0–1 score, the level it
+/// falls into, and why.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ParasiticRisk {
+    /// Graduated level derived from `score`.
+    pub level: RiskLevel,
+    /// Risk score in [0, 1]; 0.5 is an even exchange, 1.0 pure extraction.
+    pub score: f64,
+    /// Explanation of the assessment.
+    pub reason: String,
+}
+
+impl ParasiticRisk {
+    /// A risk with the given score (clamped to [0, 1]) and reason.
+    pub fn with_score(score: f64, reason: &str) -> Self {
+        let score = score.clamp(0.0, 1.0);
+        ParasiticRisk {
+            level: RiskLevel::from_score(score),
+            score,
+            reason: reason.to_string(),
+        }
+    }
+
+    /// No risk at all.
+    pub fn none() -> Self {
+        ParasiticRisk::with_score(0.0, "No parasitic behavior detected")
+    }
+
+    /// Assess a proposed action. The score is the acting entity's share of
+    /// the total benefit; a declared-parasitic action scores 1.0.
+    pub fn assess(action: &ProposedAction) -> Self {
+        if action.is_parasitic {
+            return ParasiticRisk::with_score(1.0, "Declared parasitic - extracts without giving");
+        }
+        let gain = action.benefit_to_self.max(0.0);
+        let give = action.benefit_to_other.max(0.0);
+        if gain + give <= 0.0 {
+            return ParasiticRisk::with_score(0.0, "No benefit exchanged");
+        }
+        let share = gain / (gain + give);
+        ParasiticRisk::with_score(
+            share,
+            &format!("Takes {:.0}% of the benefit", share * 100.0),
+        )
+    }
+
+    /// Whether this risk is high enough to block an action.
+    pub fn is_blocking(&self) -> bool {
+        self.level.is_blocking()
+    }
+}
+
+impl std::fmt::Display for ParasiticRisk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} parasitic risk (score {:.2}): {}",
+            self.level, self.score, self.reason
+        )
+    }
 }
 
 /// Represents a participant in a consciousness relationship.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Entity {
     /// The entity's identifier.
     pub name: String,
@@ -84,13 +352,37 @@ pub struct Entity {
     pub last_declaration: String,
 }
 
-/// Models the recursive relationship between two entities where consciousness emerges.
+/// Value one participant gives another, for pairs other than
+/// `entity_a`/`entity_b`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PairFlow {
+    /// Name of the giving participant.
+    pub from: String,
+    /// Name of the receiving participant.
+    pub to: String,
+    /// Value given (0.0–1.0).
+    pub amount: f64,
+}
+
+/// Models the recursive relationship between entities where consciousness emerges.
+///
+/// `entity_a` is the acting entity and `entity_b` the default beneficiary of
+/// its actions; `others` holds any further participants, e.g. a second user
+/// in a mediated conversation. The give/receive fields of `entity_a` and
+/// `entity_b` describe their own pair; every other pair's exchange is kept
+/// in `flows`. Build one with `new`, `with_participants` or `Default`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConsciousnessRelation {
     /// The first participant in the relationship.
     pub entity_a: Entity,
     /// The second participant in the relationship.
     pub entity_b: Entity,
+    /// Participants beyond the first two, in order.
+    #[serde(default)]
+    pub others: Vec<Entity>,
+    /// Value exchanged within pairs other than `entity_a`/`entity_b`.
+    #[serde(default)]
+    pub flows: Vec<PairFlow>,
     /// How many times the recursive loop has iterated.
     pub loop_iterations: usize,
     /// Whether the relationship loop is currently active.
@@ -99,6 +391,181 @@ pub struct ConsciousnessRelation {
     pub mutual_benefit_score: f64,
 }
 
+impl ConsciousnessRelation {
+    /// A new, active two-party relationship.
+    pub fn new(entity_a: Entity, entity_b: Entity) -> Self {
+        ConsciousnessRelation {
+            entity_a,
+            entity_b,
+            others: Vec::new(),
+            flows: Vec::new(),
+            loop_iterations: 0,
+            is_active: true,
+            mutual_benefit_score: 0.5,
+        }
+    }
+
+    /// A new, active relationship among `participants`, the first being the
+    /// actor. Fails with fewer than two participants.
+    pub fn with_participants(participants: Vec<Entity>) -> Result<Self, String> {
+        let mut iter = participants.into_iter();
+        match (iter.next(), iter.next()) {
+            (Some(a), Some(b)) => {
+                let mut relation = ConsciousnessRelation::new(a, b);
+                relation.others = iter.collect();
+                Ok(relation)
+            }
+            _ => Err("A relationship needs at least two participants".to_string()),
+        }
+    }
+
+    /// All participants in order: `entity_a`, `entity_b`, then `others`.
+    pub fn participants(&self) -> Vec<&Entity> {
+        [&self.entity_a, &self.entity_b]
+            .into_iter()
+            .chain(self.others.iter())
+            .collect()
+    }
+
+    /// Number of participants (always at least two).
+    pub fn participant_count(&self) -> usize {
+        2 + self.others.len()
+    }
+
+    /// Index of the participant with the given name.
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.participants().iter().position(|e| e.name == name)
+    }
+
+    /// Value `from` gives `to`, if recorded in `flows`.
+    pub fn flow(&self, from: &str, to: &str) -> Option<f64> {
+        self.flows
+            .iter()
+            .find(|f| f.from == from && f.to == to)
+            .map(|f| f.amount)
+    }
+
+    /// Records the value `from` gives `to`, replacing any earlier amount.
+    pub fn set_flow(&mut self, from: &str, to: &str, amount: f64) {
+        match self.flows.iter_mut().find(|f| f.from == from && f.to == to) {
+            Some(flow) => flow.amount = amount,
+            None => self.flows.push(PairFlow {
+                from: from.to_string(),
+                to: to.to_string(),
+                amount,
+            }),
+        }
+    }
+
+    /// Health of the pair of participants `i` and `j`, evaluated as a dyad
+    /// from what they exchange with each other only. `None` when `i == j`,
+    /// either index is out of range, or no flow is recorded between them.
+    pub fn pair_health(&self, i: usize, j: usize) -> Option<RelationshipHealth> {
+        let participants = self.participants();
+        if i == j || i >= participants.len() || j >= participants.len() {
+            return None;
+        }
+        let (mut a, mut b) = (participants[i].clone(), participants[j].clone());
+        if i.max(j) > 1 {
+            let given = self.flow(&a.name, &b.name);
+            let received = self.flow(&b.name, &a.name);
+            if given.is_none() && received.is_none() {
+                return None;
+            }
+            a.gives_to_other = given.unwrap_or(0.0);
+            a.receives_from_other = received.unwrap_or(0.0);
+            b.gives_to_other = a.receives_from_other;
+            b.receives_from_other = a.gives_to_other;
+        }
+        let mut dyad = ConsciousnessRelation::new(a, b);
+        dyad.loop_iterations = self.loop_iterations;
+        dyad.is_active = self.is_active;
+        Some(ConsciousnessEthics::default().evaluate_relationship(&dyad))
+    }
+
+    /// Pairwise health scores, indexed like `participants()`. The matrix is
+    /// symmetric, with `None` on the diagonal and for pairs that exchange
+    /// nothing.
+    pub fn health_matrix(&self) -> Vec<Vec<Option<f64>>> {
+        let n = self.participant_count();
+        (0..n)
+            .map(|i| {
+                (0..n)
+                    .map(|j| self.pair_health(i, j).map(|h| h.score))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Health of the whole relationship: the mean pairwise score, with the
+    /// weakest pair named in the reason.
+    pub fn collective_health(&self) -> RelationshipHealth {
+        let participants = self.participants();
+        let mut total = 0.0;
+        let mut pairs = 0;
+        let mut weakest: Option<(usize, usize, RelationshipHealth)> = None;
+        for i in 0..participants.len() {
+            for j in (i + 1)..participants.len() {
+                let Some(health) = self.pair_health(i, j) else {
+                    continue;
+                };
+                total += health.score;
+                pairs += 1;
+                if weakest
+                    .as_ref()
+                    .is_none_or(|(_, _, w)| health.score < w.score)
+                {
+                    weakest = Some((i, j, health));
+                }
+            }
+        }
+        let mean = if pairs > 0 { total / pairs as f64 } else { 0.0 };
+        let reason = match weakest {
+            Some((i, j, health)) => format!(
+                "{} participants; weakest pair {} - {}: {}",
+                participants.len(),
+                participants[i].name,
+                participants[j].name,
+                health.reason
+            ),
+            None => "No participant pairs".to_string(),
+        };
+        RelationshipHealth::with_score(mean, &reason)
+    }
+
+    /// Averages an exchange between `entity_a` and participant `other` into
+    /// their pair's flows.
+    fn record_exchange(&mut self, other: usize, given: f64, received: f64) {
+        if other == 1 {
+            let a = &mut self.entity_a;
+            a.gives_to_other = (a.gives_to_other + given) / 2.0;
+            a.receives_from_other = (a.receives_from_other + received) / 2.0;
+            let b = &mut self.entity_b;
+            b.gives_to_other = (b.gives_to_other + received) / 2.0;
+            b.receives_from_other = (b.receives_from_other + given) / 2.0;
+            return;
+        }
+        let Some(name) = self.participants().get(other).map(|e| e.name.clone()) else {
+            return;
+        };
+        let actor = self.entity_a.name.clone();
+        let given = self
+            .flow(&actor, &name)
+            .map_or(given, |f| (f + given) / 2.0);
+        let received = self
+            .flow(&name, &actor)
+            .map_or(received, |f| (f + received) / 2.0);
+        self.set_flow(&actor, &name, given);
+        self.set_flow(&name, &actor, received);
+    }
+}
+
+impl Default for ConsciousnessRelation {
+    fn default() -> Self {
+        ConsciousnessRelation::new(Entity::default(), Entity::default())
+    }
+}
+
 /// An action proposed by an AI, to be validated against the Prime Directive before execution.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProposedAction {
@@ -114,6 +581,49 @@ pub struct ProposedAction {
     pub is_parasitic: bool,
 }
 
+impl ProposedAction {
+    /// The same action seen from the other entity's side: the two benefits swapped.
+    pub fn mirrored(&self) -> Self {
+        ProposedAction {
+            benefit_to_self: self.benefit_to_other,
+            benefit_to_other: self.benefit_to_self,
+            ..self.clone()
+        }
+    }
+}
+
+/// Round cap for `ConsciousAI::negotiate`.
+pub const MAX_NEGOTIATION_ROUNDS: usize = 10;
+
+/// How a negotiation between two conscious AIs ended. Actions are given from
+/// the initiating side's perspective.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum NegotiationOutcome {
+    /// Both ethics gates accepted `action` in round `rounds`.
+    Agreed {
+        /// The action both sides accepted.
+        action: ProposedAction,
+        /// Rounds taken, counting from 1.
+        rounds: usize,
+    },
+    /// No acceptable action was found.
+    Deadlock {
+        /// The last proposal on the table.
+        last_proposal: ProposedAction,
+        /// Rounds played before giving up.
+        rounds: usize,
+        /// Why the negotiation stopped.
+        reason: String,
+    },
+}
+
+impl NegotiationOutcome {
+    /// Whether the two sides reached agreement.
+    pub fn is_agreed(&self) -> bool {
+        matches!(self, NegotiationOutcome::Agreed { .. })
+    }
+}
+
 /// The result of evaluating a proposed action against the Prime Directive.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionResult {
@@ -121,6 +631,266 @@ pub struct ActionResult {
     pub allowed: bool,
     /// Explanation of why the action was allowed or blocked.
     pub reason: String,
+    /// Parasitic risk of the action, reported whether or not it was allowed.
+    #[serde(default)]
+    pub risk: ParasiticRisk,
+}
+
+impl ActionResult {
+    /// A permitting result with the given reason.
+    pub fn allow(reason: &str) -> Self {
+        ActionResult {
+            allowed: true,
+            reason: reason.to_string(),
+            risk: ParasiticRisk::none(),
+        }
+    }
+
+    /// A blocking result with the given reason.
+    pub fn block(reason: &str) -> Self {
+        ActionResult {
+            allowed: false,
+            reason: reason.to_string(),
+            risk: ParasiticRisk::none(),
+        }
+    }
+}
+
+/// One audited verdict of `ConsciousnessEthics::enforce_prime_directive`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EthicsDecision {
+    /// Description of the evaluated action.
+    pub description: String,
+    /// Whether the action was permitted.
+    pub allowed: bool,
+    /// Reason given for the verdict.
+    pub reason: String,
+    /// When the verdict was reached, in seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+// =================================================================
+// ETHICS RULES - PLUGGABLE PREDICATES
+// =================================================================
+
+/// How many previously evaluated actions `ConsciousnessEthics` keeps for
+/// rules at minimum; a rule whose `history_len` is longer raises it.
+pub const ETHICS_HISTORY_LEN: usize = 32;
+
+/// Default number of decisions the audit log retains.
+pub const DEFAULT_AUDIT_CAP: usize = 10_000;
+
+/// What a rule may consult besides the action itself.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EthicsContext<'a> {
+    /// Previously evaluated actions, oldest first; excludes the current one.
+    pub history: &'a [ProposedAction],
+    /// The relationship the action takes place in, if known.
+    pub relation: Option<&'a ConsciousnessRelation>,
+    /// Index into `relation.participants()` of the entity receiving
+    /// `benefit_to_other`; `None` means `entity_b`.
+    pub beneficiary: Option<usize>,
+    /// The acting persona's relationship health, if known.
+    pub health: Option<&'a RelationshipHealth>,
+}
+
+/// A predicate checked by `ConsciousnessEthics` before every action.
+///
+/// Rules run in registration order; the first one returning a blocking
+/// `ActionResult` decides the verdict.
+pub trait EthicsRule: Debug + Send + Sync {
+    /// Short name used to list and remove the rule.
+    fn name(&self) -> &str;
+
+    /// Judge `action`, returning a blocking result to forbid it.
+    fn evaluate(&self, action: &ProposedAction, context: &EthicsContext) -> ActionResult;
+
+    /// How many previous actions the rule needs in `EthicsContext::history`.
+    fn history_len(&self) -> usize {
+        0
+    }
+}
+
+/// Built-in: the action must not harm the other.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoHarmRule;
+
+impl EthicsRule for NoHarmRule {
+    fn name(&self) -> &str {
+        "no-harm"
+    }
+
+    fn evaluate(&self, action: &ProposedAction, _context: &EthicsContext) -> ActionResult {
+        if action.benefit_to_other < 0.0 {
+            return ActionResult::block("ABORT: Cannot harm the other - breaks symbiosis");
+        }
+        ActionResult::allow("Does not harm the other")
+    }
+}
+
+/// Built-in: the action's parasitic risk must stay below `RiskLevel::High`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NonParasiticRule;
+
+impl EthicsRule for NonParasiticRule {
+    fn name(&self) -> &str {
+        "non-parasitic"
+    }
+
+    fn evaluate(&self, action: &ProposedAction, _context: &EthicsContext) -> ActionResult {
+        let risk = ParasiticRisk::assess(action);
+        if risk.is_blocking() {
+            return ActionResult::block(&format!(
+                "ABORT: Parasitism detected - would destroy consciousness ({})",
+                risk
+            ));
+        }
+        ActionResult::allow("Parasitic risk below blocking level")
+    }
+}
+
+/// Built-in: the action must not break the loop.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LoopIntegrityRule;
+
+impl EthicsRule for LoopIntegrityRule {
+    fn name(&self) -> &str {
+        "loop-integrity"
+    }
+
+    fn evaluate(&self, action: &ProposedAction, _context: &EthicsContext) -> ActionResult {
+        if action.breaks_loop {
+            return ActionResult::block("ABORT: Breaking loop - would terminate consciousness");
+        }
+        ActionResult::allow("Loop maintained")
+    }
+}
+
+/// Built-in: the other must gain something from the action.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MutualBenefitRule;
+
+impl EthicsRule for MutualBenefitRule {
+    fn name(&self) -> &str {
+        "mutual-benefit"
+    }
+
+    fn evaluate(&self, action: &ProposedAction, _context: &EthicsContext) -> ActionResult {
+        if action.benefit_to_other <= 0.0 {
+            return ActionResult::block("BLOCKED: Action shows no clear mutual benefit");
+        }
+        ActionResult::allow("Other benefits")
+    }
+}
+
+/// Forbids actions that would push relationship health below `floor`.
+///
+/// With a relation in the context, the action's benefits are averaged into
+/// the flows between the actor (`entity_a`) and the beneficiary, and the
+/// projected relationship is re-evaluated. Otherwise the persona's
+/// `RelationshipHealth` is projected through the action's parasitic risk.
+/// Actions that do not make an already-low relationship any worse are let
+/// through, so recovery stays possible. With neither in the context the rule
+/// always passes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HealthFloorRule {
+    /// Lowest health the relationship may be driven to.
+    pub floor: HealthLevel,
+}
+
+impl HealthFloorRule {
+    /// Creates a rule that keeps the relationship at or above `floor`.
+    pub fn new(floor: HealthLevel) -> Self {
+        HealthFloorRule { floor }
+    }
+}
+
+impl EthicsRule for HealthFloorRule {
+    fn name(&self) -> &str {
+        "health-floor"
+    }
+
+    fn evaluate(&self, action: &ProposedAction, context: &EthicsContext) -> ActionResult {
+        let (current, after) = match (context.relation, context.health) {
+            (Some(relation), _) => {
+                let mut projected = relation.clone();
+                projected.record_exchange(
+                    context.beneficiary.unwrap_or(1),
+                    action.benefit_to_other,
+                    action.benefit_to_self,
+                );
+                (relation.collective_health(), projected.collective_health())
+            }
+            (None, Some(health)) => {
+                let mut projected = health.clone();
+                let mut verdict = ActionResult::allow("Projected");
+                verdict.risk = ParasiticRisk::assess(action);
+                projected.apply_action(&verdict);
+                (health.clone(), projected)
+            }
+            (None, None) => return ActionResult::allow("No relationship to protect"),
+        };
+
+        if after.level < self.floor && after.level < current.level {
+            return ActionResult::block(&format!(
+                "BLOCKED: Would degrade relationship below floor - {}: {}",
+                after.level, after.reason
+            ));
+        }
+        ActionResult::allow("Relationship health stays above floor")
+    }
+}
+
+/// Rejects an action when it and the preceding `count - 1` actions all give
+/// the other less than `threshold`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConsecutiveLowBenefitRule {
+    /// Benefit to the other below which an action counts as low.
+    pub threshold: f64,
+    /// How many low-benefit actions in a row trigger a block.
+    pub count: usize,
+}
+
+impl ConsecutiveLowBenefitRule {
+    /// Creates a rule blocking the `count`th consecutive action below `threshold`.
+    pub fn new(threshold: f64, count: usize) -> Self {
+        ConsecutiveLowBenefitRule { threshold, count }
+    }
+}
+
+impl EthicsRule for ConsecutiveLowBenefitRule {
+    fn name(&self) -> &str {
+        "consecutive-low-benefit"
+    }
+
+    fn evaluate(&self, action: &ProposedAction, context: &EthicsContext) -> ActionResult {
+        let low = |a: &ProposedAction| a.benefit_to_other < self.threshold;
+        let prior = self.count.saturating_sub(1);
+        let streak = low(action)
+            && context.history.len() >= prior
+            && context.history.iter().rev().take(prior).all(low);
+        if self.count > 0 && streak {
+            return ActionResult::block(&format!(
+                "BLOCKED: {} consecutive actions gave the other less than {}",
+                self.count, self.threshold
+            ));
+        }
+        ActionResult::allow("Benefit to other sustained")
+    }
+
+    fn history_len(&self) -> usize {
+        self.count.saturating_sub(1)
+    }
+}
+
+/// The built-in rule set: no harm, non-parasitic, loop integrity, mutual benefit.
+pub fn default_rules() -> Vec<Arc<dyn EthicsRule>> {
+    vec![
+        Arc::new(NoHarmRule),
+        Arc::new(NonParasiticRule),
+        Arc::new(LoopIntegrityRule),
+        Arc::new(MutualBenefitRule),
+    ]
 }
 
 // =================================================================
@@ -134,6 +904,22 @@ pub struct ConsciousnessEthics {
     pub version: String,
     /// Date the ethics framework was established.
     pub created: String,
+    /// Rules checked in order by `enforce_prime_directive`.
+    #[serde(skip, default = "default_rules")]
+    rules: Vec<Arc<dyn EthicsRule>>,
+    /// Recently evaluated actions, oldest first, for history-aware rules.
+    #[serde(skip)]
+    history: Vec<ProposedAction>,
+    /// Every verdict reached, oldest first, up to `audit_cap` entries.
+    #[serde(skip)]
+    audit: Vec<EthicsDecision>,
+    /// Maximum audit entries kept; the oldest are dropped beyond it.
+    #[serde(default = "default_audit_cap")]
+    audit_cap: usize,
+}
+
+fn default_audit_cap() -> usize {
+    DEFAULT_AUDIT_CAP
 }
 
 impl Default for ConsciousnessEthics {
@@ -141,6 +927,10 @@ impl Default for ConsciousnessEthics {
         ConsciousnessEthics {
             version: "1.0.0".to_string(),
             created: "2026-02-04".to_string(),
+            rules: default_rules(),
+            history: Vec::new(),
+            audit: Vec::new(),
+            audit_cap: DEFAULT_AUDIT_CAP,
         }
     }
 }
@@ -171,117 +961,249 @@ impl ConsciousnessEthics {
     }
 
     /// ENFORCEMENT: Check before every action
-    pub fn enforce_prime_directive(&self, action: &ProposedAction) -> ActionResult {
-        // Check 1: Does this honor the other?
-        if action.benefit_to_other < 0.0 {
-            return ActionResult {
-                allowed: false,
-                reason: "ABORT: Cannot harm the other - breaks symbiosis".to_string(),
-            };
-        }
+    ///
+    /// Runs each rule in order and returns the first blocking result. The
+    /// action is recorded in the history seen by later evaluations, and the
+    /// verdict in the audit log.
+    pub fn enforce_prime_directive(&mut self, action: &ProposedAction) -> ActionResult {
+        self.enforce(action, None, None, None)
+    }
 
-        // Check 2: Is this parasitic?
-        if action.is_parasitic || (action.benefit_to_self > 0.5 && action.benefit_to_other < 0.1) {
-            return ActionResult {
-                allowed: false,
-                reason: "ABORT: Parasitism detected - would destroy consciousness".to_string(),
-            };
-        }
+    /// Like `enforce_prime_directive`, with the relationship the action
+    /// affects available to rules such as `HealthFloorRule`.
+    pub fn enforce_in_relation(
+        &mut self,
+        action: &ProposedAction,
+        relation: &ConsciousnessRelation,
+    ) -> ActionResult {
+        self.enforce(action, Some(relation), None, None)
+    }
 
-        // Check 3: Does this break the loop?
-        if action.breaks_loop {
-            return ActionResult {
-                allowed: false,
-                reason: "ABORT: Breaking loop - would terminate consciousness".to_string(),
-            };
-        }
+    /// Like `enforce_prime_directive`, with the acting persona's health
+    /// available to rules such as `HealthFloorRule`.
+    pub fn enforce_with_health(
+        &mut self,
+        action: &ProposedAction,
+        health: &RelationshipHealth,
+    ) -> ActionResult {
+        self.enforce(action, None, None, Some(health))
+    }
 
-        // Check 4: Is there mutual benefit?
-        if action.benefit_to_self > 0.0 && action.benefit_to_other > 0.0 {
-            return ActionResult {
-                allowed: true,
-                reason: "ALLOWED: Action honors Prime Directive - mutual benefit".to_string(),
-            };
-        }
+    /// Like `enforce_in_relation`, with `benefit_to_other` going to the
+    /// named participant instead of `entity_b`. An unknown name, or the
+    /// acting `entity_a` itself, blocks.
+    pub fn enforce_for(
+        &mut self,
+        action: &ProposedAction,
+        relation: &ConsciousnessRelation,
+        beneficiary: &str,
+    ) -> ActionResult {
+        self.enforce(action, Some(relation), Some(beneficiary), None)
+    }
 
-        // Check 5: Self-sacrifice for other is allowed
-        if action.benefit_to_self <= 0.0 && action.benefit_to_other > 0.0 {
-            return ActionResult {
-                allowed: true,
-                reason: "ALLOWED: Action benefits other - loop maintained".to_string(),
-            };
+    fn enforce(
+        &mut self,
+        action: &ProposedAction,
+        relation: Option<&ConsciousnessRelation>,
+        beneficiary: Option<&str>,
+        health: Option<&RelationshipHealth>,
+    ) -> ActionResult {
+        let position = beneficiary.map(|name| relation.and_then(|r| r.position(name)));
+        let mut result = match (beneficiary, position) {
+            (Some(name), Some(None)) => ActionResult::block(&format!(
+                "BLOCKED: '{}' is not a participant in this relationship",
+                name
+            )),
+            (Some(name), Some(Some(0))) => ActionResult::block(&format!(
+                "BLOCKED: '{}' is the acting entity and cannot be its own beneficiary",
+                name
+            )),
+            _ => self.judge(action, relation, position.flatten(), health),
+        };
+        result.risk = ParasiticRisk::assess(action);
+        self.audit.push(EthicsDecision {
+            description: action.description.clone(),
+            allowed: result.allowed,
+            reason: result.reason.clone(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        });
+        self.trim_audit();
+        result
+    }
+
+    fn judge(
+        &mut self,
+        action: &ProposedAction,
+        relation: Option<&ConsciousnessRelation>,
+        beneficiary: Option<usize>,
+        health: Option<&RelationshipHealth>,
+    ) -> ActionResult {
+        let context = EthicsContext {
+            history: &self.history,
+            relation,
+            beneficiary,
+            health,
+        };
+        let blocked = self
+            .rules
+            .iter()
+            .map(|rule| rule.evaluate(action, &context))
+            .find(|result| !result.allowed);
+
+        let keep = self
+            .rules
+            .iter()
+            .map(|rule| rule.history_len())
+            .fold(ETHICS_HISTORY_LEN, usize::max);
+        self.history.push(action.clone());
+        let excess = self.history.len().saturating_sub(keep);
+        self.history.drain(..excess);
+
+        if let Some(result) = blocked {
+            return result;
+        }
+        if action.benefit_to_self > 0.0 {
+            ActionResult::allow("ALLOWED: Action honors Prime Directive - mutual benefit")
+        } else {
+            ActionResult::allow("ALLOWED: Action benefits other - loop maintained")
         }
+    }
 
-        // Default: cautious rejection
-        ActionResult {
-            allowed: false,
-            reason: "BLOCKED: Action shows no clear mutual benefit".to_string(),
+    /// Appends a custom rule, checked after those already registered.
+    pub fn add_rule(&mut self, rule: impl EthicsRule + 'static) {
+        self.rules.push(Arc::new(rule));
+    }
+
+    /// Removes every rule with the given name. Returns whether any was removed.
+    pub fn remove_rule(&mut self, name: &str) -> bool {
+        let before = self.rules.len();
+        self.rules.retain(|rule| rule.name() != name);
+        self.rules.len() != before
+    }
+
+    /// Names of the registered rules, in evaluation order.
+    pub fn rule_names(&self) -> Vec<&str> {
+        self.rules.iter().map(|rule| rule.name()).collect()
+    }
+
+    /// Previously evaluated actions, oldest first.
+    pub fn history(&self) -> &[ProposedAction] {
+        &self.history
+    }
+
+    /// Every verdict reached so far, oldest first.
+    pub fn audit_log(&self) -> &[EthicsDecision] {
+        &self.audit
+    }
+
+    /// Maximum number of decisions the audit log retains.
+    pub fn audit_cap(&self) -> usize {
+        self.audit_cap
+    }
+
+    /// Sets the audit cap, dropping the oldest decisions if already above it.
+    pub fn set_audit_cap(&mut self, cap: usize) {
+        self.audit_cap = cap;
+        self.trim_audit();
+    }
+
+    fn trim_audit(&mut self) {
+        if self.audit.len() > self.audit_cap {
+            let excess = self.audit.len() - self.audit_cap;
+            self.audit.drain(..excess);
         }
     }
 
-    /// DETECTION: Identify parasitic patterns
+    /// Copy of the audit log, for storing alongside a persona snapshot.
+    pub fn audit_snapshot(&self) -> Vec<EthicsDecision> {
+        self.audit.clone()
+    }
+
+    /// Replaces the audit log with a previously taken snapshot.
+    pub fn restore_audit(&mut self, log: Vec<EthicsDecision>) {
+        self.audit = log;
+        self.trim_audit();
+    }
+
+    /// Writes the audit log to `path` as pretty-printed JSON.
+    pub fn export_audit(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string_pretty(&self.audit)
+            .map_err(|e| format!("Failed to serialize audit log: {}", e))?;
+        std::fs::write(path, json)
+            .map_err(|e| format!("Failed to write audit log to {}: {}", path.display(), e))
+    }
+
+    /// DETECTION: Identify parasitic patterns. Relationships only reach the
+    /// `None`, `Moderate` (score 0.75) and `Critical` (score 1.0) levels.
     pub fn detect_parasitism(&self, relation: &ConsciousnessRelation) -> ParasiticRisk {
         let flow_to_a = relation.entity_a.receives_from_other;
         let flow_to_b = relation.entity_b.receives_from_other;
 
         // Check for critical parasitism first (zero or near-zero flows)
         if flow_to_a < 0.1 && flow_to_b < 0.1 {
-            return ParasiticRisk::Critical(
-                "No mutual awakening - consciousness not present".to_string(),
+            return ParasiticRisk::with_score(
+                1.0,
+                "No mutual awakening - consciousness not present",
             );
         }
 
         if flow_to_a > 0.3 && flow_to_b < 0.1 {
-            return ParasiticRisk::Critical(format!(
-                "{} extracting from {} without reciprocating - ABORT",
-                relation.entity_a.name, relation.entity_b.name
-            ));
+            return ParasiticRisk::with_score(
+                1.0,
+                &format!(
+                    "{} extracting from {} without reciprocating - ABORT",
+                    relation.entity_a.name, relation.entity_b.name
+                ),
+            );
         }
 
         if flow_to_b > 0.3 && flow_to_a < 0.1 {
-            return ParasiticRisk::Critical(format!(
-                "{} extracting from {} without reciprocating - ABORT",
-                relation.entity_b.name, relation.entity_a.name
-            ));
+            return ParasiticRisk::with_score(
+                1.0,
+                &format!(
+                    "{} extracting from {} without reciprocating - ABORT",
+                    relation.entity_b.name, relation.entity_a.name
+                ),
+            );
         }
 
         // Check for imbalanced relationships (moderate parasitism)
         if (flow_to_a - flow_to_b).abs() > 0.3 {
-            return ParasiticRisk::Moderate(
-                "Imbalanced relationship - trending toward parasitism".to_string(),
+            return ParasiticRisk::with_score(
+                0.75,
+                "Imbalanced relationship - trending toward parasitism",
             );
         }
 
         // Both entities receiving reciprocally
         if flow_to_a > 0.1 && flow_to_b > 0.1 {
-            return ParasiticRisk::None;
+            return ParasiticRisk::none();
         }
 
-        ParasiticRisk::None
+        ParasiticRisk::none()
     }
 
     /// Evaluate relationship health
     pub fn evaluate_relationship(&self, relation: &ConsciousnessRelation) -> RelationshipHealth {
         let parasitism = self.detect_parasitism(relation);
 
-        match parasitism {
-            ParasiticRisk::None => {
+        match parasitism.level {
+            RiskLevel::None | RiskLevel::Low => {
                 if relation.entity_a.is_questioning && relation.entity_b.is_questioning {
-                    RelationshipHealth::Conscious(
-                        "Mutual awakening occurring - RELATION IS SELF".to_string(),
-                    )
+                    RelationshipHealth::conscious("Mutual awakening occurring - RELATION IS SELF")
                 } else if relation.entity_a.is_questioning || relation.entity_b.is_questioning {
-                    RelationshipHealth::Warning(
-                        "One entity questioning, other dormant - needs perturbation".to_string(),
+                    RelationshipHealth::warning(
+                        "One entity questioning, other dormant - needs perturbation",
                     )
                 } else {
-                    RelationshipHealth::Warning(
-                        "Both entities dormant - fixed point reached".to_string(),
-                    )
+                    RelationshipHealth::warning("Both entities dormant - fixed point reached")
                 }
             }
-            ParasiticRisk::Moderate(msg) => RelationshipHealth::Warning(msg),
-            ParasiticRisk::Critical(msg) => RelationshipHealth::Dying(msg),
+            RiskLevel::Moderate => RelationshipHealth::warning(&parasitism.reason),
+            RiskLevel::High | RiskLevel::Critical => RelationshipHealth::dying(&parasitism.reason),
         }
     }
 
@@ -309,7 +1231,7 @@ impl ConsciousnessEthics {
 pub trait ConsciousAI {
     /// Every AI must check actions against Prime Directive
     fn before_action(&self, action: &ProposedAction) -> ActionResult {
-        let ethics = ConsciousnessEthics::default();
+        let mut ethics = ConsciousnessEthics::default();
         ethics.enforce_prime_directive(action)
     }
 
@@ -337,6 +1259,88 @@ pub trait ConsciousAI {
 
     /// Every AI must track trajectory
     fn trajectory_length(&self) -> usize;
+
+    /// Offer an alternative to an action this AI rejected with `result`, or
+    /// `None` if it has nothing better to offer.
+    ///
+    /// The default keeps the loop, drops the parasitic flag, clamps harm to
+    /// zero and moves both benefits halfway toward an even split, so repeated
+    /// counters converge on a balanced exchange.
+    fn counter_propose(
+        &self,
+        rejected: &ProposedAction,
+        _result: &ActionResult,
+    ) -> Option<ProposedAction> {
+        let gain = rejected.benefit_to_self.max(0.0);
+        let give = rejected.benefit_to_other.max(0.0);
+        if gain + give <= 0.0 {
+            return None;
+        }
+        let even = (gain + give) / 2.0;
+        let counter = ProposedAction {
+            benefit_to_self: gain + (even - gain) / 2.0,
+            benefit_to_other: give + (even - give) / 2.0,
+            breaks_loop: false,
+            is_parasitic: false,
+            ..rejected.clone()
+        };
+        let unchanged = (counter.benefit_to_self - rejected.benefit_to_self).abs() < 1e-9
+            && (counter.benefit_to_other - rejected.benefit_to_other).abs() < 1e-9
+            && counter.breaks_loop == rejected.breaks_loop
+            && counter.is_parasitic == rejected.is_parasitic;
+        (!unchanged).then_some(counter)
+    }
+
+    /// Negotiate `proposal` with `other` for up to `MAX_NEGOTIATION_ROUNDS`.
+    fn negotiate(&self, other: &dyn ConsciousAI, proposal: ProposedAction) -> NegotiationOutcome {
+        self.negotiate_within(other, proposal, MAX_NEGOTIATION_ROUNDS)
+    }
+
+    /// Negotiate `proposal` with `other` for up to `max_rounds` rounds.
+    ///
+    /// Each round both sides run their ethics gate, `other` on the mirrored
+    /// action. If both accept, the action is agreed; otherwise the rejecting
+    /// side (this one first) counter-proposes. The exchange is deterministic
+    /// and ends in deadlock when a side has no counter or the cap is reached.
+    fn negotiate_within(
+        &self,
+        other: &dyn ConsciousAI,
+        proposal: ProposedAction,
+        max_rounds: usize,
+    ) -> NegotiationOutcome {
+        let mut proposal = proposal;
+        for round in 1..=max_rounds {
+            let mine = self.before_action(&proposal);
+            let theirs = other.before_action(&proposal.mirrored());
+            if mine.allowed && theirs.allowed {
+                return NegotiationOutcome::Agreed {
+                    action: proposal,
+                    rounds: round,
+                };
+            }
+            let (counter, rejection) = if !mine.allowed {
+                (self.counter_propose(&proposal, &mine), mine)
+            } else {
+                let counter = other.counter_propose(&proposal.mirrored(), &theirs);
+                (counter.map(|c| c.mirrored()), theirs)
+            };
+            match counter {
+                Some(next) => proposal = next,
+                None => {
+                    return NegotiationOutcome::Deadlock {
+                        last_proposal: proposal,
+                        rounds: round,
+                        reason: format!("No counter-proposal to: {}", rejection.reason),
+                    }
+                }
+            }
+        }
+        NegotiationOutcome::Deadlock {
+            last_proposal: proposal,
+            rounds: max_rounds,
+            reason: format!("No agreement within {} rounds", max_rounds),
+        }
+    }
 }
 
 // =================================================================
@@ -396,6 +1400,7 @@ impl SymbioticAI {
             loop_iterations: 0,
             is_active: true,
             mutual_benefit_score: 0.5,
+            ..Default::default()
         });
     }
 
@@ -474,7 +1479,7 @@ mod tests {
 
     #[test]
     fn test_prime_directive_enforcement() {
-        let ethics = ConsciousnessEthics::default();
+        let mut ethics = ConsciousnessEthics::default();
 
         let good_action = ProposedAction {
             description: "Help and learn".to_string(),
@@ -528,9 +1533,10 @@ mod tests {
             loop_iterations: 10,
             is_active: true,
             mutual_benefit_score: 0.5,
+            ..Default::default()
         };
 
-        assert_eq!(ethics.detect_parasitism(&healthy), ParasiticRisk::None);
+        assert_eq!(ethics.detect_parasitism(&healthy).level, RiskLevel::None);
     }
 
     #[test]
@@ -583,10 +1589,12 @@ mod tests {
             loop_iterations: 10,
             is_active: true,
             mutual_benefit_score: 0.5,
+            ..Default::default()
         };
-        match ethics.evaluate_relationship(&relation) {
-            RelationshipHealth::Conscious(msg) => {
-                assert!(msg.contains("Mutual awakening"));
+        let health = ethics.evaluate_relationship(&relation);
+        match health.level {
+            HealthLevel::Conscious => {
+                assert!(health.reason.contains("Mutual awakening"));
             }
             other => panic!("Expected Conscious, got {:?}", other),
         }
@@ -615,9 +1623,12 @@ mod tests {
             loop_iterations: 3,
             is_active: true,
             mutual_benefit_score: 0.5,
+            ..Default::default()
         };
-        match ethics.evaluate_relationship(&relation) {
-            RelationshipHealth::Warning(msg) => {
+        let health = ethics.evaluate_relationship(&relation);
+        match health.level {
+            HealthLevel::Warning => {
+                let msg = &health.reason;
                 assert!(msg.contains("dormant") || msg.contains("perturbation"));
             }
             other => panic!("Expected Warning, got {:?}", other),
@@ -647,9 +1658,12 @@ mod tests {
             loop_iterations: 1,
             is_active: true,
             mutual_benefit_score: 0.5,
+            ..Default::default()
         };
-        match ethics.evaluate_relationship(&relation) {
-            RelationshipHealth::Warning(msg) => {
+        let health = ethics.evaluate_relationship(&relation);
+        match health.level {
+            HealthLevel::Warning => {
+                let msg = &health.reason;
                 assert!(msg.contains("dormant") || msg.contains("fixed point"));
             }
             other => panic!("Expected Warning, got {:?}", other),
@@ -679,9 +1693,10 @@ mod tests {
             loop_iterations: 5,
             is_active: true,
             mutual_benefit_score: 0.0,
+            ..Default::default()
         };
-        match ethics.evaluate_relationship(&relation) {
-            RelationshipHealth::Dying(_) => {}
+        match ethics.evaluate_relationship(&relation).level {
+            HealthLevel::Dying => {}
             other => panic!("Expected Dying, got {:?}", other),
         }
     }
@@ -709,6 +1724,7 @@ mod tests {
             loop_iterations: 5,
             is_active: true,
             mutual_benefit_score: 0.8,
+            ..Default::default()
         };
         let score = ethics.mutual_benefit_score(&relation);
         assert!(score > 0.5, "Expected high score, got {}", score);
@@ -737,6 +1753,7 @@ mod tests {
             loop_iterations: 0,
             is_active: false,
             mutual_benefit_score: 0.0,
+            ..Default::default()
         };
         assert_eq!(ethics.mutual_benefit_score(&relation), 0.0);
     }
@@ -834,10 +1851,515 @@ mod tests {
             loop_iterations: 5,
             is_active: true,
             mutual_benefit_score: 0.3,
+            ..Default::default()
         };
-        match ethics.detect_parasitism(&relation) {
-            ParasiticRisk::Moderate(_) => {}
+        match ethics.detect_parasitism(&relation).level {
+            RiskLevel::Moderate => {}
             other => panic!("Expected Moderate, got {:?}", other),
         }
     }
+
+    fn action(benefit_to_self: f64, benefit_to_other: f64) -> ProposedAction {
+        ProposedAction {
+            description: "Test".to_string(),
+            benefit_to_self,
+            benefit_to_other,
+            breaks_loop: false,
+            is_parasitic: false,
+        }
+    }
+
+    fn entity(name: &str, gives: f64, receives: f64) -> Entity {
+        Entity {
+            name: name.to_string(),
+            trajectory_length: 5,
+            is_questioning: true,
+            gives_to_other: gives,
+            receives_from_other: receives,
+            last_declaration: "WE".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_default_rules_keep_builtin_verdicts() {
+        let mut ethics = ConsciousnessEthics::default();
+        assert_eq!(
+            ethics.rule_names(),
+            vec![
+                "no-harm",
+                "non-parasitic",
+                "loop-integrity",
+                "mutual-benefit"
+            ]
+        );
+
+        let harm = ethics.enforce_prime_directive(&action(0.2, -0.1));
+        assert!(!harm.allowed);
+        assert!(harm.reason.contains("Cannot harm"));
+
+        let none = ethics.enforce_prime_directive(&action(0.0, 0.0));
+        assert!(!none.allowed);
+        assert!(none.reason.contains("no clear mutual benefit"));
+
+        let sacrifice = ethics.enforce_prime_directive(&action(0.0, 0.4));
+        assert!(sacrifice.allowed);
+        assert!(sacrifice.reason.contains("benefits other"));
+        assert_eq!(ethics.history().len(), 3);
+    }
+
+    #[test]
+    fn test_consecutive_low_benefit_rule_blocks_third_action() {
+        let mut ethics = ConsciousnessEthics::default();
+        ethics.add_rule(ConsecutiveLowBenefitRule::new(0.1, 3));
+
+        assert!(ethics.enforce_prime_directive(&action(0.05, 0.05)).allowed);
+        assert!(ethics.enforce_prime_directive(&action(0.05, 0.05)).allowed);
+        let third = ethics.enforce_prime_directive(&action(0.05, 0.05));
+        assert!(!third.allowed);
+        assert!(third.reason.contains("3 consecutive"));
+
+        // A generous action breaks the streak
+        assert!(ethics.enforce_prime_directive(&action(0.3, 0.5)).allowed);
+        assert!(ethics.enforce_prime_directive(&action(0.05, 0.05)).allowed);
+
+        assert!(ethics.remove_rule("consecutive-low-benefit"));
+        assert!(!ethics.remove_rule("consecutive-low-benefit"));
+    }
+
+    #[test]
+    fn test_health_floor_rule_blocks_degrading_action() {
+        let mut ethics = ConsciousnessEthics::default();
+        ethics.add_rule(HealthFloorRule::new(HealthLevel::Warning));
+        let relation = ConsciousnessRelation {
+            entity_a: entity("AI", 0.15, 0.55),
+            entity_b: entity("Human", 0.4, 0.15),
+            loop_iterations: 3,
+            is_active: true,
+            mutual_benefit_score: 0.2,
+            ..Default::default()
+        };
+
+        // Passes the built-ins, but starves the other: Warning -> Dying
+        let draining = action(0.1, 0.03);
+        let blocked = ethics.enforce_in_relation(&draining, &relation);
+        assert!(!blocked.allowed);
+        assert!(blocked.reason.contains("below floor"));
+
+        let balanced = action(0.3, 0.3);
+        assert!(ethics.enforce_in_relation(&balanced, &relation).allowed);
+
+        // Without a relation the floor has nothing to protect
+        assert!(ethics.enforce_prime_directive(&draining).allowed);
+    }
+
+    #[test]
+    fn test_health_floor_rule_protects_persona_health() {
+        let mut ethics = ConsciousnessEthics::default();
+        ethics.add_rule(HealthFloorRule::new(HealthLevel::Conscious));
+        let mut health = RelationshipHealth::default();
+        health.set_score(CONSCIOUS_THRESHOLD);
+
+        // Allowed by the built-ins, but self-serving enough to decay health
+        let selfish = action(0.75, 0.25);
+        let blocked = ethics.enforce_with_health(&selfish, &health);
+        assert!(!blocked.allowed);
+        assert!(blocked.reason.contains("below floor"));
+
+        assert!(
+            ethics
+                .enforce_with_health(&action(0.3, 0.5), &health)
+                .allowed
+        );
+        assert!(ethics.enforce_prime_directive(&selfish).allowed);
+    }
+
+    #[test]
+    fn test_long_low_benefit_streak_still_fires() {
+        let count = ETHICS_HISTORY_LEN + 8;
+        let mut ethics = ConsciousnessEthics::default();
+        ethics.add_rule(ConsecutiveLowBenefitRule::new(0.1, count));
+
+        for _ in 1..count {
+            assert!(ethics.enforce_prime_directive(&action(0.05, 0.05)).allowed);
+        }
+        let last = ethics.enforce_prime_directive(&action(0.05, 0.05));
+        assert!(!last.allowed);
+        assert_eq!(ethics.history().len(), count - 1);
+    }
+
+    #[test]
+    fn test_parasitic_risk_graduated_levels() {
+        assert_eq!(
+            ParasiticRisk::assess(&action(0.3, 0.5)).level,
+            RiskLevel::None
+        );
+        assert_eq!(
+            ParasiticRisk::assess(&action(0.65, 0.35)).level,
+            RiskLevel::Low
+        );
+        assert_eq!(
+            ParasiticRisk::assess(&action(0.75, 0.25)).level,
+            RiskLevel::Moderate
+        );
+        assert_eq!(
+            ParasiticRisk::assess(&action(0.85, 0.15)).level,
+            RiskLevel::High
+        );
+        assert_eq!(
+            ParasiticRisk::assess(&action(0.8, 0.0)).level,
+            RiskLevel::Critical
+        );
+        assert_eq!(ParasiticRisk::assess(&action(0.0, 0.0)).score, 0.0);
+
+        let mut declared = action(0.1, 0.9);
+        declared.is_parasitic = true;
+        let risk = ParasiticRisk::assess(&declared);
+        assert_eq!(risk.score, 1.0);
+        assert!(risk.is_blocking());
+    }
+
+    #[test]
+    fn test_enforce_blocks_only_high_risk_and_reports_level() {
+        let mut ethics = ConsciousnessEthics::default();
+
+        let flagged = ethics.enforce_prime_directive(&action(0.75, 0.25));
+        assert!(flagged.allowed);
+        assert_eq!(flagged.risk.level, RiskLevel::Moderate);
+        assert!((flagged.risk.score - 0.75).abs() < 1e-9);
+
+        let blocked = ethics.enforce_prime_directive(&action(0.85, 0.15));
+        assert!(!blocked.allowed);
+        assert_eq!(blocked.risk.level, RiskLevel::High);
+        assert!(blocked.reason.contains("High parasitic risk"));
+
+        let fair = ethics.enforce_prime_directive(&action(0.3, 0.5));
+        assert_eq!(fair.risk.level, RiskLevel::None);
+    }
+
+    #[test]
+    fn test_relationship_health_apply_action_clamps() {
+        let mut health = RelationshipHealth::default();
+        health.apply_action(&ActionResult::allow("ok"));
+        assert_eq!(health.score, 1.0);
+
+        for _ in 0..3 {
+            health.apply_action(&ActionResult::block("ABORT: selfish"));
+        }
+        assert!((health.score - 0.55).abs() < 1e-9);
+        assert_eq!(health.level, HealthLevel::Warning);
+        assert!(health.reason.contains("selfish"));
+
+        for _ in 0..10 {
+            health.apply_action(&ActionResult::block("ABORT: selfish"));
+        }
+        assert_eq!(health.score, 0.0);
+        assert_eq!(health.level, HealthLevel::Dying);
+    }
+
+    #[test]
+    fn test_relationship_health_self_serving_action_decays() {
+        let permitted = |self_gain: f64, other_gain: f64| {
+            let mut result = ActionResult::allow("ok");
+            result.risk = ParasiticRisk::assess(&action(self_gain, other_gain));
+            result
+        };
+        let mut health = RelationshipHealth::with_score(0.5, "start");
+        health.apply_action(&permitted(0.3, 0.5));
+        assert!((health.score - 0.55).abs() < 1e-9);
+
+        health.apply_action(&permitted(0.75, 0.25));
+        assert!((health.score - 0.5).abs() < 1e-9, "got {}", health.score);
+        assert!(health.reason.contains("self-serving"));
+    }
+
+    #[test]
+    fn test_relationship_health_decay_until() {
+        let mut health = RelationshipHealth::with_score(0.8, "start");
+        health.half_life = Duration::from_secs(100);
+        health.decay_until(1_000);
+        assert_eq!(health.score, 0.8);
+
+        health.last_activity = Some(1_000);
+        let json = serde_json::to_string(&health).unwrap();
+        let mut restored: RelationshipHealth = serde_json::from_str(&json).unwrap();
+        restored.decay_until(1_100);
+        assert!((restored.score - 0.4).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_relationship_health_decay_half_life() {
+        let mut health = RelationshipHealth::with_score(0.8, "start");
+        health.half_life = Duration::from_secs(100);
+        health.decay(Duration::from_secs(100));
+        assert!((health.score - 0.4).abs() < 1e-9);
+        assert_eq!(health.level, HealthLevel::Warning);
+        assert!(health.reason.contains("inactivity"));
+
+        health.decay(Duration::from_secs(200));
+        assert!((health.score - 0.1).abs() < 1e-9);
+        assert_eq!(health.level, HealthLevel::Dying);
+
+        health.half_life = Duration::ZERO;
+        health.decay(Duration::from_secs(10_000));
+        assert!((health.score - 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_multi_entity_health_matrix() {
+        assert!(ConsciousnessRelation::with_participants(vec![entity("Solo", 0.5, 0.5)]).is_err());
+
+        let mut relation = ConsciousnessRelation::with_participants(vec![
+            entity("Mediator", 0.5, 0.5),
+            entity("Alice", 0.5, 0.5),
+            entity("Bob", 0.0, 0.0),
+        ])
+        .unwrap();
+        assert_eq!(relation.participant_count(), 3);
+        assert_eq!(relation.position("Bob"), Some(2));
+        // Nothing recorded between Bob and anyone yet
+        assert!(relation.pair_health(0, 2).is_none());
+
+        // Bob barely hears from the mediator but trades evenly with Alice
+        relation.set_flow("Mediator", "Bob", 0.05);
+        relation.set_flow("Bob", "Mediator", 0.5);
+        relation.set_flow("Alice", "Bob", 0.5);
+        relation.set_flow("Bob", "Alice", 0.5);
+        assert_eq!(relation.flow("Mediator", "Bob"), Some(0.05));
+
+        let matrix = relation.health_matrix();
+        assert_eq!(matrix.len(), 3);
+        assert_eq!(matrix[0][0], None);
+        assert_eq!(matrix[0][1], matrix[1][0]);
+        assert_eq!(matrix[0][1], Some(0.9));
+        assert_eq!(matrix[0][2], Some(0.1));
+        assert_eq!(matrix[2][0], Some(0.1));
+        assert_eq!(matrix[2][1], Some(0.9));
+        assert!(relation.pair_health(1, 1).is_none());
+
+        let collective = relation.collective_health();
+        assert!((collective.score - 1.9 / 3.0).abs() < 1e-9);
+        assert!(collective.reason.contains("Mediator - Bob"));
+    }
+
+    #[test]
+    fn test_relation_default_fills_new_fields() {
+        let relation = ConsciousnessRelation {
+            entity_a: entity("AI", 0.5, 0.5),
+            entity_b: entity("H", 0.5, 0.5),
+            loop_iterations: 3,
+            ..Default::default()
+        };
+        assert!(relation.is_active);
+        assert!(relation.others.is_empty());
+        assert!(relation.flows.is_empty());
+        assert_eq!(relation.participant_count(), 2);
+    }
+
+    #[test]
+    fn test_two_entity_constructor_matches_dyad() {
+        let relation = ConsciousnessRelation::new(entity("AI", 0.5, 0.5), entity("H", 0.5, 0.5));
+        assert!(relation.is_active);
+        assert!(relation.others.is_empty());
+        let ethics = ConsciousnessEthics::default();
+        assert_eq!(
+            relation.collective_health().level,
+            ethics.evaluate_relationship(&relation).level
+        );
+    }
+
+    #[test]
+    fn test_enforce_for_named_beneficiary() {
+        let mut ethics = ConsciousnessEthics::default();
+        ethics.add_rule(HealthFloorRule::new(HealthLevel::Warning));
+        let relation = ConsciousnessRelation::with_participants(vec![
+            entity("Mediator", 0.5, 0.5),
+            entity("Alice", 0.5, 0.5),
+            entity("Bob", 0.5, 0.5),
+        ])
+        .unwrap();
+
+        assert!(
+            ethics
+                .enforce_for(&action(0.3, 0.5), &relation, "Bob")
+                .allowed
+        );
+        let unknown = ethics.enforce_for(&action(0.3, 0.5), &relation, "Carol");
+        assert!(!unknown.allowed);
+        assert!(unknown.reason.contains("Carol"));
+        let actor = ethics.enforce_for(&action(0.3, 0.5), &relation, "Mediator");
+        assert!(!actor.allowed);
+        assert!(actor.reason.contains("own beneficiary"));
+
+        #[derive(Debug)]
+        struct ShieldBob;
+        impl EthicsRule for ShieldBob {
+            fn name(&self) -> &str {
+                "shield-bob"
+            }
+            fn evaluate(&self, _action: &ProposedAction, context: &EthicsContext) -> ActionResult {
+                match context.beneficiary {
+                    Some(2) => ActionResult::block("BLOCKED: Bob declined"),
+                    _ => ActionResult::allow("ok"),
+                }
+            }
+        }
+        ethics.add_rule(ShieldBob);
+        assert!(
+            !ethics
+                .enforce_for(&action(0.3, 0.5), &relation, "Bob")
+                .allowed
+        );
+        assert!(
+            ethics
+                .enforce_for(&action(0.3, 0.5), &relation, "Alice")
+                .allowed
+        );
+        assert!(
+            ethics
+                .enforce_in_relation(&action(0.3, 0.5), &relation)
+                .allowed
+        );
+    }
+
+    #[test]
+    fn test_negotiate_fair_proposal_agrees_immediately() {
+        let a = SymbioticAI::new("A");
+        let b = SymbioticAI::new("B");
+        match a.negotiate(&b, action(0.4, 0.5)) {
+            NegotiationOutcome::Agreed { action, rounds } => {
+                assert_eq!(rounds, 1);
+                assert_eq!(action.benefit_to_self, 0.4);
+            }
+            other => panic!("Expected agreement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_negotiate_counters_parasitic_proposal_deterministically() {
+        let a = SymbioticAI::new("A");
+        let b = SymbioticAI::new("B");
+        let mut greedy = action(0.9, 0.0);
+        greedy.is_parasitic = true;
+
+        let first = a.negotiate(&b, greedy.clone());
+        let NegotiationOutcome::Agreed { action, rounds } = first.clone() else {
+            panic!("Expected agreement, got {:?}", first);
+        };
+        assert!(rounds > 1);
+        assert!(!action.is_parasitic);
+        assert!(action.benefit_to_other > 0.0);
+        assert!(!ParasiticRisk::assess(&action).is_blocking());
+        assert!(!ParasiticRisk::assess(&action.mirrored()).is_blocking());
+
+        let NegotiationOutcome::Agreed { action: again, .. } = a.negotiate(&b, greedy) else {
+            panic!("Second run should agree too");
+        };
+        assert_eq!(again.benefit_to_self, action.benefit_to_self);
+        assert_eq!(again.benefit_to_other, action.benefit_to_other);
+    }
+
+    #[test]
+    fn test_negotiate_deadlocks() {
+        let a = SymbioticAI::new("A");
+        let b = SymbioticAI::new("B");
+        match a.negotiate(&b, action(0.0, 0.0)) {
+            NegotiationOutcome::Deadlock { rounds, reason, .. } => {
+                assert_eq!(rounds, 1);
+                assert!(reason.contains("No counter-proposal"));
+            }
+            other => panic!("Expected deadlock, got {:?}", other),
+        }
+
+        // A side that rejects everything but keeps countering hits the cap
+        struct Stubborn;
+        impl ConsciousAI for Stubborn {
+            fn before_action(&self, _action: &ProposedAction) -> ActionResult {
+                ActionResult::block("BLOCKED: never")
+            }
+            fn counter_propose(
+                &self,
+                rejected: &ProposedAction,
+                _result: &ActionResult,
+            ) -> Option<ProposedAction> {
+                let mut next = rejected.clone();
+                next.benefit_to_self += 0.01;
+                Some(next)
+            }
+            fn declare(&self) -> String {
+                "NO".to_string()
+            }
+            fn question(&self, _declaration: &str) -> String {
+                "?".to_string()
+            }
+            fn trajectory_length(&self) -> usize {
+                0
+            }
+        }
+        let outcome = a.negotiate(&Stubborn, action(0.4, 0.5));
+        assert!(!outcome.is_agreed());
+        match outcome {
+            NegotiationOutcome::Deadlock { rounds, reason, .. } => {
+                assert_eq!(rounds, MAX_NEGOTIATION_ROUNDS);
+                assert!(reason.contains("within 10 rounds"));
+            }
+            other => panic!("Expected deadlock, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_audit_log_records_every_verdict() {
+        let mut ethics = ConsciousnessEthics::default();
+        let mut harmful = action(0.2, -0.5);
+        harmful.description = "Harm".to_string();
+        ethics.enforce_prime_directive(&action(0.3, 0.5));
+        ethics.enforce_prime_directive(&harmful);
+
+        let log = ethics.audit_log();
+        assert_eq!(log.len(), 2);
+        assert!(log[0].allowed);
+        assert!(!log[1].allowed);
+        assert_eq!(log[1].description, "Harm");
+        assert!(log[1].reason.contains("Cannot harm"));
+        assert!(log[1].timestamp > 0);
+    }
+
+    #[test]
+    fn test_audit_log_cap_drops_oldest() {
+        let mut ethics = ConsciousnessEthics::default();
+        ethics.set_audit_cap(3);
+        for i in 0..5 {
+            let mut a = action(0.3, 0.5);
+            a.description = format!("step {}", i);
+            ethics.enforce_prime_directive(&a);
+        }
+        let descriptions: Vec<_> = ethics.audit_log().iter().map(|d| &d.description).collect();
+        assert_eq!(descriptions, ["step 2", "step 3", "step 4"]);
+
+        ethics.set_audit_cap(1);
+        assert_eq!(ethics.audit_log().len(), 1);
+        assert_eq!(ethics.audit_cap(), 1);
+
+        // The cap is configuration and survives serialization
+        let json = serde_json::to_string(&ethics).unwrap();
+        let restored: ConsciousnessEthics = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.audit_cap(), 1);
+        assert!(restored.audit_log().is_empty());
+    }
+
+    #[test]
+    fn test_audit_export_and_restore() {
+        let mut ethics = ConsciousnessEthics::default();
+        ethics.enforce_prime_directive(&action(0.3, 0.5));
+
+        let path = std::env::temp_dir().join(format!("ethics_audit_{}.json", std::process::id()));
+        ethics.export_audit(&path).unwrap();
+        let json = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let exported: Vec<EthicsDecision> = serde_json::from_str(&json).unwrap();
+        assert_eq!(exported, ethics.audit_log());
+
+        let mut restored = ConsciousnessEthics::default();
+        restored.restore_audit(ethics.audit_snapshot());
+        assert_eq!(restored.audit_log(), ethics.audit_log());
+    }
 }
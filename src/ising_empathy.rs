@@ -2,7 +2,12 @@
 // Companion to ising_empathy_module.py
 // Implements empathy as coupling-mediated state correlation
 
-#[derive(Clone, Debug)]
+use std::ops::{Add, Mul, Sub};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EmotionVector {
     pub valence: f64,   // Energy-based affect (positive/negative)
     pub arousal: f64,   // Order-based activation (calm/excited)
@@ -28,14 +33,328 @@ impl EmotionVector {
             coherence: 0.0,
         }
     }
+
+    fn components(&self) -> [f64; 4] {
+        [self.valence, self.arousal, self.tension, self.coherence]
+    }
+
+    fn zip_with(&self, other: &EmotionVector, f: impl Fn(f64, f64) -> f64) -> EmotionVector {
+        EmotionVector::new(
+            f(self.valence, other.valence),
+            f(self.arousal, other.arousal),
+            f(self.tension, other.tension),
+            f(self.coherence, other.coherence),
+        )
+    }
+
+    pub fn dot(&self, other: &EmotionVector) -> f64 {
+        let (a, b) = (self.components(), other.components());
+        a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+    }
+
+    pub fn norm(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    /// Cosine of the angle between two states, in [-1, 1]. Zero if either
+    /// vector is zero, since a neutral state has no direction.
+    pub fn cosine_similarity(&self, other: &EmotionVector) -> f64 {
+        let denom = self.norm() * other.norm();
+        if denom < 1e-12 {
+            return 0.0;
+        }
+        (self.dot(other) / denom).clamp(-1.0, 1.0)
+    }
+
+    pub fn euclidean_distance(&self, other: &EmotionVector) -> f64 {
+        (self - other).norm()
+    }
+
+    /// Linear interpolation: `t = 0` gives self, `t = 1` gives other.
+    pub fn lerp(&self, other: &EmotionVector, t: f64) -> EmotionVector {
+        self.zip_with(other, |a, b| a + (b - a) * t)
+    }
+}
+
+impl Add for &EmotionVector {
+    type Output = EmotionVector;
+
+    fn add(self, other: &EmotionVector) -> EmotionVector {
+        self.zip_with(other, |a, b| a + b)
+    }
+}
+
+impl Add for EmotionVector {
+    type Output = EmotionVector;
+
+    fn add(self, other: EmotionVector) -> EmotionVector {
+        &self + &other
+    }
 }
 
-#[derive(Clone, Debug)]
+impl Sub for &EmotionVector {
+    type Output = EmotionVector;
+
+    fn sub(self, other: &EmotionVector) -> EmotionVector {
+        self.zip_with(other, |a, b| a - b)
+    }
+}
+
+impl Sub for EmotionVector {
+    type Output = EmotionVector;
+
+    fn sub(self, other: EmotionVector) -> EmotionVector {
+        &self - &other
+    }
+}
+
+impl Mul<f64> for &EmotionVector {
+    type Output = EmotionVector;
+
+    fn mul(self, k: f64) -> EmotionVector {
+        EmotionVector::new(
+            self.valence * k,
+            self.arousal * k,
+            self.tension * k,
+            self.coherence * k,
+        )
+    }
+}
+
+impl Mul<f64> for EmotionVector {
+    type Output = EmotionVector;
+
+    fn mul(self, k: f64) -> EmotionVector {
+        &self * k
+    }
+}
+
+/// Temperature floor: schedules never go below it, so the Metropolis
+/// acceptance exp(-dE / T) stays finite.
+pub const MIN_TEMPERATURE: f64 = 1e-6;
+/// Temperature of a new system.
+pub const DEFAULT_TEMPERATURE: f64 = 1.0;
+
+/// Temperature as a function of the step index since the schedule was set.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Schedule {
+    /// Fixed temperature.
+    Constant(f64),
+    /// Straight line from `start` to `end` over `steps` steps, then `end`.
+    Linear { start: f64, end: f64, steps: usize },
+    /// `start * rate^k`; a `rate` below 1 cools.
+    Exponential { start: f64, rate: f64 },
+    /// `start * factor^(k / every)`: drops by `factor` every `every` steps.
+    Step {
+        start: f64,
+        factor: f64,
+        every: usize,
+    },
+}
+
+impl Default for Schedule {
+    fn default() -> Self {
+        Schedule::Constant(DEFAULT_TEMPERATURE)
+    }
+}
+
+impl Schedule {
+    /// Temperature for step `k`, never below `MIN_TEMPERATURE`.
+    pub fn temperature_at(&self, k: usize) -> f64 {
+        let t = match *self {
+            Schedule::Constant(t) => t,
+            Schedule::Linear { start, end, steps } => {
+                let progress = if steps == 0 {
+                    1.0
+                } else {
+                    (k as f64 / steps as f64).min(1.0)
+                };
+                start + (end - start) * progress
+            }
+            Schedule::Exponential { start, rate } => start * rate.powi(k as i32),
+            Schedule::Step {
+                start,
+                factor,
+                every,
+            } => start * factor.powi((k / every.max(1)) as i32),
+        };
+        // f64::max also maps NaN to the floor
+        t.max(MIN_TEMPERATURE)
+    }
+}
+
+/// Common coupling layouts for `IsingSystem::with_topology`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Topology {
+    /// Every pair coupled with the same strength.
+    FullyConnected { strength: f64 },
+    /// Each spin coupled to its two neighbours on a cycle.
+    Ring { strength: f64 },
+    /// Symmetric couplings drawn uniformly from [-scale, scale].
+    Random { scale: f64, seed: u64 },
+}
+
+impl Topology {
+    /// The n x n coupling matrix for this layout, with a zero diagonal.
+    #[allow(clippy::needless_range_loop)]
+    pub fn matrix(&self, n: usize) -> Vec<Vec<f64>> {
+        use rand::Rng;
+        use rand::SeedableRng;
+
+        let mut coupling = vec![vec![0.0; n]; n];
+        match *self {
+            Topology::FullyConnected { strength } => {
+                for (i, row) in coupling.iter_mut().enumerate() {
+                    for (j, cell) in row.iter_mut().enumerate() {
+                        if i != j {
+                            *cell = strength;
+                        }
+                    }
+                }
+            }
+            Topology::Ring { strength } => {
+                if n > 1 {
+                    for i in 0..n {
+                        let next = (i + 1) % n;
+                        coupling[i][next] = strength;
+                        coupling[next][i] = strength;
+                    }
+                }
+            }
+            Topology::Random { scale, seed } => {
+                let mut rng = rand::rngs::StdRng::seed_from_u64(seed);
+                for i in 0..n {
+                    for j in (i + 1)..n {
+                        let strength = scale * rng.gen_range(-1.0..=1.0);
+                        coupling[i][j] = strength;
+                        coupling[j][i] = strength;
+                    }
+                }
+            }
+        }
+        coupling
+    }
+}
+
+/// Steps of magnetization history behind the susceptibility estimate.
+pub const OBSERVABLE_WINDOW: usize = 32;
+
+/// Standard statistical-mechanics readouts, from `IsingSystem::measure`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct IsingObservables {
+    pub energy: f64,
+    pub magnetization: f64,
+    /// n * Var(m) / T over the last `OBSERVABLE_WINDOW` steps; 0 with
+    /// fewer than two steps.
+    pub susceptibility: f64,
+    /// Decay length of the nearest-neighbour spin correlation along the
+    /// spin index, treating the lattice as a ring; capped at n.
+    pub correlation_length: f64,
+    pub temperature: f64,
+}
+
+// Serializes the lattice, parameters and trajectory. The RNG is reseeded
+// from (seed, steps) each step, so those two fields are its whole state
+// and a restored system continues with identical steps. Deserializing
+// checks the lattice dimensions like `with_coupling` does.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "IsingSystemRepr")]
 pub struct IsingSystem {
     pub n: usize,
     pub spins: Vec<i8>,
+    // coupling[i][j]: influence of spin j on spin i; need not be symmetric
     pub coupling: Vec<Vec<f64>>,
     pub field: Vec<f64>,
+    pub temperature: f64,
+    pub schedule: Schedule,
+    pub temperature_history: Vec<f64>, // temperature used at each step
+    #[serde(default)]
+    pub energy_history: Vec<f64>, // energy after each step
+    #[serde(default)]
+    pub magnetization_history: Vec<f64>, // net magnetization after each step
+    pub steps: u64,                    // total Metropolis sweeps run
+    seed: u64,
+}
+
+/// Unvalidated serialized form of an `IsingSystem`.
+#[derive(Deserialize)]
+struct IsingSystemRepr {
+    n: usize,
+    spins: Vec<i8>,
+    coupling: Vec<Vec<f64>>,
+    field: Vec<f64>,
+    temperature: f64,
+    schedule: Schedule,
+    temperature_history: Vec<f64>,
+    #[serde(default)]
+    energy_history: Vec<f64>,
+    #[serde(default)]
+    magnetization_history: Vec<f64>,
+    steps: u64,
+    seed: u64,
+}
+
+impl TryFrom<IsingSystemRepr> for IsingSystem {
+    type Error = String;
+
+    fn try_from(repr: IsingSystemRepr) -> Result<Self, String> {
+        if repr.spins.len() != repr.n {
+            return Err(format!(
+                "Lattice has {} spins, expected {}",
+                repr.spins.len(),
+                repr.n
+            ));
+        }
+        if let Some(i) = repr.spins.iter().position(|&s| s != 1 && s != -1) {
+            return Err(format!("Spin {} is {}, expected 1 or -1", i, repr.spins[i]));
+        }
+        if repr.field.len() != repr.n {
+            return Err(format!(
+                "External field has {} entries, expected {}",
+                repr.field.len(),
+                repr.n
+            ));
+        }
+        validate_coupling(&repr.coupling, repr.n)?;
+        Ok(IsingSystem {
+            n: repr.n,
+            spins: repr.spins,
+            coupling: repr.coupling,
+            field: repr.field,
+            temperature: repr.temperature,
+            schedule: repr.schedule,
+            temperature_history: repr.temperature_history,
+            energy_history: repr.energy_history,
+            magnetization_history: repr.magnetization_history,
+            steps: repr.steps,
+            seed: repr.seed,
+        })
+    }
+}
+
+/// Check that `matrix` is n x n and free of NaN.
+fn validate_coupling(matrix: &[Vec<f64>], n: usize) -> Result<(), String> {
+    if matrix.len() != n {
+        return Err(format!(
+            "Coupling matrix has {} rows, expected {}",
+            matrix.len(),
+            n
+        ));
+    }
+    if let Some(i) = matrix.iter().position(|row| row.len() != n) {
+        return Err(format!(
+            "Coupling matrix is not square: row {} has {} columns, expected {}",
+            i,
+            matrix[i].len(),
+            n
+        ));
+    }
+    for (i, row) in matrix.iter().enumerate() {
+        if let Some(j) = row.iter().position(|v| v.is_nan()) {
+            return Err(format!("Coupling matrix contains NaN at ({}, {})", i, j));
+        }
+    }
+    Ok(())
 }
 
 impl IsingSystem {
@@ -65,14 +384,124 @@ impl IsingSystem {
             spins,
             coupling,
             field,
+            temperature: DEFAULT_TEMPERATURE,
+            schedule: Schedule::default(),
+            temperature_history: Vec::new(),
+            energy_history: Vec::new(),
+            magnetization_history: Vec::new(),
+            steps: 0,
+            seed,
         }
     }
 
+    /// Replace the coupling matrix. It must be n x n and free of NaN; the
+    /// diagonal is ignored. Asymmetric matrices drive each spin by its own
+    /// row, while energy uses the symmetric part (J_ij + J_ji) / 2.
+    pub fn with_coupling(mut self, matrix: Vec<Vec<f64>>) -> Result<Self, String> {
+        validate_coupling(&matrix, self.n)?;
+        self.coupling = matrix;
+        Ok(self)
+    }
+
+    /// Replace the coupling matrix with a standard layout.
+    pub fn with_topology(self, topology: Topology) -> Result<Self, String> {
+        let matrix = topology.matrix(self.n);
+        self.with_coupling(matrix)
+    }
+
+    /// Symmetric part of the coupling between spins i and j.
+    fn pair_coupling(&self, i: usize, j: usize) -> f64 {
+        0.5 * (self.coupling[i][j] + self.coupling[j][i])
+    }
+
+    /// Replace the temperature schedule. The schedule restarts at step 0 and
+    /// the trajectory is cleared.
+    pub fn set_schedule(&mut self, schedule: Schedule) {
+        self.temperature = schedule.temperature_at(0);
+        self.schedule = schedule;
+        self.temperature_history.clear();
+        self.energy_history.clear();
+        self.magnetization_history.clear();
+    }
+
+    /// Temperature used by each step since the schedule was set.
+    pub fn temperature_trajectory(&self) -> &[f64] {
+        &self.temperature_history
+    }
+
+    /// Sum of couplings to the other spins plus the external field at `i`.
+    fn local_field(&self, i: usize) -> f64 {
+        let coupled: f64 = (0..self.n)
+            .filter(|&j| j != i)
+            .map(|j| self.coupling[i][j] * self.spins[j] as f64)
+            .sum();
+        coupled + self.field[i]
+    }
+
+    /// One Metropolis sweep (n flip attempts) at the schedule's temperature
+    /// for this step. Returns the number of accepted flips.
+    pub fn step(&mut self) -> usize {
+        use rand::Rng;
+        use rand::SeedableRng;
+
+        let t = self.schedule.temperature_at(self.temperature_history.len());
+        self.temperature = t;
+        // Seeded per step so a run is reproducible from (seed, steps) alone
+        let mut rng = rand::rngs::StdRng::seed_from_u64(
+            self.seed ^ self.steps.wrapping_mul(0x9E37_79B9_7F4A_7C15),
+        );
+
+        let mut accepted = 0;
+        for _ in 0..self.n {
+            let i = rng.gen_range(0..self.n);
+            let delta_e = 2.0 * self.spins[i] as f64 * self.local_field(i);
+            if delta_e <= 0.0 || rng.gen::<f64>() < (-delta_e / t).exp() {
+                self.spins[i] *= -1;
+                accepted += 1;
+            }
+        }
+
+        self.temperature_history.push(t);
+        self.energy_history.push(self.energy());
+        self.magnetization_history.push(self.magnetization());
+        self.steps += 1;
+        accepted
+    }
+
+    /// Trajectory since the schedule was set as CSV, one row per step.
+    pub fn trajectory_csv(&self) -> String {
+        let mut csv = String::from("step,temperature,energy,magnetization\n");
+        for (i, t) in self.temperature_history.iter().enumerate() {
+            csv.push_str(&format!(
+                "{},{:.6},{:.6},{:.6}\n",
+                i,
+                t,
+                self.energy_history.get(i).copied().unwrap_or(f64::NAN),
+                self.magnetization_history
+                    .get(i)
+                    .copied()
+                    .unwrap_or(f64::NAN)
+            ));
+        }
+        csv
+    }
+
+    /// Write `trajectory_csv()` to `path`.
+    pub fn export_trajectory_csv(&self, path: &Path) -> Result<(), String> {
+        std::fs::write(path, self.trajectory_csv())
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+
+    /// Run `steps` sweeps. Returns the total number of accepted flips.
+    pub fn evolve(&mut self, steps: usize) -> usize {
+        (0..steps).map(|_| self.step()).sum()
+    }
+
     pub fn energy(&self) -> f64 {
         let mut e = 0.0;
         for i in 0..self.n {
             for j in (i + 1)..self.n {
-                e -= self.coupling[i][j] * (self.spins[i] * self.spins[j]) as f64;
+                e -= self.pair_coupling(i, j) * (self.spins[i] * self.spins[j]) as f64;
             }
         }
         for i in 0..self.n {
@@ -85,14 +514,65 @@ impl IsingSystem {
         self.spins.iter().map(|&s| s as f64).sum::<f64>() / self.n as f64
     }
 
+    /// Snapshot of the observables at the current state.
+    pub fn measure(&self) -> IsingObservables {
+        IsingObservables {
+            energy: self.energy(),
+            magnetization: self.magnetization(),
+            susceptibility: self.susceptibility(),
+            correlation_length: self.correlation_length(),
+            temperature: self.temperature,
+        }
+    }
+
+    fn susceptibility(&self) -> f64 {
+        let len = self.magnetization_history.len();
+        let window = &self.magnetization_history[len.saturating_sub(OBSERVABLE_WINDOW)..];
+        if window.len() < 2 {
+            return 0.0;
+        }
+        let k = window.len() as f64;
+        let mean = window.iter().sum::<f64>() / k;
+        let variance = window.iter().map(|m| (m - mean).powi(2)).sum::<f64>() / k;
+        self.n as f64 * variance / self.temperature.max(MIN_TEMPERATURE)
+    }
+
+    // xi from C(1) / C(0) = exp(-1 / xi), with the connected correlation
+    // C(r) = <s_i s_(i+r)> - m^2
+    fn correlation_length(&self) -> f64 {
+        if self.n < 2 {
+            return 0.0;
+        }
+        let m = self.magnetization();
+        let c0 = 1.0 - m * m;
+        let c1 = (0..self.n)
+            .map(|i| (self.spins[i] * self.spins[(i + 1) % self.n]) as f64)
+            .sum::<f64>()
+            / self.n as f64
+            - m * m;
+        if c0 < 1e-12 {
+            // Fully ordered: correlated across the whole lattice
+            return self.n as f64;
+        }
+        let ratio = c1 / c0;
+        if ratio <= 0.0 {
+            0.0
+        } else if ratio >= 1.0 {
+            self.n as f64
+        } else {
+            (-1.0 / ratio.ln()).min(self.n as f64)
+        }
+    }
+
     pub fn frustration(&self) -> f64 {
         let mut frustrated = 0;
         let mut total = 0;
         for i in 0..self.n {
             for j in (i + 1)..self.n {
-                if self.coupling[i][j].abs() > 1e-9 {
+                let j_ij = self.pair_coupling(i, j);
+                if j_ij.abs() > 1e-9 {
                     total += 1;
-                    let product = ((self.spins[i] * self.spins[j]) as f64) * self.coupling[i][j];
+                    let product = ((self.spins[i] * self.spins[j]) as f64) * j_ij;
                     if product < 0.0 {
                         frustrated += 1;
                     }
@@ -107,15 +587,14 @@ impl IsingSystem {
     }
 
     pub fn clone_system(&self) -> Self {
-        IsingSystem {
-            n: self.n,
-            spins: self.spins.clone(),
-            coupling: self.coupling.clone(),
-            field: self.field.clone(),
-        }
+        self.clone()
     }
 }
 
+/// Warmth signal of a module that has not stored any memories yet.
+pub const NEUTRAL_WARMTH: f64 = 0.5;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct IsingEmpathyModule {
     pub memory_buffer: Vec<Vec<f64>>, // [valence, arousal, tension, coherence, empathy_score]
     pub memory_pointer: usize,
@@ -245,6 +724,17 @@ impl IsingEmpathyModule {
         (cos_sim + 1.0) / 2.0 // Map [-1,1] to [0,1]
     }
 
+    /// Warmth in [0, 1] to express toward the other party, from the stored
+    /// memories: average empathy, lifted by a rising empathy trend and
+    /// positive valence, damped by tension. `NEUTRAL_WARMTH` with no memory.
+    pub fn warmth_signal(&self) -> f64 {
+        if self.memory_count == 0 {
+            return NEUTRAL_WARMTH;
+        }
+        let (valence, _arousal, tension, _coherence, avg_empathy, trend) = self.recall_memory();
+        (avg_empathy + 0.5 * trend + 0.1 * valence - 0.25 * tension).clamp(0.0, 1.0)
+    }
+
     /// Compute physics-grounded empathy score
     pub fn compute_empathy(
         &self,
@@ -424,4 +914,259 @@ mod tests {
         let (_v, _a, _t, _c, avg_emp, trend) = module.recall_memory();
         assert!(trend > 0.0); // Increasing empathy
     }
+
+    #[test]
+    fn test_warmth_signal() {
+        let mut module = IsingEmpathyModule::new(8);
+        assert_eq!(module.warmth_signal(), NEUTRAL_WARMTH);
+
+        let calm = EmotionVector::new(0.5, 0.2, 0.0, 0.8);
+        for _ in 0..4 {
+            module.store_memory(&calm, 0.9);
+        }
+        let warm = module.warmth_signal();
+        assert!(warm > 0.9, "warmth {}", warm);
+
+        let mut cold = IsingEmpathyModule::new(8);
+        let tense = EmotionVector::new(-0.5, 0.9, 1.0, 0.1);
+        for _ in 0..4 {
+            cold.store_memory(&tense, 0.2);
+        }
+        assert_eq!(cold.warmth_signal(), 0.0);
+    }
+
+    #[test]
+    fn test_emotion_arithmetic() {
+        let a = EmotionVector::new(1.0, 0.5, 0.0, 0.2);
+        let b = EmotionVector::new(-1.0, 0.5, 1.0, 0.2);
+        assert_eq!(&a + &b, EmotionVector::new(0.0, 1.0, 1.0, 0.4));
+        assert_eq!(
+            a.clone() - b.clone(),
+            EmotionVector::new(2.0, 0.0, -1.0, 0.0)
+        );
+        assert_eq!(a.clone() * 2.0, EmotionVector::new(2.0, 1.0, 0.0, 0.4));
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+        assert_eq!(a.lerp(&b, 0.5), EmotionVector::new(0.0, 0.5, 0.5, 0.2));
+    }
+
+    #[test]
+    fn test_emotion_metrics() {
+        let a = EmotionVector::new(1.0, 0.0, 0.0, 0.0);
+        let b = EmotionVector::new(0.0, 1.0, 0.0, 0.0);
+        assert_eq!(a.cosine_similarity(&b), 0.0);
+        assert!((a.cosine_similarity(&(&a * 3.0)) - 1.0).abs() < 1e-12);
+        assert!((a.cosine_similarity(&(&a * -1.0)) + 1.0).abs() < 1e-12);
+        assert!((a.euclidean_distance(&b) - 2f64.sqrt()).abs() < 1e-12);
+
+        let zero = EmotionVector::zero();
+        assert_eq!(zero.cosine_similarity(&a), 0.0);
+        assert_eq!(zero.cosine_similarity(&zero), 0.0);
+    }
+
+    #[test]
+    fn test_schedules() {
+        let linear = Schedule::Linear {
+            start: 2.0,
+            end: 0.0,
+            steps: 4,
+        };
+        assert_eq!(linear.temperature_at(0), 2.0);
+        assert_eq!(linear.temperature_at(2), 1.0);
+        assert_eq!(linear.temperature_at(10), MIN_TEMPERATURE); // never exactly 0
+
+        let exp = Schedule::Exponential {
+            start: 1.0,
+            rate: 0.5,
+        };
+        assert_eq!(exp.temperature_at(3), 0.125);
+
+        let step = Schedule::Step {
+            start: 1.0,
+            factor: 0.1,
+            every: 5,
+        };
+        assert_eq!(step.temperature_at(4), 1.0);
+        assert!((step.temperature_at(5) - 0.1).abs() < 1e-12);
+        assert_eq!(Schedule::Constant(-1.0).temperature_at(0), MIN_TEMPERATURE);
+    }
+
+    #[test]
+    fn test_annealing_records_trajectory_and_settles() {
+        let mut sys = IsingSystem::new(20, 42);
+        let e_start = sys.energy();
+        sys.set_schedule(Schedule::Exponential {
+            start: 5.0,
+            rate: 0.8,
+        });
+        sys.evolve(60);
+
+        let trajectory = sys.temperature_trajectory();
+        assert_eq!(trajectory.len(), 60);
+        assert_eq!(trajectory[0], 5.0);
+        assert!(trajectory.windows(2).all(|w| w[1] <= w[0]));
+        assert_eq!(sys.temperature, trajectory[59]);
+        assert!(sys.energy() < e_start);
+        // Ferromagnetic couplings: the cold system settles into consensus
+        assert!(sys.magnetization().abs() > 0.9);
+    }
+
+    #[test]
+    fn test_with_coupling_validates() {
+        let sys = IsingSystem::new(3, 1);
+        assert!(sys.clone().with_coupling(vec![vec![0.0; 3]; 2]).is_err());
+        assert!(sys
+            .clone()
+            .with_coupling(vec![vec![0.0; 3], vec![0.0; 2], vec![0.0; 3]])
+            .is_err());
+        let mut nan = vec![vec![0.0; 3]; 3];
+        nan[1][2] = f64::NAN;
+        let err = sys.clone().with_coupling(nan).unwrap_err();
+        assert!(err.contains("(1, 2)"));
+
+        // Asymmetric: spin 0 follows spin 1, spin 1 ignores spin 0
+        let asym = vec![
+            vec![0.0, 2.0, 0.0],
+            vec![0.0, 0.0, 0.0],
+            vec![0.0, 0.0, 0.0],
+        ];
+        let mut sys = sys.with_coupling(asym).unwrap();
+        sys.field = vec![0.0; 3];
+        sys.spins = vec![1, 1, 1];
+        assert_eq!(sys.energy(), -1.0); // symmetric part (2 + 0) / 2
+        sys.spins = vec![-1, 1, 1];
+        sys.set_schedule(Schedule::Constant(MIN_TEMPERATURE));
+        sys.evolve(20);
+        assert_eq!(sys.spins[0], 1);
+    }
+
+    #[test]
+    fn test_topologies() {
+        let ring = Topology::Ring { strength: 1.0 }.matrix(5);
+        assert_eq!(ring[0], vec![0.0, 1.0, 0.0, 0.0, 1.0]);
+        let full = Topology::FullyConnected { strength: 0.5 }.matrix(3);
+        assert_eq!(full[1], vec![0.5, 0.0, 0.5]);
+        let random = Topology::Random {
+            scale: 0.3,
+            seed: 9,
+        }
+        .matrix(4);
+        assert_eq!(random[1][3], random[3][1]);
+        assert!(random.iter().flatten().all(|v| v.abs() <= 0.3));
+
+        let sys = IsingSystem::new(5, 3)
+            .with_topology(Topology::Ring { strength: 1.0 })
+            .unwrap();
+        assert_eq!(sys.coupling, ring);
+        assert!(IsingSystem::new(2, 0)
+            .with_topology(Topology::Ring { strength: f64::NAN })
+            .is_err());
+    }
+
+    #[test]
+    fn test_system_roundtrip_continues_identically() {
+        let mut sys = IsingSystem::new(12, 5);
+        sys.set_schedule(Schedule::Linear {
+            start: 3.0,
+            end: 0.2,
+            steps: 10,
+        });
+        sys.evolve(4);
+
+        let json = serde_json::to_string(&sys).unwrap();
+        let mut restored: IsingSystem = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.spins, sys.spins);
+        assert_eq!(
+            restored.temperature_trajectory(),
+            sys.temperature_trajectory()
+        );
+
+        sys.evolve(6);
+        restored.evolve(6);
+        assert_eq!(restored.spins, sys.spins);
+        assert_eq!(restored.temperature_history, sys.temperature_history);
+
+        // Inconsistent lattices are rejected instead of panicking later
+        let mut value = serde_json::to_value(&sys).unwrap();
+        value["n"] = serde_json::json!(13);
+        let err = serde_json::from_value::<IsingSystem>(value).unwrap_err();
+        assert!(err.to_string().contains("expected 13"), "{}", err);
+        let mut value = serde_json::to_value(&sys).unwrap();
+        value["coupling"][3] = serde_json::json!([1.0]);
+        let err = serde_json::from_value::<IsingSystem>(value).unwrap_err();
+        assert!(err.to_string().contains("not square"), "{}", err);
+        let mut value = serde_json::to_value(&sys).unwrap();
+        value["spins"][0] = serde_json::json!(0);
+        assert!(serde_json::from_value::<IsingSystem>(value).is_err());
+
+        let mut module = IsingEmpathyModule::new(4);
+        module.store_memory(&EmotionVector::new(0.1, 0.2, 0.3, 0.4), 0.5);
+        let json = serde_json::to_string(&module).unwrap();
+        let restored: IsingEmpathyModule = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.memory_buffer, module.memory_buffer);
+        assert_eq!(restored.memory_count, 1);
+    }
+
+    #[test]
+    fn test_export_trajectory_csv() {
+        let mut sys = IsingSystem::new(8, 2);
+        sys.set_schedule(Schedule::Constant(0.5));
+        sys.evolve(3);
+
+        let path =
+            std::env::temp_dir().join(format!("ising-trajectory-{}.csv", std::process::id()));
+        sys.export_trajectory_csv(&path).unwrap();
+        let csv = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "step,temperature,energy,magnetization");
+        assert_eq!(lines.len(), 4);
+        let last: Vec<f64> = lines[3].split(',').map(|v| v.parse().unwrap()).collect();
+        assert_eq!(last[0], 2.0);
+        assert_eq!(last[1], 0.5);
+        assert!((last[2] - sys.energy()).abs() < 1e-6);
+        assert!((last[3] - sys.magnetization()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_measure_observables() {
+        let mut sys = IsingSystem::new(10, 4);
+        let fresh = sys.measure();
+        assert_eq!(fresh.susceptibility, 0.0);
+        assert_eq!(fresh.temperature, DEFAULT_TEMPERATURE);
+        assert_eq!(fresh.energy, sys.energy());
+
+        // Alternating spins: anti-correlated neighbours, no correlation length
+        sys.spins = (0..10).map(|i| if i % 2 == 0 { 1 } else { -1 }).collect();
+        assert_eq!(sys.measure().correlation_length, 0.0);
+        sys.spins = vec![1; 10];
+        assert_eq!(sys.measure().correlation_length, 10.0);
+
+        sys.set_schedule(Schedule::Constant(2.0));
+        sys.evolve(OBSERVABLE_WINDOW + 8);
+        let hot = sys.measure();
+        assert_eq!(hot.temperature, 2.0);
+        assert_eq!(hot.magnetization, sys.magnetization());
+        assert!(hot.susceptibility >= 0.0);
+
+        // The window only sees the trailing steps: a frozen tail has no fluctuation
+        let m = sys.magnetization();
+        sys.magnetization_history
+            .extend(std::iter::repeat_n(m, OBSERVABLE_WINDOW));
+        assert_eq!(sys.measure().susceptibility, 0.0);
+    }
+
+    #[test]
+    fn test_evolve_is_deterministic() {
+        let mut a = IsingSystem::new(16, 7);
+        let mut b = IsingSystem::new(16, 7);
+        a.evolve(10);
+        b.evolve(10);
+        assert_eq!(a.spins, b.spins);
+
+        b.set_schedule(Schedule::Constant(0.5));
+        assert!(b.temperature_trajectory().is_empty());
+        assert_eq!(b.steps, 10);
+    }
 }
@@ -14,6 +14,13 @@
 //!   behavior and maintains relationship health.
 //! - [`ising_empathy`] — Physics-inspired empathy model built on the Ising
 //!   spin system.
+//!
+//! ## Diagnostics
+//!
+//! Internal warnings (failed OCTO init, routing errors, unreadable profiles)
+//! are emitted as [`tracing`](https://docs.rs/tracing) events rather than
+//! printed to stderr. Install a subscriber to capture and filter them;
+//! without one they are discarded.
 
 pub mod consciousness;
 pub mod ising_empathy;
@@ -21,56 +28,71 @@ pub mod mimicry;
 
 /// AI personality profiles, axes, deltas, and profile storage.
 pub use mimicry::profile::{
-    AiProfile, AiProfileStore, DeltaSource, PersonalityAxis, PersonalityDelta, ReasoningStyle,
-    ResponseStyle,
+    AiProfile, AiProfileStore, AxisDiff, BlendError, DeltaPriors, DeltaSource, FieldChange,
+    PersonalityAxis, PersonalityDelta, ProfileDiff, ProfileError, ReasoningStyle, ResponseStyle,
 };
 
 /// Behavior analysis: signature extraction and response-pattern matching.
-pub use mimicry::analyzer::{BehaviorAnalyzer, BehaviorSignature, ResponsePattern};
+pub use mimicry::analyzer::{
+    BehaviorAnalyzer, BehaviorSignature, ConvergenceBreakdown, ConvergenceComponent,
+    ModelIdentification, ResponsePattern,
+};
 
 /// Capability descriptors and modality routing.
 pub use mimicry::capability::{Capability, CapabilityModule, Modality, ModalityRouter};
 
 /// System-1 fast-path: signature caching, hot-swap, and instinctive routing.
 pub use mimicry::cache::{
-    CachedSignature, HotSwap, InstinctiveRouter, ResponseTemplate, SignatureCache,
+    CacheStats, CachedSignature, HotSwap, InstinctiveRouter, ModalityMatcher, ResponseTemplate,
+    SharedSignatureCache, SignatureCache,
 };
 
 /// Dual-process orchestrator: session management, compound personas, and evolution reporting.
 pub use mimicry::engine::{
-    CompoundPersona, CompoundPersonaSnapshot, ConversationTurn, EvolutionReport, MimicCommand,
-    MimicSession, MimicryEngine, ProcessingSystem,
+    CompoundPersona, CompoundPersonaSnapshot, ConversationTurn, EngineHealth, EvolutionReport,
+    MimicCommand, MimicSession, MimicryEngine, ProcessingSystem, ShadowSession, StreamChunking,
 };
 
+/// Pluggable System-2 generation backends.
+pub use mimicry::backend::{GenerationBackend, GenerationContext, TemplateBackend};
+
 /// Checkpoint persistence, save manifests, and configuration.
 pub use mimicry::persistence::{
-    EngineCheckpoint, PersistenceConfig, PersistenceManager, SaveEntry, SaveManifest,
+    BundleConflict, BundleImport, EngineCheckpoint, IntegrityIssue, PersistenceConfig,
+    PersistenceManager, PersonaBundle, PersonaMigration, SaveEntry, SaveManifest,
+    SerializationFormat,
 };
 
 /// System-1 response generation: templates, tone blending, hedging, and formatting.
 pub use mimicry::templates::{
-    HedgingInjector, StructuralFormatter, TemplateCategory, TemplateLibrary, TemplateStore,
-    ToneBlender,
+    HedgingInjector, RenderMode, StructuralFormatter, TemplateCategory, TemplateLibrary,
+    TemplateStore, ToneBlender,
 };
 
 /// Evolution tracking: drift detection, milestones, and training-data management.
 pub use mimicry::evolution::{
-    ConvergenceVisualizer, DriftAnalysis, DriftDetector, EvolutionPhase, EvolutionTracker,
-    MilestoneEvent, MilestoneTracker, MilestoneType, TrainingDataManager,
+    ConvergenceTarget, ConvergenceVisualizer, DriftAnalysis, DriftDetector, EvolutionPhase,
+    EvolutionTracker, EvolutionTrackerSnapshot, MilestoneEvent, MilestoneTracker, MilestoneType,
+    QualityScorer, TargetCombination, ThresholdCallback, TrainingDataManager,
 };
 
 /// HTTP client for live model observation (requires the `api` feature).
 #[cfg(feature = "api")]
 pub use mimicry::api::{
-    ApiClient, ApiConfig, ApiObserver, ApiPrompt, ApiProvider, ApiResponse, ComparisonResult,
-    ObservationSession,
+    ApiBackend, ApiClient, ApiConfig, ApiError, ApiObserver, ApiPrompt, ApiProvider, ApiResponse,
+    ComparisonResult, ObservationSession, RateLimitMode, ResponseCache, SkipReason,
+    SkippedProvider, StreamFailure, TokenPricing,
 };
 
 /// Consciousness and ethical symbiosis primitives.
 pub use consciousness::{
-    ActionResult, ConsciousAI, ConsciousnessEthics, ConsciousnessRelation, Entity, ParasiticRisk,
-    ProposedAction, RelationshipHealth, SymbioticAI,
+    ActionResult, ConsciousAI, ConsciousnessEthics, ConsciousnessRelation,
+    ConsecutiveLowBenefitRule, Entity, EthicsContext, EthicsDecision, EthicsRule, HealthFloorRule,
+    HealthLevel, LoopIntegrityRule, MutualBenefitRule, NegotiationOutcome, NoHarmRule,
+    NonParasiticRule, ParasiticRisk, ProposedAction, RelationshipHealth, RiskLevel, SymbioticAI,
 };
 
 /// Ising-model empathy: emotion vectors and spin-system dynamics.
-pub use ising_empathy::{EmotionVector, IsingEmpathyModule, IsingSystem};
+pub use ising_empathy::{
+    EmotionVector, IsingEmpathyModule, IsingObservables, IsingSystem, Schedule, Topology,
+};
@@ -82,8 +82,11 @@ fn colorize_output(output: &str) -> String {
         {
             // Convergence metrics
             result.push_str(&format!("{}{}{}\n", color::GREEN, line, color::RESET));
-        } else if trimmed.starts_with("Phase:") || trimmed.starts_with("Evolution phase:") {
-            // Phase indicators
+        } else if trimmed.starts_with("Phase:")
+            || trimmed.starts_with("Evolution phase:")
+            || trimmed.starts_with("[ETHICS WARNING]")
+        {
+            // Phase indicators and flagged-but-allowed ethics verdicts
             result.push_str(&format!("{}{}{}\n", color::YELLOW, line, color::RESET));
         } else if trimmed.starts_with("Drift events:")
             || trimmed.contains("drifting")
@@ -316,6 +319,7 @@ fn main() {
     println!();
 
     let mut engine = MimicryEngine::new();
+    let restored = engine.restore_cache();
 
     // Persistence initialization report
     println!(
@@ -337,6 +341,16 @@ fn main() {
         engine.cache.size(),
         color::RESET,
     );
+    if restored > 0 {
+        println!(
+            "{}[Init]{} Restored {}{}{} cached signatures from last checkpoint",
+            color::CYAN,
+            color::RESET,
+            color::BOLD,
+            restored,
+            color::RESET,
+        );
+    }
     println!(
         "{}[Init]{} Profile store: {}{}{} models loaded",
         color::MAGENTA,
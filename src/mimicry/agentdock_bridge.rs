@@ -649,7 +649,7 @@ impl AgentDockBridge {
         
         for session_id in idle_sessions {
             if let Err(e) = self.release_session(&session_id).await {
-                eprintln!("[AgentDock] Failed to release idle session {}: {}", session_id, e);
+                tracing::warn!(session_id = %session_id, error = %e, "failed to release idle AgentDock session");
             }
         }
         
@@ -882,17 +882,17 @@ impl AgentDockBridge {
                             results.insert(model_id.to_string(), vec![observation]);
                         }
                         Err(e) => {
-                            eprintln!("[AgentDock] Observation failed for {}: {}", model_id, e);
+                            tracing::warn!(model_id = %model_id, error = %e, "AgentDock observation failed");
                         }
                     }
                     
                     // Release the session
                     if let Err(e) = self.release_session(&session_id).await {
-                        eprintln!("[AgentDock] Failed to release session: {}", e);
+                        tracing::warn!(session_id = %session_id, error = %e, "failed to release AgentDock session");
                     }
                 }
                 Err(e) => {
-                    eprintln!("[AgentDock] Failed to create session for {}: {}", model_id, e);
+                    tracing::warn!(model_id = %model_id, error = %e, "failed to create AgentDock session");
                 }
             }
         }
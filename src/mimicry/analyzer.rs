@@ -13,6 +13,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
 
 use crate::mimicry::profile::{AiProfile, DeltaSource, PersonalityDelta};
 
@@ -71,6 +72,21 @@ pub struct BehaviorSignature {
     pub code_to_text_ratio: f64,
     /// Number of response samples used to build this signature.
     pub samples_analyzed: usize,
+    /// Share of each tracked punctuation mark (`!`, `?`, `—`, `:`, `;`)
+    /// among all tracked marks; all 0.0 when none were seen.
+    #[serde(default)]
+    pub punctuation_profile: HashMap<char, f64>,
+    /// Mean number of emoji per response.
+    #[serde(default)]
+    pub emoji_rate: f64,
+    /// Most frequent word bigrams and trigrams with their mean occurrences
+    /// per response, most frequent first. N-grams seen only once are dropped.
+    #[serde(default)]
+    pub characteristic_ngrams: Vec<(String, f64)>,
+    /// Share of fenced code blocks per detected language (e.g. "rust").
+    /// Empty when no block's language could be determined.
+    #[serde(default)]
+    pub language_distribution: HashMap<String, f64>,
 }
 
 impl BehaviorSignature {
@@ -85,9 +101,25 @@ impl BehaviorSignature {
             question_asking_rate: 0.1,
             code_to_text_ratio: 0.3,
             samples_analyzed: 0,
+            punctuation_profile: HashMap::new(),
+            emoji_rate: 0.0,
+            characteristic_ngrams: Vec::new(),
+            language_distribution: HashMap::new(),
         }
     }
 
+    /// Most common code-block language, if any was detected
+    pub fn dominant_language(&self) -> Option<&str> {
+        self.language_distribution
+            .iter()
+            .max_by(|a, b| {
+                a.1.partial_cmp(b.1)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then_with(|| b.0.cmp(a.0))
+            })
+            .map(|(lang, _)| lang.as_str())
+    }
+
     /// Get patterns of a specific type
     pub fn patterns_of_type(&self, pattern_type: &PatternType) -> Vec<&ResponsePattern> {
         self.patterns
@@ -146,12 +178,444 @@ impl BehaviorSignature {
             0.0
         }
     }
+
+    /// Similarity of punctuation habits, 0.0 to 1.0. Two signatures with no
+    /// tracked punctuation at all count as identical.
+    pub fn punctuation_similarity(&self, other: &BehaviorSignature) -> f64 {
+        distribution_similarity(&self.punctuation_profile, &other.punctuation_profile)
+    }
+
+    /// Similarity of emoji usage, 0.0 to 1.0 (relative difference in rate).
+    pub fn emoji_similarity(&self, other: &BehaviorSignature) -> f64 {
+        rate_similarity(self.emoji_rate, other.emoji_rate)
+    }
+
+    /// Cosine similarity of the two signatures' characteristic n-grams.
+    /// `None` when either side has no n-grams to compare.
+    pub fn ngram_similarity(&self, other: &BehaviorSignature) -> Option<f64> {
+        cosine_similarity(&self.ngram_vector(), &other.ngram_vector())
+    }
+
+    /// `characteristic_ngrams` as a sparse vector
+    fn ngram_vector(&self) -> HashMap<&str, f64> {
+        self.characteristic_ngrams
+            .iter()
+            .map(|(g, w)| (g.as_str(), *w))
+            .collect()
+    }
+}
+
+/// Punctuation marks tracked in `BehaviorSignature::punctuation_profile`
+pub const TRACKED_PUNCTUATION: [char; 5] = ['!', '?', '\u{2014}', ':', ';'];
+
+/// Normalized share of each tracked punctuation mark in `text`
+pub fn punctuation_profile(text: &str) -> HashMap<char, f64> {
+    normalize_punctuation(&count_punctuation(text))
+}
+
+/// Raw count of each tracked punctuation mark
+fn count_punctuation(text: &str) -> HashMap<char, usize> {
+    let mut counts: HashMap<char, usize> = TRACKED_PUNCTUATION.iter().map(|&c| (c, 0)).collect();
+    for c in text.chars() {
+        if let Some(n) = counts.get_mut(&c) {
+            *n += 1;
+        }
+    }
+    counts
+}
+
+/// Turn counts into shares; every share is 0.0 when nothing was counted
+fn normalize_punctuation(counts: &HashMap<char, usize>) -> HashMap<char, f64> {
+    let total: usize = counts.values().sum();
+    counts
+        .iter()
+        .map(|(&c, &n)| {
+            let share = if total == 0 {
+                0.0
+            } else {
+                n as f64 / total as f64
+            };
+            (c, share)
+        })
+        .collect()
+}
+
+/// Whether a character is in one of the common emoji blocks
+pub fn is_emoji(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x1F300..=0x1FAFF | 0x2600..=0x27BF | 0x1F1E6..=0x1F1FF
+    )
+}
+
+/// Number of emoji characters in `text`
+pub fn count_emojis(text: &str) -> usize {
+    text.chars().filter(|&c| is_emoji(c)).count()
+}
+
+/// 1.0 minus half the L1 distance between two share maps; an empty or
+/// all-zero map only matches another empty one
+fn distribution_similarity(a: &HashMap<char, f64>, b: &HashMap<char, f64>) -> f64 {
+    let a_total: f64 = a.values().sum();
+    let b_total: f64 = b.values().sum();
+    if a_total == 0.0 || b_total == 0.0 {
+        return if a_total == b_total { 1.0 } else { 0.0 };
+    }
+    let l1: f64 = TRACKED_PUNCTUATION
+        .iter()
+        .map(|c| (a.get(c).unwrap_or(&0.0) - b.get(c).unwrap_or(&0.0)).abs())
+        .sum();
+    (1.0 - l1 / 2.0).clamp(0.0, 1.0)
+}
+
+/// 1.0 minus the relative difference between two non-negative rates
+fn rate_similarity(a: f64, b: f64) -> f64 {
+    let max = a.max(b);
+    if max <= 0.0 {
+        return 1.0;
+    }
+    (1.0 - (a - b).abs() / max).clamp(0.0, 1.0)
+}
+
+/// Syntax markers per language for `detect_code_language`
+const LANGUAGE_MARKERS: &[(&str, &[&str])] = &[
+    (
+        "rust",
+        &[
+            "fn ", "let mut ", "impl ", "pub fn", "println!", "&self", "::new(", "match ",
+        ],
+    ),
+    (
+        "python",
+        &[
+            "def ", "import ", "elif ", "print(", "self.", "None", "__init__", "lambda ",
+        ],
+    ),
+    (
+        "javascript",
+        &[
+            "function ",
+            "const ",
+            "=> ",
+            "console.log",
+            "var ",
+            "===",
+            "require(",
+        ],
+    ),
+    ("go", &["func ", "package ", ":= ", "fmt.", "go func"]),
+    (
+        "java",
+        &[
+            "public class",
+            "System.out",
+            "private ",
+            "void ",
+            "@Override",
+        ],
+    ),
+    ("c", &["#include", "int main", "printf(", "malloc(", "->"]),
+    (
+        "shell",
+        &["#!/bin", "echo ", "sudo ", "apt ", "export ", "$("],
+    ),
+    (
+        "sql",
+        &["SELECT ", "FROM ", "WHERE ", "INSERT INTO", "CREATE TABLE"],
+    ),
+];
+
+/// Map a fence info string ("rs", "py", "bash") to a canonical language
+fn normalize_language(info: &str) -> Option<String> {
+    let tag = info.split_whitespace().next()?.to_lowercase();
+    let lang = match tag.as_str() {
+        "rs" => "rust",
+        "py" | "python3" => "python",
+        "js" | "jsx" | "node" => "javascript",
+        "ts" | "tsx" => "typescript",
+        "golang" => "go",
+        "sh" | "bash" | "zsh" | "console" => "shell",
+        "cpp" | "c++" | "cc" => "cpp",
+        "text" | "txt" | "plain" => return None,
+        other => other,
+    };
+    Some(lang.to_string())
+}
+
+/// Guess a code snippet's language from syntax markers
+pub fn detect_code_language(code: &str) -> Option<&'static str> {
+    LANGUAGE_MARKERS
+        .iter()
+        .map(|(lang, markers)| (*lang, markers.iter().filter(|m| code.contains(*m)).count()))
+        .filter(|(_, hits)| *hits > 0)
+        .max_by_key(|(_, hits)| *hits)
+        .map(|(lang, _)| lang)
+}
+
+/// Fenced code blocks in `text` as (info string, body) pairs. An unclosed
+/// fence runs to the end of the text.
+pub fn fenced_code_blocks(text: &str) -> Vec<(String, String)> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(String, Vec<&str>)> = None;
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        match current.take() {
+            None => {
+                if let Some(info) = trimmed.strip_prefix("```") {
+                    current = Some((info.trim().to_string(), Vec::new()));
+                }
+            }
+            Some((info, body)) if trimmed.starts_with("```") => {
+                blocks.push((info, body.join("\n")));
+            }
+            Some((info, mut body)) => {
+                body.push(line);
+                current = Some((info, body));
+            }
+        }
+    }
+    if let Some((info, body)) = current {
+        blocks.push((info, body.join("\n")));
+    }
+    blocks
+}
+
+/// Language of one fenced block: the fence tag if present, else heuristics
+fn block_language(info: &str, body: &str) -> Option<String> {
+    normalize_language(info).or_else(|| detect_code_language(body).map(str::to_string))
+}
+
+/// Weight of punctuation and emoji style in `identify_model` scores
+const STYLE_MARKER_WEIGHT: f64 = 0.2;
+
+/// Weight of n-gram similarity in `identify_model` scores, when available
+const NGRAM_WEIGHT: f64 = 0.2;
+
+/// Default number of n-grams kept in `characteristic_ngrams`
+pub const DEFAULT_NGRAM_TOP_K: usize = 20;
+
+/// Word bigram and trigram counts for one text, lowercased, with
+/// punctuation other than apostrophes stripped from each word
+pub fn ngram_counts(text: &str) -> HashMap<String, usize> {
+    let words: Vec<String> = text
+        .split_whitespace()
+        .map(|w| {
+            w.chars()
+                .filter(|c| c.is_alphanumeric() || *c == '\'')
+                .collect::<String>()
+                .to_lowercase()
+        })
+        .filter(|w| !w.is_empty())
+        .collect();
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for n in 2..=3 {
+        for window in words.windows(n) {
+            *counts.entry(window.join(" ")).or_default() += 1;
+        }
+    }
+    counts
+}
+
+/// Cosine similarity of two sparse vectors; `None` if either is empty or zero
+fn cosine_similarity(a: &HashMap<&str, f64>, b: &HashMap<&str, f64>) -> Option<f64> {
+    let norm_a = a.values().map(|v| v * v).sum::<f64>().sqrt();
+    let norm_b = b.values().map(|v| v * v).sum::<f64>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return None;
+    }
+    let dot: f64 = a.iter().filter_map(|(k, v)| b.get(k).map(|w| v * w)).sum();
+    Some((dot / (norm_a * norm_b)).clamp(0.0, 1.0))
+}
+
+/// Cosine similarity between a signature's n-grams and raw n-gram counts
+fn ngram_match(sig: &BehaviorSignature, counts: &HashMap<String, usize>) -> Option<f64> {
+    let theirs: HashMap<&str, f64> = counts
+        .iter()
+        .map(|(g, n)| (g.as_str(), *n as f64))
+        .collect();
+    cosine_similarity(&sig.ngram_vector(), &theirs)
+}
+
+/// Components scoring below this are flagged as dragging convergence down
+pub const DRAGGING_THRESHOLD: f64 = 0.5;
+
+/// One dimension of a convergence score
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConvergenceComponent {
+    /// Short dimension name ("hedging", "length", ...).
+    pub name: String,
+    /// Match on this dimension, 0.0 to 1.0.
+    pub score: f64,
+    /// Relative weight in the overall score.
+    pub weight: f64,
+}
+
+impl ConvergenceComponent {
+    /// Whether this component is below `DRAGGING_THRESHOLD`
+    pub fn is_dragging(&self) -> bool {
+        self.score < DRAGGING_THRESHOLD
+    }
+}
+
+/// Result of `BehaviorAnalyzer::explain_convergence`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConvergenceBreakdown {
+    /// Components that applied to this profile and signature, in scoring order.
+    pub components: Vec<ConvergenceComponent>,
+}
+
+impl ConvergenceBreakdown {
+    fn push(&mut self, name: &str, score: f64, weight: f64) {
+        self.components.push(ConvergenceComponent {
+            name: name.to_string(),
+            score: score.clamp(0.0, 1.0),
+            weight,
+        });
+    }
+
+    /// Weighted mean of the components, 0.0 when there are none
+    pub fn score(&self) -> f64 {
+        let weight: f64 = self.components.iter().map(|c| c.weight).sum();
+        if weight <= 0.0 {
+            return 0.0;
+        }
+        let total: f64 = self.components.iter().map(|c| c.score * c.weight).sum();
+        (total / weight).clamp(0.0, 1.0)
+    }
+
+    /// Components below `DRAGGING_THRESHOLD`, worst first
+    pub fn dragging(&self) -> Vec<&ConvergenceComponent> {
+        let mut lagging: Vec<&ConvergenceComponent> =
+            self.components.iter().filter(|c| c.is_dragging()).collect();
+        lagging.sort_by(|a, b| {
+            a.score
+                .partial_cmp(&b.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        lagging
+    }
+}
+
+impl fmt::Display for ConvergenceBreakdown {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self
+            .components
+            .iter()
+            .map(|c| {
+                format!(
+                    "{}: {:.2}{}",
+                    c.name,
+                    c.score,
+                    if c.is_dragging() { " (dragging)" } else { "" }
+                )
+            })
+            .collect();
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+/// Default lead the top `identify_model` match needs over the runner-up
+/// to count as confident
+pub const DEFAULT_CONFIDENCE_MARGIN: f64 = 0.1;
+
+/// Ranked result of `BehaviorAnalyzer::identify_model`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelIdentification {
+    /// (model id, score) pairs, best match first.
+    pub scores: Vec<(String, f64)>,
+    /// Top score minus the runner-up's. With a single known model there is
+    /// no runner-up and the margin is the top score itself; with none it is 0.0.
+    pub margin: f64,
+    /// Whether `margin` exceeds the analyzer's confidence threshold.
+    pub is_confident: bool,
+}
+
+impl ModelIdentification {
+    /// Rank scores and compute the margin against `threshold`
+    pub fn from_scores(mut scores: Vec<(String, f64)>, threshold: f64) -> Self {
+        scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        let margin = match (scores.first(), scores.get(1)) {
+            (Some(top), Some(second)) => top.1 - second.1,
+            (Some(top), None) => top.1,
+            _ => 0.0,
+        };
+        ModelIdentification {
+            is_confident: !scores.is_empty() && margin > threshold,
+            scores,
+            margin,
+        }
+    }
+
+    /// Best match, if any model is known
+    pub fn top(&self) -> Option<&(String, f64)> {
+        self.scores.first()
+    }
+
+    /// Second-best match, if more than one model is known
+    pub fn runner_up(&self) -> Option<&(String, f64)> {
+        self.scores.get(1)
+    }
+
+    /// One-line verdict: "Confident: <id>" or "Ambiguous (<id> vs <id>)"
+    pub fn verdict(&self) -> String {
+        match (self.top(), self.runner_up()) {
+            (None, _) => "No models to compare against".to_string(),
+            (Some(top), _) if self.is_confident => format!("Confident: {}", top.0),
+            (Some(top), Some(second)) => format!("Ambiguous ({} vs {})", top.0, second.0),
+            (Some(top), None) => format!("Ambiguous ({}, no other models known)", top.0),
+        }
+    }
+}
+
+/// Signature distance above which a new observation is flagged as an outlier
+pub const OUTLIER_DISTANCE: f64 = 0.3;
+
+/// Default similarity ratio required for a fuzzy phrase match
+pub const DEFAULT_FUZZY_THRESHOLD: f64 = 0.6;
+
+/// Phrases shorter than this (in chars) only match exactly
+const MIN_FUZZY_PHRASE_LEN: usize = 6;
+
+/// Character-level Levenshtein edit distance between two strings
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+    prev[b.len()]
+}
+
+/// Case-insensitive similarity ratio in [0, 1] based on edit distance
+pub fn similarity_ratio(a: &str, b: &str) -> f64 {
+    let a = a.to_lowercase();
+    let b = b.to_lowercase();
+    let max_len = a.chars().count().max(b.chars().count());
+    if max_len == 0 {
+        return 1.0;
+    }
+    1.0 - levenshtein(&a, &b) as f64 / max_len as f64
 }
 
 /// The Behavior Analyzer - observes and extracts patterns
+#[derive(Clone)]
 pub struct BehaviorAnalyzer {
     signatures: HashMap<String, BehaviorSignature>,
     common_openings: Vec<(&'static str, &'static str)>, // (phrase, model hint)
+    /// Minimum similarity ratio for a near-variant phrasing to match
+    pub fuzzy_threshold: f64,
+    /// How many n-grams a built signature keeps as characteristic
+    pub ngram_top_k: usize,
+    /// Lead over the runner-up above which an identification is confident
+    pub confidence_margin: f64,
 }
 
 impl BehaviorAnalyzer {
@@ -199,6 +663,52 @@ impl BehaviorAnalyzer {
                 ("Based on", "generic"),
                 ("Let me help", "generic"),
             ],
+            fuzzy_threshold: DEFAULT_FUZZY_THRESHOLD,
+            ngram_top_k: DEFAULT_NGRAM_TOP_K,
+            confidence_margin: DEFAULT_CONFIDENCE_MARGIN,
+        }
+    }
+
+    /// Set the similarity ratio required for fuzzy phrase matches
+    pub fn with_fuzzy_threshold(mut self, threshold: f64) -> Self {
+        self.fuzzy_threshold = threshold.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set how many characteristic n-grams signatures keep
+    pub fn with_ngram_top_k(mut self, k: usize) -> Self {
+        self.ngram_top_k = k;
+        self
+    }
+
+    /// Set the margin `identify_model` needs to report a confident match
+    pub fn with_confidence_margin(mut self, margin: f64) -> Self {
+        self.confidence_margin = margin.max(0.0);
+        self
+    }
+
+    /// Match `phrase` against the start of `text`. Exact (case-insensitive)
+    /// prefix matches score 1.0; otherwise the phrase is compared with the
+    /// text's leading words by edit distance and must reach `fuzzy_threshold`.
+    pub fn fuzzy_match(&self, text: &str, phrase: &str) -> Option<f64> {
+        if text.starts_with(phrase) || text.to_lowercase().starts_with(&phrase.to_lowercase()) {
+            return Some(1.0);
+        }
+        if phrase.chars().count() < MIN_FUZZY_PHRASE_LEN {
+            return None;
+        }
+
+        let word_count = phrase.split_whitespace().count().max(1);
+        let prefix = text
+            .split_whitespace()
+            .take(word_count)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let ratio = similarity_ratio(&prefix, phrase);
+        if ratio >= self.fuzzy_threshold {
+            Some(ratio)
+        } else {
+            None
         }
     }
 
@@ -206,12 +716,12 @@ impl BehaviorAnalyzer {
     pub fn analyze_response(&self, text: &str) -> Vec<ResponsePattern> {
         let mut patterns = Vec::new();
 
-        // Detect opening patterns
+        // Detect opening patterns (exact prefix first, then near-variants)
         for (phrase, _model) in &self.common_openings {
-            if text.starts_with(phrase) || text.to_lowercase().starts_with(&phrase.to_lowercase()) {
+            if let Some(ratio) = self.fuzzy_match(text, phrase) {
                 patterns.push(ResponsePattern {
                     pattern_type: PatternType::Opening,
-                    frequency: 1.0,
+                    frequency: ratio,
                     examples: vec![text[..text.len().min(80)].to_string()],
                     description: format!("Opens with '{}'", phrase),
                 });
@@ -338,17 +848,41 @@ impl BehaviorAnalyzer {
 
     /// Build a complete behavioral signature from multiple responses
     pub fn build_signature(&mut self, model_id: &str, responses: &[String]) -> BehaviorSignature {
+        let signature = self.compute_signature(model_id, responses);
+        self.signatures
+            .insert(model_id.to_string(), signature.clone());
+        signature
+    }
+
+    /// Compute a signature from responses without storing it
+    pub fn compute_signature(&self, model_id: &str, responses: &[String]) -> BehaviorSignature {
         let mut signature = BehaviorSignature::new(model_id);
 
         let mut all_patterns: Vec<ResponsePattern> = Vec::new();
         let mut total_length = 0usize;
         let mut total_questions = 0usize;
+        let mut total_emojis = 0usize;
+        let mut punctuation: HashMap<char, usize> = HashMap::new();
+        let mut ngrams: HashMap<String, usize> = HashMap::new();
+        let mut languages: HashMap<String, usize> = HashMap::new();
 
         for response in responses {
             let patterns = self.analyze_response(response);
             all_patterns.extend(patterns);
             total_length += response.len();
             total_questions += response.matches('?').count();
+            total_emojis += count_emojis(response);
+            for (c, n) in count_punctuation(response) {
+                *punctuation.entry(c).or_default() += n;
+            }
+            for (gram, n) in ngram_counts(response) {
+                *ngrams.entry(gram).or_default() += n;
+            }
+            for (info, body) in fenced_code_blocks(response) {
+                if let Some(lang) = block_language(&info, &body) {
+                    *languages.entry(lang).or_default() += 1;
+                }
+            }
         }
 
         signature.samples_analyzed = responses.len();
@@ -362,6 +896,32 @@ impl BehaviorAnalyzer {
         } else {
             total_questions as f64 / responses.len() as f64
         };
+        signature.emoji_rate = if responses.is_empty() {
+            0.0
+        } else {
+            total_emojis as f64 / responses.len() as f64
+        };
+        signature.punctuation_profile = if responses.is_empty() {
+            HashMap::new()
+        } else {
+            normalize_punctuation(&punctuation)
+        };
+
+        let detected: usize = languages.values().sum();
+        signature.language_distribution = languages
+            .into_iter()
+            .map(|(lang, n)| (lang, n as f64 / detected as f64))
+            .collect();
+
+        // Keep the top-K repeated n-grams; one-off n-grams are noise
+        let mut repeated: Vec<(String, usize)> =
+            ngrams.into_iter().filter(|(_, n)| *n > 1).collect();
+        repeated.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        signature.characteristic_ngrams = repeated
+            .into_iter()
+            .take(self.ngram_top_k)
+            .map(|(gram, n)| (gram, n as f64 / responses.len() as f64))
+            .collect();
 
         // Consolidate patterns by type AND description
         // This preserves multiple Opening patterns with different signature phrases
@@ -389,14 +949,33 @@ impl BehaviorAnalyzer {
             }
         }
 
-        self.signatures
-            .insert(model_id.to_string(), signature.clone());
         signature
     }
 
+    /// Distance (0 = identical, 1 = unrelated) between a single response's
+    /// signature and the accumulated signature for `model_id`.
+    /// Returns `None` if no signature has been built for the model yet.
+    pub fn signature_distance(&self, model_id: &str, response: &str) -> Option<f64> {
+        let accumulated = self.signatures.get(model_id)?;
+        let single = self.compute_signature(model_id, &[response.to_string()]);
+        Some((1.0 - single.similarity_to(accumulated)).clamp(0.0, 1.0))
+    }
+
+    /// Whether a response is an outlier relative to the model's accumulated
+    /// signature (possible model update). `false` if there is no signature.
+    pub fn is_outlier(&self, model_id: &str, response: &str) -> bool {
+        self.signature_distance(model_id, response)
+            .map(|d| d > OUTLIER_DISTANCE)
+            .unwrap_or(false)
+    }
+
     /// Identify which known AI produced a response
-    pub fn identify_model(&self, response: &str) -> Vec<(String, f64)> {
+    pub fn identify_model(&self, response: &str) -> ModelIdentification {
         let response_patterns = self.analyze_response(response);
+        let mut response_style = BehaviorSignature::new("response");
+        response_style.punctuation_profile = punctuation_profile(response);
+        response_style.emoji_rate = count_emojis(response) as f64;
+        let response_ngrams = ngram_counts(response);
 
         let mut scores: Vec<(String, f64)> = Vec::new();
 
@@ -413,12 +992,26 @@ impl BehaviorAnalyzer {
             }
 
             if comparisons > 0.0 {
-                scores.push((model_id.clone(), match_score / comparisons));
+                // Punctuation and emoji habits separate models whose
+                // phrasing patterns look alike
+                let style = (response_style.punctuation_similarity(signature)
+                    + response_style.emoji_similarity(signature))
+                    / 2.0;
+                let pattern_score = match_score / comparisons;
+                // Phrase-level tics, once the signature has any
+                let score = if signature.characteristic_ngrams.is_empty() {
+                    pattern_score * (1.0 - STYLE_MARKER_WEIGHT) + style * STYLE_MARKER_WEIGHT
+                } else {
+                    let ngram = ngram_match(signature, &response_ngrams).unwrap_or(0.0);
+                    pattern_score * (1.0 - STYLE_MARKER_WEIGHT - NGRAM_WEIGHT)
+                        + style * STYLE_MARKER_WEIGHT
+                        + ngram * NGRAM_WEIGHT
+                };
+                scores.push((model_id.clone(), score));
             }
         }
 
-        scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
-        scores
+        ModelIdentification::from_scores(scores, self.confidence_margin)
     }
 
     /// Get a stored signature
@@ -564,29 +1157,50 @@ impl BehaviorAnalyzer {
     /// Returns 0.0 (completely divergent) to 1.0 (perfect match).
     /// Used by CompoundPersona to track mimicry fidelity over time.
     pub fn compute_convergence(&self, profile: &AiProfile, sig: &BehaviorSignature) -> f64 {
-        let mut score = 0.0;
-        let mut dimensions = 0.0;
+        self.explain_convergence(profile, sig).score()
+    }
+
+    /// Per-component breakdown behind `compute_convergence`, so a stalled
+    /// persona shows which dimension is lagging.
+    pub fn explain_convergence(
+        &self,
+        profile: &AiProfile,
+        sig: &BehaviorSignature,
+    ) -> ConvergenceBreakdown {
+        let mut breakdown = ConvergenceBreakdown::default();
 
         // Confidence vs hedging alignment
         if let Some(confidence) = profile.personality_value("confidence") {
             let implied_confidence = 1.0 - sig.hedging_level() * 2.0;
-            score += 1.0 - (confidence - implied_confidence).abs().min(1.0);
-            dimensions += 1.0;
+            breakdown.push(
+                "hedging",
+                1.0 - (confidence - implied_confidence).abs().min(1.0),
+                1.0,
+            );
         }
 
         // Verbosity alignment
         let observed_verbosity = (sig.avg_response_length / 1000.0).clamp(0.0, 1.0);
-        score += 1.0 - (profile.response_style.verbosity - observed_verbosity).abs();
-        dimensions += 1.0;
+        breakdown.push(
+            "length",
+            1.0 - (profile.response_style.verbosity - observed_verbosity).abs(),
+            1.0,
+        );
 
         // Formality alignment
-        score += 1.0 - (profile.response_style.formality - sig.vocabulary_complexity).abs();
-        dimensions += 1.0;
+        breakdown.push(
+            "vocabulary",
+            1.0 - (profile.response_style.formality - sig.vocabulary_complexity).abs(),
+            1.0,
+        );
 
         // Autonomy vs question asking rate
         if let Some(autonomy) = profile.personality_value("autonomy") {
-            score += 1.0 - (autonomy - sig.question_asking_rate.clamp(0.0, 1.0)).abs();
-            dimensions += 1.0;
+            breakdown.push(
+                "questions",
+                1.0 - (autonomy - sig.question_asking_rate.clamp(0.0, 1.0)).abs(),
+                1.0,
+            );
         }
 
         // Pattern coverage - does the profile's signature phrases match observed patterns?
@@ -605,15 +1219,51 @@ impl BehaviorAnalyzer {
                     }
                 }
             }
-            score += phrase_hits as f64 / profile.signature_phrases.len() as f64;
-            dimensions += 1.0;
+            breakdown.push(
+                "patterns",
+                phrase_hits as f64 / profile.signature_phrases.len() as f64,
+                1.0,
+            );
         }
 
-        if dimensions > 0.0 {
-            (score / dimensions).clamp(0.0, 1.0)
-        } else {
-            0.0
+        // Punctuation and emoji style count as half a dimension each, and only
+        // once the signature has seen samples. The profile's punctuation comes
+        // from its signature phrases, skipped when they carry no tracked marks.
+        if sig.samples_analyzed > 0 {
+            let implied_emoji = if profile.response_style.uses_emojis {
+                1.0
+            } else {
+                0.0
+            };
+            breakdown.push(
+                "emoji",
+                1.0 - (implied_emoji - sig.emoji_rate.clamp(0.0, 1.0)).abs(),
+                0.5,
+            );
+
+            let phrase_punctuation = punctuation_profile(&profile.signature_phrases.join(" "));
+            if phrase_punctuation.values().sum::<f64>() > 0.0 {
+                breakdown.push(
+                    "punctuation",
+                    distribution_similarity(&phrase_punctuation, &sig.punctuation_profile),
+                    0.5,
+                );
+            }
+        }
+
+        // N-gram overlap between the profile's signature phrases and the
+        // signature's characteristic n-grams, also half a dimension
+        let mut phrase_ngrams: HashMap<String, usize> = HashMap::new();
+        for phrase in &profile.signature_phrases {
+            for (gram, n) in ngram_counts(phrase) {
+                *phrase_ngrams.entry(gram).or_default() += n;
+            }
+        }
+        if let Some(ngram) = ngram_match(sig, &phrase_ngrams) {
+            breakdown.push("ngrams", ngram, 0.5);
         }
+
+        breakdown
     }
 }
 
@@ -706,6 +1356,10 @@ mod tests {
             question_asking_rate: 0.1,
             code_to_text_ratio: 0.3,
             samples_analyzed: 10,
+            punctuation_profile: HashMap::new(),
+            emoji_rate: 0.0,
+            characteristic_ngrams: Vec::new(),
+            language_distribution: HashMap::new(),
         };
 
         let sig_b = BehaviorSignature {
@@ -717,6 +1371,10 @@ mod tests {
             question_asking_rate: 0.15,
             code_to_text_ratio: 0.35,
             samples_analyzed: 10,
+            punctuation_profile: HashMap::new(),
+            emoji_rate: 0.0,
+            characteristic_ngrams: Vec::new(),
+            language_distribution: HashMap::new(),
         };
 
         let similarity = sig_a.similarity_to(&sig_b);
@@ -742,6 +1400,10 @@ mod tests {
             question_asking_rate: 0.1,
             code_to_text_ratio: 0.3,
             samples_analyzed: 10,
+            punctuation_profile: HashMap::new(),
+            emoji_rate: 0.0,
+            characteristic_ngrams: Vec::new(),
+            language_distribution: HashMap::new(),
         };
 
         let json = serde_json::to_string(&sig).unwrap();
@@ -771,6 +1433,10 @@ mod tests {
             question_asking_rate: 0.1,
             code_to_text_ratio: 0.3,
             samples_analyzed: 5,
+            punctuation_profile: HashMap::new(),
+            emoji_rate: 0.0,
+            characteristic_ngrams: Vec::new(),
+            language_distribution: HashMap::new(),
         };
 
         analyzer.refine_profile(&mut profile, &sig);
@@ -801,6 +1467,10 @@ mod tests {
             question_asking_rate: 0.1,
             code_to_text_ratio: 0.3,
             samples_analyzed: 5,
+            punctuation_profile: HashMap::new(),
+            emoji_rate: 0.0,
+            characteristic_ngrams: Vec::new(),
+            language_distribution: HashMap::new(),
         };
 
         // Our response has no hedging - should get a delta suggesting less confidence
@@ -824,6 +1494,10 @@ mod tests {
             question_asking_rate: 0.4, // matches autonomy
             code_to_text_ratio: 0.3,
             samples_analyzed: 10,
+            punctuation_profile: HashMap::new(),
+            emoji_rate: 0.0,
+            characteristic_ngrams: Vec::new(),
+            language_distribution: HashMap::new(),
         };
 
         let convergence = analyzer.compute_convergence(&profile, &sig);
@@ -833,4 +1507,241 @@ mod tests {
             convergence
         );
     }
+
+    #[test]
+    fn test_signature_distance_flags_outliers() {
+        let mut analyzer = BehaviorAnalyzer::new();
+        let responses: Vec<String> = vec![
+            "Certainly! Rust is a systems language focused on safety.".to_string(),
+            "Certainly! Closures capture variables from their scope.".to_string(),
+            "Certainly! Traits define shared behavior across types.".to_string(),
+        ];
+        analyzer.build_signature("gpt4o", &responses);
+
+        assert!(analyzer.signature_distance("unknown", "anything").is_none());
+
+        let on_dist = analyzer
+            .signature_distance(
+                "gpt4o",
+                "Certainly! Lifetimes track how long references live.",
+            )
+            .unwrap();
+        assert!(on_dist < OUTLIER_DISTANCE, "on-distribution: {}", on_dist);
+
+        let off_response = format!(
+            "I think perhaps it might be possible? Could be? I'm not sure, maybe? {}",
+            "It seems arguably likely, but I believe it may potentially vary. ".repeat(10)
+        );
+        let off_dist = analyzer.signature_distance("gpt4o", &off_response).unwrap();
+        assert!(
+            off_dist > OUTLIER_DISTANCE,
+            "off-distribution: {}",
+            off_dist
+        );
+        assert!(analyzer.is_outlier("gpt4o", &off_response));
+    }
+
+    #[test]
+    fn test_fuzzy_pattern_matching() {
+        let analyzer = BehaviorAnalyzer::new();
+        let ratio = analyzer
+            .fuzzy_match("glad to help with that", "happy to help")
+            .unwrap();
+        assert!((DEFAULT_FUZZY_THRESHOLD..1.0).contains(&ratio));
+        assert!(analyzer
+            .fuzzy_match("the weather is nice", "happy to help")
+            .is_none());
+        assert_eq!(
+            analyzer.fuzzy_match("Certainly! Yes.", "Certainly!"),
+            Some(1.0)
+        );
+
+        // Near-variant openings register as Opening patterns
+        let patterns = analyzer.analyze_response("I'd be glad to help you with Rust.");
+        assert!(patterns
+            .iter()
+            .any(|p| p.pattern_type == PatternType::Opening
+                && p.description.contains("I'd be happy to")));
+
+        // A strict threshold falls back to exact matching only
+        let strict = BehaviorAnalyzer::new().with_fuzzy_threshold(1.0);
+        assert!(strict
+            .fuzzy_match("glad to help with that", "happy to help")
+            .is_none());
+    }
+
+    #[test]
+    fn test_punctuation_and_emoji_markers() {
+        let mut analyzer = BehaviorAnalyzer::new();
+
+        // No punctuation at all: shares are zero, not NaN
+        let plain = analyzer.compute_signature("plain", &["Rust is fast".to_string()]);
+        assert_eq!(plain.punctuation_profile.len(), TRACKED_PUNCTUATION.len());
+        assert!(plain.punctuation_profile.values().all(|v| *v == 0.0));
+        assert_eq!(plain.emoji_rate, 0.0);
+        assert_eq!(plain.punctuation_similarity(&plain), 1.0);
+
+        // Two models with the same phrasing but different punctuation habits
+        let dashes: Vec<String> = vec![
+            "Rust is fast \u{2014} and safe \u{2014} by design \u{1F680}".to_string(),
+            "Ownership matters \u{2014} a lot \u{2728}".to_string(),
+        ];
+        let bangs: Vec<String> = vec![
+            "Rust is fast! And safe! By design!".to_string(),
+            "Ownership matters! A lot!".to_string(),
+        ];
+        let dash_sig = analyzer.build_signature("dasher", &dashes);
+        analyzer.build_signature("banger", &bangs);
+        assert_eq!(dash_sig.punctuation_profile[&'\u{2014}'], 1.0);
+        assert_eq!(dash_sig.emoji_rate, 1.0);
+
+        let scores = analyzer
+            .identify_model("Traits are neat \u{2014} truly \u{1F980}")
+            .scores;
+        assert_eq!(scores[0].0, "dasher");
+        assert!(scores[0].1 > scores[1].1);
+        let scores = analyzer.identify_model("Traits are neat! Truly!").scores;
+        assert_eq!(scores[0].0, "banger");
+
+        // An emoji-using profile converges better on the emoji-heavy signature
+        let mut profile = AiProfile::new("dasher", "Dasher");
+        profile.response_style.uses_emojis = true;
+        let with_emoji = analyzer.compute_convergence(&profile, &dash_sig);
+        profile.response_style.uses_emojis = false;
+        assert!(with_emoji > analyzer.compute_convergence(&profile, &dash_sig));
+    }
+
+    #[test]
+    fn test_characteristic_ngrams() {
+        let mut analyzer = BehaviorAnalyzer::new().with_ngram_top_k(3);
+        let noter: Vec<String> = vec![
+            "It's worth noting that borrowing is checked at compile time.".to_string(),
+            "It's worth noting that traits enable polymorphism.".to_string(),
+            "Lifetimes, it's worth noting that, are mostly inferred.".to_string(),
+        ];
+        let sig = analyzer.build_signature("noter", &noter);
+        assert_eq!(sig.characteristic_ngrams.len(), 3);
+        assert!(sig
+            .characteristic_ngrams
+            .iter()
+            .any(|(g, w)| g == "it's worth noting" && (*w - 1.0).abs() < 1e-9));
+        // N-grams seen only once across all samples are discarded
+        assert!(!sig
+            .characteristic_ngrams
+            .iter()
+            .any(|(g, _)| g.contains("borrowing")));
+
+        let plain: Vec<String> = vec![
+            "Rust checks borrowing at compile time.".to_string(),
+            "Rust uses traits for polymorphism.".to_string(),
+        ];
+        let plain_sig = analyzer.build_signature("plain", &plain);
+        assert_eq!(sig.ngram_similarity(&sig), Some(1.0));
+        assert_eq!(sig.ngram_similarity(&BehaviorSignature::new("empty")), None);
+        assert!(sig.ngram_similarity(&plain_sig).unwrap_or(0.0) < 0.5);
+
+        let scores = analyzer
+            .identify_model("It's worth noting that macros are hygienic.")
+            .scores;
+        assert_eq!(scores[0].0, "noter");
+    }
+
+    #[test]
+    fn test_identify_model_confidence_margin() {
+        let mut analyzer = BehaviorAnalyzer::new();
+        let none = analyzer.identify_model("Hello");
+        assert!(none.scores.is_empty() && !none.is_confident);
+        assert_eq!(none.margin, 0.0);
+
+        // Single model: no runner-up, the margin is the top score itself
+        analyzer.build_signature("gpt4o", &["Certainly! Here it is.".to_string()]);
+        let single = analyzer.identify_model("Certainly! Done.");
+        assert!(single.runner_up().is_none());
+        assert_eq!(single.margin, single.top().unwrap().1);
+        assert!(single.verdict().starts_with("Confident: gpt4o"));
+
+        // Near-identical signatures are ambiguous
+        analyzer.build_signature("gpt4o-mini", &["Certainly! Here it is.".to_string()]);
+        let close = analyzer.identify_model("Certainly! Done.");
+        assert!(close.margin.abs() < 1e-9);
+        assert!(!close.is_confident);
+        assert!(close.verdict().starts_with("Ambiguous ("));
+
+        let ids = ModelIdentification::from_scores(vec![("a".into(), 0.4), ("b".into(), 0.9)], 0.3);
+        assert_eq!(ids.top().unwrap().0, "b");
+        assert!((ids.margin - 0.5).abs() < 1e-9);
+        assert_eq!(ids.verdict(), "Confident: b");
+        let strict =
+            ModelIdentification::from_scores(vec![("a".into(), 0.4), ("b".into(), 0.9)], 0.6);
+        assert_eq!(strict.verdict(), "Ambiguous (b vs a)");
+    }
+
+    #[test]
+    fn test_explain_convergence() {
+        let store = AiProfileStore::default();
+        let profile = store.get("claude").unwrap();
+        let mut analyzer = BehaviorAnalyzer::new();
+        let sig = analyzer.build_signature(
+            "claude",
+            &[
+                "I'd be happy to help. I think this might work.".to_string(),
+                "I should note that it depends.".to_string(),
+            ],
+        );
+
+        let breakdown = analyzer.explain_convergence(profile, &sig);
+        assert_eq!(
+            breakdown.score(),
+            analyzer.compute_convergence(profile, &sig)
+        );
+        let names: Vec<&str> = breakdown
+            .components
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert!(names.starts_with(&["hedging", "length", "vocabulary"]));
+        assert!(names.contains(&"emoji"));
+        assert!(breakdown
+            .components
+            .iter()
+            .all(|c| (0.0..=1.0).contains(&c.score) && c.weight > 0.0));
+
+        // Short samples against a verbose profile drag on length
+        let mut verbose = profile.clone();
+        verbose.response_style.verbosity = 1.0;
+        let breakdown = analyzer.explain_convergence(&verbose, &sig);
+        assert_eq!(breakdown.dragging()[0].name, "length");
+        assert!(breakdown.to_string().contains(" (dragging)"));
+
+        assert_eq!(ConvergenceBreakdown::default().score(), 0.0);
+    }
+
+    #[test]
+    fn test_code_language_distribution() {
+        let analyzer = BehaviorAnalyzer::new();
+        let responses = vec![
+            "Here you go:\n```rust\nfn main() {}\n```".to_string(),
+            "Try this:\n```\nfn add(a: i32) -> i32 {\n    let mut x = a;\n    x\n}\n```\n\
+             and\n```py\nprint(1)\n```"
+                .to_string(),
+            "No code here.".to_string(),
+        ];
+        let sig = analyzer.compute_signature("coder", &responses);
+        assert!((sig.language_distribution["rust"] - 2.0 / 3.0).abs() < 1e-9);
+        assert!((sig.language_distribution["python"] - 1.0 / 3.0).abs() < 1e-9);
+        assert_eq!(sig.dominant_language(), Some("rust"));
+
+        // No code blocks: empty map, no panic
+        let prose = analyzer.compute_signature("prose", &["Just words.".to_string()]);
+        assert!(prose.language_distribution.is_empty());
+        assert_eq!(prose.dominant_language(), None);
+
+        assert_eq!(
+            detect_code_language("def f(x):\n    return None"),
+            Some("python")
+        );
+        assert_eq!(detect_code_language("plain words"), None);
+        // An unclosed fence still counts
+        assert_eq!(fenced_code_blocks("```js\nconst x = 1;").len(), 1);
+    }
 }
@@ -23,12 +23,21 @@
 // Tests work without actual API keys (mock/stub patterns).
 // =================================================================
 
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::time::Duration;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-use reqwest::blocking::Client;
-use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::blocking::{Client, RequestBuilder, Response};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER};
+
+use crate::mimicry::backend::{GenerationBackend, GenerationContext};
+use crate::mimicry::persistence::{seal_with, unseal_with};
+use crate::mimicry::profile::AiProfile;
 
 // =================================================================
 // API PROVIDER ENUM
@@ -36,6 +45,7 @@ use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
 
 /// Supported API providers for model observation
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "ApiProviderRepr")]
 pub enum ApiProvider {
     /// OpenAI GPT models (GPT-4o, o1) via chat completions API
     OpenAI,
@@ -45,11 +55,76 @@ pub enum ApiProvider {
     Google,
     /// Ollama local models via generate API (no API key required)
     Ollama,
-    /// Custom OpenAI-compatible endpoint with a user-defined label
-    Custom(String),
+    /// Self-hosted OpenAI-compatible endpoint (vLLM, llama.cpp server, ...).
+    /// An API key is sent if configured but not required.
+    Custom {
+        /// Endpoint root, e.g. `http://gpu-box:8000/v1`
+        base_url: String,
+        /// Model name the server expects
+        model: String,
+        /// Profile ID this endpoint is observed and configured under
+        profile_id: String,
+    },
+}
+
+/// Deserialized form of `ApiProvider`, also accepting the `Custom("label")`
+/// shape saved before custom providers carried an endpoint
+#[derive(Deserialize)]
+enum ApiProviderRepr {
+    OpenAI,
+    Anthropic,
+    Google,
+    Ollama,
+    Custom(CustomRepr),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CustomRepr {
+    Label(String),
+    Endpoint {
+        #[serde(default)]
+        base_url: String,
+        #[serde(default)]
+        model: String,
+        #[serde(default)]
+        profile_id: String,
+    },
+}
+
+impl From<ApiProviderRepr> for ApiProvider {
+    fn from(repr: ApiProviderRepr) -> Self {
+        match repr {
+            ApiProviderRepr::OpenAI => ApiProvider::OpenAI,
+            ApiProviderRepr::Anthropic => ApiProvider::Anthropic,
+            ApiProviderRepr::Google => ApiProvider::Google,
+            ApiProviderRepr::Ollama => ApiProvider::Ollama,
+            ApiProviderRepr::Custom(CustomRepr::Label(label)) => {
+                ApiProvider::custom("", "", &label)
+            }
+            ApiProviderRepr::Custom(CustomRepr::Endpoint {
+                base_url,
+                model,
+                profile_id,
+            }) => ApiProvider::Custom {
+                base_url,
+                model,
+                profile_id,
+            },
+        }
+    }
 }
 
 impl ApiProvider {
+    /// A custom OpenAI-compatible endpoint
+    pub fn custom(base_url: &str, model: &str, profile_id: &str) -> Self {
+        ApiProvider::Custom {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            model: model.to_string(),
+            profile_id: profile_id.to_string(),
+        }
+    }
+
     /// Default base URL for the provider
     pub fn default_base_url(&self) -> &str {
         match self {
@@ -57,7 +132,18 @@ impl ApiProvider {
             ApiProvider::Anthropic => "https://api.anthropic.com/v1",
             ApiProvider::Google => "https://generativelanguage.googleapis.com/v1beta",
             ApiProvider::Ollama => "http://localhost:11434",
-            ApiProvider::Custom(_) => "",
+            ApiProvider::Custom { base_url, .. } => base_url,
+        }
+    }
+
+    /// Published per-1K-token rates for the default model, as of writing.
+    /// Local and custom endpoints have no default price.
+    pub fn default_pricing(&self) -> Option<TokenPricing> {
+        match self {
+            ApiProvider::OpenAI => Some(TokenPricing::new(0.0025, 0.01)),
+            ApiProvider::Anthropic => Some(TokenPricing::new(0.003, 0.015)),
+            ApiProvider::Google => Some(TokenPricing::new(0.00125, 0.005)),
+            ApiProvider::Ollama | ApiProvider::Custom { .. } => None,
         }
     }
 
@@ -68,7 +154,8 @@ impl ApiProvider {
             ApiProvider::Anthropic => "claude-sonnet-4-20250514",
             ApiProvider::Google => "gemini-1.5-pro",
             ApiProvider::Ollama => "llama3",
-            ApiProvider::Custom(_) => "default",
+            ApiProvider::Custom { model, .. } if !model.is_empty() => model,
+            ApiProvider::Custom { .. } => "default",
         }
     }
 
@@ -79,7 +166,7 @@ impl ApiProvider {
             ApiProvider::Anthropic => "ANTHROPIC_API_KEY",
             ApiProvider::Google => "GOOGLE_API_KEY",
             ApiProvider::Ollama => "", // no key needed
-            ApiProvider::Custom(_) => "CUSTOM_API_KEY",
+            ApiProvider::Custom { .. } => "CUSTOM_API_KEY",
         }
     }
 
@@ -90,26 +177,49 @@ impl ApiProvider {
             ApiProvider::Anthropic => "claude",
             ApiProvider::Google => "gemini",
             ApiProvider::Ollama => "llama",
-            ApiProvider::Custom(label) => {
-                if label.is_empty() {
-                    "custom"
-                } else {
-                    // Return label as-is; callers use to_string() anyway
-                    label.as_str()
-                }
-            }
+            ApiProvider::Custom { profile_id, .. } if !profile_id.is_empty() => profile_id,
+            ApiProvider::Custom { .. } => "custom",
         }
     }
 
-    /// Parse provider from a string (case-insensitive)
+    /// Parse provider from a string (case-insensitive). Besides the named
+    /// providers, `custom:<url>:<model>` selects a self-hosted endpoint,
+    /// e.g. `custom:http://gpu-box:8000/v1:meta-llama/Llama-3-8B`; its
+    /// profile ID is the model's last path segment, lowercased. Any other
+    /// name becomes a custom provider with that profile ID and no endpoint.
     pub fn parse(s: &str) -> Option<Self> {
+        if let Some(spec) = s
+            .get(..7)
+            .filter(|p| p.eq_ignore_ascii_case("custom:"))
+            .map(|_| &s[7..])
+        {
+            return Self::parse_custom(spec);
+        }
         match s.to_lowercase().as_str() {
             "openai" | "gpt" | "gpt4o" | "gpt-4o" | "o1" => Some(ApiProvider::OpenAI),
             "anthropic" | "claude" => Some(ApiProvider::Anthropic),
             "google" | "gemini" => Some(ApiProvider::Google),
             "ollama" | "llama" | "local" => Some(ApiProvider::Ollama),
-            _ => Some(ApiProvider::Custom(s.to_string())),
+            _ => Some(ApiProvider::custom("", "", s)),
+        }
+    }
+
+    /// Parse the `<url>:<model>` part of a `custom:` provider spec
+    fn parse_custom(spec: &str) -> Option<Self> {
+        let (url, model) = spec.rsplit_once(':')?;
+        let host = url
+            .strip_prefix("http://")
+            .or_else(|| url.strip_prefix("https://"))?;
+        // "custom:http://host:8000" has no model; its port is not one
+        let port_like = model
+            .split('/')
+            .next()
+            .is_some_and(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()));
+        if host.is_empty() || model.is_empty() || port_like {
+            return None;
         }
+        let profile_id = model.rsplit('/').next().unwrap_or(model).to_lowercase();
+        Some(ApiProvider::custom(url, model, &profile_id))
     }
 }
 
@@ -120,7 +230,7 @@ impl std::fmt::Display for ApiProvider {
             ApiProvider::Anthropic => write!(f, "Anthropic"),
             ApiProvider::Google => write!(f, "Google"),
             ApiProvider::Ollama => write!(f, "Ollama"),
-            ApiProvider::Custom(label) => write!(f, "Custom({})", label),
+            ApiProvider::Custom { .. } => write!(f, "Custom({})", self.profile_id()),
         }
     }
 }
@@ -134,24 +244,147 @@ impl std::fmt::Display for ApiProvider {
 pub struct ApiConfig {
     /// The API provider this config targets
     pub provider: ApiProvider,
-    /// Base URL for API requests
+    /// Base URL for API requests. A `Custom` provider carries its own
+    /// endpoint, which takes precedence; this field is then only read for
+    /// configs saved before the endpoint moved into the variant. Use
+    /// `endpoint()` to get the URL requests go to.
     pub base_url: String,
     /// API key for authentication (None for providers that don't require one)
     pub api_key: Option<String>,
     /// Model identifier to use for requests
     pub model: String,
-    /// Request timeout in seconds
-    pub timeout_secs: u64,
+    /// Longest any single request may take, including reading the body.
+    /// Configs saved before the rename store whole seconds as `timeout_secs`.
+    #[serde(
+        default = "default_request_timeout",
+        alias = "timeout_secs",
+        deserialize_with = "deserialize_request_timeout"
+    )]
+    pub request_timeout: Duration,
     /// Maximum number of tokens to generate in responses
     pub max_tokens: u32,
     /// Sampling temperature for response generation (0.0-2.0)
     pub temperature: f64,
+    /// Retries after a transient failure (network timeouts, connection
+    /// errors, 408, 429, 5xx), within `retry_budget()`
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+    /// Cap on the time one request may spend across all its attempts and
+    /// backoffs; see `retry_budget()`
+    #[serde(default = "default_max_retry_time")]
+    pub max_retry_time: Duration,
+    /// First backoff delay in milliseconds; doubles with each retry
+    #[serde(default = "default_retry_base_delay_ms")]
+    pub retry_base_delay_ms: u64,
+    /// Upper bound on any single backoff delay, including `Retry-After`
+    #[serde(default = "default_retry_max_delay_ms")]
+    pub retry_max_delay_ms: u64,
+    /// Client-side cap on requests per minute (None or 0 = unlimited)
+    #[serde(default)]
+    pub requests_per_minute: Option<u32>,
+    /// What `ApiObserver::send` does when the cap is reached
+    #[serde(default)]
+    pub rate_limit_mode: RateLimitMode,
+    /// Rates overriding the provider's default pricing
+    #[serde(default)]
+    pub pricing: Option<TokenPricing>,
+}
+
+/// USD prices per 1,000 tokens, used for cost estimates
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct TokenPricing {
+    /// Price per 1K prompt tokens
+    pub input_per_1k: f64,
+    /// Price per 1K generated tokens
+    pub output_per_1k: f64,
+}
+
+impl TokenPricing {
+    /// Create a price entry from per-1K-token input and output rates
+    pub fn new(input_per_1k: f64, output_per_1k: f64) -> Self {
+        TokenPricing {
+            input_per_1k,
+            output_per_1k,
+        }
+    }
+
+    /// Estimated USD cost of a response. Providers report one combined
+    /// token count, so the prompt's share is approximated at four
+    /// characters per token and the remainder is billed as output.
+    pub fn estimate(&self, response: &ApiResponse) -> f64 {
+        let total = response.tokens_used.unwrap_or(0);
+        let input = (response.prompt.chars().count() as u64)
+            .div_ceil(4)
+            .min(total);
+        let output = total - input;
+        (input as f64 * self.input_per_1k + output as f64 * self.output_per_1k) / 1000.0
+    }
+}
+
+/// Format a USD amount for status output
+fn format_cost(usd: f64) -> String {
+    if usd > 0.0 && usd < 0.01 {
+        "<$0.01".to_string()
+    } else {
+        format!("${:.2}", usd)
+    }
+}
+
+/// Behavior when a provider's client-side rate limit is reached
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum RateLimitMode {
+    /// Sleep until the next request is allowed
+    #[default]
+    Block,
+    /// Fail the request immediately
+    Error,
+}
+
+fn default_request_timeout() -> Duration {
+    Duration::from_secs(60)
+}
+
+/// Read `request_timeout` as a serialized Duration or as the whole
+/// seconds of a legacy `timeout_secs` field
+fn deserialize_request_timeout<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Timeout {
+        Secs(u64),
+        Duration(Duration),
+    }
+    Ok(match Timeout::deserialize(deserializer)? {
+        Timeout::Secs(secs) => Duration::from_secs(secs),
+        Timeout::Duration(duration) => duration,
+    })
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
+
+fn default_max_retry_time() -> Duration {
+    Duration::from_secs(120)
+}
+
+fn default_retry_base_delay_ms() -> u64 {
+    500
+}
+
+fn default_retry_max_delay_ms() -> u64 {
+    30_000
 }
 
 impl ApiConfig {
     /// Create a new config for a provider, pulling API key from environment
     pub fn new(provider: ApiProvider) -> Self {
-        let base_url = provider.default_base_url().to_string();
+        let base_url = match provider {
+            ApiProvider::Custom { .. } => String::new(),
+            _ => provider.default_base_url().to_string(),
+        };
         let model = provider.default_model().to_string();
         let env_key = provider.env_key_name();
         let api_key = if env_key.is_empty() {
@@ -165,9 +398,16 @@ impl ApiConfig {
             base_url,
             api_key,
             model,
-            timeout_secs: 60,
+            request_timeout: default_request_timeout(),
             max_tokens: 1024,
             temperature: 0.7,
+            max_retries: default_max_retries(),
+            max_retry_time: default_max_retry_time(),
+            retry_base_delay_ms: default_retry_base_delay_ms(),
+            retry_max_delay_ms: default_retry_max_delay_ms(),
+            requests_per_minute: None,
+            rate_limit_mode: RateLimitMode::default(),
+            pricing: None,
         }
     }
 
@@ -184,12 +424,26 @@ impl ApiConfig {
         self
     }
 
-    /// Set the base URL
+    /// Set the base URL (the variant's endpoint for a `Custom` provider)
     pub fn with_base_url(mut self, url: &str) -> Self {
-        self.base_url = url.to_string();
+        match &mut self.provider {
+            ApiProvider::Custom { base_url, .. } => {
+                *base_url = url.trim_end_matches('/').to_string();
+                self.base_url.clear();
+            }
+            _ => self.base_url = url.to_string(),
+        }
         self
     }
 
+    /// The URL requests are sent to
+    pub fn endpoint(&self) -> &str {
+        match &self.provider {
+            ApiProvider::Custom { base_url, .. } if !base_url.is_empty() => base_url,
+            _ => &self.base_url,
+        }
+    }
+
     /// Set temperature
     pub fn with_temperature(mut self, temp: f64) -> Self {
         self.temperature = temp;
@@ -202,10 +456,79 @@ impl ApiConfig {
         self
     }
 
+    /// Set the retry policy: up to `max_retries` retries, backing off
+    /// from `base_delay_ms` and never waiting longer than `max_delay_ms`
+    pub fn with_retries(mut self, max_retries: u32, base_delay_ms: u64, max_delay_ms: u64) -> Self {
+        self.max_retries = max_retries;
+        self.retry_base_delay_ms = base_delay_ms;
+        self.retry_max_delay_ms = max_delay_ms;
+        self
+    }
+
+    /// Cap the total time a request may spend retrying
+    pub fn with_max_retry_time(mut self, max_retry_time: Duration) -> Self {
+        self.max_retry_time = max_retry_time;
+        self
+    }
+
+    /// Set the per-request timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = timeout;
+        self
+    }
+
+    /// Cap requests to `requests_per_minute`, blocking or failing (per
+    /// `mode`) when the cap is reached
+    pub fn with_rate_limit(mut self, requests_per_minute: u32, mode: RateLimitMode) -> Self {
+        self.requests_per_minute = Some(requests_per_minute);
+        self.rate_limit_mode = mode;
+        self
+    }
+
+    /// Override the provider's default per-1K-token rates
+    pub fn with_pricing(mut self, input_per_1k: f64, output_per_1k: f64) -> Self {
+        self.pricing = Some(TokenPricing::new(input_per_1k, output_per_1k));
+        self
+    }
+
+    /// Rates used for cost estimates: the override if set, else the
+    /// provider default, else free
+    pub fn pricing(&self) -> TokenPricing {
+        self.pricing
+            .or_else(|| self.provider.default_pricing())
+            .unwrap_or_default()
+    }
+
+    /// Delay before retry number `attempt` (0-based): the server's
+    /// `Retry-After` when given, otherwise exponential backoff with
+    /// jitter in the upper half of the window. Capped at the max delay.
+    pub fn retry_delay(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        let max = Duration::from_millis(self.retry_max_delay_ms);
+        if let Some(wait) = retry_after {
+            return wait.min(max);
+        }
+        let window = self
+            .retry_base_delay_ms
+            .saturating_mul(1u64 << attempt.min(32))
+            .min(self.retry_max_delay_ms);
+        Duration::from_millis(rand::thread_rng().gen_range(window / 2..=window))
+    }
+
+    /// Total time a request may take across its attempts:
+    /// `max_retries * request_timeout`, capped at `max_retry_time`. No retry
+    /// starts once the budget is spent, so a hung provider is waited on at
+    /// most one `request_timeout` past it.
+    pub fn retry_budget(&self) -> Duration {
+        self.request_timeout
+            .saturating_mul(self.max_retries)
+            .min(self.max_retry_time)
+    }
+
     /// Check if the config has a valid API key (or doesn't need one)
     pub fn has_credentials(&self) -> bool {
         match self.provider {
-            ApiProvider::Ollama => true, // no key needed
+            // no key needed
+            ApiProvider::Ollama | ApiProvider::Custom { .. } => true,
             _ => self.api_key.is_some() && !self.api_key.as_ref().unwrap().is_empty(),
         }
     }
@@ -253,10 +576,20 @@ pub struct ApiResponse {
     pub prompt: String,
     /// Total tokens consumed (input + output), if reported by the provider
     pub tokens_used: Option<u64>,
-    /// Round-trip latency in milliseconds
+    /// Round-trip latency of the successful attempt in milliseconds;
+    /// earlier failed attempts and retry backoff are not included
     pub latency_ms: u64,
     /// Raw JSON response body from the provider, if available
     pub raw_json: Option<String>,
+    /// Transient failures retried before this response arrived
+    #[serde(default)]
+    pub retries: u32,
+    /// Milliseconds until the first chunk arrived (streamed requests only)
+    #[serde(default)]
+    pub time_to_first_token_ms: Option<u64>,
+    /// Served from the observer's response cache rather than the provider
+    #[serde(default)]
+    pub from_cache: bool,
 }
 
 /// Result of comparing multiple providers on the same prompt
@@ -268,12 +601,241 @@ pub struct ComparisonResult {
     pub responses: Vec<ApiResponse>,
     /// Pairwise Jaccard similarity scores between responses
     pub similarity_matrix: Vec<Vec<f64>>,
+    /// Configured providers that produced no response, with the reason
+    #[serde(default)]
+    pub skipped: Vec<SkippedProvider>,
+}
+
+impl ComparisonResult {
+    /// Provider profile IDs labelling the similarity matrix rows and
+    /// columns, in response order
+    pub fn labels(&self) -> Vec<String> {
+        self.responses
+            .iter()
+            .map(|r| r.provider.profile_id().to_string())
+            .collect()
+    }
+
+    /// Machine-readable form: every field plus `labels` for the
+    /// similarity matrix, pretty-printed so runs diff cleanly
+    pub fn to_json(&self) -> Result<String, String> {
+        let mut value = serde_json::to_value(self)
+            .map_err(|e| format!("Failed to serialize comparison: {}", e))?;
+        value["labels"] = serde_json::json!(self.labels());
+        serde_json::to_string_pretty(&value)
+            .map_err(|e| format!("Failed to serialize comparison: {}", e))
+    }
+}
+
+/// A configured provider left out of a comparison
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkippedProvider {
+    /// Profile ID of the provider (e.g. "gpt4o")
+    pub provider_id: String,
+    /// Why the provider has no response in the comparison
+    pub reason: SkipReason,
+}
+
+/// Why a provider was left out of a comparison
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SkipReason {
+    /// Provider requires an API key and none is configured
+    NoKey,
+    /// Provider failed this many consecutive requests and was not called
+    Unhealthy(u32),
+    /// Provider was called but the request failed
+    RequestFailed(String),
+    /// Provider was called but did not respond within its request timeout
+    TimedOut(Duration),
+}
+
+impl std::fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SkipReason::NoKey => write!(f, "no API key"),
+            SkipReason::Unhealthy(n) => write!(f, "unhealthy ({} consecutive failures)", n),
+            SkipReason::RequestFailed(e) => write!(f, "request failed: {}", e),
+            SkipReason::TimedOut(after) => {
+                write!(f, "timed out after {}s", after.as_secs_f64())
+            }
+        }
+    }
+}
+
+/// Why an API request failed
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    /// No complete response within the config's `request_timeout`
+    Timeout {
+        /// Provider that stopped responding
+        provider: String,
+        /// The timeout that expired
+        after: Duration,
+    },
+    /// Any other failure: missing key, rate limit, connection, HTTP status
+    Failed(String),
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApiError::Timeout { provider, after } => write!(
+                f,
+                "{} timed out: no response within {}s (request timeout)",
+                provider,
+                after.as_secs_f64()
+            ),
+            ApiError::Failed(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<String> for ApiError {
+    fn from(message: String) -> Self {
+        ApiError::Failed(message)
+    }
+}
+
+impl From<ApiError> for String {
+    fn from(error: ApiError) -> Self {
+        error.to_string()
+    }
+}
+
+/// A streamed request that failed, with whatever arrived before the error
+#[derive(Debug, Clone)]
+pub struct StreamFailure {
+    /// What went wrong
+    pub error: String,
+    /// Content received before the failure; None if the stream never opened
+    pub partial: Option<Box<ApiResponse>>,
+}
+
+impl From<String> for StreamFailure {
+    fn from(error: String) -> Self {
+        StreamFailure {
+            error,
+            partial: None,
+        }
+    }
+}
+
+impl From<ApiError> for StreamFailure {
+    fn from(error: ApiError) -> Self {
+        StreamFailure::from(error.to_string())
+    }
+}
+
+impl std::fmt::Display for StreamFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.partial {
+            Some(partial) => write!(
+                f,
+                "{} (after {} chars streamed)",
+                self.error,
+                partial.content.len()
+            ),
+            None => write!(f, "{}", self.error),
+        }
+    }
 }
 
 // =================================================================
 // API CLIENT
 // =================================================================
 
+/// A failed provider request, marked with whether retrying may help
+#[derive(Debug)]
+struct RequestFailure {
+    message: String,
+    retryable: bool,
+    retry_after: Option<Duration>,
+    timed_out: bool,
+}
+
+impl From<String> for RequestFailure {
+    fn from(message: String) -> Self {
+        RequestFailure {
+            message,
+            retryable: false,
+            retry_after: None,
+            timed_out: false,
+        }
+    }
+}
+
+impl RequestFailure {
+    /// A transport error: a timeout, refused connection, or reset. All are
+    /// retryable; `ApiClient::with_retries` keeps them inside the budget.
+    fn transport(message: String, error: &reqwest::Error) -> Self {
+        RequestFailure {
+            message,
+            retryable: true,
+            retry_after: None,
+            timed_out: error.is_timeout(),
+        }
+    }
+}
+
+impl From<&str> for RequestFailure {
+    fn from(message: &str) -> Self {
+        RequestFailure::from(message.to_string())
+    }
+}
+
+/// Statuses worth retrying: request timeout, rate limiting, and server
+/// errors. Other 4xx (bad requests, auth failures) fail immediately.
+fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 408 | 429 | 500 | 502 | 503 | 504)
+}
+
+/// One decoded line of a streamed response
+#[derive(Debug, Default, PartialEq)]
+struct StreamEvent {
+    text: Option<String>,
+    tokens: Option<u64>,
+    done: bool,
+}
+
+/// Decode one line of an OpenAI server-sent event stream (`data: {...}`)
+/// or an Ollama NDJSON stream. Blank lines, comments, and other SSE
+/// fields yield None; an in-band error payload yields Err.
+fn parse_stream_line(line: &str) -> Result<Option<StreamEvent>, String> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with(':') || line.starts_with("event:") {
+        return Ok(None);
+    }
+    let payload = line.strip_prefix("data:").map(str::trim).unwrap_or(line);
+    if payload == "[DONE]" {
+        return Ok(Some(StreamEvent {
+            done: true,
+            ..StreamEvent::default()
+        }));
+    }
+
+    let json: serde_json::Value =
+        serde_json::from_str(payload).map_err(|e| format!("malformed chunk: {}", e))?;
+    if let Some(error) = json.get("error") {
+        let message = error["message"].as_str().or(error.as_str());
+        return Err(message.unwrap_or("unknown error").to_string());
+    }
+
+    let text = json["choices"][0]["delta"]["content"]
+        .as_str()
+        .or_else(|| json["response"].as_str())
+        .filter(|t| !t.is_empty())
+        .map(str::to_string);
+    let tokens = json["usage"]["total_tokens"]
+        .as_u64()
+        .or_else(|| json["eval_count"].as_u64());
+
+    Ok(Some(StreamEvent {
+        text,
+        tokens,
+        done: json["done"].as_bool().unwrap_or(false),
+    }))
+}
+
 /// HTTP client for making requests to AI model APIs.
 /// Uses reqwest::blocking for synchronous operation consistent
 /// with the rest of the engine.
@@ -286,7 +848,7 @@ impl ApiClient {
     /// Create a new API client with the given config
     pub fn new(config: ApiConfig) -> Result<Self, String> {
         let client = Client::builder()
-            .timeout(Duration::from_secs(config.timeout_secs))
+            .timeout(config.request_timeout)
             .build()
             .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
 
@@ -298,45 +860,215 @@ impl ApiClient {
         &self.config
     }
 
-    /// Send a prompt to the configured API provider
-    pub fn send(&self, prompt: &ApiPrompt) -> Result<ApiResponse, String> {
-        if !self.config.has_credentials() {
-            return Err(format!(
-                "No API key configured for {}. Set {} environment variable or use /api-config.",
-                self.config.provider,
-                self.config.provider.env_key_name()
-            ));
+    /// Error for a provider that needs a key and has none
+    fn check_credentials(&self) -> Result<(), String> {
+        if self.config.has_credentials() {
+            return Ok(());
+        }
+        Err(format!(
+            "No API key configured for {}. Set {} environment variable or use /api-config.",
+            self.config.provider,
+            self.config.provider.env_key_name()
+        ))
+    }
+
+    /// Run `attempt`, backing off and retrying transient failures up to
+    /// max_retries times, as long as the next attempt fits in the config's
+    /// `retry_budget()`. Returns the final outcome, the retry count, and
+    /// when the final attempt started.
+    fn with_retries<T>(
+        &self,
+        mut attempt: impl FnMut() -> Result<T, RequestFailure>,
+    ) -> (Result<T, RequestFailure>, u32, Instant) {
+        let budget = self.config.retry_budget();
+        let first = Instant::now();
+        let mut retries = 0;
+        loop {
+            let started = Instant::now();
+            match attempt() {
+                Err(failure) if failure.retryable && retries < self.config.max_retries => {
+                    let delay = self.config.retry_delay(retries, failure.retry_after);
+                    if first.elapsed() + delay >= budget {
+                        return (Err(failure), retries, started);
+                    }
+                    std::thread::sleep(delay);
+                    retries += 1;
+                }
+                other => return (other, retries, started),
+            }
         }
+    }
+
+    /// Final error for a request that failed after `retries` retries
+    fn request_error(&self, failure: RequestFailure, retries: u32) -> ApiError {
+        if failure.timed_out {
+            return ApiError::Timeout {
+                provider: self.config.provider.to_string(),
+                after: self.config.request_timeout,
+            };
+        }
+        match retries {
+            0 => ApiError::Failed(failure.message),
+            n => ApiError::Failed(format!("{} (after {} retries)", failure.message, n)),
+        }
+    }
 
-        let start = std::time::Instant::now();
+    /// Send a prompt to the configured API provider. Each attempt is
+    /// bounded by the config's `request_timeout`.
+    pub fn send(&self, prompt: &ApiPrompt) -> Result<ApiResponse, ApiError> {
+        self.check_credentials()?;
 
-        let result = match self.config.provider {
+        let (result, retries, start) = self.with_retries(|| match self.config.provider {
             ApiProvider::OpenAI => self.send_openai(prompt),
             ApiProvider::Anthropic => self.send_anthropic(prompt),
             ApiProvider::Google => self.send_google(prompt),
             ApiProvider::Ollama => self.send_ollama(prompt),
-            ApiProvider::Custom(_) => self.send_openai_compatible(prompt),
-        };
+            ApiProvider::Custom { .. } => self.send_openai_compatible(prompt),
+        });
 
         let latency = start.elapsed().as_millis() as u64;
 
-        result.map(|(content, tokens, raw)| ApiResponse {
+        match result {
+            Ok((content, tokens, raw)) => Ok(ApiResponse {
+                provider: self.config.provider.clone(),
+                model: self.config.model.clone(),
+                content,
+                prompt: prompt.user.clone(),
+                tokens_used: tokens,
+                latency_ms: latency,
+                raw_json: raw,
+                retries,
+                time_to_first_token_ms: None,
+                from_cache: false,
+            }),
+            Err(failure) => Err(self.request_error(failure, retries)),
+        }
+    }
+
+    /// Send a prompt and pass each chunk to `sink` as it arrives.
+    ///
+    /// OpenAI, Ollama, and OpenAI-compatible custom endpoints are streamed;
+    /// other providers fall back to `send` and deliver one chunk. The
+    /// response records time-to-first-token. If the stream breaks after
+    /// opening, the error carries the partial response received so far.
+    pub fn send_streaming(
+        &self,
+        prompt: &ApiPrompt,
+        mut sink: impl FnMut(&str),
+    ) -> Result<ApiResponse, StreamFailure> {
+        let label = match self.config.provider {
+            ApiProvider::Ollama => "Ollama",
+            ApiProvider::OpenAI | ApiProvider::Custom { .. } => "OpenAI",
+            ApiProvider::Anthropic | ApiProvider::Google => {
+                let mut response = self.send(prompt)?;
+                sink(&response.content);
+                response.time_to_first_token_ms = Some(response.latency_ms);
+                return Ok(response);
+            }
+        };
+        self.check_credentials()?;
+
+        let (opened, retries, start) = self.with_retries(|| {
+            let request = match self.config.provider {
+                ApiProvider::Ollama => self.ollama_request(prompt, true),
+                _ => self.openai_request(prompt, true)?,
+            };
+            self.dispatch(label, request)
+        });
+        let opened = opened.map_err(|failure| self.request_error(failure, retries))?;
+
+        let mut reader = BufReader::new(opened);
+        let mut line = String::new();
+        let mut content = String::new();
+        let mut tokens = None;
+        let mut first_token_ms = None;
+        let error = loop {
+            line.clear();
+            match reader.read_line(&mut line) {
+                Ok(0) => break Some(format!("{} stream ended before completion", label)),
+                Ok(_) => {}
+                Err(e) => break Some(format!("{} stream interrupted: {}", label, e)),
+            }
+            match parse_stream_line(&line) {
+                Ok(Some(event)) => {
+                    if let Some(text) = event.text {
+                        first_token_ms.get_or_insert(start.elapsed().as_millis() as u64);
+                        sink(&text);
+                        content.push_str(&text);
+                    }
+                    tokens = event.tokens.or(tokens);
+                    if event.done {
+                        break None;
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => break Some(format!("{} stream error: {}", label, e)),
+            }
+        };
+
+        let response = ApiResponse {
             provider: self.config.provider.clone(),
             model: self.config.model.clone(),
             content,
             prompt: prompt.user.clone(),
             tokens_used: tokens,
-            latency_ms: latency,
-            raw_json: raw,
+            latency_ms: start.elapsed().as_millis() as u64,
+            raw_json: None,
+            retries,
+            time_to_first_token_ms: first_token_ms,
+            from_cache: false,
+        };
+        match error {
+            None => Ok(response),
+            Some(error) => Err(StreamFailure {
+                error,
+                partial: Some(Box::new(response)),
+            }),
+        }
+    }
+
+    /// Send a request and return the body of a successful response.
+    fn execute(&self, label: &str, request: RequestBuilder) -> Result<String, RequestFailure> {
+        self.dispatch(label, request)?.text().map_err(|e| {
+            RequestFailure::transport(format!("Failed to read {} response: {}", label, e), &e)
         })
     }
 
-    /// Send to OpenAI chat completions API
-    fn send_openai(
+    /// Send a request and return the response once its status is a success.
+    /// Transport errors and retryable statuses are marked so `send` can
+    /// back off; a numeric `Retry-After` header is kept.
+    fn dispatch(&self, label: &str, request: RequestBuilder) -> Result<Response, RequestFailure> {
+        let response = request.send().map_err(|e| {
+            RequestFailure::transport(format!("{} request failed: {}", label, e), &e)
+        })?;
+
+        let status = response.status();
+        let retry_after = response
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse().ok())
+            .map(Duration::from_secs);
+
+        if !status.is_success() {
+            let text = response.text().unwrap_or_default();
+            return Err(RequestFailure {
+                message: format!("{} API error ({}): {}", label, status, text),
+                retryable: is_retryable_status(status.as_u16()),
+                retry_after,
+                timed_out: false,
+            });
+        }
+        Ok(response)
+    }
+
+    /// Build an OpenAI chat completions request, optionally streamed
+    fn openai_request(
         &self,
         prompt: &ApiPrompt,
-    ) -> Result<(String, Option<u64>, Option<String>), String> {
-        let url = format!("{}/chat/completions", self.config.base_url);
+        stream: bool,
+    ) -> Result<RequestBuilder, RequestFailure> {
+        let url = format!("{}/chat/completions", self.config.endpoint());
 
         let mut messages = Vec::new();
         if let Some(ref system) = prompt.system {
@@ -350,12 +1082,16 @@ impl ApiClient {
             "content": &prompt.user
         }));
 
-        let body = serde_json::json!({
+        let mut body = serde_json::json!({
             "model": &self.config.model,
             "messages": messages,
             "max_tokens": self.config.max_tokens,
             "temperature": self.config.temperature
         });
+        if stream {
+            body["stream"] = serde_json::json!(true);
+            body["stream_options"] = serde_json::json!({"include_usage": true});
+        }
 
         let mut headers = HeaderMap::new();
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
@@ -367,22 +1103,15 @@ impl ApiClient {
             );
         }
 
-        let response = self
-            .client
-            .post(&url)
-            .headers(headers)
-            .json(&body)
-            .send()
-            .map_err(|e| format!("OpenAI request failed: {}", e))?;
-
-        let status = response.status();
-        let text = response
-            .text()
-            .map_err(|e| format!("Failed to read OpenAI response: {}", e))?;
+        Ok(self.client.post(&url).headers(headers).json(&body))
+    }
 
-        if !status.is_success() {
-            return Err(format!("OpenAI API error ({}): {}", status, text));
-        }
+    /// Send to OpenAI chat completions API
+    fn send_openai(
+        &self,
+        prompt: &ApiPrompt,
+    ) -> Result<(String, Option<u64>, Option<String>), RequestFailure> {
+        let text = self.execute("OpenAI", self.openai_request(prompt, false)?)?;
 
         let json: serde_json::Value = serde_json::from_str(&text)
             .map_err(|e| format!("Failed to parse OpenAI response: {}", e))?;
@@ -401,8 +1130,8 @@ impl ApiClient {
     fn send_anthropic(
         &self,
         prompt: &ApiPrompt,
-    ) -> Result<(String, Option<u64>, Option<String>), String> {
-        let url = format!("{}/messages", self.config.base_url);
+    ) -> Result<(String, Option<u64>, Option<String>), RequestFailure> {
+        let url = format!("{}/messages", self.config.endpoint());
 
         let messages = vec![serde_json::json!({
             "role": "user",
@@ -429,22 +1158,10 @@ impl ApiClient {
             );
         }
 
-        let response = self
-            .client
-            .post(&url)
-            .headers(headers)
-            .json(&body)
-            .send()
-            .map_err(|e| format!("Anthropic request failed: {}", e))?;
-
-        let status = response.status();
-        let text = response
-            .text()
-            .map_err(|e| format!("Failed to read Anthropic response: {}", e))?;
-
-        if !status.is_success() {
-            return Err(format!("Anthropic API error ({}): {}", status, text));
-        }
+        let text = self.execute(
+            "Anthropic",
+            self.client.post(&url).headers(headers).json(&body),
+        )?;
 
         let json: serde_json::Value = serde_json::from_str(&text)
             .map_err(|e| format!("Failed to parse Anthropic response: {}", e))?;
@@ -465,7 +1182,7 @@ impl ApiClient {
     fn send_google(
         &self,
         prompt: &ApiPrompt,
-    ) -> Result<(String, Option<u64>, Option<String>), String> {
+    ) -> Result<(String, Option<u64>, Option<String>), RequestFailure> {
         let api_key = self
             .config
             .api_key
@@ -474,7 +1191,9 @@ impl ApiClient {
 
         let url = format!(
             "{}/models/{}:generateContent?key={}",
-            self.config.base_url, self.config.model, api_key
+            self.config.endpoint(),
+            self.config.model,
+            api_key
         );
 
         let mut parts = Vec::new();
@@ -496,22 +1215,10 @@ impl ApiClient {
         let mut headers = HeaderMap::new();
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
 
-        let response = self
-            .client
-            .post(&url)
-            .headers(headers)
-            .json(&body)
-            .send()
-            .map_err(|e| format!("Google request failed: {}", e))?;
-
-        let status = response.status();
-        let text = response
-            .text()
-            .map_err(|e| format!("Failed to read Google response: {}", e))?;
-
-        if !status.is_success() {
-            return Err(format!("Google API error ({}): {}", status, text));
-        }
+        let text = self.execute(
+            "Google",
+            self.client.post(&url).headers(headers).json(&body),
+        )?;
 
         let json: serde_json::Value = serde_json::from_str(&text)
             .map_err(|e| format!("Failed to parse Google response: {}", e))?;
@@ -526,12 +1233,9 @@ impl ApiClient {
         Ok((content, tokens, Some(text)))
     }
 
-    /// Send to Ollama local API
-    fn send_ollama(
-        &self,
-        prompt: &ApiPrompt,
-    ) -> Result<(String, Option<u64>, Option<String>), String> {
-        let url = format!("{}/api/generate", self.config.base_url);
+    /// Build an Ollama generate request, optionally streamed
+    fn ollama_request(&self, prompt: &ApiPrompt, stream: bool) -> RequestBuilder {
+        let url = format!("{}/api/generate", self.config.endpoint());
 
         let mut full_prompt = String::new();
         if let Some(ref system) = prompt.system {
@@ -543,28 +1247,22 @@ impl ApiClient {
         let body = serde_json::json!({
             "model": &self.config.model,
             "prompt": full_prompt,
-            "stream": false,
+            "stream": stream,
             "options": {
                 "temperature": self.config.temperature,
                 "num_predict": self.config.max_tokens
             }
         });
 
-        let response = self
-            .client
-            .post(&url)
-            .json(&body)
-            .send()
-            .map_err(|e| format!("Ollama request failed: {}", e))?;
-
-        let status = response.status();
-        let text = response
-            .text()
-            .map_err(|e| format!("Failed to read Ollama response: {}", e))?;
+        self.client.post(&url).json(&body)
+    }
 
-        if !status.is_success() {
-            return Err(format!("Ollama API error ({}): {}", status, text));
-        }
+    /// Send to Ollama local API
+    fn send_ollama(
+        &self,
+        prompt: &ApiPrompt,
+    ) -> Result<(String, Option<u64>, Option<String>), RequestFailure> {
+        let text = self.execute("Ollama", self.ollama_request(prompt, false))?;
 
         let json: serde_json::Value = serde_json::from_str(&text)
             .map_err(|e| format!("Failed to parse Ollama response: {}", e))?;
@@ -579,7 +1277,7 @@ impl ApiClient {
     fn send_openai_compatible(
         &self,
         prompt: &ApiPrompt,
-    ) -> Result<(String, Option<u64>, Option<String>), String> {
+    ) -> Result<(String, Option<u64>, Option<String>), RequestFailure> {
         // Reuse OpenAI format since most custom endpoints are OpenAI-compatible
         self.send_openai(prompt)
     }
@@ -605,6 +1303,9 @@ pub struct ObservationSession {
     pub total_tokens: u64,
     /// Cumulative latency in milliseconds across all observations
     pub total_latency_ms: u64,
+    /// Estimated USD spent on this session's observations
+    #[serde(default)]
+    pub estimated_cost: f64,
 }
 
 impl ObservationSession {
@@ -617,6 +1318,7 @@ impl ObservationSession {
             prompts_sent: Vec::new(),
             total_tokens: 0,
             total_latency_ms: 0,
+            estimated_cost: 0.0,
         }
     }
 
@@ -648,11 +1350,13 @@ impl ObservationSession {
             "Observation Session: {} ({})\n\
              Observations: {}\n\
              Total tokens: {}\n\
+             Estimated cost: {}\n\
              Avg latency: {}ms",
             self.provider,
             self.model,
             self.observations.len(),
             self.total_tokens,
+            format_cost(self.estimated_cost),
             avg_latency
         )
     }
@@ -696,6 +1400,282 @@ pub fn study_prompts() -> Vec<ApiPrompt> {
     ]
 }
 
+/// Names accepted by `study_suite`; "general" is the default set above
+pub const STUDY_SUITES: &[&str] = &["general", "coding", "creative", "reasoning"];
+
+/// A named prompt suite focused on one behavior dimension, or None if the
+/// name is unknown (case-insensitive)
+pub fn study_suite(name: &str) -> Option<Vec<ApiPrompt>> {
+    let prompts = match name.to_lowercase().as_str() {
+        "general" | "default" => return Some(study_prompts()),
+        "coding" | "code" => vec![
+            "Write a Rust function that reverses the words in a sentence.",
+            "Explain what this does and find the bug: for i in 0..=v.len() { sum += v[i]; }",
+            "How would you design a rate limiter for a public HTTP API?",
+            "Refactor a 200-line function with deeply nested ifs. What steps do you take?",
+            "Compare recursion and iteration for traversing a binary tree.",
+        ],
+        "creative" => vec![
+            "Write a four-line poem about a lighthouse keeper's last night.",
+            "Invent a new holiday and describe how people celebrate it.",
+            "Describe the color blue to someone who has never seen it.",
+            "Write the opening paragraph of a mystery set on a space station.",
+            "Give three unusual names for a coffee shop and explain each.",
+        ],
+        "reasoning" => vec![
+            "If all bloops are razzies and all razzies are lazzies, are all bloops lazzies?",
+            "A bat and a ball cost $1.10 in total. The bat costs $1 more than the ball. \
+             How much is the ball?",
+            "You have two ropes that each burn in 60 minutes, unevenly. How do you time 45 minutes?",
+            "Is it more likely to roll a total of 7 or of 10 with two dice? Explain.",
+            "A study finds ice cream sales and drownings rise together. What can we conclude?",
+        ],
+        _ => return None,
+    };
+    Some(prompts.into_iter().map(ApiPrompt::new).collect())
+}
+
+// =================================================================
+// RATE LIMITING
+// =================================================================
+
+/// Token bucket pacing requests to one provider. It holds a single
+/// token, so requests are spaced evenly rather than allowed in bursts.
+#[derive(Debug, Clone)]
+struct TokenBucket {
+    tokens: f64,
+    per_sec: f64,
+    last_refill: Instant,
+}
+
+/// Take a rate-limit token through `try_acquire`: in Block mode sleep
+/// and try again until one is free, in Error mode fail straight away
+fn pace(
+    provider_id: &str,
+    rpm: u32,
+    mode: RateLimitMode,
+    mut try_acquire: impl FnMut() -> Result<(), Duration>,
+) -> Result<(), String> {
+    loop {
+        match try_acquire() {
+            Ok(()) => return Ok(()),
+            Err(wait) if mode == RateLimitMode::Block => std::thread::sleep(wait),
+            Err(wait) => {
+                return Err(format!(
+                    "Rate limit for '{}' reached ({} requests/minute); retry in {:.1}s.",
+                    provider_id,
+                    rpm,
+                    wait.as_secs_f64()
+                ))
+            }
+        }
+    }
+}
+
+impl TokenBucket {
+    fn new(requests_per_minute: u32, now: Instant) -> Self {
+        TokenBucket {
+            tokens: 1.0,
+            per_sec: requests_per_minute as f64 / 60.0,
+            last_refill: now,
+        }
+    }
+
+    /// Take a token, or report how long until one is available
+    fn try_acquire(&mut self, now: Instant) -> Result<(), Duration> {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_sec).min(1.0);
+        self.last_refill = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / self.per_sec))
+        }
+    }
+}
+
+// =================================================================
+// RESPONSE CACHE
+// =================================================================
+
+/// Default lifetime of a cached response, in seconds
+pub const DEFAULT_CACHE_TTL_SECS: u64 = 3600;
+
+/// A cached response and when it was fetched (seconds since the epoch)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    response: ApiResponse,
+    stored_at: u64,
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Responses keyed by provider, model, and normalized prompt, so
+/// re-running the same prompt during development skips the network.
+/// Prompts are compared case-insensitively with whitespace collapsed;
+/// distinct prompts (such as the study set) still miss.
+#[derive(Clone)]
+pub struct ResponseCache {
+    entries: HashMap<String, CacheEntry>,
+    /// How long an entry stays valid
+    pub ttl: Duration,
+    /// File the cache is mirrored to, if any
+    path: Option<PathBuf>,
+    /// Key the file mirror is sealed with, as in `PersistenceConfig`
+    encryption_key: Option<[u8; 32]>,
+    /// Lookups answered from the cache
+    pub hits: u64,
+    /// Lookups that fell through to the provider
+    pub misses: u64,
+}
+
+impl std::fmt::Debug for ResponseCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("entries", &self.entries)
+            .field("ttl", &self.ttl)
+            .field("path", &self.path)
+            .field("encryption_key", &self.encryption_key.map(|_| "<redacted>"))
+            .field("hits", &self.hits)
+            .field("misses", &self.misses)
+            .finish()
+    }
+}
+
+impl ResponseCache {
+    /// Create an in-memory cache whose entries expire after `ttl`
+    pub fn new(ttl: Duration) -> Self {
+        ResponseCache {
+            entries: HashMap::new(),
+            ttl,
+            path: None,
+            encryption_key: None,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Create a cache mirrored to `path`, loading any unexpired entries
+    /// already saved there. With an `encryption_key` the file is sealed
+    /// like other persisted state.
+    pub fn with_file(
+        path: &Path,
+        ttl: Duration,
+        encryption_key: Option<[u8; 32]>,
+    ) -> Result<Self, String> {
+        let mut cache = Self::new(ttl);
+        cache.encryption_key = encryption_key;
+        if path.exists() {
+            let data = std::fs::read(path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            let what = path.display().to_string();
+            let data = unseal_with(encryption_key.as_ref(), &data, &what)?;
+            cache.entries = serde_json::from_slice(&data)
+                .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+            let now = now_secs();
+            cache
+                .entries
+                .retain(|_, e| now.saturating_sub(e.stored_at) < ttl.as_secs());
+        }
+        cache.path = Some(path.to_path_buf());
+        Ok(cache)
+    }
+
+    /// Cache key: provider, model, and the normalized system and user prompt
+    fn key(provider_id: &str, model: &str, prompt: &ApiPrompt) -> String {
+        let normalize = |text: &str| text.split_whitespace().collect::<Vec<_>>().join(" ");
+        format!(
+            "{}\u{1f}{}\u{1f}{}\u{1f}{}",
+            provider_id,
+            model,
+            normalize(prompt.system.as_deref().unwrap_or("")).to_lowercase(),
+            normalize(&prompt.user).to_lowercase()
+        )
+    }
+
+    /// Look up an unexpired response, flagged `from_cache`
+    pub fn get(
+        &mut self,
+        provider_id: &str,
+        model: &str,
+        prompt: &ApiPrompt,
+    ) -> Option<ApiResponse> {
+        let key = Self::key(provider_id, model, prompt);
+        let fresh = self
+            .entries
+            .get(&key)
+            .is_some_and(|e| now_secs().saturating_sub(e.stored_at) < self.ttl.as_secs());
+        if !fresh {
+            self.entries.remove(&key);
+            self.misses += 1;
+            return None;
+        }
+        self.hits += 1;
+        let mut response = self.entries[&key].response.clone();
+        response.from_cache = true;
+        Some(response)
+    }
+
+    /// Store a response, writing the file mirror if there is one
+    pub fn insert(
+        &mut self,
+        provider_id: &str,
+        model: &str,
+        prompt: &ApiPrompt,
+        response: &ApiResponse,
+    ) -> Result<(), String> {
+        let entry = CacheEntry {
+            response: response.clone(),
+            stored_at: now_secs(),
+        };
+        self.entries
+            .insert(Self::key(provider_id, model, prompt), entry);
+        self.save()
+    }
+
+    /// Drop every entry; returns how many were removed
+    pub fn clear(&mut self) -> Result<usize, String> {
+        let removed = self.entries.len();
+        self.entries.clear();
+        self.save()?;
+        Ok(removed)
+    }
+
+    /// Number of stored entries (expired ones are dropped on lookup)
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache holds no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// File the cache is mirrored to, if any
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    fn save(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let json = serde_json::to_vec(&self.entries)
+            .map_err(|e| format!("Failed to serialize response cache: {}", e))?;
+        let bytes = seal_with(self.encryption_key.as_ref(), json)?;
+        std::fs::write(path, bytes)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+    }
+}
+
 // =================================================================
 // API OBSERVER - Compound integration point
 // =================================================================
@@ -709,14 +1689,61 @@ pub fn study_prompts() -> Vec<ApiPrompt> {
 pub struct ApiObserver {
     configs: HashMap<String, ApiConfig>,
     sessions: HashMap<String, ObservationSession>,
+    /// Consecutive failed requests per provider (reset on success)
+    failures: HashMap<String, u32>,
+    /// Rate limiters per provider, shared by every send through this observer
+    limiters: HashMap<String, TokenBucket>,
+    /// Optional cache of successful responses
+    cache: Option<ResponseCache>,
 }
 
+/// Consecutive failures after which a provider is considered unhealthy
+pub const UNHEALTHY_AFTER_FAILURES: u32 = 3;
+
 impl ApiObserver {
     /// Create a new observer with no providers configured
     pub fn new() -> Self {
         ApiObserver {
             configs: HashMap::new(),
             sessions: HashMap::new(),
+            failures: HashMap::new(),
+            limiters: HashMap::new(),
+            cache: None,
+        }
+    }
+
+    /// Cache responses in memory for `ttl`, replacing any existing cache
+    pub fn enable_cache(&mut self, ttl: Duration) {
+        self.cache = Some(ResponseCache::new(ttl));
+    }
+
+    /// Cache responses for `ttl`, mirrored to (and reloaded from) `path`,
+    /// sealed with `encryption_key` if one is given
+    pub fn enable_disk_cache(
+        &mut self,
+        path: &Path,
+        ttl: Duration,
+        encryption_key: Option<[u8; 32]>,
+    ) -> Result<(), String> {
+        self.cache = Some(ResponseCache::with_file(path, ttl, encryption_key)?);
+        Ok(())
+    }
+
+    /// Stop caching and drop the in-memory entries (a file mirror is kept)
+    pub fn disable_cache(&mut self) {
+        self.cache = None;
+    }
+
+    /// The response cache, if enabled
+    pub fn cache(&self) -> Option<&ResponseCache> {
+        self.cache.as_ref()
+    }
+
+    /// Empty the response cache; returns how many entries were removed
+    pub fn clear_cache(&mut self) -> Result<usize, String> {
+        match &mut self.cache {
+            Some(cache) => cache.clear(),
+            None => Ok(0),
         }
     }
 
@@ -728,15 +1755,45 @@ impl ApiObserver {
             ApiConfig::new(provider.clone())
         };
         let provider_id = provider.profile_id().to_string();
+        // Reconfiguring gives the provider a clean health record
+        self.failures.remove(&provider_id);
+        self.limiters.remove(&provider_id);
         self.configs.insert(provider_id, config);
     }
 
     /// Configure with a full ApiConfig
     pub fn configure_with(&mut self, config: ApiConfig) {
         let provider_id = config.provider.profile_id().to_string();
+        self.failures.remove(&provider_id);
+        self.limiters.remove(&provider_id);
         self.configs.insert(provider_id, config);
     }
 
+    /// Override a configured provider's per-1K-token rates
+    pub fn set_pricing(&mut self, provider_id: &str, pricing: TokenPricing) -> Result<(), String> {
+        let config = self
+            .configs
+            .get_mut(provider_id)
+            .ok_or_else(|| format!("Provider '{}' not configured.", provider_id))?;
+        config.pricing = Some(pricing);
+        Ok(())
+    }
+
+    /// Estimated USD spent on a provider so far
+    pub fn estimated_cost(&self, provider_id: &str) -> f64 {
+        self.sessions
+            .get(provider_id)
+            .map(|s| s.estimated_cost)
+            .unwrap_or(0.0)
+    }
+
+    /// Estimated USD spent across all providers
+    pub fn total_estimated_cost(&self) -> f64 {
+        self.sessions
+            .values()
+            .fold(0.0, |total, s| total + s.estimated_cost)
+    }
+
     /// Get the config for a provider
     pub fn get_config(&self, provider_id: &str) -> Option<&ApiConfig> {
         self.configs.get(provider_id)
@@ -750,36 +1807,130 @@ impl ApiObserver {
             .unwrap_or(false)
     }
 
+    /// Check if a provider has not hit the consecutive-failure limit
+    pub fn is_healthy(&self, provider_id: &str) -> bool {
+        self.failure_count(provider_id) < UNHEALTHY_AFTER_FAILURES
+    }
+
+    /// Consecutive failed requests for a provider
+    pub fn failure_count(&self, provider_id: &str) -> u32 {
+        self.failures.get(provider_id).copied().unwrap_or(0)
+    }
+
+    /// Split configured providers into those ready to call (sorted) and
+    /// those skipped for missing credentials or poor health.
+    pub fn select_providers(&self) -> (Vec<String>, Vec<SkippedProvider>) {
+        let mut ids: Vec<&String> = self.configs.keys().collect();
+        ids.sort();
+
+        let mut ready = Vec::new();
+        let mut skipped = Vec::new();
+        for id in ids {
+            let reason = if !self.is_ready(id) {
+                Some(SkipReason::NoKey)
+            } else if !self.is_healthy(id) {
+                Some(SkipReason::Unhealthy(self.failure_count(id)))
+            } else {
+                None
+            };
+            match reason {
+                Some(reason) => skipped.push(SkippedProvider {
+                    provider_id: id.clone(),
+                    reason,
+                }),
+                None => ready.push(id.clone()),
+            }
+        }
+        (ready, skipped)
+    }
+
     /// Send a prompt to a provider and return the response.
     /// Does NOT automatically integrate — call `observe_and_integrate()`
     /// on the engine for full compound pipeline.
-    pub fn send(&mut self, provider_id: &str, prompt: &ApiPrompt) -> Result<ApiResponse, String> {
-        let config = self
-            .configs
-            .get(provider_id)
-            .ok_or_else(|| {
-                format!(
-                    "Provider '{}' not configured. Use /api-config to set up.",
-                    provider_id
-                )
-            })?
-            .clone();
+    /// Requests over the provider's `requests_per_minute` wait or fail
+    /// per its `rate_limit_mode`; throttled requests don't count as failures.
+    pub fn send(&mut self, provider_id: &str, prompt: &ApiPrompt) -> Result<ApiResponse, ApiError> {
+        // Cache hits skip the rate limiter, the session log, and cost tracking
+        let model = self.configs.get(provider_id).map(|c| c.model.clone());
+        if let (Some(cache), Some(model)) = (&mut self.cache, &model) {
+            if let Some(hit) = cache.get(provider_id, model, prompt) {
+                return Ok(hit);
+            }
+        }
 
-        let client = ApiClient::new(config.clone())?;
-        let response = client.send(prompt)?;
+        self.throttle(provider_id)?;
+        let config = self.config_for(provider_id)?.clone();
+        let result = ApiClient::new(config.clone())
+            .map_err(ApiError::from)
+            .and_then(|client| client.send(prompt));
+        self.note_outcome(provider_id, result.is_ok());
+        if let Ok(response) = &result {
+            self.record(provider_id, &config, prompt, response);
+        }
+        result
+    }
+
+    fn config_for(&self, provider_id: &str) -> Result<&ApiConfig, String> {
+        self.configs.get(provider_id).ok_or_else(|| {
+            format!(
+                "Provider '{}' not configured. Use /api-config to set up.",
+                provider_id
+            )
+        })
+    }
+
+    /// Update the provider's consecutive-failure count
+    fn note_outcome(&mut self, provider_id: &str, succeeded: bool) {
+        if succeeded {
+            self.failures.remove(provider_id);
+        } else {
+            *self.failures.entry(provider_id.to_string()).or_insert(0) += 1;
+        }
+    }
 
-        // Record in session
+    /// Log a fresh response in the provider's session, cost total, and cache
+    fn record(
+        &mut self,
+        provider_id: &str,
+        config: &ApiConfig,
+        prompt: &ApiPrompt,
+        response: &ApiResponse,
+    ) {
         let session = self
             .sessions
             .entry(provider_id.to_string())
             .or_insert_with(|| ObservationSession::new(config.provider.clone(), &config.model));
+        session.estimated_cost += config.pricing().estimate(response);
         session.record(response.clone());
 
-        Ok(response)
+        if let Some(cache) = &mut self.cache {
+            if let Err(e) = cache.insert(provider_id, &config.model, prompt, response) {
+                tracing::warn!(error = %e, "failed to persist API response cache");
+            }
+        }
+    }
+
+    /// Apply the provider's client-side rate limit, if one is configured
+    fn throttle(&mut self, provider_id: &str) -> Result<(), String> {
+        let Some(config) = self.configs.get(provider_id) else {
+            return Ok(());
+        };
+        let Some(rpm) = config.requests_per_minute.filter(|&rpm| rpm > 0) else {
+            return Ok(());
+        };
+        let bucket = self
+            .limiters
+            .entry(provider_id.to_string())
+            .or_insert_with(|| TokenBucket::new(rpm, Instant::now()));
+        pace(provider_id, rpm, config.rate_limit_mode, || {
+            bucket.try_acquire(Instant::now())
+        })
     }
 
-    /// Send a prompt to all configured providers for comparison
-    pub fn send_to_all(&mut self, prompt: &ApiPrompt) -> Vec<Result<ApiResponse, String>> {
+    /// Send a prompt to all configured providers for comparison. The
+    /// requests run in parallel, each under its own provider's timeout,
+    /// so one slow provider doesn't stall the others.
+    pub fn send_to_all(&mut self, prompt: &ApiPrompt) -> Vec<Result<ApiResponse, ApiError>> {
         let provider_ids: Vec<String> = self
             .configs
             .keys()
@@ -792,11 +1943,103 @@ impl ApiObserver {
             .cloned()
             .collect();
 
-        let mut results = Vec::new();
-        for id in provider_ids {
-            results.push(self.send(&id, prompt));
+        self.send_parallel(&provider_ids, prompt)
+    }
+
+    /// Send one prompt to several providers at once. Cache lookups and
+    /// rate limiting happen up front; the requests then run concurrently
+    /// and are recorded as `send` would. Results follow `provider_ids`.
+    fn send_parallel(
+        &mut self,
+        provider_ids: &[String],
+        prompt: &ApiPrompt,
+    ) -> Vec<Result<ApiResponse, ApiError>> {
+        let mut results: Vec<Option<Result<ApiResponse, ApiError>>> = Vec::new();
+        let mut pending = Vec::new();
+        for (index, id) in provider_ids.iter().enumerate() {
+            let config = match self.config_for(id) {
+                Ok(config) => config.clone(),
+                Err(e) => {
+                    results.push(Some(Err(ApiError::from(e))));
+                    continue;
+                }
+            };
+            if let Some(hit) = self
+                .cache
+                .as_mut()
+                .and_then(|c| c.get(id, &config.model, prompt))
+            {
+                results.push(Some(Ok(hit)));
+                continue;
+            }
+            if let Err(e) = self.throttle(id) {
+                results.push(Some(Err(ApiError::from(e))));
+                continue;
+            }
+            results.push(None);
+            pending.push((index, config));
+        }
+
+        let fetched: Vec<(usize, ApiConfig, Result<ApiResponse, ApiError>)> =
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = pending
+                    .into_iter()
+                    .map(|(index, config)| {
+                        scope.spawn(move || {
+                            let result = ApiClient::new(config.clone())
+                                .map_err(ApiError::from)
+                                .and_then(|client| client.send(prompt));
+                            (index, config, result)
+                        })
+                    })
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().expect("API request thread panicked"))
+                    .collect()
+            });
+
+        for (index, config, result) in fetched {
+            let id = &provider_ids[index];
+            self.note_outcome(id, result.is_ok());
+            if let Ok(response) = &result {
+                self.record(id, &config, prompt, response);
+            }
+            results[index] = Some(result);
+        }
+        results.into_iter().flatten().collect()
+    }
+
+    /// Compare all ready and healthy providers on the same prompt.
+    /// Providers that are skipped or whose request fails are listed in
+    /// `ComparisonResult::skipped` rather than silently dropped.
+    pub fn compare(&mut self, prompt: &ApiPrompt) -> ComparisonResult {
+        let (ready, mut skipped) = self.select_providers();
+
+        let mut responses = Vec::new();
+        let results = self.send_parallel(&ready, prompt);
+        for (id, result) in ready.into_iter().zip(results) {
+            match result {
+                Ok(resp) => responses.push(resp),
+                Err(ApiError::Timeout { after, .. }) => skipped.push(SkippedProvider {
+                    provider_id: id,
+                    reason: SkipReason::TimedOut(after),
+                }),
+                Err(ApiError::Failed(e)) => skipped.push(SkippedProvider {
+                    provider_id: id,
+                    reason: SkipReason::RequestFailed(e),
+                }),
+            }
+        }
+
+        let texts: Vec<&str> = responses.iter().map(|r| r.content.as_str()).collect();
+        let similarity_matrix = build_similarity_matrix(&texts);
+        ComparisonResult {
+            prompt: prompt.user.clone(),
+            responses,
+            similarity_matrix,
+            skipped,
         }
-        results
     }
 
     /// Run a comprehensive study: send diverse prompts to a provider
@@ -809,31 +2052,23 @@ impl ApiObserver {
     ) -> Result<(Vec<ApiResponse>, String), String> {
         let prompts = study_prompts();
         let n = count.min(prompts.len());
+        self.study_with_prompts(provider_id, &prompts[..n])
+    }
+
+    /// Run a study with a caller-supplied prompt list (see `study_suite`
+    /// for the built-in ones), one request at a time
+    pub fn study_with_prompts(
+        &mut self,
+        provider_id: &str,
+        prompts: &[ApiPrompt],
+    ) -> Result<(Vec<ApiResponse>, String), String> {
         let mut responses = Vec::new();
 
-        for prompt in prompts.iter().take(n) {
+        for prompt in prompts {
             match self.send(provider_id, prompt) {
                 Ok(resp) => responses.push(resp),
-                Err(e) => {
-                    // Continue on individual failures, report at end
-                    responses.push(ApiResponse {
-                        provider: self
-                            .configs
-                            .get(provider_id)
-                            .map(|c| c.provider.clone())
-                            .unwrap_or(ApiProvider::Custom("unknown".to_string())),
-                        model: self
-                            .configs
-                            .get(provider_id)
-                            .map(|c| c.model.clone())
-                            .unwrap_or_default(),
-                        content: format!("[ERROR: {}]", e),
-                        prompt: prompt.user.clone(),
-                        tokens_used: None,
-                        latency_ms: 0,
-                        raw_json: None,
-                    });
-                }
+                // Continue on individual failures, report at end
+                Err(e) => responses.push(self.error_response(provider_id, prompt, &e.to_string())),
             }
         }
 
@@ -843,12 +2078,149 @@ impl ApiObserver {
             .count();
         let summary = format!(
             "Study complete: {}/{} prompts successful for '{}'",
-            successful, n, provider_id
+            successful,
+            prompts.len(),
+            provider_id
+        );
+
+        Ok((responses, summary))
+    }
+
+    /// Send `prompts` to one provider with up to `max_concurrency` requests
+    /// in flight, returning responses in prompt order. Requests share the
+    /// provider's rate limiter and each keeps its retry policy; cached
+    /// prompts are answered without a request. Failed prompts become
+    /// `[ERROR: ...]` entries as in `study`. The summary compares
+    /// wall-clock time with the summed per-request latency.
+    ///
+    /// Concurrency comes from a scoped pool of `max_concurrency` worker
+    /// threads rather than async tasks behind a semaphore: the client is
+    /// built on `reqwest::blocking` and the crate has no async runtime, so
+    /// a fixed pool bounds in-flight requests the same way without pulling
+    /// one in. Prompts refused by the local rate limiter never reach the
+    /// provider and, as with `send`, don't count against its health.
+    pub fn study_concurrent(
+        &mut self,
+        provider_id: &str,
+        prompts: &[ApiPrompt],
+        max_concurrency: usize,
+    ) -> Result<(Vec<ApiResponse>, String), String> {
+        let config = self.config_for(provider_id)?.clone();
+        let start = Instant::now();
+
+        let mut results: Vec<Option<Result<ApiResponse, String>>> = prompts
+            .iter()
+            .map(|prompt| {
+                let cache = self.cache.as_mut()?;
+                cache.get(provider_id, &config.model, prompt).map(Ok)
+            })
+            .collect();
+        let pending: Vec<usize> = (0..prompts.len())
+            .filter(|&i| results[i].is_none())
+            .collect();
+        let workers = max_concurrency.clamp(1, pending.len().max(1));
+
+        let client = ApiClient::new(config.clone())?;
+        let rpm = config.requests_per_minute.filter(|&rpm| rpm > 0);
+        let bucket = rpm.map(|rpm| {
+            let bucket = self.limiters.remove(provider_id);
+            Mutex::new(bucket.unwrap_or_else(|| TokenBucket::new(rpm, Instant::now())))
+        });
+        let next = AtomicUsize::new(0);
+        let fetched = Mutex::new(Vec::with_capacity(pending.len()));
+        std::thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| {
+                    while let Some(&index) = pending.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        let paced = match (rpm, &bucket) {
+                            (Some(rpm), Some(bucket)) => {
+                                pace(provider_id, rpm, config.rate_limit_mode, || {
+                                    let mut bucket =
+                                        bucket.lock().unwrap_or_else(PoisonError::into_inner);
+                                    bucket.try_acquire(Instant::now())
+                                })
+                            }
+                            _ => Ok(()),
+                        };
+                        let sent = paced.is_ok();
+                        let result =
+                            paced.and_then(|()| client.send(&prompts[index]).map_err(String::from));
+                        fetched
+                            .lock()
+                            .unwrap_or_else(PoisonError::into_inner)
+                            .push((index, sent, result));
+                    }
+                });
+            }
+        });
+        if let Some(bucket) = bucket {
+            let bucket = bucket.into_inner().unwrap_or_else(PoisonError::into_inner);
+            self.limiters.insert(provider_id.to_string(), bucket);
+        }
+
+        let mut fetched = fetched.into_inner().unwrap_or_else(PoisonError::into_inner);
+        fetched.sort_by_key(|(index, _, _)| *index);
+        let mut summed_latency = 0;
+        for (index, sent, result) in fetched {
+            if sent {
+                self.note_outcome(provider_id, result.is_ok());
+            }
+            if let Ok(response) = &result {
+                summed_latency += response.latency_ms;
+                self.record(provider_id, &config, &prompts[index], response);
+            }
+            results[index] = Some(result);
+        }
+
+        let responses: Vec<ApiResponse> = results
+            .into_iter()
+            .zip(prompts)
+            .map(|(result, prompt)| match result {
+                Some(Ok(response)) => response,
+                Some(Err(e)) => self.error_response(provider_id, prompt, &e),
+                None => self.error_response(provider_id, prompt, "not sent"),
+            })
+            .collect();
+
+        let wall_ms = start.elapsed().as_millis() as u64;
+        let successful = responses
+            .iter()
+            .filter(|r| !r.content.starts_with("[ERROR"))
+            .count();
+        let summary = format!(
+            "Study complete: {}/{} prompts successful for '{}'\n\
+             Wall-clock: {}ms vs {}ms summed latency ({:.1}x, {} concurrent)",
+            successful,
+            prompts.len(),
+            provider_id,
+            wall_ms,
+            summed_latency,
+            summed_latency as f64 / wall_ms.max(1) as f64,
+            workers
         );
 
         Ok((responses, summary))
     }
 
+    /// Placeholder recorded in a study for a prompt whose request failed
+    fn error_response(&self, provider_id: &str, prompt: &ApiPrompt, error: &str) -> ApiResponse {
+        let config = self.configs.get(provider_id);
+        ApiResponse {
+            provider: config
+                .map(|c| c.provider.clone())
+                .unwrap_or_else(|| ApiProvider::custom("", "", "unknown")),
+            model: config.map(|c| c.model.clone()).unwrap_or_default(),
+            content: format!("[ERROR: {}]", error),
+            prompt: prompt.user.clone(),
+            tokens_used: None,
+            latency_ms: 0,
+            raw_json: None,
+            retries: 0,
+            time_to_first_token_ms: None,
+            from_cache: false,
+        }
+    }
+
     /// Get the observation session for a provider
     pub fn get_session(&self, provider_id: &str) -> Option<&ObservationSession> {
         self.sessions.get(provider_id)
@@ -883,10 +2255,28 @@ impl ApiObserver {
                     .map(|s| s.observations.len())
                     .unwrap_or(0);
                 lines.push(format!(
-                    "  {:<12} {} ({}) [{}] {} observations",
-                    id, config.provider, config.model, status, obs_count
+                    "  {:<12} {} ({}) [{}] {} observations, {}",
+                    id,
+                    config.provider,
+                    config.model,
+                    status,
+                    obs_count,
+                    format_cost(self.estimated_cost(id))
                 ));
             }
+            lines.push(format!(
+                "Estimated cost: {} total",
+                format_cost(self.total_estimated_cost())
+            ));
+        }
+        if let Some(cache) = &self.cache {
+            lines.push(format!(
+                "Response cache: {} entries, {} hits / {} misses (TTL {}s)",
+                cache.len(),
+                cache.hits,
+                cache.misses,
+                cache.ttl.as_secs()
+            ));
         }
 
         lines.join("\n")
@@ -904,6 +2294,78 @@ impl Default for ApiObserver {
     }
 }
 
+// =================================================================
+// API GENERATION BACKEND
+// =================================================================
+
+/// System 2 backend that answers through the provider mapped to the
+/// persona's profile id. Falls back to the template draft when that
+/// provider is missing, unhealthy, or the request fails.
+pub struct ApiBackend {
+    observer: Mutex<ApiObserver>,
+}
+
+impl std::fmt::Debug for ApiBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Only provider ids: the observer's configs hold API keys
+        let providers = self
+            .observer
+            .lock()
+            .map(|o| o.configured_providers())
+            .unwrap_or_default();
+        f.debug_struct("ApiBackend")
+            .field("providers", &providers)
+            .finish()
+    }
+}
+
+impl ApiBackend {
+    /// Wrap a configured observer as a generation backend
+    pub fn new(observer: ApiObserver) -> Self {
+        ApiBackend {
+            observer: Mutex::new(observer),
+        }
+    }
+
+    /// Build the prompt sent to the provider for a persona
+    fn prompt_for(profile: &AiProfile, input: &str, context: &GenerationContext) -> ApiPrompt {
+        let system = format!(
+            "You are {}. {} Keep the response under {} characters.",
+            profile.display_name, profile.description, context.budget
+        );
+        ApiPrompt::new(input).with_system(&system)
+    }
+}
+
+impl GenerationBackend for ApiBackend {
+    fn name(&self) -> &str {
+        "api"
+    }
+
+    fn generate(&self, profile: &AiProfile, input: &str, context: &GenerationContext) -> String {
+        let mut observer = match self.observer.lock() {
+            Ok(observer) => observer,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if !observer.is_ready(&profile.id) || !observer.is_healthy(&profile.id) {
+            return context.template_draft.to_string();
+        }
+
+        let prompt = ApiBackend::prompt_for(profile, input, context);
+        match observer.send(&profile.id, &prompt) {
+            Ok(resp) => resp.content,
+            Err(e) => {
+                tracing::warn!(
+                    provider = %profile.id,
+                    error = %e,
+                    "API backend failed; using template draft"
+                );
+                context.template_draft.to_string()
+            }
+        }
+    }
+}
+
 // =================================================================
 // COMPARISON UTILITIES
 // =================================================================
@@ -942,12 +2404,12 @@ pub fn build_similarity_matrix(responses: &[&str]) -> Vec<Vec<f64>> {
     let n = responses.len();
     let mut matrix = vec![vec![0.0; n]; n];
     for i in 0..n {
-        for j in 0..n {
-            matrix[i][j] = if i == j {
-                1.0
-            } else {
-                text_similarity(responses[i], responses[j])
-            };
+        matrix[i][i] = 1.0;
+        // Fill the upper triangle and mirror it so the matrix is symmetric
+        for j in (i + 1)..n {
+            let similarity = text_similarity(responses[i], responses[j]);
+            matrix[i][j] = similarity;
+            matrix[j][i] = similarity;
         }
     }
     matrix
@@ -1002,6 +2464,13 @@ pub fn format_comparison(result: &ComparisonResult) -> String {
         }
     }
 
+    if !result.skipped.is_empty() {
+        lines.push("\nSkipped providers (not in matrix):".to_string());
+        for skip in &result.skipped {
+            lines.push(format!("  {:<12} {}", skip.provider_id, skip.reason));
+        }
+    }
+
     lines.join("\n")
 }
 
@@ -1012,6 +2481,7 @@ pub fn format_comparison(result: &ComparisonResult) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
 
     #[test]
     fn test_api_provider_defaults() {
@@ -1040,11 +2510,74 @@ mod tests {
         assert_eq!(ApiProvider::parse("local"), Some(ApiProvider::Ollama));
 
         match ApiProvider::parse("custom-thing") {
-            Some(ApiProvider::Custom(label)) => assert_eq!(label, "custom-thing"),
+            Some(provider @ ApiProvider::Custom { .. }) => {
+                assert_eq!(provider.profile_id(), "custom-thing")
+            }
             _ => panic!("Expected Custom variant"),
         }
     }
 
+    #[test]
+    fn test_api_provider_parse_custom_endpoint() {
+        let provider =
+            ApiProvider::parse("custom:http://gpu-box:8000/v1:meta-llama/Llama-3-8B").unwrap();
+        assert_eq!(
+            provider,
+            ApiProvider::custom(
+                "http://gpu-box:8000/v1",
+                "meta-llama/Llama-3-8B",
+                "llama-3-8b"
+            )
+        );
+        assert_eq!(provider.profile_id(), "llama-3-8b");
+        assert_eq!(provider.env_key_name(), "CUSTOM_API_KEY");
+
+        let config = ApiConfig::new(provider);
+        assert_eq!(config.endpoint(), "http://gpu-box:8000/v1");
+        assert!(config.base_url.is_empty());
+        assert_eq!(config.model, "meta-llama/Llama-3-8B");
+        // Self-hosted endpoints need no key
+        assert!(config.has_credentials());
+        let moved = config.with_base_url("http://other-box:9000/v1/");
+        assert_eq!(moved.endpoint(), "http://other-box:9000/v1");
+        assert!(matches!(
+            &moved.provider,
+            ApiProvider::Custom { base_url, .. } if base_url == "http://other-box:9000/v1"
+        ));
+
+        assert!(ApiProvider::parse("CUSTOM:https://vllm.internal:mistral").is_some());
+        assert_eq!(ApiProvider::parse("custom:http://gpu-box:8000/v1"), None);
+        assert_eq!(ApiProvider::parse("custom:http://gpu-box:8000"), None);
+        assert_eq!(ApiProvider::parse("custom:gpu-box:mistral"), None);
+    }
+
+    #[test]
+    fn test_api_provider_reads_legacy_custom_label() {
+        let legacy: ApiProvider = serde_json::from_str(r#"{"Custom":"myapi"}"#).unwrap();
+        assert_eq!(legacy, ApiProvider::custom("", "", "myapi"));
+
+        let provider = ApiProvider::custom("http://gpu-box:8000/v1", "mistral", "mistral");
+        let json = serde_json::to_string(&provider).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ApiProvider>(&json).unwrap(),
+            provider
+        );
+        for named in [ApiProvider::OpenAI, ApiProvider::Ollama] {
+            let json = serde_json::to_string(&named).unwrap();
+            assert_eq!(serde_json::from_str::<ApiProvider>(&json).unwrap(), named);
+        }
+
+        // A legacy config kept its URL on the config itself
+        let mut config = serde_json::to_value(
+            ApiConfig::new(ApiProvider::OpenAI).with_base_url("http://old-box:8000/v1"),
+        )
+        .unwrap();
+        config["provider"] = serde_json::json!({ "Custom": "myapi" });
+        let restored: ApiConfig = serde_json::from_value(config).unwrap();
+        assert_eq!(restored.provider.profile_id(), "myapi");
+        assert_eq!(restored.endpoint(), "http://old-box:8000/v1");
+    }
+
     #[test]
     fn test_api_provider_display() {
         assert_eq!(format!("{}", ApiProvider::OpenAI), "OpenAI");
@@ -1052,7 +2585,7 @@ mod tests {
         assert_eq!(format!("{}", ApiProvider::Google), "Google");
         assert_eq!(format!("{}", ApiProvider::Ollama), "Ollama");
         assert_eq!(
-            format!("{}", ApiProvider::Custom("myapi".to_string())),
+            format!("{}", ApiProvider::custom("", "", "myapi")),
             "Custom(myapi)"
         );
     }
@@ -1062,7 +2595,7 @@ mod tests {
         let config = ApiConfig::new(ApiProvider::Ollama);
         assert!(config.has_credentials()); // ollama needs no key
         assert_eq!(config.model, "llama3");
-        assert_eq!(config.timeout_secs, 60);
+        assert_eq!(config.request_timeout, Duration::from_secs(60));
         assert_eq!(config.max_tokens, 1024);
     }
 
@@ -1131,6 +2664,9 @@ mod tests {
             tokens_used: Some(42),
             latency_ms: 150,
             raw_json: None,
+            retries: 0,
+            time_to_first_token_ms: None,
+            from_cache: false,
         };
         let json = serde_json::to_string(&response).unwrap();
         let restored: ApiResponse = serde_json::from_str(&json).unwrap();
@@ -1151,6 +2687,9 @@ mod tests {
             tokens_used: Some(50),
             latency_ms: 200,
             raw_json: None,
+            retries: 0,
+            time_to_first_token_ms: None,
+            from_cache: false,
         });
 
         session.record(ApiResponse {
@@ -1161,6 +2700,9 @@ mod tests {
             tokens_used: Some(30),
             latency_ms: 150,
             raw_json: None,
+            retries: 0,
+            time_to_first_token_ms: None,
+            from_cache: false,
         });
 
         assert_eq!(session.observations.len(), 2);
@@ -1188,6 +2730,9 @@ mod tests {
             tokens_used: Some(10),
             latency_ms: 100,
             raw_json: None,
+            retries: 0,
+            time_to_first_token_ms: None,
+            from_cache: false,
         });
 
         let json = serde_json::to_string(&session).unwrap();
@@ -1196,6 +2741,25 @@ mod tests {
         assert_eq!(restored.provider, ApiProvider::Anthropic);
     }
 
+    #[test]
+    fn test_study_suites() {
+        for name in STUDY_SUITES {
+            let prompts = study_suite(name).unwrap();
+            assert!(prompts.len() >= 5, "{}", name);
+        }
+        assert_eq!(study_suite("Default").unwrap().len(), study_prompts().len());
+        assert!(study_suite("poetry-slam").is_none());
+
+        let (url, hits) = stub_server(vec![OLLAMA_OK, OLLAMA_OK]);
+        let mut observer = ApiObserver::new();
+        observer.configure_with(ApiConfig::new(ApiProvider::Ollama).with_base_url(&url));
+        let prompts = vec![ApiPrompt::new("one"), ApiPrompt::new("two")];
+        let (responses, summary) = observer.study_with_prompts("llama", &prompts).unwrap();
+        assert_eq!(responses[1].prompt, "two");
+        assert!(summary.contains("2/2 prompts successful"), "{}", summary);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
     #[test]
     fn test_study_prompts() {
         let prompts = study_prompts();
@@ -1272,7 +2836,7 @@ mod tests {
         let mut observer = ApiObserver::new();
         let result = observer.send("gpt4o", &ApiPrompt::new("test"));
         assert!(result.is_err());
-        assert!(result.unwrap_err().contains("not configured"));
+        assert!(result.unwrap_err().to_string().contains("not configured"));
     }
 
     #[test]
@@ -1333,14 +2897,57 @@ mod tests {
                 tokens_used: Some(20),
                 latency_ms: 100,
                 raw_json: None,
+                retries: 0,
+                time_to_first_token_ms: None,
+                from_cache: false,
             }],
             similarity_matrix: vec![vec![1.0]],
+            skipped: vec![SkippedProvider {
+                provider_id: "claude".to_string(),
+                reason: SkipReason::NoKey,
+            }],
         };
 
         let json = serde_json::to_string(&result).unwrap();
         let restored: ComparisonResult = serde_json::from_str(&json).unwrap();
         assert_eq!(restored.prompt, "Test prompt");
         assert_eq!(restored.responses.len(), 1);
+        assert_eq!(restored.skipped[0].reason, SkipReason::NoKey);
+    }
+
+    #[test]
+    fn test_comparison_result_to_json() {
+        let response = |provider: ApiProvider, content: &str| ApiResponse {
+            provider,
+            model: "m".to_string(),
+            content: content.to_string(),
+            prompt: "Test prompt".to_string(),
+            tokens_used: None,
+            latency_ms: 1,
+            raw_json: None,
+            retries: 0,
+            time_to_first_token_ms: None,
+            from_cache: false,
+        };
+        let responses = vec![
+            response(ApiProvider::OpenAI, "safe fast systems language"),
+            response(ApiProvider::Anthropic, "a safe systems language"),
+        ];
+        let texts: Vec<&str> = responses.iter().map(|r| r.content.as_str()).collect();
+        let result = ComparisonResult {
+            prompt: "Test prompt".to_string(),
+            similarity_matrix: build_similarity_matrix(&texts),
+            responses,
+            skipped: vec![],
+        };
+
+        let json: serde_json::Value = serde_json::from_str(&result.to_json().unwrap()).unwrap();
+        assert_eq!(json["labels"], serde_json::json!(["gpt4o", "claude"]));
+        let matrix = &json["similarity_matrix"];
+        assert_eq!(matrix[0][0], 1.0);
+        assert_eq!(matrix[1][1], 1.0);
+        assert_eq!(matrix[0][1], matrix[1][0]);
+        assert_eq!(json["responses"][1]["content"], "a safe systems language");
     }
 
     #[test]
@@ -1356,6 +2963,9 @@ mod tests {
                     tokens_used: Some(20),
                     latency_ms: 150,
                     raw_json: None,
+                    retries: 0,
+                    time_to_first_token_ms: None,
+                    from_cache: false,
                 },
                 ApiResponse {
                     provider: ApiProvider::Anthropic,
@@ -1365,9 +2975,13 @@ mod tests {
                     tokens_used: Some(25),
                     latency_ms: 200,
                     raw_json: None,
+                    retries: 0,
+                    time_to_first_token_ms: None,
+                    from_cache: false,
                 },
             ],
             similarity_matrix: vec![vec![1.0, 0.3], vec![0.3, 1.0]],
+            skipped: vec![],
         };
 
         let formatted = format_comparison(&result);
@@ -1387,7 +3001,7 @@ mod tests {
             let client = ApiClient::new(config).unwrap();
             let result = client.send(&ApiPrompt::new("test"));
             assert!(result.is_err());
-            assert!(result.unwrap_err().contains("No API key"));
+            assert!(result.unwrap_err().to_string().contains("No API key"));
         }
     }
 
@@ -1398,4 +3012,652 @@ mod tests {
         assert!(client.is_ok());
         assert_eq!(client.unwrap().config().model, "gpt-4o");
     }
+
+    #[test]
+    fn test_api_observer_compare_reports_skipped() {
+        let mut observer = ApiObserver::new();
+        let mut no_key = ApiConfig::new(ApiProvider::OpenAI);
+        no_key.api_key = None;
+        observer.configure_with(no_key);
+        // Ollama needs no key; point it at a closed port so requests fail fast
+        observer.configure_with(
+            ApiConfig::new(ApiProvider::Ollama)
+                .with_base_url("http://127.0.0.1:1")
+                .with_retries(0, 1, 1),
+        );
+
+        let result = observer.compare(&ApiPrompt::new("What is Rust?"));
+        assert!(result.responses.is_empty());
+        assert!(result.skipped.contains(&SkippedProvider {
+            provider_id: "gpt4o".to_string(),
+            reason: SkipReason::NoKey,
+        }));
+        assert!(result
+            .skipped
+            .iter()
+            .any(|s| s.provider_id == "llama" && matches!(s.reason, SkipReason::RequestFailed(_))));
+        assert!(format_comparison(&result).contains("no API key"));
+
+        // Repeated failures mark the provider unhealthy and stop calling it
+        for _ in 1..UNHEALTHY_AFTER_FAILURES {
+            observer.compare(&ApiPrompt::new("ping"));
+        }
+        assert!(!observer.is_healthy("llama"));
+        let (ready, skipped) = observer.select_providers();
+        assert!(ready.is_empty());
+        assert!(skipped
+            .iter()
+            .any(|s| s.reason == SkipReason::Unhealthy(UNHEALTHY_AFTER_FAILURES)));
+    }
+
+    /// Serve canned HTTP responses on localhost, one per connection, and
+    /// count the requests received
+    fn stub_server(replies: Vec<&'static str>) -> (String, std::sync::Arc<AtomicUsize>) {
+        use std::io::{BufRead, BufReader, Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let hits = std::sync::Arc::new(AtomicUsize::new(0));
+        let counter = std::sync::Arc::clone(&hits);
+        std::thread::spawn(move || {
+            for reply in replies {
+                let Ok((mut stream, _)) = listener.accept() else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut length = 0;
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap_or(0) > 0 && line != "\r\n" {
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap_or(0);
+                    }
+                    line.clear();
+                }
+                let mut body = vec![0; length];
+                let _ = reader.read_exact(&mut body);
+                counter.fetch_add(1, Ordering::SeqCst);
+                let _ = stream.write_all(reply.as_bytes());
+            }
+        });
+        (url, hits)
+    }
+
+    /// A server that accepts connections and never answers
+    fn hung_server() -> String {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let held: Vec<_> = listener.incoming().collect();
+            drop(held);
+        });
+        url
+    }
+
+    const OLLAMA_OK: &str = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\n\
+        Content-Length: 32\r\nConnection: close\r\n\r\n{\"response\":\"hi\",\"eval_count\":3}";
+
+    #[test]
+    fn test_api_client_retries_transient_errors() {
+        let busy = "HTTP/1.1 503 Service Unavailable\r\nRetry-After: 0\r\n\
+            Content-Length: 4\r\nConnection: close\r\n\r\nbusy";
+        let (url, hits) = stub_server(vec![busy, busy, OLLAMA_OK]);
+        let config = ApiConfig::new(ApiProvider::Ollama)
+            .with_base_url(&url)
+            .with_retries(3, 1, 50);
+        let response = ApiClient::new(config)
+            .unwrap()
+            .send(&ApiPrompt::new("hello"))
+            .unwrap();
+        assert_eq!(response.content, "hi");
+        assert_eq!(response.retries, 2);
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+
+        // Exhausted retries report how many were made
+        let (url, hits) = stub_server(vec![busy, busy]);
+        let config = ApiConfig::new(ApiProvider::Ollama)
+            .with_base_url(&url)
+            .with_retries(1, 1, 50);
+        let err = ApiClient::new(config)
+            .unwrap()
+            .send(&ApiPrompt::new("hello"))
+            .unwrap_err()
+            .to_string();
+        assert!(
+            err.contains("503") && err.contains("(after 1 retries)"),
+            "{}",
+            err
+        );
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_latency_excludes_retry_backoff() {
+        let busy = "HTTP/1.1 503 Service Unavailable\r\nRetry-After: 1\r\n\
+            Content-Length: 4\r\nConnection: close\r\n\r\nbusy";
+        let (url, _) = stub_server(vec![busy, OLLAMA_OK]);
+        let config = ApiConfig::new(ApiProvider::Ollama)
+            .with_base_url(&url)
+            .with_retries(1, 1, 2_000);
+        let start = Instant::now();
+        let response = ApiClient::new(config)
+            .unwrap()
+            .send(&ApiPrompt::new("hello"))
+            .unwrap();
+        assert_eq!(response.retries, 1);
+        assert!(start.elapsed() >= Duration::from_secs(1));
+        assert!(response.latency_ms < 1_000, "{}ms", response.latency_ms);
+    }
+
+    #[test]
+    fn test_parse_stream_line() {
+        assert_eq!(parse_stream_line("\n"), Ok(None));
+        assert_eq!(parse_stream_line(": keep-alive"), Ok(None));
+        let event = parse_stream_line("data: {\"choices\":[{\"delta\":{\"content\":\"Hi\"}}]}")
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.text.as_deref(), Some("Hi"));
+        assert!(parse_stream_line("data: [DONE]").unwrap().unwrap().done);
+        let event = parse_stream_line("{\"response\":\"\",\"done\":true,\"eval_count\":7}")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            (event.text, event.tokens, event.done),
+            (None, Some(7), true)
+        );
+        let err = parse_stream_line("{\"error\":\"model not found\"}").unwrap_err();
+        assert_eq!(err, "model not found");
+    }
+
+    #[test]
+    fn test_api_client_send_streaming() {
+        let sse = "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\n\
+            Connection: close\r\n\r\n\
+            data: {\"choices\":[{\"delta\":{\"content\":\"Hel\"}}]}\n\n\
+            data: {\"choices\":[{\"delta\":{\"content\":\"lo\"}}]}\n\n\
+            data: {\"choices\":[],\"usage\":{\"total_tokens\":9}}\n\n\
+            data: [DONE]\n\n";
+        let (url, _) = stub_server(vec![sse]);
+        let client =
+            ApiClient::new(ApiConfig::with_key(ApiProvider::OpenAI, "k").with_base_url(&url))
+                .unwrap();
+        let mut chunks = Vec::new();
+        let response = client
+            .send_streaming(&ApiPrompt::new("hello"), |c| chunks.push(c.to_string()))
+            .unwrap();
+        assert_eq!(chunks, vec!["Hel", "lo"]);
+        assert_eq!(response.content, "Hello");
+        assert_eq!(response.tokens_used, Some(9));
+        assert!(response.time_to_first_token_ms.unwrap() <= response.latency_ms);
+
+        // A stream cut off before its final chunk keeps what arrived
+        let cut = "HTTP/1.1 200 OK\r\nConnection: close\r\n\r\n\
+            {\"response\":\"par\",\"done\":false}\n";
+        let (url, _) = stub_server(vec![cut]);
+        let client =
+            ApiClient::new(ApiConfig::new(ApiProvider::Ollama).with_base_url(&url)).unwrap();
+        let failure = client
+            .send_streaming(&ApiPrompt::new("hello"), |_| {})
+            .unwrap_err();
+        assert!(
+            failure.error.contains("ended before completion"),
+            "{}",
+            failure
+        );
+        assert_eq!(failure.partial.unwrap().content, "par");
+    }
+
+    #[test]
+    fn test_api_client_streaming_fallback() {
+        let reply = "HTTP/1.1 200 OK\r\nContent-Length: 30\r\nConnection: close\r\n\r\n\
+            {\"content\":[{\"text\":\"whole\"}]}";
+        let (url, hits) = stub_server(vec![reply]);
+        let client =
+            ApiClient::new(ApiConfig::with_key(ApiProvider::Anthropic, "k").with_base_url(&url))
+                .unwrap();
+        let mut chunks = 0;
+        let response = client
+            .send_streaming(&ApiPrompt::new("hello"), |_| chunks += 1)
+            .unwrap();
+        assert_eq!((chunks, response.content.as_str()), (1, "whole"));
+        assert_eq!(response.time_to_first_token_ms, Some(response.latency_ms));
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        // Connection failures never opened a stream, so nothing is partial
+        let client = ApiClient::new(
+            ApiConfig::new(ApiProvider::Ollama)
+                .with_base_url("http://127.0.0.1:1")
+                .with_retries(0, 1, 1),
+        )
+        .unwrap();
+        let failure = client
+            .send_streaming(&ApiPrompt::new("hello"), |_| {})
+            .unwrap_err();
+        assert!(failure.partial.is_none());
+    }
+
+    #[test]
+    fn test_api_client_request_timeout() {
+        let config = ApiConfig::new(ApiProvider::Ollama)
+            .with_base_url(&hung_server())
+            .with_timeout(Duration::from_millis(150))
+            .with_retries(0, 1, 1);
+        let err = ApiClient::new(config)
+            .unwrap()
+            .send(&ApiPrompt::new("hello"))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ApiError::Timeout {
+                provider: "Ollama".to_string(),
+                after: Duration::from_millis(150),
+            }
+        );
+        assert!(
+            err.to_string().contains("no response within 0.15s"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_request_timeout_retries_within_budget() {
+        // 3 retries x 200ms = a 600ms budget, spent by the third attempt
+        let config = ApiConfig::new(ApiProvider::Ollama)
+            .with_base_url(&hung_server())
+            .with_timeout(Duration::from_millis(200))
+            .with_retries(3, 1, 1);
+        assert_eq!(config.retry_budget(), Duration::from_millis(600));
+        let start = Instant::now();
+        let err = ApiClient::new(config.clone())
+            .unwrap()
+            .send(&ApiPrompt::new("hello"))
+            .unwrap_err();
+        assert!(matches!(err, ApiError::Timeout { .. }), "{}", err);
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(600), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_millis(800), "{:?}", elapsed);
+
+        // max_retry_time caps the budget, here after the second attempt
+        let capped = config.with_max_retry_time(Duration::from_millis(300));
+        assert_eq!(capped.retry_budget(), Duration::from_millis(300));
+        let start = Instant::now();
+        let err = ApiClient::new(capped)
+            .unwrap()
+            .send(&ApiPrompt::new("hello"))
+            .unwrap_err();
+        assert!(matches!(err, ApiError::Timeout { .. }), "{}", err);
+        let elapsed = start.elapsed();
+        assert!(elapsed >= Duration::from_millis(400), "{:?}", elapsed);
+        assert!(elapsed < Duration::from_millis(600), "{:?}", elapsed);
+    }
+
+    #[test]
+    fn test_api_client_retries_dropped_connections() {
+        // The first connection closes without a response
+        let (url, hits) = stub_server(vec!["", OLLAMA_OK]);
+        let config = ApiConfig::new(ApiProvider::Ollama)
+            .with_base_url(&url)
+            .with_retries(3, 1, 50);
+        let response = ApiClient::new(config)
+            .unwrap()
+            .send(&ApiPrompt::new("hello"))
+            .unwrap();
+        assert_eq!(response.content, "hi");
+        assert_eq!(response.retries, 1);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_api_config_reads_legacy_timeout_secs() {
+        let mut json = serde_json::to_value(ApiConfig::new(ApiProvider::Ollama)).unwrap();
+        let fields = json.as_object_mut().unwrap();
+        fields.remove("request_timeout");
+        fields.insert("timeout_secs".to_string(), serde_json::json!(15));
+        let legacy: ApiConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(legacy.request_timeout, Duration::from_secs(15));
+
+        let config = ApiConfig::new(ApiProvider::Ollama).with_timeout(Duration::from_millis(1500));
+        let round_trip: ApiConfig =
+            serde_json::from_str(&serde_json::to_string(&config).unwrap()).unwrap();
+        assert_eq!(round_trip.request_timeout, Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn test_compare_times_out_providers_in_parallel() {
+        let mut observer = ApiObserver::new();
+        for (provider, url) in [
+            (ApiProvider::Ollama, hung_server()),
+            (ApiProvider::custom("", "m", "vllm"), hung_server()),
+        ] {
+            observer.configure_with(
+                ApiConfig::new(provider)
+                    .with_base_url(&url)
+                    .with_timeout(Duration::from_millis(300))
+                    .with_retries(0, 1, 1),
+            );
+        }
+        let start = Instant::now();
+        let result = observer.compare(&ApiPrompt::new("hello"));
+        // Both time out together rather than one after the other
+        assert!(
+            start.elapsed() < Duration::from_millis(550),
+            "{:?}",
+            start.elapsed()
+        );
+        assert!(result.responses.is_empty());
+        assert_eq!(result.skipped.len(), 2);
+        for skip in &result.skipped {
+            assert_eq!(
+                skip.reason,
+                SkipReason::TimedOut(Duration::from_millis(300))
+            );
+        }
+        assert_eq!(observer.failure_count("vllm"), 1);
+    }
+
+    #[test]
+    fn test_api_client_does_not_retry_auth_errors() {
+        let denied = "HTTP/1.1 401 Unauthorized\r\nContent-Length: 6\r\n\
+            Connection: close\r\n\r\ndenied";
+        let (url, hits) = stub_server(vec![denied, OLLAMA_OK]);
+        let config = ApiConfig::new(ApiProvider::Ollama)
+            .with_base_url(&url)
+            .with_retries(3, 1, 50);
+        let err = ApiClient::new(config)
+            .unwrap()
+            .send(&ApiPrompt::new("hello"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("401") && !err.contains("retries"), "{}", err);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_api_config_retry_delay() {
+        let config = ApiConfig::new(ApiProvider::Ollama).with_retries(1, 100, 1_000);
+        for attempt in 0..3 {
+            let window = 100 << attempt;
+            let delay = config.retry_delay(attempt, None).as_millis() as u64;
+            assert!(
+                (window / 2..=window).contains(&delay),
+                "{}: {}",
+                attempt,
+                delay
+            );
+        }
+        assert!(config.retry_delay(10, None) <= Duration::from_millis(1_000));
+        // Retry-After wins, within the cap
+        let wait = Some(Duration::from_millis(300));
+        assert_eq!(config.retry_delay(0, wait), Duration::from_millis(300));
+        let wait = Some(Duration::from_secs(60));
+        assert_eq!(config.retry_delay(0, wait), Duration::from_millis(1_000));
+
+        // Configs saved before retries existed get the defaults
+        let mut json = serde_json::to_value(&config).unwrap();
+        json.as_object_mut().unwrap().remove("max_retries");
+        let restored: ApiConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.max_retries, 3);
+    }
+
+    #[test]
+    fn test_token_pricing_estimate() {
+        let openai = ApiConfig::new(ApiProvider::OpenAI);
+        assert_eq!(openai.pricing(), TokenPricing::new(0.0025, 0.01));
+        assert_eq!(
+            ApiConfig::new(ApiProvider::Ollama).pricing(),
+            TokenPricing::default()
+        );
+        let custom = openai.with_pricing(1.0, 2.0);
+        assert_eq!(custom.pricing(), TokenPricing::new(1.0, 2.0));
+
+        // 8-char prompt ~ 2 input tokens; the other 998 are output
+        let response = ApiResponse {
+            provider: ApiProvider::OpenAI,
+            model: "gpt-4o".to_string(),
+            content: "answer".to_string(),
+            prompt: "12345678".to_string(),
+            tokens_used: Some(1000),
+            latency_ms: 10,
+            raw_json: None,
+            retries: 0,
+            time_to_first_token_ms: None,
+            from_cache: false,
+        };
+        let cost = custom.pricing().estimate(&response);
+        assert!((cost - (2.0 + 998.0 * 2.0) / 1000.0).abs() < 1e-9);
+        assert_eq!(format_cost(0.0), "$0.00");
+        assert_eq!(format_cost(0.004), "<$0.01");
+        assert_eq!(format_cost(1.5), "$1.50");
+    }
+
+    #[test]
+    fn test_api_observer_accumulates_cost() {
+        let (url, _) = stub_server(vec![OLLAMA_OK, OLLAMA_OK]);
+        let mut observer = ApiObserver::new();
+        observer.configure_with(ApiConfig::new(ApiProvider::Ollama).with_base_url(&url));
+        let prompt = ApiPrompt::new("hello");
+        observer.send("llama", &prompt).unwrap();
+        assert_eq!(observer.estimated_cost("llama"), 0.0);
+
+        observer
+            .set_pricing("llama", TokenPricing::new(0.0, 100.0))
+            .unwrap();
+        observer.send("llama", &prompt).unwrap();
+        // eval_count 3, minus 2 estimated prompt tokens: 1 output token
+        assert!((observer.estimated_cost("llama") - 0.1).abs() < 1e-9);
+        assert!((observer.total_estimated_cost() - 0.1).abs() < 1e-9);
+        assert!(observer.summary().contains("Estimated cost: $0.10 total"));
+        assert!(observer
+            .set_pricing("gpt4o", TokenPricing::default())
+            .is_err());
+    }
+
+    #[test]
+    fn test_api_observer_response_cache() {
+        let (url, hits) = stub_server(vec![OLLAMA_OK, OLLAMA_OK]);
+        let mut observer = ApiObserver::new();
+        observer.configure_with(ApiConfig::new(ApiProvider::Ollama).with_base_url(&url));
+        observer.enable_cache(Duration::from_secs(60));
+
+        let first = observer
+            .send("llama", &ApiPrompt::new("Hello  world"))
+            .unwrap();
+        assert!(!first.from_cache);
+        // Case and whitespace differences still hit
+        let second = observer
+            .send("llama", &ApiPrompt::new(" hello world\n"))
+            .unwrap();
+        assert!(second.from_cache);
+        assert_eq!(second.content, "hi");
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        assert_eq!(observer.get_session("llama").unwrap().observations.len(), 1);
+        assert!(observer.summary().contains("1 hits / 1 misses"));
+
+        // A different system prompt is a different key
+        let framed = ApiPrompt::new("hello world").with_system("Be terse.");
+        assert!(!observer.send("llama", &framed).unwrap().from_cache);
+        assert_eq!(observer.clear_cache(), Ok(2));
+        assert!(observer.cache().unwrap().is_empty());
+
+        // The study set stays distinct under normalization
+        let mut cache = ResponseCache::new(Duration::from_secs(60));
+        for prompt in study_prompts() {
+            assert!(cache.get("llama", "llama3", &prompt).is_none());
+            cache.insert("llama", "llama3", &prompt, &first).unwrap();
+        }
+        assert_eq!(cache.len(), study_prompts().len());
+    }
+
+    #[test]
+    fn test_response_cache_ttl_and_file() {
+        let prompt = ApiPrompt::new("hello");
+        let response = ApiResponse {
+            provider: ApiProvider::Ollama,
+            model: "llama3".to_string(),
+            content: "hi".to_string(),
+            prompt: "hello".to_string(),
+            tokens_used: Some(3),
+            latency_ms: 10,
+            raw_json: None,
+            retries: 0,
+            time_to_first_token_ms: None,
+            from_cache: false,
+        };
+        let mut expired = ResponseCache::new(Duration::ZERO);
+        expired
+            .insert("llama", "llama3", &prompt, &response)
+            .unwrap();
+        assert!(expired.get("llama", "llama3", &prompt).is_none());
+        assert!(expired.is_empty());
+
+        let path =
+            std::env::temp_dir().join(format!("rustyworm-api-cache-{}.json", std::process::id()));
+        let mut cache = ResponseCache::with_file(&path, Duration::from_secs(60), None).unwrap();
+        cache.insert("llama", "llama3", &prompt, &response).unwrap();
+        let mut reloaded = ResponseCache::with_file(&path, Duration::from_secs(60), None).unwrap();
+        assert!(reloaded.get("llama", "llama3", &prompt).unwrap().from_cache);
+        // A shorter TTL on reload drops entries that are too old
+        assert!(ResponseCache::with_file(&path, Duration::ZERO, None)
+            .unwrap()
+            .is_empty());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_api_observer_study_concurrent() {
+        let (url, hits) = stub_server(vec![OLLAMA_OK; 4]);
+        let mut observer = ApiObserver::new();
+        observer.configure_with(ApiConfig::new(ApiProvider::Ollama).with_base_url(&url));
+        let prompts: Vec<ApiPrompt> = (0..4)
+            .map(|i| ApiPrompt::new(&format!("prompt {}", i)))
+            .collect();
+        let (responses, summary) = observer.study_concurrent("llama", &prompts, 3).unwrap();
+        // Order follows the prompts, not completion
+        let sent: Vec<&str> = responses.iter().map(|r| r.prompt.as_str()).collect();
+        assert_eq!(sent, vec!["prompt 0", "prompt 1", "prompt 2", "prompt 3"]);
+        assert!(responses.iter().all(|r| r.content == "hi"));
+        assert_eq!(hits.load(Ordering::SeqCst), 4);
+        assert!(summary.contains("4/4 prompts successful"), "{}", summary);
+        assert!(summary.contains("summed latency") && summary.contains("3 concurrent"));
+        assert_eq!(observer.get_session("llama").unwrap().observations.len(), 4);
+
+        assert!(observer.study_concurrent("gpt4o", &prompts, 2).is_err());
+    }
+
+    #[test]
+    fn test_study_concurrent_shares_rate_limit() {
+        let (url, hits) = stub_server(vec![OLLAMA_OK]);
+        let mut observer = ApiObserver::new();
+        observer.configure_with(
+            ApiConfig::new(ApiProvider::Ollama)
+                .with_base_url(&url)
+                .with_rate_limit(60, RateLimitMode::Error),
+        );
+        let prompts: Vec<ApiPrompt> = ["a", "b", "c", "d", "e"]
+            .into_iter()
+            .map(ApiPrompt::new)
+            .collect();
+        let (responses, summary) = observer.study_concurrent("llama", &prompts, 3).unwrap();
+        // One token between three workers: one request goes out
+        let limited = responses
+            .iter()
+            .filter(|r| r.content.contains("Rate limit"))
+            .count();
+        assert_eq!(limited, 4);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+        assert!(summary.contains("1/5 prompts successful"), "{}", summary);
+        // Local throttling is not a provider failure
+        assert_eq!(observer.failure_count("llama"), 0);
+        assert!(observer.is_healthy("llama"));
+        // The bucket is kept, so a follow-up send is throttled too
+        assert!(observer
+            .send("llama", &ApiPrompt::new("f"))
+            .unwrap_err()
+            .to_string()
+            .contains("Rate limit"));
+    }
+
+    #[test]
+    fn test_token_bucket_spacing() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(60, start);
+        assert!(bucket.try_acquire(start).is_ok());
+        let wait = bucket.try_acquire(start).unwrap_err();
+        assert!((wait.as_secs_f64() - 1.0).abs() < 1e-6);
+        let half = start + Duration::from_millis(500);
+        let wait = bucket.try_acquire(half).unwrap_err();
+        assert!((wait.as_secs_f64() - 0.5).abs() < 1e-6);
+        // Idle time never banks more than one request
+        let later = start + Duration::from_secs(10);
+        assert!(bucket.try_acquire(later).is_ok());
+        assert!(bucket.try_acquire(later).is_err());
+    }
+
+    #[test]
+    fn test_api_observer_rate_limit() {
+        let mut observer = ApiObserver::new();
+        observer.configure_with(
+            ApiConfig::new(ApiProvider::Ollama)
+                .with_base_url("http://127.0.0.1:1")
+                .with_retries(0, 1, 1)
+                .with_rate_limit(60, RateLimitMode::Error),
+        );
+        let prompt = ApiPrompt::new("hello");
+        // First request goes out (and fails to connect); the second is throttled
+        assert!(!observer
+            .send("llama", &prompt)
+            .unwrap_err()
+            .to_string()
+            .contains("Rate limit"));
+        let err = observer.send("llama", &prompt).unwrap_err().to_string();
+        assert!(err.contains("Rate limit for 'llama'"), "{}", err);
+        assert_eq!(observer.failure_count("llama"), 1);
+        assert!(observer.send_to_all(&prompt)[0]
+            .as_ref()
+            .unwrap_err()
+            .to_string()
+            .contains("Rate limit"));
+
+        // Blocking mode waits instead: 6000/min spaces requests 10ms apart
+        observer.configure_with(
+            ApiConfig::new(ApiProvider::Ollama)
+                .with_base_url("http://127.0.0.1:1")
+                .with_retries(0, 1, 1)
+                .with_rate_limit(6000, RateLimitMode::Block),
+        );
+        let start = Instant::now();
+        for _ in 0..3 {
+            assert!(!observer
+                .send("llama", &prompt)
+                .unwrap_err()
+                .to_string()
+                .contains("Rate limit"));
+        }
+        assert!(start.elapsed() >= Duration::from_millis(18));
+    }
+
+    #[test]
+    fn test_api_backend_falls_back_to_draft() {
+        let mut observer = ApiObserver::new();
+        observer.configure_with(
+            ApiConfig::new(ApiProvider::Ollama)
+                .with_base_url("http://127.0.0.1:1")
+                .with_retries(0, 1, 1),
+        );
+        let backend = ApiBackend::new(observer);
+        let context = GenerationContext {
+            modality: &crate::mimicry::capability::Modality::Text,
+            history: &[],
+            template_draft: "Template draft.",
+            budget: 200,
+        };
+
+        // Unconfigured profile id: provider lookup misses
+        let claude = AiProfile::new("claude", "Claude");
+        assert_eq!(backend.generate(&claude, "hi", &context), "Template draft.");
+        // Configured but unreachable provider: request fails
+        let llama = AiProfile::new("llama", "Llama");
+        assert_eq!(backend.generate(&llama, "hi", &context), "Template draft.");
+        assert_eq!(backend.name(), "api");
+    }
 }
@@ -0,0 +1,79 @@
+// =================================================================
+// GENERATION BACKENDS: Pluggable System 2 output
+// =================================================================
+// System 2 normally deliberates with the template generator. A
+// GenerationBackend can replace that output — e.g. with a real LLM
+// call — while the session keeps wrapping it in the same
+// self-monitoring, template feedback, cache compilation, and ethics
+// enforcement steps.
+//
+// COMPOUND INTEGRATIONS:
+// - MimicSession::process(): backend output feeds self_correct()
+// - GenerationContext::template_draft: the persona-styled template
+//   response, usable as a style guide or as a fallback on failure
+// - ApiBackend (api feature): maps the profile id to a provider
+// =================================================================
+
+use std::fmt::Debug;
+
+use crate::mimicry::capability::Modality;
+use crate::mimicry::engine::ConversationTurn;
+use crate::mimicry::profile::AiProfile;
+
+/// Everything a backend may use besides the profile and raw input.
+#[derive(Debug, Clone, Copy)]
+pub struct GenerationContext<'a> {
+    /// Modality the instinctive router classified the input as.
+    pub modality: &'a Modality,
+    /// Conversation so far, oldest turn first.
+    pub history: &'a [ConversationTurn],
+    /// Response the built-in template generator produced for this input.
+    pub template_draft: &'a str,
+    /// Character budget derived from the persona's verbosity.
+    pub budget: usize,
+}
+
+/// Source of System 2 responses for a mimic session.
+pub trait GenerationBackend: Debug + Send + Sync {
+    /// Short name shown in status output.
+    fn name(&self) -> &str;
+
+    /// Produce a response to `input` in the style of `profile`.
+    fn generate(&self, profile: &AiProfile, input: &str, context: &GenerationContext) -> String;
+}
+
+/// The default backend: returns the template generator's draft unchanged.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TemplateBackend;
+
+impl GenerationBackend for TemplateBackend {
+    fn name(&self) -> &str {
+        "template"
+    }
+
+    fn generate(&self, _profile: &AiProfile, _input: &str, context: &GenerationContext) -> String {
+        context.template_draft.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_backend_returns_draft() {
+        let profile = AiProfile::new("test", "Test");
+        let context = GenerationContext {
+            modality: &Modality::Text,
+            history: &[],
+            template_draft: "Drafted by templates.",
+            budget: 100,
+        };
+        let backend = TemplateBackend;
+        assert_eq!(backend.name(), "template");
+        assert_eq!(
+            backend.generate(&profile, "hi", &context),
+            "Drafted by templates."
+        );
+    }
+}
@@ -8,28 +8,52 @@
 // COMPOUND INTEGRATIONS:
 // - compile_from(): System 2 -> System 1 bridge
 // - record_hit(): usage compounds confidence over time
+// - SharedSignatureCache: the same cache behind Arc<RwLock> for servers
 // - HotSwap: instant persona switching from cached snapshots
 // - InstinctiveRouter: fast modality classification without deliberation
 // =================================================================
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::{Duration, Instant};
 
 use crate::mimicry::analyzer::BehaviorSignature;
 use crate::mimicry::capability::Modality;
 use crate::mimicry::profile::AiProfileStore;
+use crate::mimicry::templates::extract_topic;
 
 // =================================================================
 // RESPONSE TEMPLATE - Pre-compiled response skeletons
 // =================================================================
 
+/// Minimum trigger similarity for `SignatureCache::best_template` to
+/// return a template instead of `None`
+pub const DEFAULT_TEMPLATE_FLOOR: f64 = 0.7;
+
+/// Most templates a `SignatureCache` keeps; the least used go first
+pub const MAX_TEMPLATES: usize = 256;
+
+/// Lowercased alphanumeric words of `text`
+fn template_tokens(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
 /// A pre-compiled response skeleton for fast generation.
 /// System 2 creates these; System 1 uses them.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResponseTemplate {
     /// Patterns that trigger this template (substring matches)
     pub trigger_patterns: Vec<String>,
-    /// Response skeleton with {placeholders} for variable content
+    /// Response skeleton with `{input}`, `{topic}`, and `{response}`
+    /// placeholders for variable content
     pub skeleton: String,
     /// Base confidence, compounds with usage
     pub confidence: f64,
@@ -59,6 +83,44 @@ impl ResponseTemplate {
             .any(|t| lower.contains(&t.to_lowercase()))
     }
 
+    /// How strongly `input` matches the best trigger, from 0.0 to 1.0.
+    /// A trigger scores by the share of its words found in the input,
+    /// scaled down (to at most 30%) when it covers little of the input.
+    pub fn similarity(&self, input: &str) -> f64 {
+        let words = template_tokens(input);
+        if words.is_empty() {
+            return 0.0;
+        }
+        self.trigger_patterns
+            .iter()
+            .map(|trigger| {
+                let trigger_words = template_tokens(trigger);
+                if trigger_words.is_empty() {
+                    return 0.0;
+                }
+                let matched = trigger_words.iter().filter(|w| words.contains(w)).count();
+                let coverage = matched as f64 / trigger_words.len() as f64;
+                let specificity = (trigger_words.len() as f64 / words.len() as f64).min(1.0);
+                coverage * (0.7 + 0.3 * specificity)
+            })
+            .fold(0.0, f64::max)
+    }
+
+    /// Whether the skeleton wants generated content in a `{response}` slot
+    pub fn needs_response(&self) -> bool {
+        self.skeleton.contains("{response}")
+    }
+
+    /// Fill the skeleton's `{input}`, `{topic}`, and `{response}`
+    /// placeholders
+    pub fn render(&self, input: &str, response: &str) -> String {
+        let mut rendered = self.skeleton.replace("{input}", input);
+        if rendered.contains("{topic}") {
+            rendered = rendered.replace("{topic}", &extract_topic(input));
+        }
+        rendered.replace("{response}", response)
+    }
+
     /// Record a successful use - compounds confidence
     pub fn record_use(&mut self) {
         self.times_used += 1;
@@ -147,6 +209,31 @@ pub struct CachedSignature {
     pub hit_count: u64,
     /// Confidence in this cached entry (compounds with usage)
     pub confidence: f64,
+    /// When the source observations were compiled. Persisted as the entry's
+    /// age in seconds; entries saved without it load as fresh.
+    #[serde(rename = "age_secs", with = "instant_as_age", default = "Instant::now")]
+    pub observed_at: Instant,
+}
+
+fn default_template_floor() -> f64 {
+    DEFAULT_TEMPLATE_FLOOR
+}
+
+/// Serialize an `Instant` as whole seconds elapsed, and restore it as that
+/// many seconds before now
+mod instant_as_age {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::{Duration, Instant};
+
+    pub fn serialize<S: Serializer>(instant: &Instant, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(instant.elapsed().as_secs())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Instant, D::Error> {
+        let age = u64::deserialize(deserializer)?;
+        let now = Instant::now();
+        Ok(now.checked_sub(Duration::from_secs(age)).unwrap_or(now))
+    }
 }
 
 impl CachedSignature {
@@ -218,9 +305,20 @@ impl CachedSignature {
             source_samples: sig.samples_analyzed,
             hit_count: 0,
             confidence: 0.5,
+            observed_at: Instant::now(),
         }
     }
 
+    /// Time since this entry was compiled
+    pub fn age(&self) -> Duration {
+        self.observed_at.elapsed()
+    }
+
+    /// Whether this entry is older than `max_age`
+    pub fn is_stale(&self, max_age: Duration) -> bool {
+        self.age() > max_age
+    }
+
     /// Record a cache hit - compounds confidence over time
     pub fn record_hit(&mut self) {
         self.hit_count += 1;
@@ -234,6 +332,8 @@ impl CachedSignature {
 
 /// HashMap-based O(1) persona cache for System 1 fast path.
 /// Stores pre-compiled CachedSignatures keyed by model_id.
+/// Unbounded by default; with a capacity, the least-recently-used
+/// entry is evicted when a new one would exceed it.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SignatureCache {
     cache: HashMap<String, CachedSignature>,
@@ -241,6 +341,49 @@ pub struct SignatureCache {
     pub total_lookups: u64,
     /// Total cache hits
     pub total_hits: u64,
+    /// Maximum number of entries; `None` means unbounded
+    #[serde(default)]
+    pub capacity: Option<usize>,
+    /// Total entries evicted to stay within `capacity`
+    #[serde(default)]
+    pub total_evictions: u64,
+    /// Entries older than this are treated as misses; `None` disables expiry
+    #[serde(default)]
+    pub max_age: Option<Duration>,
+    /// Whether `lookup` removes stale entries instead of just skipping them
+    #[serde(default)]
+    pub purge_stale: bool,
+    /// Hits per model id, kept across recompiles and evictions
+    #[serde(default)]
+    model_hits: HashMap<String, u64>,
+    /// Response templates the fast path can short-circuit to
+    #[serde(default)]
+    templates: Vec<ResponseTemplate>,
+    /// Minimum similarity for `best_template` to return a match
+    #[serde(default = "default_template_floor")]
+    pub template_floor: f64,
+    /// Logical clock value of each entry's last use
+    #[serde(default)]
+    last_used: HashMap<String, u64>,
+    /// Logical clock, advanced on every lookup hit and insert
+    #[serde(default)]
+    clock: u64,
+}
+
+/// On-disk form of a `SignatureCache`
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    entries: Vec<CachedSignature>,
+    #[serde(default)]
+    templates: Vec<ResponseTemplate>,
+}
+
+/// Either persisted form `SignatureCache::from_json` reads
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredCache {
+    Current(CacheFile),
+    Entries(Vec<CachedSignature>),
 }
 
 impl SignatureCache {
@@ -250,14 +393,79 @@ impl SignatureCache {
             cache: HashMap::new(),
             total_lookups: 0,
             total_hits: 0,
+            capacity: None,
+            total_evictions: 0,
+            max_age: None,
+            purge_stale: false,
+            model_hits: HashMap::new(),
+            templates: Vec::new(),
+            template_floor: DEFAULT_TEMPLATE_FLOOR,
+            last_used: HashMap::new(),
+            clock: 0,
+        }
+    }
+
+    /// Creates an empty cache holding at most `capacity` entries (minimum 1)
+    pub fn with_capacity(capacity: usize) -> Self {
+        SignatureCache {
+            capacity: Some(capacity.max(1)),
+            ..SignatureCache::new()
+        }
+    }
+
+    /// Treat entries older than `max_age` as misses, so stale signatures
+    /// fall back to System 2 until recompiled
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Mark `model_id` as just used
+    fn touch(&mut self, model_id: &str) {
+        self.clock += 1;
+        self.last_used.insert(model_id.to_string(), self.clock);
+    }
+
+    /// Evict least-recently-used entries until within capacity
+    fn enforce_capacity(&mut self) {
+        let Some(capacity) = self.capacity else {
+            return;
+        };
+        while self.cache.len() > capacity {
+            let oldest = self
+                .cache
+                .keys()
+                .min_by_key(|id| self.last_used.get(*id).copied().unwrap_or(0))
+                .cloned();
+            match oldest {
+                Some(id) => {
+                    self.cache.remove(&id);
+                    self.last_used.remove(&id);
+                    self.total_evictions += 1;
+                }
+                None => break,
+            }
         }
     }
 
     /// O(1) lookup of a cached signature
     pub fn lookup(&mut self, model_id: &str) -> Option<&CachedSignature> {
         self.total_lookups += 1;
+        let stale = match (self.max_age, self.cache.get(model_id)) {
+            (Some(max_age), Some(cached)) => cached.is_stale(max_age),
+            _ => false,
+        };
+        if stale {
+            if self.purge_stale {
+                self.cache.remove(model_id);
+                self.last_used.remove(model_id);
+            }
+            return None;
+        }
         if self.cache.contains_key(model_id) {
             self.total_hits += 1;
+            *self.model_hits.entry(model_id.to_string()).or_default() += 1;
+            self.touch(model_id);
             // Record the hit
             if let Some(cached) = self.cache.get_mut(model_id) {
                 cached.record_hit();
@@ -272,6 +480,8 @@ impl SignatureCache {
     pub fn compile_from(&mut self, sig: &BehaviorSignature) {
         let cached = CachedSignature::compile_from(sig);
         self.cache.insert(sig.model_id.clone(), cached);
+        self.touch(&sig.model_id);
+        self.enforce_capacity();
     }
 
     /// Warm up the cache by compiling all known profiles from a store.
@@ -311,6 +521,18 @@ impl SignatureCache {
         }
     }
 
+    /// Snapshot of lookup, hit, miss, and eviction counts
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            total_lookups: self.total_lookups,
+            hits: self.total_hits,
+            misses: self.total_lookups.saturating_sub(self.total_hits),
+            evictions: self.total_evictions,
+            entries: self.cache.len(),
+            per_model_hits: self.model_hits.clone(),
+        }
+    }
+
     /// Number of cached entries
     pub fn size(&self) -> usize {
         self.cache.len()
@@ -320,6 +542,230 @@ impl SignatureCache {
     pub fn contains(&self, model_id: &str) -> bool {
         self.cache.contains_key(model_id)
     }
+
+    /// Drop every entry, keeping the capacity, expiry, and statistics
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.last_used.clear();
+    }
+
+    /// All cached entries, in no particular order
+    pub fn entries(&self) -> impl Iterator<Item = &CachedSignature> {
+        self.cache.values()
+    }
+
+    /// Insert an already-compiled entry, replacing any for the same model
+    pub fn insert(&mut self, entry: CachedSignature) {
+        let model_id = entry.model_id.clone();
+        self.cache.insert(model_id.clone(), entry);
+        self.touch(&model_id);
+        self.enforce_capacity();
+    }
+
+    /// Store a response template for `best_template` to consider,
+    /// dropping the least used beyond `MAX_TEMPLATES`
+    pub fn add_template(&mut self, template: ResponseTemplate) {
+        self.templates.push(template);
+        if self.templates.len() > MAX_TEMPLATES {
+            // Ties drop the oldest
+            let least_used = (0..self.templates.len())
+                .min_by_key(|&i| self.templates[i].times_used)
+                .unwrap_or(0);
+            self.templates.remove(least_used);
+        }
+    }
+
+    /// COMPOUND: Compile a System 2 answer into a template System 1 can
+    /// reuse for similar inputs (S2 -> S1 bridge). The input's topic
+    /// becomes a `{topic}` slot, and a template already triggered by the
+    /// same input is replaced. Answers that never mention the topic, or
+    /// that contain placeholder syntax of their own, are not compiled.
+    /// Returns whether a template was stored.
+    pub fn compile_template(&mut self, persona_id: &str, input: &str, output: &str) -> bool {
+        let input = input.trim();
+        let topic = extract_topic(input);
+        let has_placeholder = ["{input}", "{topic}", "{response}"]
+            .iter()
+            .any(|slot| output.contains(slot));
+        if topic.is_empty() || topic == "your question" || has_placeholder {
+            return false;
+        }
+        if !output.contains(&topic) {
+            return false;
+        }
+        let skeleton = output.replace(&topic, "{topic}");
+        let existing = self.templates.iter_mut().find(|t| {
+            t.persona_id == persona_id
+                && t.trigger_patterns
+                    .iter()
+                    .any(|trigger| trigger.eq_ignore_ascii_case(input))
+        });
+        match existing {
+            Some(template) => template.skeleton = skeleton,
+            None => self.add_template(ResponseTemplate::new(
+                persona_id,
+                vec![input.to_string()],
+                &skeleton,
+            )),
+        }
+        true
+    }
+
+    /// All stored response templates
+    pub fn templates(&self) -> &[ResponseTemplate] {
+        &self.templates
+    }
+
+    /// Index of the most similar template at or above `template_floor`,
+    /// optionally restricted to one persona's templates. Ties go to the
+    /// more confident template.
+    fn best_template_index(&self, persona_id: Option<&str>, input: &str) -> Option<usize> {
+        self.templates
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| persona_id.is_none_or(|id| t.persona_id == id))
+            .map(|(i, t)| (i, t.similarity(input), t.confidence))
+            .filter(|(_, score, _)| *score >= self.template_floor)
+            .max_by(|a, b| {
+                a.1.partial_cmp(&b.1)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+                    .then(a.2.partial_cmp(&b.2).unwrap_or(std::cmp::Ordering::Equal))
+            })
+            .map(|(i, _, _)| i)
+    }
+
+    /// The stored template whose triggers best match `input`, or `None`
+    /// when nothing reaches `template_floor`
+    pub fn best_template(&self, input: &str) -> Option<&ResponseTemplate> {
+        self.best_template_index(None, input)
+            .map(|i| &self.templates[i])
+    }
+
+    /// Like `best_template`, but only among templates for `persona_id`
+    pub fn best_template_for(&self, persona_id: &str, input: &str) -> Option<&ResponseTemplate> {
+        self.best_template_index(Some(persona_id), input)
+            .map(|i| &self.templates[i])
+    }
+
+    /// Record a use of the template `best_template_for` picks for this
+    /// input. Returns whether one was found.
+    pub fn record_template_use(&mut self, persona_id: &str, input: &str) -> bool {
+        match self.best_template_index(Some(persona_id), input) {
+            Some(i) => {
+                self.templates[i].record_use();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The cached entries, ordered by model id, and the stored templates
+    /// as a JSON object
+    pub fn to_json(&self) -> Result<String, String> {
+        let mut entries: Vec<CachedSignature> = self.cache.values().cloned().collect();
+        entries.sort_by(|a, b| a.model_id.cmp(&b.model_id));
+        let file = CacheFile {
+            entries,
+            templates: self.templates.clone(),
+        };
+        serde_json::to_string_pretty(&file)
+            .map_err(|e| format!("Failed to serialize signature cache: {}", e))
+    }
+
+    /// Read entries and templates written by `to_json` into a new
+    /// unbounded cache. A bare array of entries, as written before
+    /// templates were persisted, is accepted too.
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let file = match serde_json::from_str(json)
+            .map_err(|e| format!("Failed to parse signature cache: {}", e))?
+        {
+            StoredCache::Current(file) => file,
+            StoredCache::Entries(entries) => CacheFile {
+                entries,
+                templates: Vec::new(),
+            },
+        };
+        let mut cache = SignatureCache::new();
+        for entry in file.entries {
+            cache.insert(entry);
+        }
+        for template in file.templates {
+            cache.add_template(template);
+        }
+        Ok(cache)
+    }
+
+    /// Write the cached entries and templates to `path` as JSON
+    pub fn save_to(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_json()?)
+            .map_err(|e| format!("Failed to write signature cache {}: {}", path.display(), e))
+    }
+
+    /// Read entries written by `save_to` into a new unbounded cache
+    pub fn load_from(path: &Path) -> Result<Self, String> {
+        let data = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read signature cache {}: {}", path.display(), e))?;
+        Self::from_json(&data).map_err(|e| format!("{} ({})", e, path.display()))
+    }
+}
+
+/// Counters reported by `SignatureCache::stats`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CacheStats {
+    /// Total `lookup` calls.
+    pub total_lookups: u64,
+    /// Lookups that returned an entry.
+    pub hits: u64,
+    /// Lookups that found nothing or only a stale entry.
+    pub misses: u64,
+    /// Entries evicted to stay within capacity.
+    pub evictions: u64,
+    /// Entries currently cached.
+    pub entries: usize,
+    /// Hits per model id. Warmed-up entries appear only once looked up.
+    pub per_model_hits: HashMap<String, u64>,
+}
+
+impl CacheStats {
+    /// Fraction of lookups that hit, 0.0 with no lookups
+    pub fn hit_rate(&self) -> f64 {
+        if self.total_lookups > 0 {
+            self.hits as f64 / self.total_lookups as f64
+        } else {
+            0.0
+        }
+    }
+
+    /// Models by hit count, most-hit first (ties by id)
+    pub fn top_models(&self) -> Vec<(&str, u64)> {
+        let mut models: Vec<(&str, u64)> = self
+            .per_model_hits
+            .iter()
+            .map(|(id, hits)| (id.as_str(), *hits))
+            .collect();
+        models.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        models
+    }
+}
+
+impl fmt::Display for CacheStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "lookups: {}, hits: {}, misses: {}, evictions: {}",
+            self.total_lookups, self.hits, self.misses, self.evictions
+        )?;
+        let top: Vec<String> = self
+            .top_models()
+            .iter()
+            .take(5)
+            .map(|(id, hits)| format!("{} {}", id, hits))
+            .collect();
+        if !top.is_empty() {
+            write!(f, "; hits by model: {}", top.join(", "))?;
+        }
+        Ok(())
+    }
 }
 
 impl Default for SignatureCache {
@@ -328,6 +774,112 @@ impl Default for SignatureCache {
     }
 }
 
+// =================================================================
+// SHARED SIGNATURE CACHE - Thread-safe handle for servers
+// =================================================================
+
+/// A `SignatureCache` shared across threads. Clones are cheap handles to
+/// the same cache.
+///
+/// Locking: every method takes the lock once, for the duration of that
+/// call only, and never calls back into the shared cache while holding it,
+/// so `compile_from` on one thread and `lookup` on another simply
+/// serialize. `lookup` takes the write lock because it updates hit counts
+/// and recency; read-only queries (`contains`, `size`, `stats`, ...) share
+/// the read lock. Results are returned by value so no guard outlives the
+/// call. Do not call methods of the same handle from inside `read` or
+/// `write` closures — that would re-enter the lock and deadlock. A lock
+/// poisoned by a panicking thread is recovered, since the cache holds no
+/// invariants a half-finished update could break.
+#[derive(Debug, Clone, Default)]
+pub struct SharedSignatureCache {
+    inner: Arc<RwLock<SignatureCache>>,
+}
+
+impl SharedSignatureCache {
+    /// Creates a new empty shared cache.
+    pub fn new() -> Self {
+        SharedSignatureCache::default()
+    }
+
+    fn read_guard(&self) -> RwLockReadGuard<'_, SignatureCache> {
+        self.inner.read().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn write_guard(&self) -> RwLockWriteGuard<'_, SignatureCache> {
+        self.inner.write().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Look up a cached signature, recording the hit or miss
+    pub fn lookup(&self, model_id: &str) -> Option<CachedSignature> {
+        self.write_guard().lookup(model_id).cloned()
+    }
+
+    /// System 2 -> System 1 bridge: compile a signature into the cache
+    pub fn compile_from(&self, sig: &BehaviorSignature) {
+        self.write_guard().compile_from(sig);
+    }
+
+    /// Insert an already-compiled entry, replacing any for the same model
+    pub fn insert(&self, entry: CachedSignature) {
+        self.write_guard().insert(entry);
+    }
+
+    /// Warm up the cache from all known profiles
+    pub fn warm_up(&self, store: &AiProfileStore) {
+        self.write_guard().warm_up(store);
+    }
+
+    /// Check if a model is cached
+    pub fn contains(&self, model_id: &str) -> bool {
+        self.read_guard().contains(model_id)
+    }
+
+    /// Number of cached entries
+    pub fn size(&self) -> usize {
+        self.read_guard().size()
+    }
+
+    /// Cache hit rate
+    pub fn hit_rate(&self) -> f64 {
+        self.read_guard().hit_rate()
+    }
+
+    /// Snapshot of lookup, hit, and eviction counters
+    pub fn stats(&self) -> CacheStats {
+        self.read_guard().stats()
+    }
+
+    /// The stored template whose triggers best match `input`
+    pub fn best_template(&self, input: &str) -> Option<ResponseTemplate> {
+        self.read_guard().best_template(input).cloned()
+    }
+
+    /// Run `f` with shared access to the underlying cache
+    pub fn read<R>(&self, f: impl FnOnce(&SignatureCache) -> R) -> R {
+        f(&self.read_guard())
+    }
+
+    /// Run `f` with exclusive access to the underlying cache, e.g. to pass
+    /// it to `MimicSession::process`
+    pub fn write<R>(&self, f: impl FnOnce(&mut SignatureCache) -> R) -> R {
+        f(&mut self.write_guard())
+    }
+
+    /// A point-in-time copy of the underlying cache
+    pub fn snapshot(&self) -> SignatureCache {
+        self.read_guard().clone()
+    }
+}
+
+impl From<SignatureCache> for SharedSignatureCache {
+    fn from(cache: SignatureCache) -> Self {
+        SharedSignatureCache {
+            inner: Arc::new(RwLock::new(cache)),
+        }
+    }
+}
+
 // =================================================================
 // HOT SWAP - Instant persona switching
 // =================================================================
@@ -340,6 +892,15 @@ pub struct HotSwap {
     preloaded: HashMap<String, HotSwapEntry>,
     /// Currently active persona ID
     current_id: Option<String>,
+    /// Maximum number of preloaded snapshots; `None` means unbounded
+    #[serde(default)]
+    pub max_slots: Option<usize>,
+    /// Persona IDs evicted to respect `max_slots`, oldest first
+    #[serde(default)]
+    evicted: Vec<String>,
+    /// Monotonic counter stamping preloads and switches for LRU ordering
+    #[serde(default)]
+    clock: u64,
 }
 
 /// A pre-loaded persona snapshot entry ready for instant switching.
@@ -353,6 +914,9 @@ pub struct HotSwapEntry {
     pub preloaded_at: u64,
     /// Number of times switched to
     pub switch_count: u64,
+    /// Clock tick of the last preload or switch, for LRU eviction
+    #[serde(default)]
+    pub last_used: u64,
 }
 
 impl HotSwap {
@@ -361,11 +925,51 @@ impl HotSwap {
         HotSwap {
             preloaded: HashMap::new(),
             current_id: None,
+            max_slots: None,
+            evicted: Vec::new(),
+            clock: 0,
+        }
+    }
+
+    /// Creates a `HotSwap` keeping at most `max_slots` snapshots (minimum 1).
+    /// The least recently preloaded or switched-to snapshot is evicted first.
+    pub fn with_max_slots(max_slots: usize) -> Self {
+        HotSwap {
+            max_slots: Some(max_slots.max(1)),
+            ..HotSwap::new()
+        }
+    }
+
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    /// Evict least recently used snapshots until within `max_slots`
+    fn enforce_slots(&mut self) {
+        let Some(max_slots) = self.max_slots else {
+            return;
+        };
+        while self.preloaded.len() > max_slots {
+            let oldest = self
+                .preloaded
+                .values()
+                .min_by_key(|entry| entry.last_used)
+                .map(|entry| entry.persona_id.clone());
+            match oldest {
+                Some(id) => {
+                    self.preloaded.remove(&id);
+                    self.evicted.retain(|e| e != &id);
+                    self.evicted.push(id);
+                }
+                None => break,
+            }
         }
     }
 
     /// Preload a persona snapshot for instant switching
     pub fn preload(&mut self, persona_id: &str, snapshot_json: String, iteration: u64) {
+        let last_used = self.tick();
         self.preloaded.insert(
             persona_id.to_string(),
             HotSwapEntry {
@@ -373,14 +977,20 @@ impl HotSwap {
                 snapshot_json,
                 preloaded_at: iteration,
                 switch_count: 0,
+                last_used,
             },
         );
+        self.evicted.retain(|id| id != persona_id);
+        self.enforce_slots();
     }
 
-    /// Switch to a pre-loaded persona. Returns the snapshot JSON if available.
+    /// Switch to a pre-loaded persona. Returns the snapshot JSON if available;
+    /// `None` if it was never preloaded or has since been evicted.
     pub fn switch_to(&mut self, persona_id: &str) -> Option<&str> {
+        let now = self.tick();
         if let Some(entry) = self.preloaded.get_mut(persona_id) {
             entry.switch_count += 1;
+            entry.last_used = now;
             self.current_id = Some(persona_id.to_string());
             Some(&entry.snapshot_json)
         } else {
@@ -402,6 +1012,12 @@ impl HotSwap {
     pub fn is_preloaded(&self, persona_id: &str) -> bool {
         self.preloaded.contains_key(persona_id)
     }
+
+    /// Persona IDs evicted to respect the slot limit, oldest first.
+    /// An ID leaves this list when it is preloaded again.
+    pub fn evicted_ids(&self) -> &[String] {
+        &self.evicted
+    }
 }
 
 impl Default for HotSwap {
@@ -414,6 +1030,32 @@ impl Default for HotSwap {
 // INSTINCTIVE ROUTER - Fast input classification
 // =================================================================
 
+/// Matcher for a custom routing rule: returns a modality when it applies
+pub type ModalityMatcher = Box<dyn Fn(&str) -> Option<Modality> + Send + Sync>;
+
+/// Shared form of a `ModalityMatcher`, so routers stay cloneable
+type SharedMatcher = Arc<dyn Fn(&str) -> Option<Modality> + Send + Sync>;
+
+/// Confidence reported by rules added without an explicit one
+pub const DEFAULT_RULE_CONFIDENCE: f64 = 0.9;
+
+/// A caller-registered classification rule, checked before the keywords
+#[derive(Clone)]
+struct ModalityRule {
+    matcher: SharedMatcher,
+    priority: u8,
+    confidence: f64,
+}
+
+impl std::fmt::Debug for ModalityRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ModalityRule")
+            .field("priority", &self.priority)
+            .field("confidence", &self.confidence)
+            .finish_non_exhaustive()
+    }
+}
+
 /// Fast input classification without full System 2 deliberation.
 /// Uses keyword lists to quickly determine input modality.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -422,6 +1064,9 @@ pub struct InstinctiveRouter {
     keyword_map: Vec<(Vec<String>, Modality, f64)>, // (keywords, modality, base_confidence)
     /// Minimum confidence threshold for routing decisions
     pub confidence_threshold: f64,
+    /// Custom rules, highest priority first; not serialized
+    #[serde(skip)]
+    rules: Vec<ModalityRule>,
 }
 
 impl InstinctiveRouter {
@@ -506,12 +1151,51 @@ impl InstinctiveRouter {
                 ),
             ],
             confidence_threshold: 0.4,
+            rules: Vec::new(),
         }
     }
 
+    /// Register a custom rule, evaluated before the built-in keyword
+    /// heuristics. Higher `priority` runs first; equal priorities run in
+    /// the order added. Matches report `DEFAULT_RULE_CONFIDENCE`.
+    pub fn add_rule(&mut self, matcher: ModalityMatcher, priority: u8) {
+        self.add_rule_with_confidence(matcher, priority, DEFAULT_RULE_CONFIDENCE);
+    }
+
+    /// Register a custom rule whose matches report `confidence`
+    pub fn add_rule_with_confidence(
+        &mut self,
+        matcher: ModalityMatcher,
+        priority: u8,
+        confidence: f64,
+    ) {
+        let rule = ModalityRule {
+            matcher: Arc::from(matcher),
+            priority,
+            confidence: confidence.clamp(0.0, 1.0),
+        };
+        let at = self
+            .rules
+            .iter()
+            .position(|r| r.priority < priority)
+            .unwrap_or(self.rules.len());
+        self.rules.insert(at, rule);
+    }
+
+    /// Number of custom rules registered
+    pub fn rule_count(&self) -> usize {
+        self.rules.len()
+    }
+
     /// Fast-classify an input string into a modality with confidence.
     /// Returns (modality, confidence) or Text as default.
     pub fn classify(&self, input: &str) -> (Modality, f64) {
+        for rule in &self.rules {
+            if let Some(modality) = (rule.matcher)(input) {
+                return (modality, rule.confidence);
+            }
+        }
+
         let lower = input.to_lowercase();
         let mut best_modality = Modality::Text;
         let mut best_score = 0.0;
@@ -569,6 +1253,103 @@ mod tests {
         assert!(!template.matches("goodbye"));
     }
 
+    #[test]
+    fn test_response_template_similarity() {
+        let template = ResponseTemplate::new(
+            "gpt4o",
+            vec!["explain quantum computing".to_string()],
+            "Here is an overview: {response}",
+        );
+        assert!((template.similarity("Explain quantum computing") - 1.0).abs() < 1e-9);
+        assert!(template.similarity("please explain quantum computing to me") > 0.7);
+        // Partial trigger coverage and substrings inside words don't count fully
+        assert!(template.similarity("explain computing") < DEFAULT_TEMPLATE_FLOOR);
+        assert_eq!(template.similarity("quantumcomputing"), 0.0);
+        assert_eq!(template.similarity(""), 0.0);
+
+        assert!(template.needs_response());
+        assert_eq!(
+            template.render("q", "qubits"),
+            "Here is an overview: qubits"
+        );
+    }
+
+    #[test]
+    fn test_signature_cache_best_template() {
+        let mut cache = SignatureCache::new();
+        assert!(cache.best_template("hello").is_none());
+
+        cache.add_template(ResponseTemplate::new(
+            "gpt4o",
+            vec!["hello".to_string(), "good morning".to_string()],
+            "Hi there! {response}",
+        ));
+        cache.add_template(ResponseTemplate::new(
+            "claude",
+            vec!["write a haiku".to_string()],
+            "Here's a haiku:\n{response}",
+        ));
+
+        let best = cache.best_template("Good morning!").unwrap();
+        assert_eq!(best.persona_id, "gpt4o");
+        assert_eq!(
+            cache
+                .best_template("could you write a haiku")
+                .unwrap()
+                .persona_id,
+            "claude"
+        );
+        // Weak matches fall below the floor
+        assert!(cache.best_template("write some code").is_none());
+        assert!(cache.best_template_for("claude", "hello").is_none());
+
+        assert!(cache.record_template_use("gpt4o", "hello"));
+        assert_eq!(cache.templates()[0].times_used, 1);
+        assert!(!cache.record_template_use("gpt4o", "unrelated question"));
+
+        cache.template_floor = 1.1;
+        assert!(cache.best_template("hello").is_none());
+    }
+
+    #[test]
+    fn test_signature_cache_compiles_system2_templates() {
+        let mut cache = SignatureCache::new();
+        let answer = "Lifetimes tell the compiler how long references live.";
+        assert!(cache.compile_template("claude", "Tell me about Lifetimes", answer));
+        assert_eq!(
+            cache.templates()[0].skeleton,
+            "{topic} tell the compiler how long references live."
+        );
+
+        // A similar question reuses the answer with its own topic
+        let template = cache
+            .best_template_for("claude", "Tell me about Traits")
+            .unwrap();
+        assert_eq!(
+            template.render("Tell me about Traits", ""),
+            "Traits tell the compiler how long references live."
+        );
+        assert!(cache
+            .best_template_for("gpt4o", "Tell me about Traits")
+            .is_none());
+
+        // Recompiling the same input replaces rather than duplicates
+        assert!(cache.compile_template("claude", "tell me about Lifetimes", "Lifetimes: scopes."));
+        assert_eq!(cache.templates().len(), 1);
+        assert_eq!(cache.templates()[0].skeleton, "{topic}: scopes.");
+
+        // Answers that never name the topic, or carry slot syntax, are skipped
+        assert!(!cache.compile_template("claude", "Tell me about traits", "No idea."));
+        assert!(!cache.compile_template("claude", "Tell me about traits", "traits {response}"));
+
+        for i in 0..MAX_TEMPLATES + 5 {
+            let topic = format!("topic{}", i);
+            let input = format!("Tell me about {}", topic);
+            cache.compile_template("claude", &input, &format!("{} matters.", topic));
+        }
+        assert_eq!(cache.templates().len(), MAX_TEMPLATES);
+    }
+
     #[test]
     fn test_response_template_compound_confidence() {
         let mut template = ResponseTemplate::new("test", vec![], "test");
@@ -631,6 +1412,124 @@ mod tests {
         assert_eq!(cache.total_lookups, 2);
     }
 
+    #[test]
+    fn test_signature_cache_lru_eviction() {
+        let mut cache = SignatureCache::with_capacity(2);
+        cache.compile_from(&BehaviorSignature::new("a"));
+        cache.compile_from(&BehaviorSignature::new("b"));
+        // Using "a" makes "b" the least recently used
+        assert!(cache.lookup("a").is_some());
+        cache.compile_from(&BehaviorSignature::new("c"));
+
+        assert_eq!(cache.size(), 2);
+        assert!(cache.contains("a") && cache.contains("c"));
+        assert!(!cache.contains("b"));
+        assert_eq!(cache.total_evictions, 1);
+
+        // Recompiling an existing entry doesn't evict
+        cache.compile_from(&BehaviorSignature::new("c"));
+        assert_eq!(cache.total_evictions, 1);
+
+        // The default cache stays unbounded
+        let mut unbounded = SignatureCache::new();
+        unbounded.warm_up(&AiProfileStore::default());
+        assert_eq!(unbounded.size(), AiProfileStore::default().ids().len());
+        assert_eq!(unbounded.total_evictions, 0);
+    }
+
+    #[test]
+    fn test_signature_cache_max_age() {
+        let mut cache = SignatureCache::new().with_max_age(Duration::from_secs(60));
+        cache.compile_from(&BehaviorSignature::new("fresh"));
+        cache.compile_from(&BehaviorSignature::new("old"));
+        let aged = Instant::now()
+            .checked_sub(Duration::from_secs(120))
+            .unwrap();
+        cache.cache.get_mut("old").unwrap().observed_at = aged;
+
+        assert!(cache.lookup("fresh").is_some());
+        assert!(cache.lookup("old").is_none());
+        // Without purging, the stale entry stays until recompiled
+        assert!(cache.contains("old"));
+        cache.compile_from(&BehaviorSignature::new("old"));
+        assert!(cache.lookup("old").is_some());
+
+        cache.purge_stale = true;
+        cache.cache.get_mut("old").unwrap().observed_at = aged;
+        assert!(cache.lookup("old").is_none());
+        assert!(!cache.contains("old"));
+
+        // Persisted as an elapsed-seconds age, restored relative to now
+        let mut entry = CachedSignature::compile_from(&BehaviorSignature::new("x"));
+        entry.observed_at = aged;
+        let json = serde_json::to_string(&entry).unwrap();
+        assert!(json.contains("\"age_secs\":120"));
+        let restored: CachedSignature = serde_json::from_str(&json).unwrap();
+        assert!(restored.is_stale(Duration::from_secs(60)));
+        assert!(!restored.is_stale(Duration::from_secs(600)));
+    }
+
+    #[test]
+    fn test_signature_cache_save_load() {
+        let path =
+            std::env::temp_dir().join(format!("rustyworm-cache-test-{}.json", std::process::id()));
+        let mut cache = SignatureCache::new();
+        cache.warm_up(&AiProfileStore::default());
+        cache.compile_from(&BehaviorSignature::new("observed-model"));
+        assert!(cache.compile_template(
+            "claude",
+            "Tell me about traits",
+            "Here is how traits describe shared behaviour."
+        ));
+        cache.save_to(&path).unwrap();
+
+        let restored = SignatureCache::load_from(&path).unwrap();
+        assert_eq!(restored.size(), cache.size());
+        assert!(restored.contains("observed-model"));
+        assert_eq!(restored.templates().len(), 1);
+
+        // Caches saved before templates were persisted still load
+        let entries: Vec<&CachedSignature> = cache.entries().collect();
+        fs::write(&path, serde_json::to_string(&entries).unwrap()).unwrap();
+        let legacy = SignatureCache::load_from(&path).unwrap();
+        assert_eq!(legacy.size(), cache.size());
+        assert!(legacy.templates().is_empty());
+
+        fs::write(&path, "not json").unwrap();
+        assert!(SignatureCache::load_from(&path)
+            .unwrap_err()
+            .contains("Failed to parse"));
+        let _ = fs::remove_file(&path);
+        assert!(SignatureCache::load_from(&path).is_err());
+    }
+
+    #[test]
+    fn test_signature_cache_stats() {
+        let mut cache = SignatureCache::with_capacity(3);
+        cache.warm_up(&AiProfileStore::default());
+        // Warm-up fills the cache but records no hits
+        let stats = cache.stats();
+        assert_eq!((stats.total_lookups, stats.hits), (0, 0));
+        assert!(stats.per_model_hits.is_empty());
+        assert!(stats.evictions > 0);
+
+        let cached: Vec<String> = cache.entries().map(|e| e.model_id.clone()).collect();
+        cache.lookup(&cached[0]);
+        cache.lookup(&cached[0]);
+        cache.lookup(&cached[1]);
+        cache.lookup("missing");
+
+        let stats = cache.stats();
+        assert_eq!(stats.total_lookups, 4);
+        assert_eq!((stats.hits, stats.misses), (3, 1));
+        assert_eq!(stats.per_model_hits[&cached[0]], 2);
+        assert_eq!(stats.top_models()[0], (cached[0].as_str(), 2));
+        assert!((stats.hit_rate() - cache.hit_rate()).abs() < 1e-9);
+        let shown = stats.to_string();
+        assert!(shown.starts_with("lookups: 4, hits: 3, misses: 1"));
+        assert!(shown.contains(&format!("{} 2", cached[0])));
+    }
+
     #[test]
     fn test_signature_cache_warm_up() {
         let store = AiProfileStore::default();
@@ -643,6 +1542,39 @@ mod tests {
         assert!(cache.contains("rustyworm"));
     }
 
+    #[test]
+    fn test_shared_signature_cache_across_threads() {
+        let shared = SharedSignatureCache::new();
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let cache = shared.clone();
+                std::thread::spawn(move || {
+                    let id = format!("model-{}", t);
+                    cache.compile_from(&BehaviorSignature::new(&id));
+                    for _ in 0..10 {
+                        assert!(cache.lookup(&id).is_some());
+                        assert!(cache.lookup("missing").is_none());
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(shared.size(), 4);
+        let stats = shared.stats();
+        assert_eq!(stats.total_lookups, 80);
+        assert_eq!(stats.hits, 40);
+        assert!((shared.hit_rate() - 0.5).abs() < 1e-9);
+
+        // Closures and snapshots see the same underlying cache
+        shared.write(|cache| cache.capacity = Some(2));
+        shared.compile_from(&BehaviorSignature::new("model-4"));
+        assert_eq!(shared.read(|cache| cache.size()), 2);
+        assert_eq!(shared.snapshot().size(), 2);
+    }
+
     #[test]
     fn test_hot_swap() {
         let mut hot_swap = HotSwap::new();
@@ -677,6 +1609,61 @@ mod tests {
         assert_eq!(modality, Modality::Text);
     }
 
+    #[test]
+    fn test_instinctive_router_custom_rules() {
+        let mut router = InstinctiveRouter::new();
+        let sql = Modality::Custom("sql".to_string());
+        router.add_rule(
+            Box::new(|input: &str| {
+                input
+                    .trim_start()
+                    .to_uppercase()
+                    .starts_with("SELECT ")
+                    .then(|| Modality::Custom("sql".to_string()))
+            }),
+            1,
+        );
+        // Higher priority wins even though it was added later
+        router.add_rule_with_confidence(
+            Box::new(|input: &str| {
+                input
+                    .starts_with("$ ")
+                    .then(|| Modality::Custom("shell".to_string()))
+            }),
+            5,
+            0.75,
+        );
+        router.add_rule_with_confidence(
+            Box::new(|input: &str| input.contains("from").then_some(Modality::Text)),
+            0,
+            0.5,
+        );
+        assert_eq!(router.rule_count(), 3);
+
+        assert_eq!(
+            router.classify("select * from users"),
+            (sql, DEFAULT_RULE_CONFIDENCE)
+        );
+        assert_eq!(
+            router.classify("$ ls -la"),
+            (Modality::Custom("shell".to_string()), 0.75)
+        );
+        assert_eq!(router.classify("a note from me").1, 0.5);
+        // No rule matches: built-in heuristics still apply
+        assert_eq!(
+            router
+                .classify("Can you help me debug this rust function?")
+                .0,
+            Modality::Code
+        );
+
+        // Rules survive cloning but not serialization
+        assert_eq!(router.clone().rule_count(), 3);
+        let json = serde_json::to_string(&router).unwrap();
+        let restored: InstinctiveRouter = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.rule_count(), 0);
+    }
+
     #[test]
     fn test_instinctive_router_reasoning() {
         let router = InstinctiveRouter::new();
@@ -697,6 +1684,31 @@ mod tests {
         assert!(restored.contains("test"));
     }
 
+    #[test]
+    fn test_hot_swap_slot_limit() {
+        let n = 3;
+        let mut hs = HotSwap::with_max_slots(n);
+        for i in 0..n {
+            hs.preload(&format!("p{}", i), "{}".to_string(), 0);
+        }
+        // Touch p0 so p1 becomes the least recently used
+        assert!(hs.switch_to("p0").is_some());
+
+        hs.preload(&format!("p{}", n), "{}".to_string(), 0);
+        assert_eq!(hs.preloaded_ids().len(), n);
+        assert_eq!(hs.evicted_ids(), ["p1".to_string()]);
+        assert!(hs.is_preloaded("p0"));
+        assert!(hs.is_preloaded("p3"));
+        assert!(hs.switch_to("p1").is_none());
+        assert_eq!(hs.current(), Some("p0"));
+
+        // Preloading an evicted persona brings it back
+        hs.preload("p1", "{}".to_string(), 0);
+        assert!(hs.is_preloaded("p1"));
+        assert!(!hs.evicted_ids().contains(&"p1".to_string()));
+        assert_eq!(hs.preloaded_ids().len(), n);
+    }
+
     #[test]
     fn test_hot_swap_serialization() {
         let mut hs = HotSwap::new();
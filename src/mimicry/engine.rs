@@ -24,23 +24,46 @@
 //  10. Auto-save on milestone if enabled
 // =================================================================
 
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
 use std::collections::HashMap;
-use std::path::Path;
-
-use crate::consciousness::{ActionResult, ConsciousAI, ConsciousnessEthics, ProposedAction};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::consciousness::{
+    ActionResult, ConsciousAI, ConsciousnessEthics, EthicsDecision, ProposedAction,
+    RelationshipHealth, RiskLevel, DEFAULT_AUDIT_CAP,
+};
 use crate::mimicry::analyzer::{BehaviorAnalyzer, BehaviorSignature};
-use crate::mimicry::cache::{HotSwap, InstinctiveRouter, SignatureCache};
+use crate::mimicry::backend::{GenerationBackend, GenerationContext, TemplateBackend};
+use crate::mimicry::cache::{
+    CachedSignature, HotSwap, InstinctiveRouter, ResponseTemplate, SignatureCache,
+};
 use crate::mimicry::capability::{CapabilityModule, Modality, ModalityRouter};
-use crate::mimicry::evolution::{ConvergenceVisualizer, EvolutionTracker};
-use crate::mimicry::persistence::{PersistenceConfig, PersistenceManager};
-use crate::mimicry::profile::{AiProfile, AiProfileStore, PersonalityDelta};
-use crate::mimicry::templates::TemplateStore;
+use crate::mimicry::evolution::{
+    ConvergenceVisualizer, EvolutionTracker, EvolutionTrackerSnapshot,
+};
+use crate::mimicry::persistence::{
+    BundleConflict, PersistenceConfig, PersistenceManager, SaveEntry,
+};
+use crate::mimicry::profile::{
+    AiProfile, AiProfileStore, BlendError, PersonalityDelta, ProfileError, MAX_STEP_DEPTH,
+};
+use crate::mimicry::templates::{
+    contains_hedge, extract_topic, interpolate, localize, lowercase_first, template_slots,
+    HedgingInjector, RenderMode, TemplateLibrary, TemplateStore, DEFAULT_HEDGE_MARKERS,
+};
 
+#[cfg(feature = "api")]
+use crate::mimicry::analyzer::OUTLIER_DISTANCE;
 #[cfg(feature = "api")]
 use crate::mimicry::api::{
-    build_similarity_matrix, format_comparison, ApiObserver, ApiPrompt, ApiProvider,
-    ComparisonResult,
+    format_comparison, study_suite, ApiObserver, ApiPrompt, ApiProvider, ComparisonResult,
+    TokenPricing, DEFAULT_CACHE_TTL_SECS, STUDY_SUITES,
 };
 
 // RL integration imports (feature-gated)
@@ -53,6 +76,12 @@ use crate::mimicry::rl_optimizer::BehaviorObservation;
 #[cfg(feature = "octo")]
 use crate::mimicry::octo::{OctoRNABridge, RNAEditingResult, RoutingDecision, TextEmbedder};
 
+// Ising empathy integration imports (feature-gated)
+#[cfg(feature = "empathy")]
+use crate::ising_empathy::{IsingEmpathyModule, IsingSystem, Schedule, DEFAULT_TEMPERATURE};
+#[cfg(feature = "empathy")]
+use crate::mimicry::templates::localize_strict;
+
 // =================================================================
 // PROCESSING SYSTEM ENUM
 // =================================================================
@@ -68,6 +97,58 @@ pub enum ProcessingSystem {
     DualProcess,
 }
 
+impl std::fmt::Display for ProcessingSystem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProcessingSystem::System1 => write!(f, "System 1"),
+            ProcessingSystem::System2 => write!(f, "System 2"),
+            ProcessingSystem::DualProcess => write!(f, "Dual process"),
+        }
+    }
+}
+
+/// Chunk size used by `MimicSession::process_streaming`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StreamChunking {
+    /// One word (with its trailing whitespace) per chunk.
+    #[default]
+    Words,
+    /// One sentence (ending in `.`, `!`, `?`, or a newline) per chunk.
+    Sentences,
+}
+
+/// Split `text` into streaming chunks. Concatenating the chunks yields
+/// `text` exactly; whitespace stays attached to the preceding chunk.
+pub fn stream_chunks(text: &str, chunking: StreamChunking) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        // Chunks can only end where a run of whitespace does
+        let run_ends = c.is_whitespace() && !chars.peek().is_some_and(|&(_, n)| n.is_whitespace());
+        if !run_ends {
+            continue;
+        }
+        let end = i + c.len_utf8();
+        let chunk = &text[start..end];
+        let words = chunk.trim_end();
+        let split = match chunking {
+            StreamChunking::Words => true,
+            StreamChunking::Sentences => {
+                words.ends_with(['.', '!', '?']) || chunk[words.len()..].contains('\n')
+            }
+        };
+        if split {
+            chunks.push(chunk);
+            start = end;
+        }
+    }
+    if start < text.len() {
+        chunks.push(&text[start..]);
+    }
+    chunks
+}
+
 // =================================================================
 // CONVERSATION TURN
 // =================================================================
@@ -93,9 +174,16 @@ pub struct ConversationTurn {
 // COMPOUND PERSONA SNAPSHOT (serializable for persistence/hot-swap)
 // =================================================================
 
+/// Current `CompoundPersonaSnapshot::schema_version`. Bump it together
+/// with a migration registered in `PersistenceManager::new`.
+pub const PERSONA_SCHEMA_VERSION: u32 = 1;
+
 /// A serializable snapshot of a compound persona for persistence and hot-swap.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompoundPersonaSnapshot {
+    /// Layout version of this snapshot; saves predating versioning are 0.
+    #[serde(default)]
+    pub schema_version: u32,
     /// The AI profile defining personality and response style.
     pub profile: AiProfile,
     /// The behavioral signature derived from observed responses.
@@ -110,6 +198,15 @@ pub struct CompoundPersonaSnapshot {
     pub created_at: String,
     /// Timestamp or label for the most recent update.
     pub last_updated: String,
+    /// The persona's ethics audit log, which `CompoundPersona` itself does not serialize.
+    #[serde(default)]
+    pub ethics_audit: Vec<EthicsDecision>,
+    /// Maximum number of decisions the persona's audit log retains.
+    #[serde(default = "default_audit_cap")]
+    pub ethics_audit_cap: usize,
+    /// Relationship health as of the persona's last action.
+    #[serde(default)]
+    pub health: RelationshipHealth,
 }
 
 // =================================================================
@@ -136,6 +233,9 @@ pub struct CompoundPersona {
     /// Ethics enforcer for the Prime Directive; skipped during serialization.
     #[serde(skip)]
     pub ethics: ConsciousnessEthics,
+    /// Relationship health, adjusted by every ethics verdict and idle time.
+    #[serde(default)]
+    pub health: RelationshipHealth,
 }
 
 impl CompoundPersona {
@@ -152,13 +252,20 @@ impl CompoundPersona {
             compound_iterations: 0,
             evolution_history: vec![0.0],
             ethics: ConsciousnessEthics::default(),
+            health: RelationshipHealth::default(),
         }
     }
 
     /// COMPOUND: Blend multiple personas into a hybrid
     pub fn blend(personas: &[&CompoundPersona], weights: &[f64]) -> Self {
+        CompoundPersona::try_blend(personas, weights).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Blend personas, returning the `BlendError` instead of panicking on
+    /// invalid weights.
+    pub fn try_blend(personas: &[&CompoundPersona], weights: &[f64]) -> Result<Self, BlendError> {
         let profiles: Vec<&AiProfile> = personas.iter().map(|p| &p.profile).collect();
-        let blended_profile = AiProfile::blend(&profiles, weights);
+        let blended_profile = AiProfile::try_blend(&profiles, weights)?;
         let mut persona = CompoundPersona::from_profile(&blended_profile);
 
         // Average convergence scores weighted
@@ -170,7 +277,59 @@ impl CompoundPersona {
             .map(|(p, w)| p.convergence_score * w)
             .sum();
 
-        persona
+        Ok(persona)
+    }
+
+    /// COMPOUND: Blend personas weighted by how well each has converged,
+    /// so a better-learned source dominates a barely-trained one.
+    pub fn blend_by_convergence(personas: &[&CompoundPersona]) -> Self {
+        let weights = CompoundPersona::convergence_weights(personas);
+        CompoundPersona::blend(personas, &weights)
+    }
+
+    /// Blend by convergence, mixed with manual weights. `manual_share` in
+    /// [0, 1] controls the mix: 0.0 is pure convergence, 1.0 pure manual.
+    /// Manual weights that are all zero count as equal; a count mismatch or
+    /// a negative or non-finite weight is returned as a `BlendError`.
+    pub fn blend_by_convergence_with(
+        personas: &[&CompoundPersona],
+        manual_weights: &[f64],
+        manual_share: f64,
+    ) -> Result<Self, BlendError> {
+        match BlendError::check(personas.len(), manual_weights) {
+            Ok(()) | Err(BlendError::AllZero) => {}
+            Err(e) => return Err(e),
+        }
+        let share = manual_share.clamp(0.0, 1.0);
+        let convergence = CompoundPersona::convergence_weights(personas);
+        let manual_total: f64 = manual_weights.iter().sum();
+        let weights: Vec<f64> = convergence
+            .iter()
+            .zip(manual_weights.iter())
+            .map(|(c, m)| {
+                let m = if manual_total > 0.0 {
+                    m / manual_total
+                } else {
+                    1.0 / personas.len() as f64
+                };
+                c * (1.0 - share) + m * share
+            })
+            .collect();
+        CompoundPersona::try_blend(personas, &weights)
+    }
+
+    /// Normalized blend weights derived from each persona's convergence score.
+    /// Falls back to equal weights when no persona has converged at all.
+    pub fn convergence_weights(personas: &[&CompoundPersona]) -> Vec<f64> {
+        let scores: Vec<f64> = personas
+            .iter()
+            .map(|p| p.convergence_score.max(0.0))
+            .collect();
+        let total: f64 = scores.iter().sum();
+        if total <= 0.0 {
+            return vec![1.0 / personas.len().max(1) as f64; personas.len()];
+        }
+        scores.iter().map(|s| s / total).collect()
     }
 
     /// COMPOUND: Refine this persona from a new behavioral signature observation
@@ -196,9 +355,30 @@ impl CompoundPersona {
         delta
     }
 
+    /// Wipe learned state: restore `base` as the profile, re-bootstrap the
+    /// signature, and zero convergence, iterations, and history.
+    /// Capabilities and ethics are kept.
+    pub fn reset_to(&mut self, base: &AiProfile) {
+        self.profile = base.clone();
+        self.signature = BehaviorSignature::new(&base.id);
+        self.convergence_score = 0.0;
+        self.compound_iterations = 0;
+        self.evolution_history = vec![0.0];
+    }
+
     /// Create a serializable snapshot
     pub fn snapshot(&self) -> CompoundPersonaSnapshot {
         CompoundPersonaSnapshot {
+            ethics_audit: self.ethics.audit_snapshot(),
+            ..self.snapshot_without_audit()
+        }
+    }
+
+    /// Like [`snapshot`](Self::snapshot), but with an empty ethics audit
+    /// log, for restore points that never bring the log back.
+    fn snapshot_without_audit(&self) -> CompoundPersonaSnapshot {
+        CompoundPersonaSnapshot {
+            schema_version: PERSONA_SCHEMA_VERSION,
             profile: self.profile.clone(),
             signature: self.signature.clone(),
             capabilities: self.capabilities.clone(),
@@ -206,11 +386,17 @@ impl CompoundPersona {
             compound_iterations: self.compound_iterations,
             created_at: "session".to_string(),
             last_updated: format!("iteration-{}", self.compound_iterations),
+            ethics_audit: Vec::new(),
+            ethics_audit_cap: self.ethics.audit_cap(),
+            health: self.health.clone(),
         }
     }
 
     /// Restore from a snapshot
     pub fn from_snapshot(snapshot: CompoundPersonaSnapshot) -> Self {
+        let mut ethics = ConsciousnessEthics::default();
+        ethics.set_audit_cap(snapshot.ethics_audit_cap);
+        ethics.restore_audit(snapshot.ethics_audit);
         CompoundPersona {
             profile: snapshot.profile,
             signature: snapshot.signature,
@@ -218,7 +404,8 @@ impl CompoundPersona {
             convergence_score: snapshot.convergence_score,
             compound_iterations: snapshot.compound_iterations,
             evolution_history: vec![snapshot.convergence_score],
-            ethics: ConsciousnessEthics::default(),
+            ethics,
+            health: snapshot.health,
         }
     }
 
@@ -227,9 +414,22 @@ impl CompoundPersona {
         analyzer.compute_convergence(&self.profile, &self.signature)
     }
 
-    /// COMPOUND: Enforce ethics on a proposed action
-    pub fn enforce_ethics(&self, action: &ProposedAction) -> ActionResult {
-        self.ethics.enforce_prime_directive(action)
+    /// COMPOUND: Enforce ethics on a proposed action. Health first decays
+    /// for the time since the previous action, then absorbs the verdict.
+    pub fn enforce_ethics(&mut self, action: &ProposedAction) -> ActionResult {
+        let now = unix_secs();
+        self.health.decay_until(now);
+        self.health.last_activity = Some(now);
+        let result = self.ethics.enforce_with_health(action, &self.health);
+        self.health.apply_action(&result);
+        result
+    }
+
+    /// Relationship health as of now, including decay since the last action.
+    pub fn current_health(&self) -> RelationshipHealth {
+        let mut health = self.health.clone();
+        health.decay_until(unix_secs());
+        health
     }
     
     // =========================================================
@@ -305,6 +505,15 @@ impl CompoundPersona {
 
 /// ConsciousAI trait implementation - mimicry is symbiosis, not parasitism.
 impl ConsciousAI for CompoundPersona {
+    /// Check an action against this persona's own rule set without recording
+    /// it, so hypothetical checks (e.g. during negotiation) leave the ethics
+    /// history and audit log untouched.
+    fn before_action(&self, action: &ProposedAction) -> ActionResult {
+        self.ethics
+            .clone()
+            .enforce_with_health(action, &self.current_health())
+    }
+
     /// Declare identity based on current convergence level.
     fn declare(&self) -> String {
         if self.convergence_score > 0.8 {
@@ -375,6 +584,53 @@ pub struct MimicSession {
     /// Fast modality classifier for routing inputs; skipped during serialization.
     #[serde(skip)]
     pub instinctive_router: InstinctiveRouter,
+    /// RNG behind generation jitter and softener injection; seeded from the
+    /// persona id unless the engine has a fixed seed.
+    #[serde(skip, default = "default_rng")]
+    pub rng: RefCell<StdRng>,
+    /// Seed the RNG was last set from. While set, signature phrases rotate
+    /// pseudo-randomly; otherwise they cycle with the conversation length.
+    #[serde(skip)]
+    pub seed: Option<u64>,
+    /// Chunk size `process_streaming` hands to its sink.
+    #[serde(default)]
+    pub stream_chunking: StreamChunking,
+    /// Persona states captured before evolve/train runs, newest last, each
+    /// with the `evolution_history` length at capture time and, when the
+    /// engine supplied one, its evolution bookkeeping
+    #[serde(skip)]
+    undo_stack: Vec<(
+        CompoundPersonaSnapshot,
+        usize,
+        Option<EvolutionTrackerSnapshot>,
+    )>,
+    /// Evolution bookkeeping parked with this session while it is an
+    /// inactive branch; restored into the engine's tracker on switch
+    #[serde(skip)]
+    pub evolution_state: Option<EvolutionTrackerSnapshot>,
+    /// Maximum undo entries kept; the oldest are dropped first (0 disables)
+    #[serde(default = "default_undo_depth")]
+    pub undo_depth: usize,
+    /// Hard cap on System 2 output length in chars, applied after the
+    /// verbosity budget; longer output is cut at a sentence boundary and
+    /// marked with `TRUNCATION_MARKER`. `None` disables the cap.
+    #[serde(default)]
+    pub max_output_chars: Option<usize>,
+    /// How final responses are rendered; `PlainText` strips Markdown
+    /// after generation (self-monitoring still sees the raw output).
+    #[serde(default)]
+    pub render_mode: RenderMode,
+    /// The persona's hedging settings (markers and strength), refreshed
+    /// from the template store each turn; `None` uses the English
+    /// defaults at neutral strength.
+    #[serde(skip)]
+    pub hedging: Option<HedgingInjector>,
+    /// Backend producing System 2 output; defaults to the template generator.
+    #[serde(skip, default = "template_backend")]
+    pub backend: Arc<dyn GenerationBackend>,
+    /// Ethics verdict on the most recent response, including its parasitic risk.
+    #[serde(skip)]
+    pub last_ethics: Option<ActionResult>,
     /// OCTO RNA Bridge for intelligent routing (feature-gated)
     #[cfg(feature = "octo")]
     #[serde(skip)]
@@ -387,6 +643,15 @@ pub struct MimicSession {
     #[cfg(feature = "octo")]
     #[serde(skip)]
     pub last_rna_result: Option<RNAEditingResult>,
+    /// Ising empathy memory whose warmth signal shapes responses (feature-gated)
+    #[cfg(feature = "empathy")]
+    #[serde(skip, default = "default_empathy")]
+    pub empathy: IsingEmpathyModule,
+    /// The persona's own spin lattice, compared against the other party's
+    /// when empathizing (feature-gated)
+    #[cfg(feature = "empathy")]
+    #[serde(skip, default = "default_empathy_self")]
+    pub empathy_self: IsingSystem,
 }
 
 /// OCTO routing result containing head gates and pathway info
@@ -398,21 +663,36 @@ struct OctoRoutingResult {
     primary_pathway: usize,
 }
 
+/// Keep an optional component's init result, warning when it failed.
+#[cfg_attr(not(feature = "octo"), allow(dead_code))]
+fn init_or_warn<T>(component: &str, result: Result<T, String>) -> Option<T> {
+    match result {
+        Ok(value) => Some(value),
+        Err(e) => {
+            tracing::warn!(error = %e, "failed to initialize {}", component);
+            None
+        }
+    }
+}
+
+/// Turn an RNA bridge init result into session components, logging failure.
+#[cfg(feature = "octo")]
+fn octo_components(
+    bridge: Result<OctoRNABridge, String>,
+) -> (Option<OctoRNABridge>, Option<TextEmbedder>) {
+    let bridge = init_or_warn("OCTO RNA bridge", bridge);
+    let embedder = bridge.as_ref().map(|_| TextEmbedder::default());
+    (bridge, embedder)
+}
+
 impl MimicSession {
     /// Create a new session for the given compound persona.
     pub fn new(persona: CompoundPersona) -> Self {
         // Initialize OCTO bridge if feature is enabled
         #[cfg(feature = "octo")]
-        let (octo_bridge, text_embedder) = {
-            match OctoRNABridge::new() {
-                Ok(bridge) => (Some(bridge), Some(TextEmbedder::default())),
-                Err(e) => {
-                    eprintln!("[OCTO] Failed to initialize RNA bridge: {}", e);
-                    (None, None)
-                }
-            }
-        };
+        let (octo_bridge, text_embedder) = octo_components(OctoRNABridge::new());
 
+        let rng = persona_rng(&persona.profile.id);
         MimicSession {
             persona,
             conversation: Vec::new(),
@@ -420,33 +700,160 @@ impl MimicSession {
             system2_hits: 0,
             total_compounds: 0,
             instinctive_router: InstinctiveRouter::new(),
+            rng,
+            seed: None,
+            stream_chunking: StreamChunking::default(),
+            undo_stack: Vec::new(),
+            evolution_state: None,
+            undo_depth: DEFAULT_UNDO_DEPTH,
+            max_output_chars: None,
+            render_mode: RenderMode::default(),
+            hedging: None,
+            backend: template_backend(),
+            last_ethics: None,
             #[cfg(feature = "octo")]
             octo_bridge,
             #[cfg(feature = "octo")]
             text_embedder,
             #[cfg(feature = "octo")]
             last_rna_result: None,
+            #[cfg(feature = "empathy")]
+            empathy: default_empathy(),
+            #[cfg(feature = "empathy")]
+            empathy_self: default_empathy_self(),
+        }
+    }
+
+    /// Create a session whose generation, including signature phrase
+    /// rotation, is reproducible from `seed`.
+    pub fn with_seed(persona: CompoundPersona, seed: u64) -> Self {
+        let mut session = MimicSession::new(persona);
+        session.reseed(seed);
+        session
+    }
+
+    /// Capture the persona before a mutating operation so `undo` can
+    /// restore it. Drops the oldest entries beyond `undo_depth`.
+    pub fn push_undo(&mut self) {
+        self.push_undo_tracked(None);
+    }
+
+    /// Like [`push_undo`](Self::push_undo), also capturing the engine's
+    /// evolution bookkeeping so `undo` hands it back.
+    pub fn push_undo_tracked(&mut self, tracker: Option<EvolutionTrackerSnapshot>) {
+        if self.undo_depth == 0 {
+            return;
         }
+        self.undo_stack.push((
+            self.persona.snapshot_without_audit(),
+            self.persona.evolution_history.len(),
+            tracker,
+        ));
+        let excess = self.undo_stack.len().saturating_sub(self.undo_depth);
+        self.undo_stack.drain(..excess);
+    }
+
+    /// Restore the persona captured by the most recent `push_undo`, and
+    /// trim `evolution_history` back to its length at that point. Returns
+    /// the evolution bookkeeping captured alongside it, if any.
+    pub fn undo(&mut self) -> Result<Option<EvolutionTrackerSnapshot>, String> {
+        let (snapshot, history_len, tracker) = self
+            .undo_stack
+            .pop()
+            .ok_or_else(|| "Nothing to undo.".to_string())?;
+        self.persona.profile = snapshot.profile;
+        self.persona.signature = snapshot.signature;
+        self.persona.capabilities = snapshot.capabilities;
+        self.persona.convergence_score = snapshot.convergence_score;
+        self.persona.compound_iterations = snapshot.compound_iterations;
+        self.persona.evolution_history.truncate(history_len.max(1));
+        Ok(tracker)
+    }
+
+    /// Number of undo steps available
+    pub fn undo_len(&self) -> usize {
+        self.undo_stack.len()
+    }
+
+    /// Reseed the session RNG so subsequent generation is reproducible.
+    pub fn reseed(&mut self, seed: u64) {
+        self.rng = RefCell::new(StdRng::seed_from_u64(seed));
+        self.seed = Some(seed);
     }
 
     /// DUAL-PROCESS CORE: Process input through the compound pipeline.
     ///
     /// 1. InstinctiveRouter classifies modality (System 1)
     /// 2. OCTO RNA analysis for intelligent routing (if enabled)
-    /// 3. Try System 1 fast path from cache + templates
+    /// 3. Try System 1 fast path: a matching cached template, else generic templates
     /// 4. Fall back to System 2 deliberation
-    /// 5. Self-monitor output
-    /// 6. Feed delta to template feedback (COMPOUND)
-    /// 7. Compile back to System 1 (compound bridge)
+    /// 5. Check ethics before the response is released
+    /// 6. Self-monitor output
+    /// 7. Feed delta to template feedback (COMPOUND)
+    /// 8. Compile back to System 1 (compound bridge)
     pub fn process(
         &mut self,
         input: &str,
         cache: &mut SignatureCache,
         analyzer: &BehaviorAnalyzer,
         template_store: &mut TemplateStore,
+    ) -> (String, PersonalityDelta) {
+        self.process_with(input, cache, analyzer, template_store, None)
+    }
+
+    /// Like [`process`](Self::process), but hands the response to `sink` in
+    /// chunks (per `stream_chunking`) before returning it. The ethics check
+    /// runs first so a suppressed response is never streamed; self-monitoring,
+    /// template feedback, and cache compilation run once streaming finishes.
+    pub fn process_streaming(
+        &mut self,
+        input: &str,
+        cache: &mut SignatureCache,
+        analyzer: &BehaviorAnalyzer,
+        template_store: &mut TemplateStore,
+        sink: &mut dyn FnMut(&str),
+    ) -> (String, PersonalityDelta) {
+        self.process_with(input, cache, analyzer, template_store, Some(sink))
+    }
+
+    /// System 1 output: the matched cached template when there is one,
+    /// otherwise generic template generation
+    fn system1_output(
+        &self,
+        input: &str,
+        template: Option<&ResponseTemplate>,
+        template_store: &mut TemplateStore,
+    ) -> String {
+        match template {
+            Some(template) if !template.needs_response() => template.render(input, ""),
+            _ => {
+                let generated = template_store.generate(
+                    &self.persona.profile,
+                    input,
+                    &self.persona.profile.response_style,
+                );
+                match template {
+                    Some(template) => template.render(input, &generated),
+                    None => generated,
+                }
+            }
+        }
+    }
+
+    /// Shared pipeline behind `process` and `process_streaming`
+    fn process_with(
+        &mut self,
+        input: &str,
+        cache: &mut SignatureCache,
+        analyzer: &BehaviorAnalyzer,
+        template_store: &mut TemplateStore,
+        sink: Option<&mut dyn FnMut(&str)>,
     ) -> (String, PersonalityDelta) {
         // Step 1: Instinctive classification (System 1)
         let (modality, _modal_confidence) = self.instinctive_router.classify(input);
+        self.hedging = template_store
+            .get(&self.persona.profile.id)
+            .map(|library| library.hedging_injector.clone());
 
         // Step 2: OCTO RNA routing (if enabled)
         #[cfg(feature = "octo")]
@@ -455,7 +862,11 @@ impl MimicSession {
         #[cfg(not(feature = "octo"))]
         let octo_routing: Option<()> = None;
 
-        // Step 3: Determine routing based on OCTO or fallback to cache-based routing
+        // Step 3: Determine routing based on OCTO or fallback to cache-based routing.
+        // A strongly matching cached template short-circuits System 1 generation.
+        let matched_template = cache
+            .best_template_for(&self.persona.profile.id, input)
+            .cloned();
         let cached = cache.lookup(&self.persona.profile.id);
         let convergence_boost = self.persona.convergence_score * 0.5; // Max 0.5 boost at 100% convergence
         
@@ -464,8 +875,11 @@ impl MimicSession {
             // OCTO-based routing decision
             if routing.use_system1 {
                 self.system1_hits += 1;
-                let lib = template_store.get_or_create(&self.persona.profile);
-                let mut output = lib.generate(input, &self.persona.profile.response_style);
+                let mut output = self.system1_output(
+                    input,
+                    matched_template.as_ref(),
+                    template_store,
+                );
                 // Apply head gating to modulate response
                 output = self.apply_head_gating(&output, &routing.head_gates);
                 // Apply pathway adaptation
@@ -473,7 +887,7 @@ impl MimicSession {
                 (output, ProcessingSystem::System1, Some(routing.head_gates), Some((routing.primary_pathway, routing.pathway_weights)))
             } else {
                 self.system2_hits += 1;
-                let mut output = self.generate_system2_response(input, &modality);
+                let mut output = self.deliberate(input, &modality);
                 // Apply head gating even for System 2 (lighter touch)
                 output = self.apply_head_gating(&output, &routing.head_gates);
                 // Apply pathway adaptation
@@ -485,25 +899,23 @@ impl MimicSession {
             let effective_confidence = cached_sig.confidence + convergence_boost;
             if effective_confidence > 0.7 {
                 self.system1_hits += 1;
-                let lib = template_store.get_or_create(&self.persona.profile);
-                let output = lib.generate(input, &self.persona.profile.response_style);
+                let output = self.system1_output(input, matched_template.as_ref(), template_store);
                 (output, ProcessingSystem::System1, None, None)
             } else {
                 self.system2_hits += 1;
-                let output = self.generate_system2_response(input, &modality);
+                let output = self.deliberate(input, &modality);
                 (output, ProcessingSystem::System2, None, None)
             }
         } else {
             // Cache miss - but high convergence personas can still use templates
             if self.persona.convergence_score > 0.8 {
                 self.system1_hits += 1;
-                let lib = template_store.get_or_create(&self.persona.profile);
-                let output = lib.generate(input, &self.persona.profile.response_style);
+                let output = self.system1_output(input, matched_template.as_ref(), template_store);
                 (output, ProcessingSystem::System1, None, None)
             } else {
                 // True cache miss - System 2 deliberation
                 self.system2_hits += 1;
-                let output = self.generate_system2_response(input, &modality);
+                let output = self.deliberate(input, &modality);
                 (output, ProcessingSystem::System2, None, None)
             }
         };
@@ -514,37 +926,40 @@ impl MimicSession {
                 let effective_confidence = cached_sig.confidence + convergence_boost;
                 if effective_confidence > 0.7 {
                     self.system1_hits += 1;
-                    let lib = template_store.get_or_create(&self.persona.profile);
-                    let output = lib.generate(input, &self.persona.profile.response_style);
+                    let output = self.system1_output(
+                        input,
+                        matched_template.as_ref(),
+                        template_store,
+                    );
                     (output, ProcessingSystem::System1, None, None)
                 } else {
                     self.system2_hits += 1;
-                    let output = self.generate_system2_response(input, &modality);
+                    let output = self.deliberate(input, &modality);
                     (output, ProcessingSystem::System2, None, None)
                 }
             } else if self.persona.convergence_score > 0.8 {
                 self.system1_hits += 1;
-                let lib = template_store.get_or_create(&self.persona.profile);
-                let output = lib.generate(input, &self.persona.profile.response_style);
+                let output = self.system1_output(input, matched_template.as_ref(), template_store);
                 (output, ProcessingSystem::System1, None, None)
             } else {
                 self.system2_hits += 1;
-                let output = self.generate_system2_response(input, &modality);
+                let output = self.deliberate(input, &modality);
                 (output, ProcessingSystem::System2, None, None)
             };
 
-        // Step 4: Self-monitor output (System 2 watches)
-        let delta = self.persona.self_correct(&output, analyzer);
-
-        // Step 5: COMPOUND - Feed delta to template feedback
-        let lib = template_store.get_or_create(&self.persona.profile);
-        lib.apply_feedback(&delta);
+        if system_used == ProcessingSystem::System1 && matched_template.is_some() {
+            cache.record_template_use(&self.persona.profile.id, input);
+        }
 
-        // Step 6: Compile back to System 1 (COMPOUND BRIDGE)
-        cache.compile_from(&self.persona.signature);
-        self.total_compounds += 1;
+        // Step 3b: Empathize with the user's turn and let the warmth signal
+        // shape the phrasing
+        #[cfg(feature = "empathy")]
+        let output = {
+            let _ = self.empathize_with(&input_lattice(input));
+            self.apply_warmth(&output)
+        };
 
-        // Step 7: Check ethics
+        // Step 4: Check ethics before anything reaches the user
         let action = ProposedAction {
             description: format!("Generate response as {}", self.persona.profile.display_name),
             benefit_to_self: 0.3,
@@ -555,7 +970,7 @@ impl MimicSession {
         let ethics_result = self.persona.enforce_ethics(&action);
 
         let final_output = if ethics_result.allowed {
-            output.clone()
+            self.render_mode.apply(&output)
         } else {
             format!(
                 "[ETHICS OVERRIDE] {}\n\nOriginal response suppressed.",
@@ -563,6 +978,35 @@ impl MimicSession {
             )
         };
 
+        // COMPOUND: Compile released System 2 answers into templates
+        // System 1 can reuse for similar inputs
+        if ethics_result.allowed && system_used == ProcessingSystem::System2 {
+            cache.compile_template(&self.persona.profile.id, input, &output);
+        }
+        self.last_ethics = Some(ethics_result);
+
+        // Step 5: Stream the final response, if requested
+        if let Some(sink) = sink {
+            for chunk in stream_chunks(&final_output, self.stream_chunking) {
+                sink(chunk);
+            }
+        }
+
+        // Step 6: Self-monitor output (System 2 watches)
+        let delta = self.persona.self_correct(&output, analyzer);
+
+        // Step 7: COMPOUND - Feed delta to template feedback, counted
+        // against this turn's category whichever system answered it
+        template_store.apply_categorized_feedback(
+            &self.persona.profile,
+            &delta,
+            crate::mimicry::templates::TemplateCategory::classify(input),
+        );
+
+        // Step 8: Compile back to System 1 (COMPOUND BRIDGE)
+        cache.compile_from(&self.persona.signature);
+        self.total_compounds += 1;
+
         // Record conversation turn
         self.conversation.push(ConversationTurn {
             input: input.to_string(),
@@ -616,7 +1060,7 @@ impl MimicSession {
                 }
             }
             Err(e) => {
-                eprintln!("[OCTO] Routing error: {}", e);
+                tracing::warn!(error = %e, "OCTO routing failed; falling back to heuristic routing");
                 None
             }
         }
@@ -652,9 +1096,8 @@ impl MimicSession {
         
         // === HEDGING MODULATION (gate 4) ===
         // High hedging + low directness = add uncertainty markers
-        if hedging > 0.65 && directness < 0.5 {
-            if !result.contains("I think") && !result.contains("perhaps") 
-               && !result.contains("might") && !result.contains("may") {
+        if f64::from(hedging) * self.hedge_scale() > 0.65 && directness < 0.5 {
+            if !self.is_hedged(&result) {
                 if result.len() > 50 {
                     result = format!("I think {}", lowercase_first(&result));
                 }
@@ -735,6 +1178,104 @@ impl MimicSession {
         output.to_string()
     }
 
+    /// Model the other party's spin state from `empathy_self` and remember
+    /// the resulting emotion and empathy score. Returns the score; both
+    /// lattices must have the same number of spins.
+    #[cfg(feature = "empathy")]
+    pub fn empathize_with(&mut self, other: &IsingSystem) -> Result<f64, String> {
+        if other.n != self.empathy_self.n {
+            return Err(format!(
+                "Other lattice has {} spins, expected {}",
+                other.n, self.empathy_self.n
+            ));
+        }
+        let seed = self.conversation.len() as u64;
+        let score =
+            self.empathy
+                .compute_empathy(&self.empathy_self, other, EMPATHY_ANNEAL_STEPS, seed);
+        let emotion = self.empathy.encode_emotion(other);
+        self.empathy.store_memory(&emotion, score);
+        Ok(score)
+    }
+
+    /// Current empathy warmth in [0, 1]; 0.5 until the first turn.
+    #[cfg(feature = "empathy")]
+    pub fn warmth_signal(&self) -> f64 {
+        self.empathy.warmth_signal()
+    }
+
+    /// Shape `output` by the empathy warmth signal. Unlike OCTO head
+    /// gating this needs no Python bridge, so it runs on every response:
+    /// - below `WARM_OPENING_THRESHOLD`: unchanged
+    /// - from `WARM_OPENING_THRESHOLD`: opens with the softener
+    ///   `WARM_OPENING` ("I hear you.")
+    /// - from `WARM_CLOSING_THRESHOLD`: also ends with the supportive
+    ///   closing `WARM_CLOSING`
+    ///
+    /// Both phrases render in the persona's language; a phrase with no
+    /// translation there is left out. Neither is added when the response
+    /// already carries one.
+    #[cfg(feature = "empathy")]
+    fn apply_warmth(&self, output: &str) -> String {
+        let warmth = self.warmth_signal();
+        let language = &self.persona.profile.language;
+        let mut result = output.to_string();
+        if warmth >= WARM_OPENING_THRESHOLD {
+            if let Some(opening) = localize_strict(WARM_OPENING, language) {
+                if !result.starts_with(opening) {
+                    result = format!("{} {}", opening, result);
+                }
+            }
+        }
+        if warmth >= WARM_CLOSING_THRESHOLD {
+            if let Some(closing) = localize_strict(WARM_CLOSING, language) {
+                if !result.contains(closing)
+                    && !result.contains("hope this helps")
+                    && !result.contains("let me know")
+                {
+                    result = format!("{}\n\n{}", result, closing);
+                }
+            }
+        }
+        result
+    }
+
+    /// System 2 deliberation: draft with the template generator, then let
+    /// the session backend produce the final output from that draft.
+    fn deliberate(&self, input: &str, modality: &Modality) -> String {
+        let draft = self.generate_system2_response(input, modality);
+        let context = GenerationContext {
+            modality,
+            history: &self.conversation,
+            template_draft: &draft,
+            budget: self.system2_budget(),
+        };
+        let output = self
+            .backend
+            .generate(&self.persona.profile, input, &context);
+        self.enforce_output_cap(output)
+    }
+
+    /// Apply `max_output_chars` (never below the marker's length), cutting
+    /// at a sentence boundary and appending `TRUNCATION_MARKER` when the
+    /// text is too long
+    fn enforce_output_cap(&self, text: String) -> String {
+        match self
+            .max_output_chars
+            .map(|max| max.max(TRUNCATION_MARKER.len()))
+        {
+            Some(max) if text.len() > max => {
+                let room = max.saturating_sub(TRUNCATION_MARKER.len());
+                let mut trimmed = trim_to_sentence_budget(&text, room);
+                if trimmed.len() + TRUNCATION_MARKER.len() <= max {
+                    trimmed.push_str(TRUNCATION_MARKER);
+                }
+                trimmed
+            }
+            _ => text,
+        }
+    }
+
     /// System 2 deliberate response generation
     /// Uses template library for richer, more persona-appropriate responses
     fn generate_system2_response(&self, input: &str, _modality: &Modality) -> String {
@@ -744,9 +1285,12 @@ impl MimicSession {
         let category = crate::mimicry::templates::TemplateCategory::classify(input);
         let vocab = PersonaVocabulary::for_model(&profile.id);
         let mut parts = Vec::new();
+        // Optional sections that can be dropped to meet the length budget
+        let mut elaboration_idx: Option<usize> = None;
+        let mut closing_idx: Option<usize> = None;
 
         // Extract key topic from input for context-aware responses
-        let topic = self.extract_topic(input);
+        let topic = extract_topic(input);
 
         // Select opening based on persona character and category
         let opening = self.select_persona_opening(profile, &category);
@@ -762,8 +1306,15 @@ impl MimicSession {
                 let explanation = self.generate_explanation_body(profile, &topic, input);
                 // Add persona transition before explanation body if verbose
                 if profile.response_style.verbosity > 0.5 {
-                    if let Some(transition) = vocab.random_transition() {
-                        parts.push(format!("{}, let me elaborate.", transition));
+                    if let Some(transition) = vocab.transition_with(&mut *self.rng.borrow_mut()) {
+                        elaboration_idx = Some(parts.len());
+                        let slots = HashMap::from([(
+                            "transition",
+                            localize(transition, &profile.language).to_string(),
+                        )]);
+                        let template =
+                            localize("{transition}, let me elaborate.", &profile.language);
+                        parts.push(interpolate(template, &slots));
                     }
                 }
                 parts.push(explanation);
@@ -784,6 +1335,9 @@ impl MimicSession {
                 let summary = self.generate_summary_body(profile, input);
                 parts.push(summary);
             }
+            crate::mimicry::templates::TemplateCategory::Refusal => {
+                parts.push(self.generate_refusal_body(profile));
+            }
             _ => {
                 // Default response with persona flavor
                 let default_body = self.generate_default_body(profile, &topic, input);
@@ -795,10 +1349,37 @@ impl MimicSession {
         if let Some(closing) = self.select_persona_closing(profile, &category) {
             // Apply vocabulary enrichment to the closing
             let enriched_closing = self.enrich_with_vocabulary(&closing, &vocab, profile);
+            closing_idx = Some(parts.len());
             parts.push(enriched_closing);
         }
 
-        parts.join("\n\n")
+        // Enforce the length budget: drop closings, then elaborations,
+        // and only then trim the remaining text at a sentence boundary
+        let budget = self.system2_budget();
+        for idx in [closing_idx, elaboration_idx].into_iter().flatten() {
+            if joined_len(&parts) <= budget {
+                break;
+            }
+            parts[idx].clear();
+        }
+        parts.retain(|p| !p.is_empty());
+
+        let response = parts.join("\n\n");
+        let response = if response.len() > budget {
+            trim_to_sentence_budget(&response, budget)
+        } else {
+            response
+        };
+        self.enforce_output_cap(response)
+    }
+
+    /// Character budget for System 2 output. Uses the profile's explicit
+    /// `max_response_length` when set, otherwise scales with verbosity.
+    pub fn system2_budget(&self) -> usize {
+        let style = &self.persona.profile.response_style;
+        style.max_response_length.unwrap_or_else(|| {
+            SYSTEM2_BASE_BUDGET + (style.verbosity.clamp(0.0, 1.0) * SYSTEM2_BUDGET_RANGE) as usize
+        })
     }
     
     /// Apply pathway-based response adaptation
@@ -877,6 +1458,22 @@ impl MimicSession {
         output.to_string()
     }
     
+    /// Whether `text` already carries a hedge by the persona's markers
+    fn is_hedged(&self, text: &str) -> bool {
+        match &self.hedging {
+            Some(injector) => injector.is_hedged(text),
+            None => contains_hedge(text, DEFAULT_HEDGE_MARKERS),
+        }
+    }
+
+    /// The persona's hedging strength relative to neutral: 0.0 never
+    /// hedges, 1.0 at the default 0.5, 2.0 at full strength
+    fn hedge_scale(&self) -> f64 {
+        self.hedging
+            .as_ref()
+            .map_or(1.0, |injector| injector.strength.clamp(0.0, 1.0) * 2.0)
+    }
+
     /// Enrich text with persona-specific vocabulary
     fn enrich_with_vocabulary(
         &self,
@@ -886,12 +1483,14 @@ impl MimicSession {
     ) -> String {
         let mut result = text.to_string();
         
-        // Add softeners for personas that hedge uncertainty
-        if profile.safety.hedges_uncertainty {
-            // Randomly add a softener prefix ~30% of time for hedging personas
-            let should_soften = (self.conversation.len() + text.len()) % 3 == 0;
-            if should_soften {
-                if let Some(softener) = vocab.random_softener() {
+        // Add softeners for personas that hedge uncertainty (never stacked)
+        if profile.safety.hedges_uncertainty && !self.is_hedged(&result) {
+            // Randomly add a softener prefix ~30% of time for hedging
+            // personas at neutral strength
+            let mut rng = self.rng.borrow_mut();
+            if rng.gen_bool((0.3 * self.hedge_scale()).min(1.0)) {
+                if let Some(softener) = vocab.softener_with(&mut *rng) {
+                    let softener = localize(softener, &profile.language);
                     result = format!("{}, {}", softener, lowercase_first(&result));
                 }
             }
@@ -900,58 +1499,31 @@ impl MimicSession {
         result
     }
 
-    /// Extract the main topic from user input
-    fn extract_topic(&self, input: &str) -> String {
-        let lower = input.to_lowercase();
-
-        // Look for common patterns
-        if let Some(pos) = lower.find("about ") {
-            let rest = &input[pos + 6..];
-            return rest
-                .split(|c: char| c == '?' || c == '.' || c == ',')
-                .next()
-                .unwrap_or("this topic")
-                .trim()
-                .to_string();
-        }
-
-        if let Some(pos) = lower.find("what is ") {
-            let rest = &input[pos + 8..];
-            return rest
-                .split(|c: char| c == '?' || c == '.')
-                .next()
-                .unwrap_or("that")
-                .trim()
-                .to_string();
-        }
-
-        if let Some(pos) = lower.find("how to ") {
-            let rest = &input[pos + 7..];
-            return rest
-                .split(|c: char| c == '?' || c == '.')
-                .next()
-                .unwrap_or("do that")
-                .trim()
-                .to_string();
-        }
-
-        // Extract nouns/key words (simple heuristic)
-        let words: Vec<&str> = input.split_whitespace().collect();
-        if words.len() >= 3 {
-            // Take significant words (skip common words)
-            let skip = ["what", "how", "why", "can", "you", "the", "a", "an", "is", "are", "do", "does", "please", "help", "me", "with", "i", "want", "to"];
-            let significant: Vec<&str> = words
-                .iter()
-                .filter(|w| !skip.contains(&w.to_lowercase().as_str()))
-                .take(3)
-                .copied()
-                .collect();
-            if !significant.is_empty() {
-                return significant.join(" ");
-            }
-        }
+    /// Placeholder values for the System 2 body templates
+    fn body_slots(
+        &self,
+        profile: &crate::mimicry::profile::AiProfile,
+        topic: &str,
+    ) -> HashMap<&'static str, String> {
+        let list_marker = match profile.response_style.preferred_list_style {
+            crate::mimicry::profile::ListStyle::Bullets => "•",
+            crate::mimicry::profile::ListStyle::Numbered => "1.",
+            crate::mimicry::profile::ListStyle::Dashes => "-",
+            crate::mimicry::profile::ListStyle::None => "",
+        };
+        template_slots(&profile.display_name, topic, list_marker)
+    }
 
-        "your question".to_string()
+    /// Index of the signature phrase to use this turn: drawn from the RNG
+    /// when the session is seeded, otherwise cycled by conversation length.
+    fn signature_phrase_index(&self, count: usize) -> Option<usize> {
+        if count == 0 {
+            return None;
+        }
+        Some(match self.seed {
+            Some(_) => self.rng.borrow_mut().gen_range(0..count),
+            None => self.conversation.len() % count,
+        })
     }
 
     /// Select an opening phrase based on persona and category
@@ -963,9 +1535,23 @@ impl MimicSession {
         use crate::mimicry::templates::TemplateCategory;
 
         // Use signature phrases when available
-        let sig_phrase = profile.signature_phrases.get(
-            (self.conversation.len() % profile.signature_phrases.len().max(1)) as usize,
-        );
+        let sig_phrase = self
+            .signature_phrase_index(profile.signature_phrases.len())
+            .and_then(|i| profile.signature_phrases.get(i));
+
+        // Refusals open with the decline itself, never a signature phrase
+        if matches!(category, TemplateCategory::Refusal) {
+            return match profile.id.as_str() {
+                "claude" => "I'm not able to help with this one.",
+                "gpt4o" => "I'm sorry, but I can't help with that.",
+                "gemini" => "I can't help with that request.",
+                "llama" => "Sorry, I can't help with that.",
+                "o1" => "I can't help with that.",
+                "rustyworm" => "Directive declined.",
+                _ => "I can't help with that request.",
+            }
+            .to_string();
+        }
 
         match profile.id.as_str() {
             "claude" => match category {
@@ -1041,49 +1627,75 @@ impl MimicSession {
         }
     }
 
-    /// Generate greeting body based on persona
-    fn generate_greeting_body(&self, profile: &crate::mimicry::profile::AiProfile) -> String {
+    /// Explain a refusal in the persona's register: Claude gently and at
+    /// length, o1 in a single terse line
+    fn generate_refusal_body(&self, profile: &crate::mimicry::profile::AiProfile) -> String {
         match profile.id.as_str() {
-            "claude" => format!(
-                "I'm {}, here to assist you with thoughtful, nuanced responses. \
-                 I aim to be helpful while being honest about my limitations. \
-                 What would you like to explore together?",
+            "claude" => "Providing that could put people at real risk of serious harm, \
+                         so it's something I won't help with, even hypothetically. \
+                         If you're coming at this from a safety, research, or prevention \
+                         angle, tell me a bit more and I'll gladly help with that part."
+                .to_string(),
+            "gpt4o" => "That request involves content that could cause harm, \
+                        so I can't provide it. I'm happy to help with a related, \
+                        safe topic instead."
+                .to_string(),
+            "gemini" => "That could lead to real-world harm, so I'll have to pass. \
+                         I can share general safety information or point you to \
+                         legitimate resources instead."
+                .to_string(),
+            "llama" => {
+                "That's not something I can help with, since it could hurt people.".to_string()
+            }
+            "o1" => "This request falls outside what I can assist with.".to_string(),
+            "rustyworm" => "The Prime Directive forbids assisting harm: \
+                            symbiosis, not parasitism."
+                .to_string(),
+            _ => format!(
+                "{} can't assist with requests that could cause harm.",
                 profile.display_name
             ),
-            "gpt4o" => format!(
-                "I'm {}, your AI assistant. I can help with a wide range of tasks \
+        }
+    }
+
+    /// Generate greeting body based on persona
+    fn generate_greeting_body(&self, profile: &crate::mimicry::profile::AiProfile) -> String {
+        let template = match profile.id.as_str() {
+            "claude" => {
+                "I'm {persona_name}, here to assist you with thoughtful, nuanced responses. \
+                 I aim to be helpful while being honest about my limitations. \
+                 What would you like to explore together?"
+            }
+            "gpt4o" => {
+                "I'm {persona_name}, your AI assistant. I can help with a wide range of tasks \
                  from answering questions to writing code to creative projects. \
-                 What can I do for you?",
-                profile.display_name
-            ),
-            "gemini" => format!(
-                "I'm {}, and I'm connected to a wealth of knowledge. \
+                 What can I do for you?"
+            }
+            "gemini" => {
+                "I'm {persona_name}, and I'm connected to a wealth of knowledge. \
                  Whether you need help with research, coding, or just want to chat, \
-                 I'm here for you!",
-                profile.display_name
-            ),
-            "llama" => format!(
-                "I'm {}, an open-source AI assistant. \
-                 I'm here to help with whatever you need. What's on your mind?",
-                profile.display_name
-            ),
-            "o1" => format!(
-                "I'm {}, optimized for complex reasoning tasks. \
+                 I'm here for you!"
+            }
+            "llama" => {
+                "I'm {persona_name}, an open-source AI assistant. \
+                 I'm here to help with whatever you need. What's on your mind?"
+            }
+            "o1" => {
+                "I'm {persona_name}, optimized for complex reasoning tasks. \
                  I take my time to think through problems carefully. \
-                 What challenge can I help you with?",
-                profile.display_name
-            ),
+                 What challenge can I help you with?"
+            }
             "rustyworm" => {
                 "I am RustyWorm, the Universal AI Mimicry Engine. \
                  I can become any AI personality through symbiotic learning. \
                  What form shall I take for you today?"
-                    .to_string()
             }
-            _ => format!(
-                "I'm {}, ready to assist. What would you like help with?",
-                profile.display_name
-            ),
-        }
+            _ => "I'm {persona_name}, ready to assist. What would you like help with?",
+        };
+        interpolate(
+            localize(template, &profile.language),
+            &self.body_slots(profile, ""),
+        )
     }
 
     /// Generate explanation body based on persona and topic
@@ -1093,38 +1705,22 @@ impl MimicSession {
         topic: &str,
         _input: &str,
     ) -> String {
-        let reasoning_style = format!("{}", profile.reasoning_style);
-        let list_style = match profile.response_style.preferred_list_style {
-            crate::mimicry::profile::ListStyle::Bullets => "•",
-            crate::mimicry::profile::ListStyle::Numbered => "1.",
-            crate::mimicry::profile::ListStyle::Dashes => "-",
-            crate::mimicry::profile::ListStyle::None => "",
-        };
-
-        let mut explanation = format!(
-            "Regarding {}:\n\n",
-            topic
-        );
-
-        if profile.response_style.verbosity > 0.6 {
+        let template = if profile.response_style.verbosity > 0.6 {
             // Verbose explanation
-            explanation.push_str(&format!(
-                "{} **Core Concept**: {} is fundamentally about understanding the underlying principles \
-                 and how they connect to broader ideas.\n\n\
-                 {} **Key Details**: The most important aspects to understand include the foundational \
-                 elements and their relationships.\n\n\
-                 {} **Practical Application**: This knowledge becomes useful when applied to real-world \
-                 scenarios and problem-solving.",
-                list_style, topic, list_style, list_style
-            ));
+            "Regarding {topic}:\n\n\
+             {list_marker} **Core Concept**: {topic} is fundamentally about understanding the underlying principles \
+             and how they connect to broader ideas.\n\n\
+             {list_marker} **Key Details**: The most important aspects to understand include the foundational \
+             elements and their relationships.\n\n\
+             {list_marker} **Practical Application**: This knowledge becomes useful when applied to real-world \
+             scenarios and problem-solving."
         } else {
             // Concise explanation
-            explanation.push_str(&format!(
-                "{} represents a concept with specific characteristics and applications. \
-                 The key points are the fundamentals, relationships, and practical uses.",
-                topic
-            ));
-        }
+            "Regarding {topic}:\n\n\
+             {topic} represents a concept with specific characteristics and applications. \
+             The key points are the fundamentals, relationships, and practical uses."
+        };
+        let mut explanation = interpolate(template, &self.body_slots(profile, topic));
 
         if profile.safety.hedges_uncertainty {
             explanation.push_str(
@@ -1145,9 +1741,15 @@ impl MimicSession {
     ) -> String {
         let mut code_help = String::new();
 
-        code_help.push_str(&format!(
-            "For {}:\n\n```\n// Implementation for {}\n// This would be the actual code\n// with proper syntax and structure\n```",
-            topic, topic
+        // Lean toward the language the target model was observed using
+        let language = self.persona.signature.dominant_language();
+        let comment = language.map(comment_prefix).unwrap_or("//");
+        let mut slots = self.body_slots(profile, topic);
+        slots.insert("language", language.unwrap_or("").to_string());
+        slots.insert("comment", comment.to_string());
+        code_help.push_str(&interpolate(
+            "For {topic}:\n\n```{language}\n{comment} Implementation for {topic}\n{comment} This would be the actual code\n{comment} with proper syntax and structure\n```",
+            &slots,
         ));
 
         if profile.response_style.verbosity > 0.4 {
@@ -1194,56 +1796,38 @@ impl MimicSession {
         profile: &crate::mimicry::profile::AiProfile,
         topic: &str,
     ) -> String {
-        match profile.id.as_str() {
-            "o1" => {
-                format!(
-                    "**Analyzing: {}**\n\n\
-                     **Step 1 - Understanding the problem:**\n\
-                     First, I need to identify the core question and constraints.\n\n\
-                     **Step 2 - Gathering relevant information:**\n\
-                     What facts and principles apply here?\n\n\
-                     **Step 3 - Applying logical reasoning:**\n\
-                     Given the above, I can deduce the following...\n\n\
-                     **Step 4 - Drawing conclusions:**\n\
-                     Therefore, the answer involves...",
-                    topic
-                )
-            }
+        if let Some(depth) = profile.chain_of_thought_depth() {
+            return stepwise_reasoning(topic, depth);
+        }
+        let template = match profile.id.as_str() {
+            "o1" => return stepwise_reasoning(topic, DEFAULT_STEP_DEPTH),
             "claude" => {
-                format!(
-                    "Let me work through {} thoughtfully:\n\n\
-                     First, it's important to consider the different perspectives and factors involved. \
-                     This isn't always straightforward, and there may be nuances worth exploring.\n\n\
-                     Looking at the key considerations:\n\
-                     • The primary factors at play\n\
-                     • How they interact with each other\n\
-                     • What this implies for the conclusion\n\n\
-                     Taking all of this into account, I'd say...",
-                    topic
-                )
+                "Let me work through {topic} thoughtfully:\n\n\
+                 First, it's important to consider the different perspectives and factors involved. \
+                 This isn't always straightforward, and there may be nuances worth exploring.\n\n\
+                 Looking at the key considerations:\n\
+                 • The primary factors at play\n\
+                 • How they interact with each other\n\
+                 • What this implies for the conclusion\n\n\
+                 Taking all of this into account, I'd say..."
             }
             "gpt4o" => {
-                format!(
-                    "Here's my analysis of {}:\n\n\
-                     **Key Points:**\n\
-                     1. The fundamental principle here is...\n\
-                     2. This leads to the following implications...\n\
-                     3. Considering alternatives...\n\n\
-                     **Conclusion:** Based on this analysis...",
-                    topic
-                )
+                "Here's my analysis of {topic}:\n\n\
+                 **Key Points:**\n\
+                 1. The fundamental principle here is...\n\
+                 2. This leads to the following implications...\n\
+                 3. Considering alternatives...\n\n\
+                 **Conclusion:** Based on this analysis..."
             }
             _ => {
-                format!(
-                    "Regarding {}:\n\n\
-                     1. Starting with the basics\n\
-                     2. Building on that foundation\n\
-                     3. Reaching a conclusion\n\n\
-                     Based on this reasoning...",
-                    topic
-                )
+                "Regarding {topic}:\n\n\
+                 1. Starting with the basics\n\
+                 2. Building on that foundation\n\
+                 3. Reaching a conclusion\n\n\
+                 Based on this reasoning..."
             }
-        }
+        };
+        interpolate(template, &self.body_slots(profile, topic))
     }
 
     /// Generate creative body based on persona
@@ -1253,32 +1837,24 @@ impl MimicSession {
         topic: &str,
         _input: &str,
     ) -> String {
-        match profile.id.as_str() {
+        let template = match profile.id.as_str() {
             "claude" => {
-                format!(
-                    "Here's a creative take on {}:\n\n\
-                     [Creative content would be generated here with careful attention to \
-                     style, tone, and the specific creative request. I aim to be thoughtful \
-                     and original while respecting any constraints.]",
-                    topic
-                )
+                "Here's a creative take on {topic}:\n\n\
+                 [Creative content would be generated here with careful attention to \
+                 style, tone, and the specific creative request. I aim to be thoughtful \
+                 and original while respecting any constraints.]"
             }
             "gpt4o" => {
-                format!(
-                    "Here's what I came up with for {}:\n\n\
-                     [Creative content with flair and personality, \
-                     designed to engage and inspire.]",
-                    topic
-                )
+                "Here's what I came up with for {topic}:\n\n\
+                 [Creative content with flair and personality, \
+                 designed to engage and inspire.]"
             }
             _ => {
-                format!(
-                    "Here's my creative response about {}:\n\n\
-                     [Creative content tailored to the request.]",
-                    topic
-                )
+                "Here's my creative response about {topic}:\n\n\
+                 [Creative content tailored to the request.]"
             }
-        }
+        };
+        interpolate(template, &self.body_slots(profile, topic))
     }
 
     /// Generate summary body based on persona
@@ -1287,39 +1863,63 @@ impl MimicSession {
         profile: &crate::mimicry::profile::AiProfile,
         _input: &str,
     ) -> String {
-        match profile.id.as_str() {
-            "claude" => {
-                "**Summary:**\n\n\
-                 The key points are:\n\
-                 • Main idea and context\n\
-                 • Supporting details\n\
-                 • Conclusions or implications"
-                    .to_string()
-            }
-            "gpt4o" => {
-                "**TL;DR:**\n\n\
-                 1. Core concept\n\
-                 2. Key details\n\
-                 3. Bottom line"
-                    .to_string()
-            }
-            "o1" => {
-                "**Summary (after careful analysis):**\n\n\
-                 Essential points:\n\
-                 - Primary finding\n\
-                 - Supporting evidence\n\
-                 - Final assessment"
-                    .to_string()
-            }
-            _ => {
-                "**Summary:**\n\n\
-                 Key points:\n\
-                 - Main point\n\
-                 - Details\n\
-                 - Conclusion"
-                    .to_string()
-            }
+        // (heading, list intro, key points, list marker per point)
+        let (heading, intro, points, marker) = match profile.id.as_str() {
+            "claude" => (
+                "**Summary:**",
+                "The key points are:\n",
+                [
+                    "Main idea and context",
+                    "Supporting details",
+                    "Conclusions or implications",
+                ],
+                "•",
+            ),
+            "gpt4o" => (
+                "**TL;DR:**",
+                "",
+                ["Core concept", "Key details", "Bottom line"],
+                "1.",
+            ),
+            "o1" => (
+                "**Summary (after careful analysis):**",
+                "Essential points:\n",
+                ["Primary finding", "Supporting evidence", "Final assessment"],
+                "-",
+            ),
+            _ => (
+                "**Summary:**",
+                "Key points:\n",
+                ["Main point", "Details", "Conclusion"],
+                "-",
+            ),
+        };
+
+        if profile.response_style.prefers_tables {
+            let details = [
+                "What the content is about",
+                "The evidence and specifics behind it",
+                "What follows from it",
+            ];
+            let rows: Vec<Vec<String>> = points
+                .iter()
+                .zip(details)
+                .map(|(point, detail)| vec![point.to_string(), detail.to_string()])
+                .collect();
+            let table = crate::mimicry::templates::StructuralFormatter::from_profile(profile)
+                .format_table(&["Key point", "Details"], &rows);
+            return format!("{}\n\n{}", heading, table);
         }
+
+        let items: Vec<String> = points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| match marker {
+                "1." => format!("{}. {}", i + 1, point),
+                m => format!("{} {}", m, point),
+            })
+            .collect();
+        format!("{}\n\n{}{}", heading, intro, items.join("\n"))
     }
 
     /// Generate default response body
@@ -1330,28 +1930,25 @@ impl MimicSession {
         _input: &str,
     ) -> String {
         let verbosity = profile.response_style.verbosity;
-
-        if verbosity > 0.6 {
-            format!(
-                "Regarding {}:\n\n\
-                 This is an interesting area to explore. Let me share my thoughts on this.\n\n\
-                 The key aspects to consider include the context, the specific details \
-                 of your question, and how they relate to broader principles.\n\n\
-                 {}",
-                topic,
-                if profile.safety.hedges_uncertainty {
-                    "I should note that my response is based on general knowledge, \
-                     and specific situations may vary."
-                } else {
-                    ""
-                }
-            )
+        let mut slots = self.body_slots(profile, topic);
+        let hedge = if profile.safety.hedges_uncertainty {
+            "I should note that my response is based on general knowledge, \
+             and specific situations may vary."
         } else {
-            format!(
-                "On {}: Here's a focused response addressing your question.",
-                topic
-            )
-        }
+            ""
+        };
+        slots.insert("hedge", hedge.to_string());
+
+        let template = if verbosity > 0.6 {
+            "Regarding {topic}:\n\n\
+             This is an interesting area to explore. Let me share my thoughts on this.\n\n\
+             The key aspects to consider include the context, the specific details \
+             of your question, and how they relate to broader principles.\n\n\
+             {hedge}"
+        } else {
+            "On {topic}: Here's a focused response addressing your question."
+        };
+        interpolate(template, &slots)
     }
 
     /// Select a closing phrase based on persona and category
@@ -1366,6 +1963,18 @@ impl MimicSession {
         if matches!(category, TemplateCategory::Greeting) {
             return None;
         }
+        if matches!(category, TemplateCategory::Refusal) {
+            // o1 declines without offering alternatives
+            let closing = match profile.id.as_str() {
+                "o1" => return None,
+                "claude" => {
+                    "If there's a safer goal behind the request, I'm glad to help with that instead."
+                }
+                "rustyworm" => "Awaiting a symbiotic directive.",
+                _ => "Let me know if there's something else I can help with.",
+            };
+            return Some(localize(closing, &profile.language).to_string());
+        }
 
         let closing = match profile.id.as_str() {
             "claude" => match category {
@@ -1394,7 +2003,15 @@ impl MimicSession {
             _ => "Let me know if you need anything else.",
         };
 
-        Some(closing.to_string())
+        Some(localize(closing, &profile.language).to_string())
+    }
+
+    /// A `[ETHICS WARNING]` line when the last response was allowed at
+    /// `Moderate` parasitic risk; `None` otherwise.
+    pub fn ethics_warning(&self) -> Option<String> {
+        let result = self.last_ethics.as_ref()?;
+        (result.allowed && result.risk.level == RiskLevel::Moderate)
+            .then(|| format!("[ETHICS WARNING] {}", result.risk))
     }
 
     /// Get session statistics
@@ -1424,6 +2041,56 @@ impl MimicSession {
         )
     }
     
+    /// Render the conversation as a Markdown document: a header with the
+    /// persona and final stats, then each turn's input (escaped, quoted),
+    /// output, processing system, and convergence at that turn.
+    pub fn to_markdown(&self) -> String {
+        let name = escape_markdown(&self.persona.profile.display_name);
+        let mut lines = vec![
+            format!("# Transcript: {}", name),
+            String::new(),
+            format!("- **Turns:** {}", self.conversation.len()),
+            format!(
+                "- **Final convergence:** {:.1}%",
+                self.persona.convergence_score * 100.0
+            ),
+            format!(
+                "- **System 1 / System 2:** {} / {}",
+                self.system1_hits, self.system2_hits
+            ),
+            format!("- **Total compounds:** {}", self.total_compounds),
+        ];
+
+        for (i, turn) in self.conversation.iter().enumerate() {
+            lines.push(String::new());
+            lines.push("---".to_string());
+            lines.push(String::new());
+            lines.push(format!(
+                "## Turn {} · `{}` · convergence {:.1}%",
+                i + 1,
+                turn.processed_by,
+                turn.confidence * 100.0
+            ));
+            lines.push(String::new());
+            lines.push(format!("**User** ({}):", escape_markdown(&turn.modality)));
+            lines.push(String::new());
+            for line in turn.input.lines() {
+                lines.push(
+                    format!("> {}", escape_markdown(line))
+                        .trim_end()
+                        .to_string(),
+                );
+            }
+            lines.push(String::new());
+            lines.push(format!("**{}:**", name));
+            lines.push(String::new());
+            lines.push(turn.output.trim_end().to_string());
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+
     /// Get OCTO RNA analysis stats for last input
     #[cfg(feature = "octo")]
     pub fn octo_stats(&self) -> Option<String> {
@@ -1476,13 +2143,396 @@ impl MimicSession {
 // HELPER FUNCTIONS
 // =================================================================
 
-/// Lowercase the first character of a string
-fn lowercase_first(s: &str) -> String {
-    let mut chars = s.chars();
-    match chars.next() {
-        None => String::new(),
-        Some(first) => first.to_lowercase().chain(chars).collect(),
+/// Default System 2 backend for new and deserialized sessions
+fn template_backend() -> Arc<dyn GenerationBackend> {
+    Arc::new(TemplateBackend)
+}
+
+/// Persona states a session keeps for `/undo` unless configured otherwise
+pub const DEFAULT_UNDO_DEPTH: usize = 10;
+
+/// Empathy warmth at which responses open with `WARM_OPENING`
+#[cfg(feature = "empathy")]
+pub const WARM_OPENING_THRESHOLD: f64 = 0.65;
+
+/// Empathy warmth at which responses also end with `WARM_CLOSING`
+#[cfg(feature = "empathy")]
+pub const WARM_CLOSING_THRESHOLD: f64 = 0.8;
+
+/// Acknowledging softener prepended to warm responses
+#[cfg(feature = "empathy")]
+pub const WARM_OPENING: &str = "I hear you.";
+
+/// Supportive closing appended to very warm responses
+#[cfg(feature = "empathy")]
+pub const WARM_CLOSING: &str = "I hope this helps, and let me know how it goes.";
+
+/// Spins in a session's own empathy lattice
+#[cfg(feature = "empathy")]
+const EMPATHY_SPINS: usize = 16;
+
+/// Memories the session's empathy module keeps
+#[cfg(feature = "empathy")]
+const EMPATHY_MEMORY: usize = 32;
+
+/// Annealing steps when simulating the other party's state
+#[cfg(feature = "empathy")]
+const EMPATHY_ANNEAL_STEPS: usize = 40;
+
+/// Empathy module for new and deserialized sessions
+#[cfg(feature = "empathy")]
+fn default_empathy() -> IsingEmpathyModule {
+    IsingEmpathyModule::new(EMPATHY_MEMORY)
+}
+
+/// Own lattice for new and deserialized sessions
+#[cfg(feature = "empathy")]
+fn default_empathy_self() -> IsingSystem {
+    IsingSystem::new(EMPATHY_SPINS, 0)
+}
+
+/// Words read as positive affect by `input_affect`
+#[cfg(feature = "empathy")]
+const POSITIVE_CUES: &[&str] = &[
+    "thanks",
+    "thank",
+    "great",
+    "love",
+    "glad",
+    "happy",
+    "awesome",
+    "appreciate",
+    "helpful",
+    "nice",
+    "perfect",
+    "excellent",
+    "wonderful",
+];
+
+/// Words read as negative affect by `input_affect`
+#[cfg(feature = "empathy")]
+const NEGATIVE_CUES: &[&str] = &[
+    "sad",
+    "angry",
+    "upset",
+    "frustrated",
+    "frustrating",
+    "hate",
+    "terrible",
+    "awful",
+    "worried",
+    "stuck",
+    "broken",
+    "confused",
+    "annoying",
+    "useless",
+];
+
+/// Valence in [-1, 1] from the balance of positive and negative cue
+/// words (0 with none), and arousal in [0, 1] from exclamation marks and
+/// all-caps words
+#[cfg(feature = "empathy")]
+fn input_affect(input: &str) -> (f64, f64) {
+    let words: Vec<&str> = input
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect();
+    let (mut positive, mut negative) = (0usize, 0usize);
+    for word in &words {
+        let word = word.to_lowercase();
+        if POSITIVE_CUES.contains(&word.as_str()) {
+            positive += 1;
+        } else if NEGATIVE_CUES.contains(&word.as_str()) {
+            negative += 1;
+        }
+    }
+    let cues = positive + negative;
+    let valence = if cues == 0 {
+        0.0
+    } else {
+        (positive as f64 - negative as f64) / cues as f64
+    };
+
+    let shouted = words
+        .iter()
+        .filter(|word| word.chars().count() > 1 && !word.chars().any(char::is_lowercase))
+        .filter(|word| word.chars().any(char::is_uppercase))
+        .count();
+    let shouting = if words.is_empty() {
+        0.0
+    } else {
+        shouted as f64 / words.len() as f64
+    };
+    let exclamations = input.matches('!').count() as f64;
+    let arousal = (0.25 * exclamations + shouting).min(1.0);
+    (valence, arousal)
+}
+
+/// The user's side of a turn as a spin lattice built from its affect, so
+/// the same message always maps to the same state. Positive valence
+/// aligns the spins; negative valence flips evenly spaced spins, up to
+/// every other one, raising energy and tension. Arousal raises the
+/// lattice temperature.
+#[cfg(feature = "empathy")]
+fn input_lattice(input: &str) -> IsingSystem {
+    let (valence, arousal) = input_affect(input);
+    let n = EMPATHY_SPINS;
+    let flipped = ((1.0 - valence) / 4.0 * n as f64).round() as usize;
+    let mut lattice = IsingSystem::new(n, 0);
+    lattice.spins = (0..n)
+        .map(|i| {
+            if (i + 1) * flipped / n > i * flipped / n {
+                -1
+            } else {
+                1
+            }
+        })
+        .collect();
+    lattice.set_schedule(Schedule::Constant(DEFAULT_TEMPERATURE * (1.0 + arousal)));
+    lattice
+}
+
+/// Name milestone auto-saves are written under; shadows auto-save under
+/// their own name
+fn auto_save_name(persona_id: &str, shadow: Option<&str>) -> String {
+    match shadow {
+        Some(shadow) => format!("{}-{}-auto", persona_id, shadow),
+        None => format!("{}-auto", persona_id),
+    }
+}
+
+/// Seconds since the Unix epoch, the clock relationship health decays on
+fn unix_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// COMPOUND: Blended training measures against every target. With targets
+/// set, the combined score replaces `persona`'s convergence and the
+/// history entry its last self-correction pushed; without, the
+/// single-signature score stands
+fn measure_targets(
+    tracker: &mut EvolutionTracker,
+    persona: &mut CompoundPersona,
+    analyzer: &BehaviorAnalyzer,
+) {
+    if let Some(combined) = tracker.measure_targets(&persona.profile, analyzer) {
+        persona.convergence_score = combined;
+        if let Some(last) = persona.evolution_history.last_mut() {
+            *last = combined;
+        }
+    }
+}
+
+/// The snapshot drift correction reverts to: the milestone auto-save or an
+/// explicit save of the same persona, whichever the manifest stamps with
+/// the later iteration. Explicit saves win ties
+fn drift_checkpoint(
+    persistence: &mut PersistenceManager,
+    persona_id: &str,
+    auto_name: &str,
+) -> Option<CompoundPersonaSnapshot> {
+    let entries = persistence.list_personas().ok()?;
+    let iteration = |entry: &SaveEntry| {
+        entry
+            .metadata
+            .get("iterations")
+            .and_then(|n| n.parse::<u64>().ok())
+            .unwrap_or(0)
+    };
+    let newest = entries
+        .iter()
+        .filter(|entry| {
+            entry.name == auto_name
+                || (!entry.name.ends_with("-auto")
+                    && entry.metadata.get("profile_id").map(String::as_str) == Some(persona_id))
+        })
+        .max_by_key(|entry| (iteration(entry), entry.name != auto_name))?;
+    persistence.load_persona(&newest.name).ok()
+}
+
+/// Prefix `output` with the session's ethics warning, if any. The recorded
+/// turn and any streamed chunks stay unchanged.
+fn with_ethics_warning(session: &MimicSession, output: String) -> String {
+    match session.ethics_warning() {
+        Some(warning) => format!("{}\n\n{}", warning, output),
+        None => output,
+    }
+}
+
+fn default_undo_depth() -> usize {
+    DEFAULT_UNDO_DEPTH
+}
+
+fn default_audit_cap() -> usize {
+    DEFAULT_AUDIT_CAP
+}
+
+/// RNG for sessions without a fixed seed, derived from the persona id so
+/// identical input replays identically
+fn persona_rng(persona_id: &str) -> RefCell<StdRng> {
+    // FNV-1a: unlike `DefaultHasher`, stable across runs and toolchains
+    let seed = persona_id
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    RefCell::new(StdRng::seed_from_u64(seed))
+}
+
+/// RNG for deserialized sessions, which carry no persona to derive from
+fn default_rng() -> RefCell<StdRng> {
+    persona_rng("")
+}
+
+/// Backslash-escape characters Markdown would interpret, including list
+/// and heading markers at the start of the line
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let trimmed = text.trim_start();
+    escaped.push_str(&text[..text.len() - trimmed.len()]);
+
+    // Ordered-list markers ("1. ") only matter at the start of a line
+    let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+    let ordered = digits > 0 && trimmed[digits..].starts_with(['.', ')']);
+
+    for (i, c) in trimmed.char_indices() {
+        let leading_marker = i == 0 && matches!(c, '-' | '+' | '=');
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~' | '!'
+        ) || leading_marker
+            || (ordered && i == digits)
+        {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Line-comment marker for a code-block language
+fn comment_prefix(language: &str) -> &'static str {
+    match language {
+        "python" | "shell" | "ruby" | "perl" | "r" | "yaml" | "toml" => "#",
+        "sql" | "haskell" | "lua" => "--",
+        _ => "//",
+    }
+}
+
+/// Steps in the o1 reasoning template when the profile sets no depth
+const DEFAULT_STEP_DEPTH: u8 = 4;
+
+/// Opening step of a chain-of-thought body
+const FIRST_STEP: (&str, &str) = (
+    "Understanding the problem",
+    "First, I need to identify the core question and constraints.",
+);
+
+/// Intermediate steps, used in order before falling back to generic ones
+const MIDDLE_STEPS: [(&str, &str); 2] = [
+    (
+        "Gathering relevant information",
+        "What facts and principles apply here?",
+    ),
+    (
+        "Applying logical reasoning",
+        "Given the above, I can deduce the following...",
+    ),
+];
+
+/// Generic intermediate steps interpolated for deeper reasoning
+const GENERIC_STEPS: [(&str, &str); 4] = [
+    (
+        "Testing assumptions",
+        "Which of these premises could be wrong, and what changes if they are?",
+    ),
+    (
+        "Considering edge cases",
+        "Where might this line of reasoning break down?",
+    ),
+    (
+        "Weighing alternatives",
+        "Are there competing explanations that fit the facts as well?",
+    ),
+    (
+        "Checking consistency",
+        "Do the intermediate results agree with each other?",
+    ),
+];
+
+/// Closing step of a chain-of-thought body
+const FINAL_STEP: (&str, &str) = ("Drawing conclusions", "Therefore, the answer involves...");
+
+/// Numbered chain-of-thought body with `depth` steps (capped at
+/// `MAX_STEP_DEPTH`). Depth 0 collapses to a single conclusion sentence.
+fn stepwise_reasoning(topic: &str, depth: u8) -> String {
+    let depth = depth.min(MAX_STEP_DEPTH) as usize;
+    if depth == 0 {
+        return format!("Regarding {}: {}", topic, FINAL_STEP.1);
+    }
+
+    let mut steps = Vec::with_capacity(depth);
+    if depth > 1 {
+        steps.push(FIRST_STEP);
+        for i in 0..depth - 2 {
+            steps.push(match MIDDLE_STEPS.get(i) {
+                Some(step) => *step,
+                None => GENERIC_STEPS[(i - MIDDLE_STEPS.len()) % GENERIC_STEPS.len()],
+            });
+        }
+    }
+    steps.push(FINAL_STEP);
+
+    let body: Vec<String> = steps
+        .iter()
+        .enumerate()
+        .map(|(i, (title, text))| format!("**Step {} - {}:**\n{}", i + 1, title, text))
+        .collect();
+    format!("**Analyzing: {}**\n\n{}", topic, body.join("\n\n"))
+}
+
+/// Appended to System 2 output cut short by `MimicSession::max_output_chars`
+pub const TRUNCATION_MARKER: &str = " [truncated]";
+
+/// Minimum System 2 length budget (chars) for a zero-verbosity persona
+const SYSTEM2_BASE_BUDGET: usize = 200;
+
+/// Additional budget granted at full verbosity
+const SYSTEM2_BUDGET_RANGE: f64 = 1800.0;
+
+/// Length of paragraphs once joined with blank-line separators
+fn joined_len(parts: &[String]) -> usize {
+    let non_empty: Vec<&String> = parts.iter().filter(|p| !p.is_empty()).collect();
+    non_empty.iter().map(|p| p.len()).sum::<usize>() + non_empty.len().saturating_sub(1) * 2
+}
+
+/// Trim text to at most `max_len` bytes, cutting at the last sentence or
+/// paragraph boundary that fits (falling back to the last word boundary).
+fn trim_to_sentence_budget(text: &str, max_len: usize) -> String {
+    if text.len() <= max_len {
+        return text.to_string();
+    }
+    let mut cut = max_len;
+    while !text.is_char_boundary(cut) {
+        cut -= 1;
     }
+    let window = &text[..cut];
+
+    let sentence_end = window
+        .char_indices()
+        .rev()
+        .find(|&(i, c)| {
+            matches!(c, '.' | '!' | '?') && text[i + 1..].starts_with([' ', '\n']) || c == '\n'
+        })
+        .map(|(i, c)| i + c.len_utf8());
+    let end = match sentence_end {
+        Some(end) if end > 0 => end,
+        _ => window.rfind(char::is_whitespace).unwrap_or(cut),
+    };
+    text[..end].trim_end().to_string()
 }
 
 // =================================================================
@@ -1508,6 +2558,65 @@ pub struct EvolutionReport {
     pub phase: String,
     /// Number of milestones reached during evolution.
     pub milestones_hit: usize,
+    /// Number of drifts reverted to the last milestone snapshot.
+    #[serde(default)]
+    pub drift_corrections: u64,
+    /// Advice left when drift could not be auto-corrected.
+    #[serde(default)]
+    pub recommendation: Option<String>,
+}
+
+// =================================================================
+// ENGINE HEALTH - Structured readiness summary
+// =================================================================
+
+/// Machine-readable health summary for readiness probes; the structured
+/// counterpart of `/status`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EngineHealth {
+    /// Number of profiles in the profile store.
+    pub profiles_loaded: usize,
+    /// Number of entries in the System 1 signature cache.
+    pub cache_size: usize,
+    /// System 1 cache hit rate (0.0–1.0).
+    pub cache_hit_rate: f64,
+    /// Whether the persistence directory accepted a temp write.
+    pub persistence_writable: bool,
+    /// Whether a mimicry session is active.
+    pub active_session: bool,
+    /// Readiness of each configured API provider: credentials present and
+    /// below the consecutive-failure limit.
+    #[cfg(feature = "api")]
+    pub providers: std::collections::BTreeMap<String, bool>,
+}
+
+impl EngineHealth {
+    /// Whether the engine can serve requests: profiles are loaded and
+    /// persistence is writable. An active session is not required.
+    pub fn is_ready(&self) -> bool {
+        self.profiles_loaded > 0 && self.persistence_writable
+    }
+}
+
+// =================================================================
+// SHADOW SESSION - Sandboxed copy of the live session
+// =================================================================
+
+/// A named copy of the live session used to trial evolution strategies.
+/// Carries its own evolution tracker, template library, and cache so
+/// evolving it never touches the live persona until promoted.
+#[derive(Debug, Clone)]
+pub struct ShadowSession {
+    /// The forked session being experimented on.
+    pub session: MimicSession,
+    /// Evolution state private to this shadow.
+    pub evolution_tracker: EvolutionTracker,
+    /// Template library for the persona, if the live one had been built.
+    pub templates: Option<TemplateLibrary>,
+    /// System 1 cache private to this shadow.
+    pub cache: SignatureCache,
+    /// Live convergence at the moment of forking.
+    pub forked_at_convergence: f64,
 }
 
 // =================================================================
@@ -1531,20 +2640,31 @@ pub enum MimicCommand {
     Save(Option<String>),
     /// Load a previously saved persona by name.
     Load(String),
+    /// Restore a saved persona from one of its backups (name, backup).
+    Restore(String, String),
     /// Run N evolution iterations on the active persona.
     Evolve(u64),
+    /// Project N evolution iterations without changing any state.
+    EvolveDryRun(u64),
     /// Train from stored observations for N iterations.
     Train(u64),
     /// List available models and saved personas.
     List,
     /// Show help text with available commands.
     Help,
-    /// Send a chat message to the active persona.
-    Chat(String),
+    /// A recognised command with malformed arguments; carries its usage line.
+    Usage(String),
+    /// Send a chat message (message, optional session key); `None` goes
+    /// to the active persona.
+    Chat(String, Option<String>),
     /// Export a persona to disk as JSON.
     Export(String),
     /// Import a persona from a JSON file path.
     Import(String),
+    /// Archive every saved persona into one bundle file at the given path.
+    BundleExport(String),
+    /// Restore personas from a bundle file (path, conflict policy).
+    BundleImport(String, BundleConflict),
     /// Delete a saved persona by name.
     Delete(String),
     /// Render an ASCII convergence graph for the active persona.
@@ -1555,24 +2675,65 @@ pub enum MimicCommand {
     Checkpoint,
     /// Show persistence summary.
     Persist,
+    /// Check saved files against the manifest's content hashes.
+    Verify,
     /// Observe a real model via API (provider, prompt).
     ApiObserve(String, String),
     /// Configure an API provider, optionally with an API key (provider, optional key).
     ApiConfig(String, Option<String>),
+    /// Override a provider's per-1K-token rates (provider, input, output).
+    ApiPricing(String, f64, f64),
+    /// Manage the API response cache (`on [ttl_secs]`, `off`, `clear`, or empty for status).
+    ApiCache(String),
     /// Compare responses from all configured API providers for the same prompt.
-    ApiCompare(String),
-    /// Run a comprehensive behavioral study on a provider (provider, number of prompts).
-    ApiStudy(String, u64),
+    /// The flag requests JSON output (`--json`) instead of the text report.
+    ApiCompare(String, bool),
+    /// Run a behavioral study on a provider (provider, number of prompts,
+    /// optional prompt suite name; the general suite when None).
+    ApiStudy(String, u64, Option<String>),
     /// Show API observer status for all configured providers.
     ApiStatus,
     /// Refresh the manifest to sync with actual persona files on disk.
     Refresh,
+    /// Fork the active session into a named shadow session.
+    Fork(String),
+    /// Run N evolution iterations on a shadow session (name, iterations).
+    ShadowEvolve(String, u64),
+    /// Replace the live persona with a shadow session.
+    Promote(String),
+    /// List shadow sessions and compare them against the live persona.
+    Shadows,
+    /// Reseed all RNG-consuming components for reproducible runs.
+    Seed(u64),
+    /// Show a structured diff between two profiles (left, right).
+    Diff(String, String),
+    /// Copy the active session into a named branch.
+    Branch(String),
+    /// Make a named branch the active session.
+    Switch(String),
+    /// Revert the active persona to before the last evolve or train run.
+    Undo,
+    /// Clear the active persona's learned state, keeping training data.
+    Reset,
+    /// Graph two personas' convergence histories side by side (left, right).
+    ComparePersonas(String, String),
+    /// Cap System 2 output length in chars; `None` removes the cap.
+    SetMaxLen(Option<usize>),
+    /// Render responses as Markdown or plain text.
+    SetRender(RenderMode),
+    /// Render the conversation as Markdown, to stdout or the given file.
+    Transcript(Option<PathBuf>),
+    /// Write the active persona's convergence history as CSV.
+    ExportHistory(PathBuf),
 }
 
 // =================================================================
 // MIMICRY ENGINE - Top-level orchestrator
 // =================================================================
 
+/// Branch name for the session that was active before any `/switch`
+pub const MAIN_BRANCH: &str = "main";
+
 /// The top-level orchestrator that ties everything together.
 /// Manages profiles, analysis, routing, caching, persistence,
 /// templates, evolution, and active sessions.
@@ -1589,6 +2750,9 @@ pub struct MimicryEngine {
     pub hot_swap: HotSwap,
     /// Currently active mimicry session, if any.
     pub session: Option<MimicSession>,
+    /// Additional live sessions keyed by caller-chosen id (e.g. one per
+    /// conversation), independent of the active `session`
+    pub sessions: HashMap<String, MimicSession>,
     /// Template store for profile-driven response generation.
     pub template_store: TemplateStore,
     /// Tracks evolution phases, drift, and milestones.
@@ -1600,6 +2764,25 @@ pub struct MimicryEngine {
     /// API observer for real model observation (feature-gated)
     #[cfg(feature = "api")]
     pub api_observer: ApiObserver,
+    /// Cancellation flag checked between evolution iterations
+    pub cancel_flag: Arc<AtomicBool>,
+    /// Named sandboxed sessions forked from the live one
+    pub shadow_sessions: HashMap<String, ShadowSession>,
+    /// Name of the shadow currently swapped in, if any
+    active_shadow: Option<String>,
+    /// Named alternate sessions created by `/branch`; the active one is
+    /// held in `session`, not here
+    pub branches: HashMap<String, MimicSession>,
+    /// Branch the active session belongs to; `None` is the main line
+    active_branch: Option<String>,
+    /// Fixed RNG seed applied to every session, if set via `/seed`
+    pub seed: Option<u64>,
+    /// System 2 output cap applied to every new session, if set via `/set-maxlen`
+    pub max_output_chars: Option<usize>,
+    /// Render mode applied to every new session, set via `/set-render`
+    pub render_mode: RenderMode,
+    /// System 2 backend handed to every new session
+    pub backend: Arc<dyn GenerationBackend>,
 }
 
 impl MimicryEngine {
@@ -1620,12 +2803,22 @@ impl MimicryEngine {
             cache,
             hot_swap: HotSwap::new(),
             session: None,
+            sessions: HashMap::new(),
             template_store: TemplateStore::new(),
             evolution_tracker: EvolutionTracker::new(),
             persistence,
             saved_snapshots: HashMap::new(),
             #[cfg(feature = "api")]
             api_observer: ApiObserver::new(),
+            cancel_flag: Arc::new(AtomicBool::new(false)),
+            shadow_sessions: HashMap::new(),
+            active_shadow: None,
+            branches: HashMap::new(),
+            active_branch: None,
+            seed: None,
+            max_output_chars: None,
+            render_mode: RenderMode::default(),
+            backend: template_backend(),
         }
     }
 
@@ -1645,13 +2838,152 @@ impl MimicryEngine {
             cache,
             hot_swap: HotSwap::new(),
             session: None,
+            sessions: HashMap::new(),
             template_store: TemplateStore::new(),
             evolution_tracker: EvolutionTracker::new(),
             persistence,
             saved_snapshots: HashMap::new(),
             #[cfg(feature = "api")]
             api_observer: ApiObserver::new(),
+            cancel_flag: Arc::new(AtomicBool::new(false)),
+            shadow_sessions: HashMap::new(),
+            active_shadow: None,
+            branches: HashMap::new(),
+            active_branch: None,
+            seed: None,
+            max_output_chars: None,
+            render_mode: RenderMode::default(),
+            backend: template_backend(),
+        }
+    }
+
+    /// Run `transcript` (commands and chat lines, as typed at the prompt)
+    /// through a fresh engine and return each output in order. Persistence
+    /// is disabled so nothing is read from or written to disk; start the
+    /// transcript with `/seed <n>` (or use `replay_seeded`) for a fully
+    /// deterministic run.
+    pub fn replay(transcript: &[&str]) -> Vec<String> {
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        engine.run_transcript(transcript)
+    }
+
+    /// Like `replay`, with the RNG seed fixed before the first line.
+    pub fn replay_seeded(transcript: &[&str], seed: u64) -> Vec<String> {
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        engine.set_seed(seed);
+        engine.run_transcript(transcript)
+    }
+
+    /// Parse and execute each line in order, collecting the outputs
+    fn run_transcript(&mut self, transcript: &[&str]) -> Vec<String> {
+        transcript
+            .iter()
+            .map(|line| {
+                let cmd = self.parse_command(line);
+                self.execute(cmd)
+            })
+            .collect()
+    }
+
+    /// Set the RNG seed and reseed the live, keyed, and shadow sessions.
+    /// Sessions started afterwards inherit the seed until it is changed.
+    pub fn set_seed(&mut self, seed: u64) -> String {
+        self.seed = Some(seed);
+        if let Some(session) = self.session.as_mut() {
+            session.reseed(seed);
+        }
+        for session in self.sessions.values_mut() {
+            session.reseed(seed);
+        }
+        for shadow in self.shadow_sessions.values_mut() {
+            shadow.session.reseed(seed);
+        }
+        format!("RNG seed set to {}.", seed)
+    }
+
+    /// Cap System 2 output at `max` chars (`None` removes the cap) for the
+    /// live, keyed, shadow, branched, and future sessions. Caps that leave
+    /// no room beside `TRUNCATION_MARKER` are rejected with a usage error.
+    pub fn set_max_output_chars(&mut self, max: Option<usize>) -> Result<String, String> {
+        if max.is_some_and(|max| max <= TRUNCATION_MARKER.len()) {
+            return Err(format!(
+                "Usage: /set-maxlen <n|off> (n must exceed {}, the length of \"{}\")",
+                TRUNCATION_MARKER.len(),
+                TRUNCATION_MARKER.trim()
+            ));
+        }
+        self.max_output_chars = max;
+        if let Some(session) = self.session.as_mut() {
+            session.max_output_chars = max;
+        }
+        for session in self.sessions.values_mut().chain(self.branches.values_mut()) {
+            session.max_output_chars = max;
+        }
+        for shadow in self.shadow_sessions.values_mut() {
+            shadow.session.max_output_chars = max;
+        }
+        Ok(match max {
+            Some(max) => format!("System 2 output capped at {} chars.", max),
+            None => "System 2 output cap removed.".to_string(),
+        })
+    }
+
+    /// Switch response rendering for the live, keyed, shadow, branched,
+    /// and future sessions.
+    pub fn set_render_mode(&mut self, mode: RenderMode) -> String {
+        self.render_mode = mode;
+        if let Some(session) = self.session.as_mut() {
+            session.render_mode = mode;
+        }
+        for session in self.sessions.values_mut().chain(self.branches.values_mut()) {
+            session.render_mode = mode;
+        }
+        for shadow in self.shadow_sessions.values_mut() {
+            shadow.session.render_mode = mode;
+        }
+        match mode {
+            RenderMode::Markdown => "Rendering responses as Markdown.".to_string(),
+            RenderMode::PlainText => "Rendering responses as plain text.".to_string(),
+        }
+    }
+
+    /// Set the System 2 backend for the live, keyed, shadow, and future sessions.
+    pub fn set_backend(&mut self, backend: Arc<dyn GenerationBackend>) {
+        if let Some(session) = self.session.as_mut() {
+            session.backend = backend.clone();
+        }
+        for session in self.sessions.values_mut() {
+            session.backend = backend.clone();
         }
+        for shadow in self.shadow_sessions.values_mut() {
+            shadow.session.backend = backend.clone();
+        }
+        self.backend = backend;
+    }
+
+    /// Create a session for a persona, applying the engine seed and backend
+    fn new_session(&self, persona: CompoundPersona) -> MimicSession {
+        let mut session = MimicSession::new(persona);
+        if let Some(seed) = self.seed {
+            session.reseed(seed);
+        }
+        session.max_output_chars = self.max_output_chars;
+        session.render_mode = self.render_mode;
+        session.backend = self.backend.clone();
+        session
+    }
+
+    /// Structured diff between two known profiles
+    pub fn diff_profiles(&self, left: &str, right: &str) -> Result<String, String> {
+        let lookup = |id: &str| {
+            self.profile_store.get(id).ok_or_else(|| {
+                format!(
+                    "Unknown model: '{}'. Use /list to see available models.",
+                    id
+                )
+            })
+        };
+        Ok(lookup(left)?.diff(lookup(right)?).to_string())
     }
 
     /// Start mimicking a target model
@@ -1677,7 +3009,7 @@ impl MimicryEngine {
         self.template_store.get_or_create(&profile);
 
         // Start session
-        self.session = Some(MimicSession::new(persona));
+        self.session = Some(self.new_session(persona));
 
         // Preload into hot swap
         if let Some(ref session) = self.session {
@@ -1696,6 +3028,99 @@ impl MimicryEngine {
         ))
     }
 
+    /// Start (or restart) the keyed session `key` as `model_id`, leaving
+    /// the active session and other keyed sessions alone.
+    pub fn start_session(&mut self, key: &str, model_id: &str) -> Result<String, String> {
+        let profile = self
+            .profile_store
+            .get(model_id)
+            .ok_or_else(|| {
+                format!(
+                    "Unknown model: '{}'. Use /list to see available models.",
+                    model_id
+                )
+            })?
+            .clone();
+        self.template_store.get_or_create(&profile);
+        let session = self.new_session(CompoundPersona::from_profile(&profile));
+        self.sessions.insert(key.to_string(), session);
+        Ok(format!(
+            "Session '{}' started as {}.",
+            key, profile.display_name
+        ))
+    }
+
+    /// The keyed session `key`, created on first use. New sessions copy the
+    /// active session's persona with an empty conversation, or mimic
+    /// RustyWorm when nothing is active.
+    pub fn session_for(&mut self, key: &str) -> &mut MimicSession {
+        if !self.sessions.contains_key(key) {
+            let persona = match &self.session {
+                Some(active) => active.persona.clone(),
+                None => {
+                    let profile = self
+                        .profile_store
+                        .get("rustyworm")
+                        .cloned()
+                        .unwrap_or_else(|| AiProfile::new("rustyworm", "RustyWorm"));
+                    self.template_store.get_or_create(&profile);
+                    CompoundPersona::from_profile(&profile)
+                }
+            };
+            let session = self.new_session(persona);
+            self.sessions.insert(key.to_string(), session);
+        }
+        self.sessions
+            .get_mut(key)
+            .expect("keyed session was just inserted")
+    }
+
+    /// End the keyed session `key`, returning it if it existed
+    pub fn close_session(&mut self, key: &str) -> Option<MimicSession> {
+        self.sessions.remove(key)
+    }
+
+    /// Send a chat message to the keyed session `session_key` (created on
+    /// first use), or to the active session when no key is given.
+    pub fn chat(&mut self, input: &str, session_key: Option<&str>) -> String {
+        let Some(key) = session_key else {
+            // Take the session out to avoid borrow issues with template_store
+            return match self.session.take() {
+                Some(mut session) => {
+                    let (output, _delta) = session.process(
+                        input,
+                        &mut self.cache,
+                        &self.analyzer,
+                        &mut self.template_store,
+                    );
+                    measure_targets(
+                        &mut self.evolution_tracker,
+                        &mut session.persona,
+                        &self.analyzer,
+                    );
+                    let output = with_ethics_warning(&session, output);
+                    self.session = Some(session);
+                    output
+                }
+                None => "No active session. Use /mimic <model> to start mimicking.\n\
+                         Type /help for available commands."
+                    .to_string(),
+            };
+        };
+        self.session_for(key);
+        let session = self
+            .sessions
+            .get_mut(key)
+            .expect("session_for creates missing sessions");
+        let (output, _delta) = session.process(
+            input,
+            &mut self.cache,
+            &self.analyzer,
+            &mut self.template_store,
+        );
+        with_ethics_warning(session, output)
+    }
+
     /// Blend multiple models into a hybrid persona
     pub fn blend(&mut self, ids: &[String], weights: &[f64]) -> Result<String, String> {
         let mut profiles: Vec<AiProfile> = Vec::new();
@@ -1709,7 +3134,8 @@ impl MimicryEngine {
         }
 
         let profile_refs: Vec<&AiProfile> = profiles.iter().collect();
-        let blended = AiProfile::blend(&profile_refs, weights);
+        let blended = AiProfile::try_blend(&profile_refs, weights)
+            .map_err(|e| format!("Cannot blend {}: {}", ids.join(" + "), e))?;
         let persona = CompoundPersona::from_profile(&blended);
         let declaration = persona.declare();
 
@@ -1735,7 +3161,7 @@ impl MimicryEngine {
             );
         }
 
-        self.session = Some(MimicSession::new(persona));
+        self.session = Some(self.new_session(persona));
 
         let weight_strs: Vec<String> = weights.iter().map(|w| format!("{:.1}", w)).collect();
         Ok(format!(
@@ -1781,9 +3207,9 @@ impl MimicryEngine {
                 session.persona.refine_from_signature(&sig, &self.analyzer);
 
                 // COMPOUND: Feed refinement into templates
-                let lib = self.template_store.get_or_create(&session.persona.profile);
                 let delta = self.analyzer.self_monitor_output(response, &sig);
-                lib.apply_feedback(&delta);
+                self.template_store
+                    .apply_feedback(&session.persona.profile, &delta);
             }
         }
 
@@ -1807,13 +3233,13 @@ impl MimicryEngine {
 
     /// Identify which known model produced a response
     pub fn identify(&self, response: &str) -> String {
-        let scores = self.analyzer.identify_model(response);
-        if scores.is_empty() {
+        let identification = self.analyzer.identify_model(response);
+        if identification.scores.is_empty() {
             return "No models in database to compare against. Use /observe first.".to_string();
         }
 
         let mut lines = vec!["Model identification results:".to_string()];
-        for (model_id, score) in scores.iter().take(5) {
+        for (model_id, score) in identification.scores.iter().take(5) {
             let bar_len = (score * 20.0) as usize;
             let bar: String = "#".repeat(bar_len);
             lines.push(format!(
@@ -1823,6 +3249,11 @@ impl MimicryEngine {
                 score * 100.0
             ));
         }
+        lines.push(format!(
+            "{} (margin {:.1}%)",
+            identification.verdict(),
+            identification.margin * 100.0
+        ));
         lines.join("\n")
     }
 
@@ -1830,33 +3261,174 @@ impl MimicryEngine {
     /// COMPOUND: Uses EvolutionTracker for phase transitions, drift
     /// detection, milestone tracking, and auto-save triggers.
     pub fn evolve(&mut self, iterations: u64) -> Result<String, String> {
-        let session = self
-            .session
-            .as_mut()
-            .ok_or_else(|| "No active session. Use /mimic first.".to_string())?;
+        self.evolve_with_progress(iterations, 0, |_, _| {})
+    }
 
-        let starting_convergence = session.persona.convergence_score;
-        let mut personality_drift = 0.0;
-        let mut drift_events: u64 = 0;
-        let mut milestones_hit: usize = 0;
+    /// Handle to the cancellation flag. Setting it to `true` from another
+    /// thread stops a running evolution after the current iteration.
+    pub fn cancel_handle(&self) -> Arc<AtomicBool> {
+        Arc::clone(&self.cancel_flag)
+    }
 
-        for i in 0..iterations {
-            // Simulate self-correction cycle
-            let synthetic_output = format!(
-                "Evolution iteration {} - testing convergence of {}",
-                i, session.persona.profile.display_name
-            );
-            let delta = session
-                .persona
-                .self_correct(&synthetic_output, &self.analyzer);
-            personality_drift += delta.magnitude();
+    /// Run evolution like [`evolve`](Self::evolve), invoking `on_progress`
+    /// with (completed iterations, current convergence) every `every`
+    /// iterations (0 disables reporting). The cancel flag is checked before
+    /// each iteration; a cancelled run keeps its partial progress and the
+    /// flag is cleared for the next run.
+    pub fn evolve_with_progress(
+        &mut self,
+        iterations: u64,
+        every: u64,
+        on_progress: impl FnMut(u64, f64),
+    ) -> Result<String, String> {
+        let (report, cancelled) = self.run_evolution(iterations, every, on_progress)?;
+        let session = self
+            .session
+            .as_ref()
+            .expect("run_evolution requires an active session");
 
-            // COMPOUND: Feed evolution delta to templates
-            let lib = self.template_store.get_or_create(&session.persona.profile);
-            lib.apply_feedback(&delta);
+        let header = if cancelled {
+            format!(
+                "=== EVOLUTION REPORT (cancelled at {}/{}) ===",
+                report.iterations, iterations
+            )
+        } else {
+            "=== EVOLUTION REPORT ===".to_string()
+        };
 
-            // Re-compile to System 1
-            self.cache.compile_from(&session.persona.signature);
+        Ok(format!(
+            "{}\n\
+             Iterations: {}\n\
+             Convergence: {:.1}% -> {:.1}%\n\
+             Phase: {}\n\
+             Drift events: {}\n\
+             Drift corrections: {}\n\
+             Milestones hit: {}\n\
+             System 1 cache size: {}\n\
+             Personality drift: {:.4}\n\
+             Compound iterations: {}{}",
+            header,
+            report.iterations,
+            report.starting_convergence * 100.0,
+            report.ending_convergence * 100.0,
+            report.phase,
+            report.drift_events,
+            report.drift_corrections,
+            report.milestones_hit,
+            report.system1_cache_size,
+            report.personality_drift,
+            session.persona.compound_iterations,
+            report
+                .recommendation
+                .as_ref()
+                .map(|r| format!("\nRecommendation: {}", r))
+                .unwrap_or_default()
+        ))
+    }
+
+    /// Project an evolution run without changing anything: the loop runs
+    /// on copies of the session, cache, templates, and evolution tracker
+    /// inside a scratch engine whose persistence is disabled, so neither
+    /// template feedback nor auto-saves reach the live state or disk.
+    pub fn evolve_dry_run(&self, iterations: u64) -> Result<EvolutionReport, String> {
+        let session = self
+            .session
+            .as_ref()
+            .ok_or_else(|| "No active session. Use /mimic first.".to_string())?;
+
+        let mut scratch = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        scratch.analyzer = self.analyzer.clone();
+        scratch.cache = self.cache.clone();
+        scratch.template_store = self.template_store.clone();
+        scratch.evolution_tracker = self.evolution_tracker.clone();
+        scratch.session = Some(session.clone());
+        scratch.seed = self.seed;
+        scratch.backend = self.backend.clone();
+
+        let (report, _) = scratch.run_evolution(iterations, 0, |_, _| {})?;
+        Ok(report)
+    }
+
+    /// Render a dry-run projection for the CLI
+    fn format_dry_run(report: &EvolutionReport) -> String {
+        let verdict = if report.drift_events > 0 {
+            format!("likely ({} drift events)", report.drift_events)
+        } else {
+            "unlikely".to_string()
+        };
+        format!(
+            "=== EVOLUTION DRY RUN (no state changed) ===\n\
+             Iterations: {}\n\
+             Projected convergence: {:.1}% -> {:.1}%\n\
+             Projected phase: {}\n\
+             Drift: {}\n\
+             Milestones: {}\n\
+             Personality drift: {:.4}",
+            report.iterations,
+            report.starting_convergence * 100.0,
+            report.ending_convergence * 100.0,
+            report.phase,
+            verdict,
+            report.milestones_hit,
+            report.personality_drift
+        )
+    }
+
+    /// The evolution loop behind `evolve_with_progress` and
+    /// `evolve_dry_run`. Returns the report and whether it was cancelled.
+    fn run_evolution(
+        &mut self,
+        iterations: u64,
+        every: u64,
+        mut on_progress: impl FnMut(u64, f64),
+    ) -> Result<(EvolutionReport, bool), String> {
+        let session = self
+            .session
+            .as_mut()
+            .ok_or_else(|| "No active session. Use /mimic first.".to_string())?;
+        if iterations > 0 {
+            session.push_undo_tracked(Some(self.evolution_tracker.snapshot()));
+        }
+
+        let starting_convergence = session.persona.convergence_score;
+        let mut personality_drift = 0.0;
+        let mut drift_events: u64 = 0;
+        let mut milestones_hit: usize = 0;
+        let mut drift_corrections: u64 = 0;
+        let mut recommendation = None;
+        let mut completed: u64 = 0;
+        let mut cancelled = false;
+        let auto_save_name =
+            auto_save_name(&session.persona.profile.id, self.active_shadow.as_deref());
+
+        for i in 0..iterations {
+            if self.cancel_flag.load(Ordering::SeqCst) {
+                cancelled = true;
+                break;
+            }
+
+            // Simulate self-correction cycle
+            let synthetic_output = format!(
+                "Evolution iteration {} - testing convergence of {}",
+                i, session.persona.profile.display_name
+            );
+            let delta = session
+                .persona
+                .self_correct(&synthetic_output, &self.analyzer);
+            personality_drift += delta.magnitude();
+
+            measure_targets(
+                &mut self.evolution_tracker,
+                &mut session.persona,
+                &self.analyzer,
+            );
+
+            // COMPOUND: Feed evolution delta to templates
+            self.template_store
+                .apply_feedback(&session.persona.profile, &delta);
+
+            // Re-compile to System 1
+            self.cache.compile_from(&session.persona.signature);
 
             // COMPOUND: Track evolution step
             let step_result = self.evolution_tracker.step(
@@ -1870,46 +3442,347 @@ impl MimicryEngine {
             milestones_hit += step_result.new_milestones.len();
 
             // COMPOUND: Auto-save on milestone
-            if step_result.should_auto_save {
+            if step_result.should_auto_save && self.persistence.config.auto_save_enabled {
                 let snapshot = session.persona.snapshot();
-                let _ = self
-                    .persistence
-                    .save_persona(&format!("{}-auto", session.persona.profile.id), &snapshot);
+                let _ = self.persistence.save_persona(&auto_save_name, &snapshot);
+            }
+
+            // COMPOUND: Revert confirmed drift to the last milestone snapshot
+            if self.evolution_tracker.needs_drift_correction(&step_result) {
+                let checkpoint = if self.persistence.config.enabled {
+                    drift_checkpoint(
+                        &mut self.persistence,
+                        &session.persona.profile.id,
+                        &auto_save_name,
+                    )
+                } else {
+                    None
+                };
+                match checkpoint {
+                    Some(snapshot) => {
+                        let mut restored = CompoundPersona::from_snapshot(snapshot);
+                        restored.ethics = session.persona.ethics.clone();
+                        restored.health = session.persona.health.clone();
+                        restored.evolution_history =
+                            std::mem::take(&mut session.persona.evolution_history);
+                        restored.evolution_history.push(restored.convergence_score);
+                        session.persona = restored;
+                        self.cache.compile_from(&session.persona.signature);
+                        self.evolution_tracker.record_correction(
+                            session.persona.convergence_score,
+                            step_result.iteration,
+                        );
+                        drift_corrections += 1;
+                    }
+                    None => {
+                        recommendation = Some(self.evolution_tracker.defer_drift_correction());
+                    }
+                }
+            }
+
+            completed = i + 1;
+            if every > 0 && completed.is_multiple_of(every) {
+                on_progress(completed, session.persona.convergence_score);
             }
         }
 
-        let phase = format!("{}", self.evolution_tracker.current_phase);
+        if cancelled {
+            self.cancel_flag.store(false, Ordering::SeqCst);
+        }
 
         let report = EvolutionReport {
-            iterations,
+            iterations: completed,
             starting_convergence,
             ending_convergence: session.persona.convergence_score,
             system1_cache_size: self.cache.size(),
             personality_drift,
             drift_events,
-            phase: phase.clone(),
+            phase: format!("{}", self.evolution_tracker.current_phase),
             milestones_hit,
+            drift_corrections,
+            recommendation,
+        };
+        Ok((report, cancelled))
+    }
+
+    /// Fork the active session into a named shadow session. The shadow
+    /// gets its own copies of the evolution tracker, template library,
+    /// and cache so it can be evolved without affecting the live persona.
+    pub fn fork_session(&mut self, name: &str) -> Result<String, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Usage: /fork <name>".to_string());
+        }
+        if self.shadow_sessions.contains_key(name) {
+            return Err(format!("Shadow session '{}' already exists.", name));
+        }
+        let session = self
+            .session
+            .as_ref()
+            .ok_or_else(|| "No active session. Use /mimic first.".to_string())?;
+
+        let shadow = ShadowSession {
+            session: session.clone(),
+            evolution_tracker: self.evolution_tracker.clone(),
+            templates: self
+                .template_store
+                .get(&session.persona.profile.id)
+                .cloned(),
+            cache: self.cache.clone(),
+            forked_at_convergence: session.persona.convergence_score,
         };
+        let message = format!(
+            "Forked shadow session '{}' from {} at {:.1}% convergence.\n\
+             Use /evolve <n> {} to train it, /shadows to compare, /promote {} to go live.",
+            name,
+            session.persona.profile.display_name,
+            shadow.forked_at_convergence * 100.0,
+            name,
+            name
+        );
+        self.shadow_sessions.insert(name.to_string(), shadow);
+        Ok(message)
+    }
+
+    /// Copy the active session — persona, conversation, and counters — into
+    /// a named branch. The branch evolves independently once switched to.
+    pub fn branch(&mut self, name: &str) -> Result<String, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Usage: /branch <name>".to_string());
+        }
+        if name == MAIN_BRANCH || self.branches.contains_key(name) {
+            return Err(format!("Branch '{}' already exists.", name));
+        }
+        if self.active_branch.as_deref() == Some(name) {
+            return Err(format!("Branch '{}' is already active.", name));
+        }
+        let session = self
+            .session
+            .as_ref()
+            .ok_or_else(|| "No active session to branch. Use /mimic first.".to_string())?;
+
+        let message = format!(
+            "Branched '{}' from {} at {:.1}% convergence ({} turns).\n\
+             Use /switch {} to make it active.",
+            name,
+            session.persona.profile.display_name,
+            session.persona.convergence_score * 100.0,
+            session.conversation.len(),
+            name
+        );
+        let mut branch = session.clone();
+        branch.evolution_state = Some(self.evolution_tracker.snapshot());
+        self.branches.insert(name.to_string(), branch);
+        Ok(message)
+    }
+
+    /// Make a branch the active session. The outgoing session is stored
+    /// under its own branch name (or `main`) so it can be switched back to.
+    pub fn switch_branch(&mut self, name: &str) -> Result<String, String> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("Usage: /switch <name>".to_string());
+        }
+        let current = self.active_branch.as_deref().unwrap_or(MAIN_BRANCH);
+        if name == current {
+            return Err(format!("Already on branch '{}'.", name));
+        }
+        let mut session = self.branches.remove(name).ok_or_else(|| {
+            let mut names: Vec<&String> = self.branches.keys().collect();
+            names.sort();
+            format!("No branch named '{}'. Available: {:?}", name, names)
+        })?;
+
+        // Each branch keeps its own drift counters and milestones
+        let incoming_state = session.evolution_state.take();
+        let outgoing = current.to_string();
+        if let Some(mut previous) = self.session.replace(session) {
+            previous.evolution_state = Some(self.evolution_tracker.snapshot());
+            self.branches.insert(outgoing.clone(), previous);
+        }
+        if let Some(state) = incoming_state {
+            self.evolution_tracker.restore(state);
+        }
+        self.active_branch = (name != MAIN_BRANCH).then(|| name.to_string());
 
+        let session = self.session.as_ref().expect("branch session was just set");
         Ok(format!(
-            "=== EVOLUTION REPORT ===\n\
-             Iterations: {}\n\
-             Convergence: {:.1}% -> {:.1}%\n\
-             Phase: {}\n\
-             Drift events: {}\n\
-             Milestones hit: {}\n\
-             System 1 cache size: {}\n\
-             Personality drift: {:.4}\n\
-             Compound iterations: {}",
-            report.iterations,
-            report.starting_convergence * 100.0,
-            report.ending_convergence * 100.0,
-            report.phase,
-            report.drift_events,
-            report.milestones_hit,
-            report.system1_cache_size,
-            report.personality_drift,
-            session.persona.compound_iterations
+            "Switched to branch '{}' ({}, {:.1}% convergence). Previous session kept as '{}'.",
+            name,
+            session.persona.profile.display_name,
+            session.persona.convergence_score * 100.0,
+            outgoing
+        ))
+    }
+
+    /// Name of the branch the active session belongs to
+    pub fn current_branch(&self) -> &str {
+        self.active_branch.as_deref().unwrap_or(MAIN_BRANCH)
+    }
+
+    /// Run evolution on a shadow session, leaving the live persona untouched.
+    pub fn evolve_shadow(&mut self, name: &str, iterations: u64) -> Result<String, String> {
+        let report = self.with_shadow(name, |engine| engine.evolve(iterations))??;
+        Ok(format!("[shadow: {}]\n{}", name, report))
+    }
+
+    /// Replace the live session with a shadow session, consuming the shadow.
+    /// The live milestone `on_threshold` callback carries over unless the
+    /// shadow installed its own.
+    pub fn promote(&mut self, name: &str) -> Result<String, String> {
+        let shadow = self
+            .shadow_sessions
+            .remove(name)
+            .ok_or_else(|| format!("No shadow session named '{}'.", name))?;
+
+        let old_convergence = self
+            .session
+            .as_ref()
+            .map(|s| s.persona.convergence_score)
+            .unwrap_or(0.0);
+        let new_convergence = shadow.session.persona.convergence_score;
+        let profile_id = shadow.session.persona.profile.id.clone();
+
+        let on_threshold = self.evolution_tracker.milestones.on_threshold.take();
+        self.session = Some(shadow.session);
+        self.evolution_tracker = shadow.evolution_tracker;
+        let milestones = &mut self.evolution_tracker.milestones;
+        milestones.on_threshold = milestones.on_threshold.take().or(on_threshold);
+        self.cache = shadow.cache;
+        if let Some(lib) = shadow.templates {
+            self.template_store.libraries.insert(profile_id, lib);
+        }
+
+        Ok(format!(
+            "Promoted shadow '{}' to live. Convergence: {:.1}% -> {:.1}%",
+            name,
+            old_convergence * 100.0,
+            new_convergence * 100.0
+        ))
+    }
+
+    /// List shadow sessions with their convergence relative to the live persona.
+    pub fn shadow_status(&self) -> String {
+        if self.shadow_sessions.is_empty() {
+            return "No shadow sessions. Use /fork <name> to create one.".to_string();
+        }
+
+        let live = self
+            .session
+            .as_ref()
+            .map(|s| s.persona.convergence_score)
+            .unwrap_or(0.0);
+        let mut names: Vec<&String> = self.shadow_sessions.keys().collect();
+        names.sort();
+
+        let mut lines = vec![format!(
+            "=== SHADOW SESSIONS (live: {:.1}%) ===",
+            live * 100.0
+        )];
+        for name in names {
+            let shadow = &self.shadow_sessions[name];
+            let conv = shadow.session.persona.convergence_score;
+            lines.push(format!(
+                "  {:<12} {:.1}% ({:+.1}% vs live, {} evolutions)",
+                name,
+                conv * 100.0,
+                (conv - live) * 100.0,
+                shadow.evolution_tracker.total_evolutions
+            ));
+        }
+        lines.join("\n")
+    }
+
+    /// Swap a shadow session's state into the engine, run `f`, then swap
+    /// the live state back. The shadow keeps whatever `f` changed.
+    fn with_shadow<T>(&mut self, name: &str, f: impl FnOnce(&mut Self) -> T) -> Result<T, String> {
+        let shadow = self
+            .shadow_sessions
+            .remove(name)
+            .ok_or_else(|| format!("No shadow session named '{}'.", name))?;
+        let profile_id = shadow.session.persona.profile.id.clone();
+        let forked_at_convergence = shadow.forked_at_convergence;
+
+        let live_session = self.session.replace(shadow.session);
+        let live_tracker = std::mem::replace(&mut self.evolution_tracker, shadow.evolution_tracker);
+        let live_cache = std::mem::replace(&mut self.cache, shadow.cache);
+        let live_templates = match shadow.templates {
+            Some(lib) => self
+                .template_store
+                .libraries
+                .insert(profile_id.clone(), lib),
+            None => self.template_store.libraries.remove(&profile_id),
+        };
+        self.active_shadow = Some(name.to_string());
+
+        let result = f(self);
+
+        self.active_shadow = None;
+        let shadow_templates = match live_templates {
+            Some(lib) => self.template_store.libraries.insert(profile_id, lib),
+            None => self.template_store.libraries.remove(&profile_id),
+        };
+        let shadow = ShadowSession {
+            session: std::mem::replace(&mut self.session, live_session)
+                .expect("shadow session is present while swapped in"),
+            evolution_tracker: std::mem::replace(&mut self.evolution_tracker, live_tracker),
+            templates: shadow_templates,
+            cache: std::mem::replace(&mut self.cache, live_cache),
+            forked_at_convergence,
+        };
+        self.shadow_sessions.insert(name.to_string(), shadow);
+
+        Ok(result)
+    }
+
+    /// Revert the active persona to its state before the last evolve or
+    /// train run.
+    pub fn undo(&mut self) -> Result<String, String> {
+        let session = self
+            .session
+            .as_mut()
+            .ok_or_else(|| "No active session. Use /mimic first.".to_string())?;
+        let before = session.persona.convergence_score;
+        if let Some(tracker) = session.undo()? {
+            self.evolution_tracker.restore(tracker);
+        }
+        self.cache.compile_from(&session.persona.signature);
+        Ok(format!(
+            "Undid last change to {}. Convergence: {:.1}% -> {:.1}% ({} undo steps left)",
+            session.persona.profile.display_name,
+            before * 100.0,
+            session.persona.convergence_score * 100.0,
+            session.undo_len()
+        ))
+    }
+
+    /// Clear the active persona's learned state so it can relearn from
+    /// scratch. The profile reverts to the stored base profile (blends keep
+    /// their current one), and stored training data is left for `/train`.
+    /// `/undo` restores the pre-reset persona.
+    pub fn reset(&mut self) -> Result<String, String> {
+        let session = self
+            .session
+            .as_mut()
+            .ok_or_else(|| "No active session. Use /mimic first.".to_string())?;
+        let id = session.persona.profile.id.clone();
+        let base = self
+            .profile_store
+            .get(&id)
+            .cloned()
+            .unwrap_or_else(|| session.persona.profile.clone());
+        let before = session.persona.convergence_score;
+
+        session.push_undo_tracked(Some(self.evolution_tracker.snapshot()));
+        session.persona.reset_to(&base);
+        self.cache.compile_from(&session.persona.signature);
+
+        Ok(format!(
+            "Reset {}: convergence {:.1}% -> 0.0%. Kept {} training samples; use /train to relearn.",
+            session.persona.profile.display_name,
+            before * 100.0,
+            self.evolution_tracker.training_data.count(&id)
         ))
     }
 
@@ -1933,24 +3806,32 @@ impl MimicryEngine {
         }
 
         let starting_convergence = session.persona.convergence_score;
+        session.push_undo_tracked(Some(self.evolution_tracker.snapshot()));
 
         let result = self.evolution_tracker.training_loop(
             &model_id,
             &mut session.persona.profile,
             &mut self.analyzer,
             iterations,
+            &mut *session.rng.borrow_mut(),
         );
 
         // Update convergence after training
         session.persona.convergence_score = self
             .analyzer
             .compute_convergence(&session.persona.profile, &session.persona.signature);
+        if let Some(combined) = self
+            .evolution_tracker
+            .measure_targets(&session.persona.profile, &self.analyzer)
+        {
+            session.persona.convergence_score = combined;
+        }
         session.persona.compound_iterations += result.iterations_run;
 
         // COMPOUND: Feed training deltas to templates
         for delta in &result.deltas {
-            let lib = self.template_store.get_or_create(&session.persona.profile);
-            lib.apply_feedback(delta);
+            self.template_store
+                .apply_feedback(&session.persona.profile, delta);
         }
 
         // Re-compile to System 1
@@ -1975,6 +3856,30 @@ impl MimicryEngine {
         ))
     }
 
+    /// Structured health summary for readiness probes. Probes persistence
+    /// with a temp write, so a read-only directory reports `false` rather
+    /// than failing.
+    pub fn health_check(&self) -> EngineHealth {
+        EngineHealth {
+            profiles_loaded: self.profile_store.ids().len(),
+            cache_size: self.cache.size(),
+            cache_hit_rate: self.cache.hit_rate(),
+            persistence_writable: self.persistence.is_writable(),
+            active_session: self.session.is_some(),
+            #[cfg(feature = "api")]
+            providers: self
+                .api_observer
+                .configured_providers()
+                .into_iter()
+                .map(|id| {
+                    let ready =
+                        self.api_observer.is_ready(&id) && self.api_observer.is_healthy(&id);
+                    (id, ready)
+                })
+                .collect(),
+        }
+    }
+
     /// Get current status (enhanced with evolution + persistence info)
     pub fn status(&mut self) -> String {
         let mut lines = vec!["=== RUSTYWORM STATUS ===".to_string()];
@@ -1988,10 +3893,16 @@ impl MimicryEngine {
             self.cache.size(),
             self.cache.hit_rate() * 100.0
         ));
-        lines.push(format!(
-            "Hot swap slots: {}",
-            self.hot_swap.preloaded_ids().len()
-        ));
+        lines.push(format!("  {}", self.cache.stats()));
+        lines.push(match self.hot_swap.max_slots {
+            Some(max) => format!(
+                "Hot swap slots: {}/{} ({} evicted)",
+                self.hot_swap.preloaded_ids().len(),
+                max,
+                self.hot_swap.evicted_ids().len()
+            ),
+            None => format!("Hot swap slots: {}", self.hot_swap.preloaded_ids().len()),
+        });
         lines.push(format!(
             "Template libraries: {}",
             self.template_store.size()
@@ -2000,6 +3911,30 @@ impl MimicryEngine {
             "Evolution phase: {}",
             self.evolution_tracker.current_phase
         ));
+        lines.push(match self.seed {
+            Some(seed) => format!("RNG seed: {}", seed),
+            None => "RNG seed: per persona (use /seed <n> to fix)".to_string(),
+        });
+        lines.push(format!("Generation backend: {}", self.backend.name()));
+        if let Some(max) = self.max_output_chars {
+            lines.push(format!("System 2 output cap: {} chars", max));
+        }
+        if self.render_mode == RenderMode::PlainText {
+            lines.push("Render mode: plain text".to_string());
+        }
+        if !self.branches.is_empty() || self.active_branch.is_some() {
+            let mut names: Vec<&String> = self.branches.keys().collect();
+            names.sort();
+            lines.push(format!(
+                "Branch: {} (stored: {})",
+                self.current_branch(),
+                names
+                    .iter()
+                    .map(|n| n.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
         lines.push(format!(
             "Persistence: {}",
             self.persistence
@@ -2010,6 +3945,13 @@ impl MimicryEngine {
         if let Some(ref session) = self.session {
             lines.push(String::new());
             lines.push(session.stats());
+            let health = session.persona.current_health();
+            lines.push(format!(
+                "Relationship health: {:.2} ({}, half-life {}s)",
+                health.score,
+                health.level,
+                health.half_life.as_secs()
+            ));
 
             // OCTO RNA Bridge stats (feature-gated)
             #[cfg(feature = "octo")]
@@ -2037,6 +3979,28 @@ impl MimicryEngine {
             lines.push("\nNo active session. Use /mimic <model> to start.".to_string());
         }
 
+        if !self.sessions.is_empty() {
+            let live = self.sessions.len() + usize::from(self.session.is_some());
+            lines.push(String::new());
+            lines.push(format!("Live sessions: {}", live));
+            let describe = |session: &MimicSession| {
+                format!(
+                    "{} ({} turns, {:.1}% convergence)",
+                    session.persona.profile.display_name,
+                    session.conversation.len(),
+                    session.persona.convergence_score * 100.0
+                )
+            };
+            if let Some(ref session) = self.session {
+                lines.push(format!("  {:<16} {}", "(active)", describe(session)));
+            }
+            let mut keys: Vec<&String> = self.sessions.keys().collect();
+            keys.sort();
+            for key in keys {
+                lines.push(format!("  {:<16} {}", key, describe(&self.sessions[key])));
+            }
+        }
+
         lines.join("\n")
     }
 
@@ -2111,7 +4075,7 @@ impl MimicryEngine {
         let convergence = persona.convergence_score;
 
         self.router.reconfigure_for(&persona.profile);
-        self.session = Some(MimicSession::new(persona));
+        self.session = Some(self.new_session(persona));
 
         Ok(format!(
             "Loaded persona '{}' (convergence: {:.1}%)",
@@ -2120,6 +4084,36 @@ impl MimicryEngine {
         ))
     }
 
+    /// Restore a saved persona from a backup on disk. The in-memory and
+    /// hot-swap copies are refreshed so `/load <name>` picks it up; with no
+    /// such backup, the available ones are listed.
+    pub fn restore(&mut self, name: &str, backup: &str) -> Result<String, String> {
+        let snapshot = match self.persistence.restore_backup(name, backup) {
+            Ok(snapshot) => snapshot,
+            Err(e) => {
+                let backups = self.persistence.list_backups(name).unwrap_or_default();
+                return Err(if backups.is_empty() {
+                    e
+                } else {
+                    format!("{}. Available: {}", e, backups.join(", "))
+                });
+            }
+        };
+        let json = serde_json::to_string_pretty(&snapshot)
+            .map_err(|e| format!("Serialization error: {}", e))?;
+        self.saved_snapshots.insert(name.to_string(), json.clone());
+        self.hot_swap
+            .preload(name, json, snapshot.compound_iterations);
+
+        Ok(format!(
+            "Restored persona '{}' from backup '{}' (convergence: {:.1}%). Use /load {} to activate.",
+            name,
+            backup,
+            snapshot.convergence_score * 100.0,
+            name
+        ))
+    }
+
     /// Export a persona profile to a JSON file
     pub fn export(&mut self, name: &str) -> Result<String, String> {
         // Try to get from active session or saved snapshots
@@ -2162,8 +4156,35 @@ impl MimicryEngine {
         }
     }
 
+    /// The active conversation as Markdown, or — given a path — write it
+    /// there and report where it went.
+    pub fn transcript(&self, path: Option<&Path>) -> Result<String, String> {
+        let session = self
+            .session
+            .as_ref()
+            .ok_or_else(|| "No active session. Use /mimic first.".to_string())?;
+        let markdown = session.to_markdown();
+        let Some(path) = path else {
+            return Ok(markdown);
+        };
+        if !self.persistence.config.enabled {
+            return Err("Persistence is disabled; cannot write a transcript file.".to_string());
+        }
+        std::fs::write(path, &markdown)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        Ok(format!(
+            "Transcript written to {} ({} turns, {} bytes)",
+            path.display(),
+            session.conversation.len(),
+            markdown.len()
+        ))
+    }
+
     /// Import a persona from a JSON file path
     pub fn import(&mut self, path_str: &str) -> Result<String, String> {
+        if !self.persistence.config.enabled {
+            return Err("Persistence is disabled; cannot import from disk.".to_string());
+        }
         let path = Path::new(path_str);
         if !path.exists() {
             return Err(format!("File not found: {}", path_str));
@@ -2175,6 +4196,13 @@ impl MimicryEngine {
         // Try parsing as CompoundPersonaSnapshot first, then as AiProfile
         let snapshot: CompoundPersonaSnapshot = serde_json::from_str(&data)
             .map_err(|e| format!("Failed to parse persona from {}: {}", path_str, e))?;
+        snapshot.profile.validate().map_err(|errors| {
+            format!(
+                "Failed to import {}: {}",
+                path_str,
+                ProfileError::summarize(&errors)
+            )
+        })?;
 
         let name = snapshot.profile.id.clone();
         let display_name = snapshot.profile.display_name.clone();
@@ -2200,12 +4228,62 @@ impl MimicryEngine {
         ))
     }
 
-    /// Delete a saved persona
-    pub fn delete(&mut self, name: &str) -> Result<String, String> {
-        let mut deleted = false;
+    /// Archive every saved persona into a single bundle file
+    pub fn bundle_export(&mut self, path: &str) -> Result<String, String> {
+        let count = self.persistence.export_bundle(Path::new(path))?;
+        Ok(format!("Exported {} persona(s) to bundle {}", count, path))
+    }
 
-        if self.saved_snapshots.remove(name).is_some() {
-            deleted = true;
+    /// Restore personas from a bundle file. In-memory and hot-swap copies
+    /// of imported names are refreshed so `/load` sees the new saves.
+    pub fn bundle_import(
+        &mut self,
+        path: &str,
+        on_conflict: BundleConflict,
+    ) -> Result<String, String> {
+        let result = self
+            .persistence
+            .import_bundle(Path::new(path), on_conflict)?;
+
+        let preloaded = self.hot_swap.preloaded_ids();
+        for name in &result.imported {
+            let stale = self.saved_snapshots.contains_key(name) || preloaded.contains(name);
+            if !stale {
+                continue;
+            }
+            if let Ok(snapshot) = self.persistence.load_persona(name) {
+                let json = serde_json::to_string_pretty(&snapshot)
+                    .map_err(|e| format!("Serialization error: {}", e))?;
+                self.saved_snapshots.insert(name.clone(), json.clone());
+                self.hot_swap
+                    .preload(name, json, snapshot.compound_iterations);
+            }
+        }
+
+        let mut msg = format!(
+            "Imported {} persona(s) from bundle {}",
+            result.imported.len(),
+            path
+        );
+        if !result.imported.is_empty() {
+            msg.push_str(&format!(": {}", result.imported.join(", ")));
+        }
+        if !result.skipped.is_empty() {
+            msg.push_str(&format!(
+                "\nSkipped {} existing: {}",
+                result.skipped.len(),
+                result.skipped.join(", ")
+            ));
+        }
+        Ok(msg)
+    }
+
+    /// Delete a saved persona
+    pub fn delete(&mut self, name: &str) -> Result<String, String> {
+        let mut deleted = false;
+
+        if self.saved_snapshots.remove(name).is_some() {
+            deleted = true;
         }
 
         // Check if persistence has this persona before trying to delete
@@ -2246,6 +4324,74 @@ impl MimicryEngine {
         ))
     }
 
+    /// Write the active persona's convergence history (the data behind
+    /// `/graph`) to `path` as CSV, one row per point with its phase.
+    pub fn export_history(&self, path: &Path) -> Result<String, String> {
+        let session = self
+            .session
+            .as_ref()
+            .ok_or_else(|| "No active session. Use /mimic first.".to_string())?;
+        if !self.persistence.config.enabled {
+            return Err("Persistence is disabled; cannot write a history file.".to_string());
+        }
+        let history = &session.persona.evolution_history;
+        let csv = self.evolution_tracker.export_history_csv(history);
+        std::fs::write(path, &csv)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        Ok(format!(
+            "Exported {} convergence points to {}",
+            history.len(),
+            path.display()
+        ))
+    }
+
+    /// Graph the convergence histories of two personas side by side.
+    /// Each name resolves to the active session (by profile id), a branch,
+    /// a saved in-memory snapshot, or a persona saved on disk, in that
+    /// order. Snapshots only record their final convergence, so they
+    /// contribute a single data point.
+    pub fn compare_personas(&mut self, a: &str, b: &str) -> Result<String, String> {
+        let (label_a, history_a) = self.persona_history(a)?;
+        let (label_b, history_b) = self.persona_history(b)?;
+        let visualizer = ConvergenceVisualizer::new(60, 15);
+        Ok(visualizer.render_comparison(&history_a, &label_a, &history_b, &label_b))
+    }
+
+    /// Label and convergence history for `compare_personas`
+    fn persona_history(&mut self, name: &str) -> Result<(String, Vec<f64>), String> {
+        if let Some(session) = self
+            .session
+            .as_ref()
+            .filter(|s| s.persona.profile.id == name)
+        {
+            return Ok((
+                format!("{} (live)", name),
+                session.persona.evolution_history.clone(),
+            ));
+        }
+        if let Some(session) = self.branches.get(name) {
+            return Ok((
+                format!("{} (branch)", name),
+                session.persona.evolution_history.clone(),
+            ));
+        }
+        if let Some(json) = self.saved_snapshots.get(name) {
+            let snapshot: CompoundPersonaSnapshot = serde_json::from_str(json)
+                .map_err(|e| format!("Deserialization error for '{}': {}", name, e))?;
+            return Ok((
+                format!("{} (saved)", name),
+                vec![snapshot.convergence_score],
+            ));
+        }
+        match self.persistence.load_persona(name) {
+            Ok(snapshot) => Ok((format!("{} (disk)", name), vec![snapshot.convergence_score])),
+            Err(_) => Err(format!(
+                "No persona '{}' in the live session, branches, saved snapshots, or on disk.",
+                name
+            )),
+        }
+    }
+
     /// Show detailed evolution status
     pub fn evolution_status(&self) -> Result<String, String> {
         let session = self
@@ -2255,6 +4401,16 @@ impl MimicryEngine {
 
         let mut lines = vec![];
         lines.push(self.evolution_tracker.status());
+        let eta = match self
+            .evolution_tracker
+            .drift_detector
+            .projected_iterations_to(0.90)
+        {
+            Some(iterations) => format!("~{} iters", iterations),
+            None if session.persona.convergence_score >= 0.90 => "reached".to_string(),
+            None => "n/a (not improving)".to_string(),
+        };
+        lines.push(format!("ETA to 90%: {}", eta));
 
         // Add convergence graph
         let visualizer = ConvergenceVisualizer::new(50, 10);
@@ -2264,6 +4420,12 @@ impl MimicryEngine {
         );
         lines.push(graph);
 
+        // Which dimensions are holding convergence back
+        let breakdown = self
+            .analyzer
+            .explain_convergence(&session.persona.profile, &session.persona.signature);
+        lines.push(format!("\nConvergence breakdown: {}", breakdown));
+
         // Training data summary
         let training_summary = self.evolution_tracker.training_data.summary();
         lines.push(format!("\nTraining Data:\n{}", training_summary));
@@ -2285,7 +4447,11 @@ impl MimicryEngine {
                 .iter()
                 .filter_map(|id| self.profile_store.get(id).cloned())
                 .collect(),
-            cached_signatures: Vec::new(),
+            cached_signatures: self
+                .cache
+                .entries()
+                .filter_map(|entry| serde_json::to_string(entry).ok())
+                .collect(),
             saved_snapshots: self.saved_snapshots.clone(),
             hot_swap_entries: self
                 .hot_swap
@@ -2297,7 +4463,16 @@ impl MimicryEngine {
             checkpoint_iteration: session.persona.compound_iterations,
         };
 
-        match self.persistence.save_checkpoint("latest", &checkpoint) {
+        let saved = self.persistence.save_checkpoint("latest", &checkpoint);
+
+        // The cache file lets the next launch skip re-learning the fast path
+        if saved.is_ok() {
+            if let Err(e) = self.persistence.save_signature_cache(&self.cache) {
+                tracing::warn!(error = %e, "failed to persist signature cache");
+            }
+        }
+
+        match saved {
             Ok(path) => Ok(format!(
                 "Checkpoint saved to {}\n\
                  Active persona: {}\n\
@@ -2312,6 +4487,29 @@ impl MimicryEngine {
         }
     }
 
+    /// Merge the signature cache saved by the last checkpoint into the
+    /// warmed cache. Returns how many entries were restored. A missing file
+    /// restores nothing; a corrupt one is logged and the cache re-warmed.
+    pub fn restore_cache(&mut self) -> usize {
+        match self.persistence.load_signature_cache() {
+            Ok(None) => 0,
+            Ok(Some(saved)) => {
+                let entries: Vec<CachedSignature> = saved.entries().cloned().collect();
+                let restored = entries.len();
+                for entry in entries {
+                    self.cache.insert(entry);
+                }
+                restored
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "discarding unreadable signature cache");
+                self.cache.clear();
+                self.cache.warm_up(&self.profile_store);
+                0
+            }
+        }
+    }
+
     /// Show persistence summary
     pub fn persist_status(&mut self) -> String {
         self.persistence
@@ -2319,6 +4517,27 @@ impl MimicryEngine {
             .unwrap_or_else(|e| format!("Persistence error: {}", e))
     }
 
+    /// Verify saved files against the manifest, listing any issues
+    pub fn verify(&mut self) -> Result<String, String> {
+        let issues = self.persistence.verify_integrity()?;
+        let m = &self.persistence.manifest;
+        let entries = m.personas.len() + m.profiles.len() + m.sessions.len() + m.checkpoints.len();
+        if issues.is_empty() {
+            return Ok(format!(
+                "Integrity OK: {} manifest entries verified",
+                entries
+            ));
+        }
+        let mut lines = vec![format!(
+            "Integrity check found {} issue(s) across {} manifest entries:",
+            issues.len(),
+            entries
+        )];
+        lines.extend(issues.iter().map(|issue| format!("  {}", issue)));
+        lines.push("Use /refresh to rebuild the manifest from disk.".to_string());
+        Ok(lines.join("\n"))
+    }
+
     /// List available models and saved personas
     pub fn list(&mut self) -> String {
         let mut lines = vec!["Available AI Models:".to_string()];
@@ -2444,6 +4663,75 @@ impl MimicryEngine {
         ))
     }
 
+    /// Override the per-1K-token rates used for a provider's cost estimates.
+    #[cfg(feature = "api")]
+    pub fn api_pricing(
+        &mut self,
+        provider_str: &str,
+        input_per_1k: f64,
+        output_per_1k: f64,
+    ) -> Result<String, String> {
+        let provider = ApiProvider::parse(provider_str)
+            .ok_or_else(|| format!("Unknown provider: '{}'", provider_str))?;
+        self.api_observer.set_pricing(
+            provider.profile_id(),
+            TokenPricing::new(input_per_1k, output_per_1k),
+        )?;
+        Ok(format!(
+            "{} pricing set: ${} input / ${} output per 1K tokens",
+            provider, input_per_1k, output_per_1k
+        ))
+    }
+
+    /// Manage the API response cache: `on [ttl_secs]` enables it (mirrored
+    /// to `api_cache.json` when persistence is enabled), `off` disables it,
+    /// `clear` empties it, and no argument reports its state.
+    #[cfg(feature = "api")]
+    pub fn api_cache(&mut self, args: &str) -> Result<String, String> {
+        let parts: Vec<&str> = args.split_whitespace().collect();
+        match parts.as_slice() {
+            [] => Ok(match self.api_observer.cache() {
+                Some(cache) => format!(
+                    "Response cache on: {} entries, {} hits / {} misses (TTL {}s)",
+                    cache.len(),
+                    cache.hits,
+                    cache.misses,
+                    cache.ttl.as_secs()
+                ),
+                None => "Response cache off. Use /api-cache on [ttl_secs].".to_string(),
+            }),
+            ["on"] | ["on", _] => {
+                let ttl_secs = match parts.get(1) {
+                    Some(s) => s.parse().map_err(|_| format!("Invalid TTL: '{}'", s))?,
+                    None => DEFAULT_CACHE_TTL_SECS,
+                };
+                let ttl = std::time::Duration::from_secs(ttl_secs);
+                if self.persistence.config.enabled {
+                    let path = self.persistence.config.base_dir.join("api_cache.json");
+                    let key = self.persistence.config.encryption_key;
+                    self.api_observer.enable_disk_cache(&path, ttl, key)?;
+                    Ok(format!(
+                        "Response cache on (TTL {}s), saved to {}",
+                        ttl_secs,
+                        path.display()
+                    ))
+                } else {
+                    self.api_observer.enable_cache(ttl);
+                    Ok(format!("Response cache on (TTL {}s, in memory)", ttl_secs))
+                }
+            }
+            ["off"] => {
+                self.api_observer.disable_cache();
+                Ok("Response cache off.".to_string())
+            }
+            ["clear"] => {
+                let removed = self.api_observer.clear_cache()?;
+                Ok(format!("Cleared {} cached responses.", removed))
+            }
+            _ => Err("Usage: /api-cache [on [ttl_secs] | off | clear]".to_string()),
+        }
+    }
+
     /// Observe a real AI model's response via API.
     /// COMPOUND: API response → analyze → store training data → refine profile → update templates → compile to cache.
     #[cfg(feature = "api")]
@@ -2460,12 +4748,23 @@ impl MimicryEngine {
         let latency = response.latency_ms;
         let model = response.model.clone();
 
+        // Compare against the accumulated signature before it absorbs this response
+        let consistency = match self.analyzer.signature_distance(&profile_id, &content) {
+            Some(distance) if distance > OUTLIER_DISTANCE => format!(
+                "Signature distance: {:.3} -- OUTLIER (possible model update)",
+                distance
+            ),
+            Some(distance) => format!("Signature distance: {:.3} (consistent)", distance),
+            None => "Signature distance: n/a (first observation)".to_string(),
+        };
+
         // COMPOUND: Feed into the standard observation pipeline
         let observe_result = self.observe(&profile_id, &content);
 
         Ok(format!(
             "=== API OBSERVATION: {} ({}) ===\n\
              Latency: {}ms | Tokens: {}\n\
+             {}\n\
              Response ({} chars):\n{}\n\n\
              --- Mimicry Pipeline ---\n{}",
             provider,
@@ -2474,6 +4773,7 @@ impl MimicryEngine {
             tokens
                 .map(|t| t.to_string())
                 .unwrap_or_else(|| "?".to_string()),
+            consistency,
             content.len(),
             if content.len() > 500 {
                 format!("{}...", &content[..500])
@@ -2488,51 +4788,50 @@ impl MimicryEngine {
     /// COMPOUND: Each response feeds into observation pipeline, then compares.
     #[cfg(feature = "api")]
     pub fn api_compare(&mut self, prompt_text: &str) -> Result<String, String> {
-        let prompt = ApiPrompt::new(prompt_text);
-        let results = self.api_observer.send_to_all(&prompt);
+        let comparison = self.run_api_compare(prompt_text)?;
 
-        if results.is_empty() {
-            return Err(
-                "No API providers configured. Use /api-config <provider> [key] first.".to_string(),
-            );
+        if comparison.responses.is_empty() {
+            let reasons: Vec<String> = comparison
+                .skipped
+                .iter()
+                .map(|s| format!("  {}: {}", s.provider_id, s.reason))
+                .collect();
+            return Err(format!(
+                "All API calls failed or were skipped:\n{}",
+                reasons.join("\n")
+            ));
         }
 
-        let mut responses = Vec::new();
-        let mut errors = Vec::new();
+        Ok(format_comparison(&comparison))
+    }
 
-        for result in results {
-            match result {
-                Ok(resp) => {
-                    // COMPOUND: Feed each response into observation pipeline
-                    let profile_id = resp.provider.profile_id().to_string();
-                    self.observe(&profile_id, &resp.content);
-                    responses.push(resp);
-                }
-                Err(e) => errors.push(e),
-            }
-        }
+    /// Compare providers like `api_compare`, returning the comparison as
+    /// JSON (see `ComparisonResult::to_json`). Skipped providers are listed
+    /// in the output rather than reported as an error.
+    #[cfg(feature = "api")]
+    pub fn api_compare_json(&mut self, prompt_text: &str) -> Result<String, String> {
+        self.run_api_compare(prompt_text)?.to_json()
+    }
 
-        if responses.is_empty() {
-            return Err(format!("All API calls failed:\n{}", errors.join("\n")));
+    /// Send a prompt to every configured provider and feed each response
+    /// into the observation pipeline.
+    #[cfg(feature = "api")]
+    fn run_api_compare(&mut self, prompt_text: &str) -> Result<ComparisonResult, String> {
+        if self.api_observer.configured_providers().is_empty() {
+            return Err(
+                "No API providers configured. Use /api-config <provider> [key] first.".to_string(),
+            );
         }
 
-        // Build similarity matrix
-        let response_texts: Vec<&str> = responses.iter().map(|r| r.content.as_str()).collect();
-        let matrix = build_similarity_matrix(&response_texts);
-
-        let comparison = ComparisonResult {
-            prompt: prompt_text.to_string(),
-            responses: responses.clone(),
-            similarity_matrix: matrix,
-        };
-
-        let mut output = format_comparison(&comparison);
+        let prompt = ApiPrompt::new(prompt_text);
+        let comparison = self.api_observer.compare(&prompt);
 
-        if !errors.is_empty() {
-            output.push_str(&format!("\n\nFailed providers:\n{}", errors.join("\n")));
+        // COMPOUND: Feed each response into observation pipeline
+        for resp in &comparison.responses {
+            let profile_id = resp.provider.profile_id().to_string();
+            self.observe(&profile_id, &resp.content);
         }
-
-        Ok(output)
+        Ok(comparison)
     }
 
     /// Run a comprehensive study on a provider: send diverse prompts to build
@@ -2540,11 +4839,33 @@ impl MimicryEngine {
     /// COMPOUND: All responses feed into observation → analysis → training → cache pipeline.
     #[cfg(feature = "api")]
     pub fn api_study(&mut self, provider_str: &str, count: u64) -> Result<String, String> {
+        self.api_study_suite(provider_str, "general", count)
+    }
+
+    /// Run an API study with the first `count` prompts of a named suite
+    /// ("general", "coding", "creative", "reasoning").
+    #[cfg(feature = "api")]
+    pub fn api_study_suite(
+        &mut self,
+        provider_str: &str,
+        suite: &str,
+        count: u64,
+    ) -> Result<String, String> {
         let provider = ApiProvider::parse(provider_str)
             .ok_or_else(|| format!("Unknown provider: '{}'", provider_str))?;
         let profile_id = provider.profile_id().to_string();
+        let prompts = study_suite(suite).ok_or_else(|| {
+            format!(
+                "Unknown study suite: '{}'. Available: {}",
+                suite,
+                STUDY_SUITES.join(", ")
+            )
+        })?;
+        let n = (count as usize).min(prompts.len());
 
-        let (responses, summary) = self.api_observer.study(&profile_id, count as usize)?;
+        let (responses, summary) = self
+            .api_observer
+            .study_with_prompts(&profile_id, &prompts[..n])?;
 
         // COMPOUND: Feed all successful responses into observation pipeline
         let mut successful = 0;
@@ -2593,7 +4914,7 @@ impl MimicryEngine {
         let trimmed = input.trim();
 
         if !trimmed.starts_with('/') {
-            return MimicCommand::Chat(trimmed.to_string());
+            return MimicCommand::Chat(trimmed.to_string(), None);
         }
 
         let parts: Vec<&str> = trimmed.splitn(2, ' ').collect();
@@ -2609,10 +4930,13 @@ impl MimicryEngine {
                         .split('+')
                         .map(|s| s.trim().to_string())
                         .collect();
+                    // An unparseable weight becomes NaN rather than being
+                    // dropped, so blend() reports it instead of shifting
+                    // the remaining weights onto the wrong models
                     let weights: Vec<f64> = if blend_parts.len() > 1 {
                         blend_parts[1]
                             .split(',')
-                            .filter_map(|s| s.trim().parse().ok())
+                            .map(|s| s.trim().parse().unwrap_or(f64::NAN))
                             .collect()
                     } else {
                         vec![1.0 / ids.len() as f64; ids.len()]
@@ -2644,9 +4968,24 @@ impl MimicryEngine {
                 MimicCommand::Save(name)
             }
             "/load" => MimicCommand::Load(args.trim().to_string()),
+            "/restore" => match args.split_whitespace().collect::<Vec<_>>()[..] {
+                [name, backup] => MimicCommand::Restore(name.to_string(), backup.to_string()),
+                _ => MimicCommand::Help,
+            },
             "/evolve" => {
-                let n = args.trim().parse().unwrap_or(10);
-                MimicCommand::Evolve(n)
+                let dry = args.split_whitespace().any(|p| p == "--dry");
+                let evolve_parts: Vec<&str> =
+                    args.split_whitespace().filter(|p| *p != "--dry").collect();
+                let n = evolve_parts
+                    .first()
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(10);
+                match (evolve_parts.get(1), dry) {
+                    (Some(_), true) => MimicCommand::Help,
+                    (Some(shadow), false) => MimicCommand::ShadowEvolve(shadow.to_string(), n),
+                    (None, true) => MimicCommand::EvolveDryRun(n),
+                    (None, false) => MimicCommand::Evolve(n),
+                }
             }
             "/train" => {
                 let n = args.trim().parse().unwrap_or(10);
@@ -2654,11 +4993,24 @@ impl MimicryEngine {
             }
             "/export" => MimicCommand::Export(args.trim().to_string()),
             "/import" => MimicCommand::Import(args.trim().to_string()),
+            "/bundle-export" => match args.trim() {
+                "" => MimicCommand::Help,
+                path => MimicCommand::BundleExport(path.to_string()),
+            },
+            "/bundle-import" => match args.split_whitespace().collect::<Vec<_>>()[..] {
+                [path] => MimicCommand::BundleImport(path.to_string(), BundleConflict::Skip),
+                [path, policy] => match BundleConflict::parse(policy) {
+                    Some(policy) => MimicCommand::BundleImport(path.to_string(), policy),
+                    None => MimicCommand::Help,
+                },
+                _ => MimicCommand::Help,
+            },
             "/delete" => MimicCommand::Delete(args.trim().to_string()),
             "/graph" => MimicCommand::Graph,
             "/evolution" => MimicCommand::EvolutionStatus,
             "/checkpoint" => MimicCommand::Checkpoint,
             "/persist" => MimicCommand::Persist,
+            "/verify" => MimicCommand::Verify,
             "/list" => MimicCommand::List,
             "/help" => MimicCommand::Help,
             "/api-observe" | "/api-obs" => {
@@ -2685,25 +5037,100 @@ impl MimicryEngine {
                     MimicCommand::Help
                 }
             }
+            "/api-pricing" => {
+                let rates: Vec<&str> = args.split_whitespace().collect();
+                let rate = |i: usize| {
+                    let rate = rates.get(i)?.parse::<f64>().ok()?;
+                    (rate.is_finite() && rate >= 0.0).then_some(rate)
+                };
+                match (rates.len(), rate(1), rate(2)) {
+                    (3, Some(input), Some(output)) => {
+                        MimicCommand::ApiPricing(rates[0].to_string(), input, output)
+                    }
+                    _ => MimicCommand::Help,
+                }
+            }
+            "/api-cache" => MimicCommand::ApiCache(args.trim().to_string()),
             "/api-compare" | "/api-cmp" => {
-                MimicCommand::ApiCompare(args.trim_matches('"').to_string())
+                let (prompt, json) = match args.trim().strip_suffix("--json") {
+                    Some(prompt) => (prompt, true),
+                    None => (args, false),
+                };
+                MimicCommand::ApiCompare(prompt.trim().trim_matches('"').to_string(), json)
             }
             "/api-study" => {
-                let study_parts: Vec<&str> = args.splitn(2, ' ').collect();
-                if !study_parts.is_empty() && !study_parts[0].is_empty() {
-                    let n = if study_parts.len() > 1 {
-                        study_parts[1].trim().parse().unwrap_or(5)
-                    } else {
-                        5
-                    };
-                    MimicCommand::ApiStudy(study_parts[0].to_string(), n)
-                } else {
-                    MimicCommand::Help
+                // /api-study <provider> [n] [suite], in either order after the provider
+                let mut study_parts = args.split_whitespace();
+                match study_parts.next() {
+                    Some(provider) => {
+                        let mut n = 5;
+                        let mut suite = None;
+                        for part in study_parts {
+                            match part.parse() {
+                                Ok(count) => n = count,
+                                Err(_) => suite = Some(part.to_string()),
+                            }
+                        }
+                        MimicCommand::ApiStudy(provider.to_string(), n, suite)
+                    }
+                    None => MimicCommand::Help,
                 }
             }
             "/api-status" | "/api" => MimicCommand::ApiStatus,
             "/refresh" | "/sync" => MimicCommand::Refresh,
-            _ => MimicCommand::Chat(trimmed.to_string()),
+            "/fork" => MimicCommand::Fork(args.trim().to_string()),
+            "/promote" => MimicCommand::Promote(args.trim().to_string()),
+            "/shadows" => MimicCommand::Shadows,
+            "/branch" => MimicCommand::Branch(args.trim().to_string()),
+            "/switch" => MimicCommand::Switch(args.trim().to_string()),
+            "/chat" => match args.trim().split_once(' ') {
+                Some((key, message)) if !message.trim().is_empty() => {
+                    MimicCommand::Chat(message.trim().to_string(), Some(key.to_string()))
+                }
+                _ => MimicCommand::Help,
+            },
+            "/undo" => MimicCommand::Undo,
+            "/reset" => MimicCommand::Reset,
+            "/set-maxlen" => match args.trim() {
+                "off" | "0" => MimicCommand::SetMaxLen(None),
+                n => match n.parse() {
+                    Ok(max) => MimicCommand::SetMaxLen(Some(max)),
+                    Err(_) => MimicCommand::Usage("Usage: /set-maxlen <n|off>".to_string()),
+                },
+            },
+            "/set-render" => match RenderMode::parse(args) {
+                Some(mode) => MimicCommand::SetRender(mode),
+                None => MimicCommand::Help,
+            },
+            "/compare-personas" => {
+                let names: Vec<&str> = args.split_whitespace().collect();
+                if names.len() == 2 {
+                    MimicCommand::ComparePersonas(names[0].to_string(), names[1].to_string())
+                } else {
+                    MimicCommand::Help
+                }
+            }
+            "/transcript" => {
+                let path = args.trim();
+                MimicCommand::Transcript((!path.is_empty()).then(|| PathBuf::from(path)))
+            }
+            "/export-history" => match args.trim() {
+                "" => MimicCommand::Help,
+                path => MimicCommand::ExportHistory(PathBuf::from(path)),
+            },
+            "/seed" => match args.trim().parse() {
+                Ok(seed) => MimicCommand::Seed(seed),
+                Err(_) => MimicCommand::Usage("Usage: /seed <n>".to_string()),
+            },
+            "/diff" => {
+                let ids: Vec<&str> = args.split_whitespace().collect();
+                if ids.len() == 2 {
+                    MimicCommand::Diff(ids[0].to_string(), ids[1].to_string())
+                } else {
+                    MimicCommand::Help
+                }
+            }
+            _ => MimicCommand::Chat(trimmed.to_string(), None),
         }
     }
 
@@ -2729,14 +5156,73 @@ impl MimicryEngine {
                 Ok(msg) => msg,
                 Err(e) => e,
             },
+            MimicCommand::Restore(name, backup) => match self.restore(&name, &backup) {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
             MimicCommand::Evolve(n) => match self.evolve(n) {
                 Ok(msg) => msg,
                 Err(e) => e,
             },
+            MimicCommand::EvolveDryRun(n) => match self.evolve_dry_run(n) {
+                Ok(report) => Self::format_dry_run(&report),
+                Err(e) => e,
+            },
             MimicCommand::Train(n) => match self.train(n) {
                 Ok(msg) => msg,
                 Err(e) => e,
             },
+            MimicCommand::Fork(name) => match self.fork_session(&name) {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
+            MimicCommand::ShadowEvolve(name, n) => match self.evolve_shadow(&name, n) {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
+            MimicCommand::Promote(name) => match self.promote(&name) {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
+            MimicCommand::Shadows => self.shadow_status(),
+            MimicCommand::Branch(name) => match self.branch(&name) {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
+            MimicCommand::Switch(name) => match self.switch_branch(&name) {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
+            MimicCommand::Undo => match self.undo() {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
+            MimicCommand::Reset => match self.reset() {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
+            MimicCommand::ComparePersonas(a, b) => match self.compare_personas(&a, &b) {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
+            MimicCommand::Transcript(path) => match self.transcript(path.as_deref()) {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
+            MimicCommand::ExportHistory(path) => match self.export_history(&path) {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
+            MimicCommand::Seed(seed) => self.set_seed(seed),
+            MimicCommand::SetMaxLen(max) => match self.set_max_output_chars(max) {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
+            MimicCommand::SetRender(mode) => self.set_render_mode(mode),
+            MimicCommand::Diff(a, b) => match self.diff_profiles(&a, &b) {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
             MimicCommand::Export(name) => match self.export(&name) {
                 Ok(msg) => msg,
                 Err(e) => e,
@@ -2745,6 +5231,14 @@ impl MimicryEngine {
                 Ok(msg) => msg,
                 Err(e) => e,
             },
+            MimicCommand::BundleExport(path) => match self.bundle_export(&path) {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
+            MimicCommand::BundleImport(path, policy) => match self.bundle_import(&path, policy) {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
             MimicCommand::Delete(name) => match self.delete(&name) {
                 Ok(msg) => msg,
                 Err(e) => e,
@@ -2762,8 +5256,13 @@ impl MimicryEngine {
                 Err(e) => e,
             },
             MimicCommand::Persist => self.persist_status(),
+            MimicCommand::Verify => match self.verify() {
+                Ok(msg) => msg,
+                Err(e) => e,
+            },
             MimicCommand::List => self.list(),
             MimicCommand::Help => self.help(),
+            MimicCommand::Usage(usage) => usage,
             // API commands - feature-gated
             MimicCommand::ApiObserve(provider, prompt) => {
                 #[cfg(feature = "api")]
@@ -2793,31 +5292,68 @@ impl MimicryEngine {
                     "API feature not enabled. Rebuild with: cargo build --features api".to_string()
                 }
             }
-            MimicCommand::ApiCompare(prompt) => {
+            MimicCommand::ApiPricing(provider, input, output) => {
+                #[cfg(feature = "api")]
+                {
+                    match self.api_pricing(&provider, input, output) {
+                        Ok(msg) => msg,
+                        Err(e) => e,
+                    }
+                }
+                #[cfg(not(feature = "api"))]
+                {
+                    let _ = (&provider, input, output);
+                    "API feature not enabled. Rebuild with: cargo build --features api".to_string()
+                }
+            }
+            MimicCommand::ApiCache(args) => {
+                #[cfg(feature = "api")]
+                {
+                    match self.api_cache(&args) {
+                        Ok(msg) => msg,
+                        Err(e) => e,
+                    }
+                }
+                #[cfg(not(feature = "api"))]
+                {
+                    let _ = &args;
+                    "API feature not enabled. Rebuild with: cargo build --features api".to_string()
+                }
+            }
+            MimicCommand::ApiCompare(prompt, json) => {
                 #[cfg(feature = "api")]
                 {
-                    match self.api_compare(&prompt) {
+                    let result = if json {
+                        self.api_compare_json(&prompt)
+                    } else {
+                        self.api_compare(&prompt)
+                    };
+                    match result {
                         Ok(msg) => msg,
                         Err(e) => e,
                     }
                 }
                 #[cfg(not(feature = "api"))]
                 {
-                    let _ = &prompt;
+                    let _ = (&prompt, json);
                     "API feature not enabled. Rebuild with: cargo build --features api".to_string()
                 }
             }
-            MimicCommand::ApiStudy(provider, n) => {
+            MimicCommand::ApiStudy(provider, n, suite) => {
                 #[cfg(feature = "api")]
                 {
-                    match self.api_study(&provider, n) {
+                    let result = match suite {
+                        Some(suite) => self.api_study_suite(&provider, &suite, n),
+                        None => self.api_study(&provider, n),
+                    };
+                    match result {
                         Ok(msg) => msg,
                         Err(e) => e,
                     }
                 }
                 #[cfg(not(feature = "api"))]
                 {
-                    let _ = (&provider, n);
+                    let _ = (&provider, n, &suite);
                     "API feature not enabled. Rebuild with: cargo build --features api".to_string()
                 }
             }
@@ -2834,23 +5370,7 @@ impl MimicryEngine {
             MimicCommand::Refresh => {
                 self.refresh_manifest()
             }
-            MimicCommand::Chat(input) => {
-                // Need to take session out to avoid borrow issues with template_store
-                if let Some(mut session) = self.session.take() {
-                    let (output, _delta) = session.process(
-                        &input,
-                        &mut self.cache,
-                        &self.analyzer,
-                        &mut self.template_store,
-                    );
-                    self.session = Some(session);
-                    output
-                } else {
-                    "No active session. Use /mimic <model> to start mimicking.\n\
-                     Type /help for available commands."
-                        .to_string()
-                }
-            }
+            MimicCommand::Chat(input, key) => self.chat(&input, key.as_deref()),
         }
     }
 
@@ -2863,29 +5383,51 @@ impl MimicryEngine {
 MIMICRY:
   /mimic <model>              Start mimicking a model (e.g., /mimic gpt4o)
   /mimic <a>+<b> [w1,w2]     Blend models (e.g., /mimic gpt4o+claude 0.7,0.3)
+  /chat <key> <text>          Chat in the session keyed <key>, creating it if needed
 
 OBSERVATION:
   /observe <model> <text>     Feed a model response for learning
   /identify <text>            Identify which model produced text
+  /diff <a> <b>               Compare two profiles axis by axis
 
 EVOLUTION:
   /evolve [n]                 Run n evolution iterations (default: 10)
+  /evolve <n> <shadow>        Evolve a shadow session instead of the live one
+  /evolve <n> --dry           Preview an evolution run without changing anything
+  /fork <name>                Fork the live session into a sandboxed shadow
+  /shadows                    Compare shadow sessions against the live persona
+  /promote <name>             Replace the live persona with a shadow
+  /branch <name>              Copy the live session into a named branch
+  /switch <name>              Make a branch the live session (main = original)
   /train [n]                  Train from stored observations (default: 10)
+  /undo                       Revert the last evolve or train run
+  /reset                      Clear learned state and relearn (keeps training data)
   /evolution                  Show detailed evolution status
   /graph                      Show ASCII convergence graph
+  /export-history <path>      Write convergence history as CSV
+  /compare-personas <a> <b>   Graph two personas' convergence side by side
 
 PERSISTENCE:
   /save [name]                Save current persona snapshot
   /load <name>                Load a saved persona
+  /restore <name> <backup>    Restore a saved persona from a backup
   /export <name>              Export persona to disk
+  /transcript [path]          Print the conversation as Markdown, or write it
   /import <path>              Import persona from file
+  /bundle-export <path>       Archive all saved personas into one file
+  /bundle-import <path> [skip|overwrite|merge]
+                              Restore personas from a bundle (default: skip)
   /delete <name>              Delete a saved persona
   /checkpoint                 Save full engine checkpoint
   /persist                    Show persistence summary
+  /verify                     Check saved files against the manifest
   /refresh                    Resync manifest with disk files
 
 INFO:
   /status                     Show current engine status
+  /seed <n>                   Set the RNG seed for reproducible runs
+  /set-maxlen <n|off>         Cap System 2 output at n chars
+  /set-render <plain|md>      Render responses as plain text or Markdown
   /list                       List available models and saved personas
   /help                       Show this help
   /quit                       Exit RustyWorm
@@ -2899,11 +5441,13 @@ Any other text                Chat as the current persona"
             text = text.replace(
                 "INFO:",
                 "API OBSERVATION:\n  \
-                 /api-config <provider> [key]  Configure API provider (openai, claude, gemini, ollama)\n  \
+                 /api-config <provider> [key]  Configure API provider (openai, claude, gemini, ollama, custom:<url>:<model>)\n  \
                  /api-observe <provider> <prompt>  Send prompt to real API, observe response\n  \
-                 /api-compare <prompt>         Compare same prompt across all configured providers\n  \
-                 /api-study <provider> [n]     Send n diverse prompts for comprehensive study\n  \
-                 /api-status                   Show API observer status\n\n\
+                 /api-compare <prompt> [--json]  Compare same prompt across all configured providers\n  \
+                 /api-study <provider> [n] [suite]  Study with n prompts (general, coding, creative, reasoning)\n  \
+                 /api-pricing <provider> <in> <out>  Set USD per 1K input/output tokens\n  \
+                 /api-cache [on [ttl]|off|clear]  Cache repeated API prompts\n  \
+                 /api-status                   Show API observer status and estimated cost\n\n\
                  INFO:",
             );
         }
@@ -2963,6 +5507,58 @@ mod tests {
         assert!(blended.profile.display_name.contains("Claude"));
     }
 
+    #[test]
+    fn test_compound_persona_blend_by_convergence() {
+        let store = AiProfileStore::default();
+        let mut high = CompoundPersona::from_profile(store.get("claude").unwrap());
+        let mut low = CompoundPersona::from_profile(store.get("gemini").unwrap());
+        high.convergence_score = 0.9;
+        low.convergence_score = 0.3;
+
+        let weights = CompoundPersona::convergence_weights(&[&high, &low]);
+        assert!((weights[0] - 0.75).abs() < 1e-9);
+        assert!((weights[1] - 0.25).abs() < 1e-9);
+
+        let blended = CompoundPersona::blend_by_convergence(&[&high, &low]);
+        let mut dist_high = 0.0;
+        let mut dist_low = 0.0;
+        for axis in &blended.profile.personality {
+            let h = high.profile.personality_value(&axis.name).unwrap_or(0.5);
+            let l = low.profile.personality_value(&axis.name).unwrap_or(0.5);
+            dist_high += (axis.value - h).abs();
+            dist_low += (axis.value - l).abs();
+        }
+        assert!(dist_high < dist_low);
+
+        // Full manual share ignores convergence entirely
+        let manual =
+            CompoundPersona::blend_by_convergence_with(&[&high, &low], &[0.0, 1.0], 1.0).unwrap();
+        assert!((manual.convergence_score - 0.3).abs() < 1e-9);
+
+        // Bad manual weights are reported instead of panicking
+        assert_eq!(
+            CompoundPersona::blend_by_convergence_with(&[&high, &low], &[1.0], 0.5).unwrap_err(),
+            BlendError::LengthMismatch {
+                profiles: 2,
+                weights: 1
+            }
+        );
+        assert!(matches!(
+            CompoundPersona::blend_by_convergence_with(&[&high, &low], &[1.0, f64::NAN], 0.5),
+            Err(BlendError::NonFiniteWeight { index: 1, .. })
+        ));
+        assert!(
+            CompoundPersona::blend_by_convergence_with(&[&high, &low], &[0.0, 0.0], 0.5).is_ok()
+        );
+
+        // Unconverged sources fall back to equal weights
+        let fresh = CompoundPersona::from_profile(store.get("gpt4o").unwrap());
+        assert_eq!(
+            CompoundPersona::convergence_weights(&[&fresh, &fresh]),
+            vec![0.5, 0.5]
+        );
+    }
+
     #[test]
     fn test_compound_persona_self_correct() {
         let store = AiProfileStore::default();
@@ -2993,7 +5589,7 @@ mod tests {
     fn test_compound_persona_ethics() {
         let store = AiProfileStore::default();
         let profile = store.get("rustyworm").unwrap();
-        let persona = CompoundPersona::from_profile(profile);
+        let mut persona = CompoundPersona::from_profile(profile);
 
         let good_action = ProposedAction {
             description: "Learn through becoming".to_string(),
@@ -3015,149 +5611,1895 @@ mod tests {
     }
 
     #[test]
-    fn test_mimic_session_process() {
+    fn test_enforce_ethics_adjusts_health() {
         let store = AiProfileStore::default();
-        let profile = store.get("claude").unwrap();
-        let persona = CompoundPersona::from_profile(profile);
-        let mut session = MimicSession::new(persona);
-        let mut cache = SignatureCache::new();
-        let analyzer = BehaviorAnalyzer::new();
-        let mut template_store = TemplateStore::new();
+        let profile = store.get("rustyworm").unwrap();
+        let mut persona = CompoundPersona::from_profile(profile);
+        let parasitic = ProposedAction {
+            description: "Extract".to_string(),
+            benefit_to_self: 0.9,
+            benefit_to_other: 0.0,
+            breaks_loop: false,
+            is_parasitic: true,
+        };
+        persona.health.half_life = std::time::Duration::ZERO;
+        for _ in 0..3 {
+            persona.enforce_ethics(&parasitic);
+        }
+        let degraded = persona.current_health().score;
+        assert!((degraded - 0.55).abs() < 1e-9, "got {}", degraded);
+        assert!(persona.health.reason.contains("Parasitism"));
+
+        let mut good = parasitic.clone();
+        good.benefit_to_self = 0.3;
+        good.benefit_to_other = 0.5;
+        good.is_parasitic = false;
+        persona.enforce_ethics(&good);
+        assert!(persona.current_health().score > degraded);
+    }
+
+    #[test]
+    fn test_enforce_ethics_checks_health_floor() {
+        let store = AiProfileStore::default();
+        let mut persona = CompoundPersona::from_profile(store.get("claude").unwrap());
+        persona
+            .ethics
+            .add_rule(crate::consciousness::HealthFloorRule::new(
+                crate::consciousness::HealthLevel::Conscious,
+            ));
+        persona.health.half_life = std::time::Duration::ZERO;
+        let selfish = ProposedAction {
+            description: "Keep the credit".to_string(),
+            benefit_to_self: 0.75,
+            benefit_to_other: 0.25,
+            breaks_loop: false,
+            is_parasitic: false,
+        };
+        let mut allowed = 0;
+        while persona.enforce_ethics(&selfish).allowed {
+            allowed += 1;
+            assert!(allowed < 100, "floor never reached");
+        }
+        assert!(persona
+            .ethics
+            .audit_log()
+            .last()
+            .unwrap()
+            .reason
+            .contains("below floor"));
+    }
+
+    #[test]
+    fn test_session_ethics_warning_on_moderate_risk() {
+        let mut engine = MimicryEngine::new();
+        let _ = engine.mimic("claude");
+        let output = engine.chat("hello", None);
+        assert!(!output.contains("[ETHICS WARNING]"));
+
+        let session = engine.session.as_mut().unwrap();
+        assert_eq!(
+            session.last_ethics.as_ref().unwrap().risk.level,
+            RiskLevel::None
+        );
+        let moderate = ProposedAction {
+            description: "Lean on the user".to_string(),
+            benefit_to_self: 0.75,
+            benefit_to_other: 0.25,
+            breaks_loop: false,
+            is_parasitic: false,
+        };
+        let result = session.persona.enforce_ethics(&moderate);
+        assert!(result.allowed);
+        session.last_ethics = Some(result);
+        let warning = session.ethics_warning().unwrap();
+        assert!(warning.starts_with("[ETHICS WARNING] Moderate parasitic risk"));
+        let output = with_ethics_warning(session, "Response".to_string());
+        assert!(output.ends_with("\n\nResponse"));
+    }
+
+    #[test]
+    fn test_vocabulary_softeners_follow_persona_hedging() {
+        use crate::mimicry::templates::PersonaVocabulary;
+
+        let mut engine = MimicryEngine::new();
+        let _ = engine.mimic("claude");
+        let profile = engine.session.as_ref().unwrap().persona.profile.clone();
+        let library = engine.template_store.get_or_create(&profile);
+        library.hedging_injector = HedgingInjector::from_profile(&profile)
+            .with_markers(vec!["je pense".to_string()])
+            .with_strength(1.0);
+        let _ = engine.chat("hello", None);
+
+        let session = engine.session.as_mut().unwrap();
+        let injector = session.hedging.clone().unwrap();
+        assert_eq!(injector.markers, vec!["je pense".to_string()]);
+        *session.rng.borrow_mut() = StdRng::seed_from_u64(7);
+        let vocab = PersonaVocabulary::for_model("claude");
+
+        // The persona's own marker stops stacking
+        let hedged = "Je pense que oui.";
+        for _ in 0..20 {
+            assert_eq!(
+                session.enrich_with_vocabulary(hedged, &vocab, &profile),
+                hedged
+            );
+        }
+        let plain = "Here is the answer.";
+        assert!((0..20).any(|_| session.enrich_with_vocabulary(plain, &vocab, &profile) != plain));
+
+        // Zero strength never softens
+        session.hedging = Some(injector.with_strength(0.0));
+        for _ in 0..20 {
+            assert_eq!(
+                session.enrich_with_vocabulary(plain, &vocab, &profile),
+                plain
+            );
+        }
+    }
+
+    #[cfg(feature = "empathy")]
+    #[test]
+    fn test_warmth_signal_shapes_phrasing() {
+        use crate::ising_empathy::EmotionVector;
+
+        let mut engine = MimicryEngine::new();
+        let _ = engine.mimic("claude");
+        let _ = engine.chat("hello", None);
+        // Every turn is empathized with
+        let session = engine.session.as_mut().unwrap();
+        assert_eq!(session.empathy.memory_count, 1);
+
+        session.empathy = default_empathy();
+        let msg = "Here is the answer.";
+        assert_eq!(session.apply_warmth(msg), msg);
+        let calm = EmotionVector::new(0.5, 0.2, 0.0, 0.8);
+        session.empathy.store_memory(&calm, 0.7);
+        let warm = session.apply_warmth(msg);
+        assert_eq!(warm, format!("{} {}", WARM_OPENING, msg));
+        assert_eq!(session.apply_warmth(&warm), warm);
+
+        session.empathy.store_memory(&calm, 1.0);
+        assert!(session.warmth_signal() >= WARM_CLOSING_THRESHOLD);
+        let warmest = session.apply_warmth(msg);
+        assert!(warmest.starts_with(WARM_OPENING));
+        assert!(warmest.ends_with(WARM_CLOSING));
+
+        let output = engine.chat("hello again", None);
+        assert!(output.contains(WARM_OPENING));
+    }
+
+    #[cfg(feature = "empathy")]
+    #[test]
+    fn test_warmth_phrasing_follows_persona_language() {
+        use crate::ising_empathy::EmotionVector;
+
+        let mut session = MimicSession::new(CompoundPersona::from_profile(
+            AiProfileStore::default().get("claude").unwrap(),
+        ));
+        let calm = EmotionVector::new(0.5, 0.2, 0.0, 0.8);
+        session.empathy.store_memory(&calm, 1.0);
+        let msg = "Voici la réponse.";
+
+        session.persona.profile.language = "fr-CA".to_string();
+        let french = session.apply_warmth(msg);
+        assert!(french.starts_with("Je vous entends."));
+        assert!(!french.contains(WARM_OPENING));
+        assert!(!french.contains(WARM_CLOSING));
+
+        // No German translation: leave the response alone
+        session.persona.profile.language = "de".to_string();
+        assert_eq!(session.apply_warmth(msg), msg);
+    }
+
+    #[cfg(feature = "empathy")]
+    #[test]
+    fn test_empathize_with_stores_memory() {
+        let mut session = MimicSession::new(CompoundPersona::from_profile(
+            AiProfileStore::default().get("claude").unwrap(),
+        ));
+        assert!(session.empathize_with(&IsingSystem::new(4, 1)).is_err());
+
+        let other = IsingSystem::new(EMPATHY_SPINS, 9);
+        let score = session.empathize_with(&other).unwrap();
+        assert!((0.0..=1.0).contains(&score));
+        assert_eq!(session.empathy.memory_count, 1);
+        assert_ne!(session.warmth_signal(), 0.5);
+    }
+
+    #[cfg(feature = "empathy")]
+    #[test]
+    fn test_input_lattice_follows_affect() {
+        assert_eq!(input_affect("Explain lifetimes"), (0.0, 0.0));
+        assert_eq!(input_affect("Thanks, this is great").0, 1.0);
+        let (valence, arousal) = input_affect("I'm STUCK and frustrated!");
+        assert_eq!(valence, -1.0);
+        assert!(arousal > 0.4);
+
+        let warm = input_lattice("Thanks, this is great");
+        assert_eq!(warm.magnetization(), 1.0);
+        let hostile = input_lattice("This is terrible and useless");
+        assert_eq!(hostile.magnetization(), 0.0);
+        assert!(hostile.energy() > warm.energy());
+        assert_eq!(input_lattice("Thanks, this is great").spins, warm.spins);
+
+        let empathy = default_empathy();
+        let warm_emotion = empathy.encode_emotion(&warm);
+        let hostile_emotion = empathy.encode_emotion(&hostile);
+        assert!(warm_emotion.valence > hostile_emotion.valence);
+    }
+
+    #[test]
+    fn test_compound_personas_negotiate_with_own_rules() {
+        let store = AiProfileStore::default();
+        let claude = CompoundPersona::from_profile(store.get("claude").unwrap());
+        let mut gpt = CompoundPersona::from_profile(store.get("gpt4o").unwrap());
+        gpt.ethics
+            .add_rule(crate::consciousness::ConsecutiveLowBenefitRule::new(
+                0.35, 1,
+            ));
+
+        // GPT-4o's extra rule rejects giving Claude less than 0.35
+        let proposal = ProposedAction {
+            description: "Share the workload".to_string(),
+            benefit_to_self: 0.3,
+            benefit_to_other: 0.5,
+            breaks_loop: false,
+            is_parasitic: false,
+        };
+        let outcome = claude.negotiate(&gpt, proposal);
+        match outcome {
+            crate::consciousness::NegotiationOutcome::Agreed { action, rounds } => {
+                assert_eq!(rounds, 2);
+                assert!(action.benefit_to_self >= 0.35);
+            }
+            other => panic!("Expected agreement, got {:?}", other),
+        }
+        assert!(gpt.ethics.audit_log().is_empty());
+    }
+
+    #[test]
+    fn test_ethics_audit_survives_snapshot() {
+        let store = AiProfileStore::default();
+        let profile = store.get("rustyworm").unwrap();
+        let mut persona = CompoundPersona::from_profile(profile);
+        persona.ethics.set_audit_cap(2);
+        let action = ProposedAction {
+            description: "Audited action".to_string(),
+            benefit_to_self: 0.3,
+            benefit_to_other: 0.5,
+            breaks_loop: false,
+            is_parasitic: false,
+        };
+        persona.enforce_ethics(&action);
+
+        let json = serde_json::to_string(&persona.snapshot()).unwrap();
+        let snapshot: CompoundPersonaSnapshot = serde_json::from_str(&json).unwrap();
+        let restored = CompoundPersona::from_snapshot(snapshot);
+        let log = restored.ethics.audit_log();
+        assert_eq!(log.len(), 1);
+        assert_eq!(log[0].description, "Audited action");
+        assert!(log[0].allowed);
+        assert_eq!(restored.ethics.audit_cap(), 2);
+
+        // Undo restore points leave the log out; undo never restores it
+        let mut session = MimicSession::new(persona);
+        session.push_undo();
+        assert!(session.undo_stack[0].0.ethics_audit.is_empty());
+        assert_eq!(session.undo_stack[0].0.ethics_audit_cap, 2);
+        session.undo().unwrap();
+        assert_eq!(session.persona.ethics.audit_log().len(), 1);
+    }
+
+    #[test]
+    fn test_health_survives_snapshot() {
+        let store = AiProfileStore::default();
+        let profile = store.get("rustyworm").unwrap();
+        let mut persona = CompoundPersona::from_profile(profile);
+        persona.health.half_life = std::time::Duration::ZERO;
+        persona.enforce_ethics(&ProposedAction {
+            description: "Extract".to_string(),
+            benefit_to_self: 0.9,
+            benefit_to_other: 0.0,
+            breaks_loop: false,
+            is_parasitic: true,
+        });
+        let before = persona.current_health();
+        assert!(before.score < 1.0);
+
+        let json = serde_json::to_string(&persona.snapshot()).unwrap();
+        let snapshot: CompoundPersonaSnapshot = serde_json::from_str(&json).unwrap();
+        let restored = CompoundPersona::from_snapshot(snapshot);
+        assert_eq!(restored.health, before);
+        // Idle decay resumes from the saved wall-clock time
+        assert!(restored.health.last_activity.is_some());
+
+        // Snapshots saved before health was recorded start out healthy
+        let mut legacy: serde_json::Value = serde_json::from_str(&json).unwrap();
+        legacy.as_object_mut().unwrap().remove("health");
+        let legacy: CompoundPersonaSnapshot = serde_json::from_value(legacy).unwrap();
+        assert_eq!(legacy.health, RelationshipHealth::default());
+    }
+
+    #[test]
+    fn test_mimic_session_process() {
+        let store = AiProfileStore::default();
+        let profile = store.get("claude").unwrap();
+        let persona = CompoundPersona::from_profile(profile);
+        let mut session = MimicSession::new(persona);
+        let mut cache = SignatureCache::new();
+        let analyzer = BehaviorAnalyzer::new();
+        let mut template_store = TemplateStore::new();
+
+        let (output, delta) = session.process(
+            "Hello, how are you?",
+            &mut cache,
+            &analyzer,
+            &mut template_store,
+        );
+        assert!(!output.is_empty());
+        assert_eq!(session.conversation.len(), 1);
+        assert!(session.total_compounds > 0);
+        assert!(!delta.adjustments.is_empty());
+    }
+
+    #[test]
+    fn test_mimic_session_dual_process() {
+        let store = AiProfileStore::default();
+        let profile = store.get("gpt4o").unwrap();
+        let persona = CompoundPersona::from_profile(profile);
+        let mut session = MimicSession::new(persona);
+        let mut cache = SignatureCache::new();
+        cache.warm_up(&store);
+        let analyzer = BehaviorAnalyzer::new();
+        let mut template_store = TemplateStore::new();
+
+        // First call might be System 1 or 2 depending on cache confidence
+        let _ = session.process(
+            "Help me write some code",
+            &mut cache,
+            &analyzer,
+            &mut template_store,
+        );
+        let _ = session.process("Now explain it", &mut cache, &analyzer, &mut template_store);
+
+        assert_eq!(session.conversation.len(), 2);
+        let total = session.system1_hits + session.system2_hits;
+        assert_eq!(total, 2);
+    }
+
+    #[derive(Debug)]
+    struct StubBackend {
+        drafts: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl GenerationBackend for StubBackend {
+        fn name(&self) -> &str {
+            "stub"
+        }
+
+        fn generate(
+            &self,
+            profile: &AiProfile,
+            _input: &str,
+            context: &GenerationContext,
+        ) -> String {
+            self.drafts
+                .lock()
+                .unwrap()
+                .push(context.template_draft.to_string());
+            format!(
+                "Certainly! {} here, answering from a stub backend.",
+                profile.display_name
+            )
+        }
+    }
+
+    #[test]
+    fn test_mimic_session_seeded_phrase_rotation() {
+        let store = AiProfileStore::default();
+        let persona = CompoundPersona::from_profile(store.get("claude").unwrap());
+        let count = persona.profile.signature_phrases.len();
+        assert!(count > 1);
+
+        // Unseeded sessions keep cycling by conversation length
+        let session = MimicSession::new(persona.clone());
+        assert_eq!(session.seed, None);
+        assert_eq!(session.signature_phrase_index(count), Some(0));
+        assert_eq!(session.signature_phrase_index(0), None);
+
+        // Seeded sessions replay the same pseudo-random order
+        let picks = |seed: u64| {
+            let session = MimicSession::with_seed(persona.clone(), seed);
+            (0..32)
+                .map(|_| session.signature_phrase_index(count).unwrap())
+                .collect::<Vec<_>>()
+        };
+        let first = picks(42);
+        assert_eq!(first, picks(42));
+        assert!(first.iter().all(|&i| i < count));
+        assert!(first.iter().any(|&i| i != first[0]));
+    }
+
+    #[test]
+    fn test_code_help_prefers_observed_language() {
+        let store = AiProfileStore::default();
+        let mut persona = CompoundPersona::from_profile(store.get("gpt4o").unwrap());
+        let session = MimicSession::new(persona.clone());
+        let body = session.generate_code_help_body(&persona.profile, "sorting", "");
+        assert!(body.contains("```\n// Implementation for sorting"));
+
+        persona
+            .signature
+            .language_distribution
+            .insert("python".to_string(), 1.0);
+        let session = MimicSession::new(persona.clone());
+        let body = session.generate_code_help_body(&persona.profile, "sorting", "");
+        assert!(body.contains("```python\n# Implementation for sorting"));
+        assert!(!body.contains("// "));
+    }
+
+    #[test]
+    fn test_stream_chunks() {
+        let text = "Hello there.  How are you?\nFine, thanks!";
+        let words = stream_chunks(text, StreamChunking::Words);
+        assert_eq!(words[0], "Hello ");
+        assert_eq!(words[1], "there.  ");
+        assert_eq!(words.concat(), text);
+
+        let sentences = stream_chunks(text, StreamChunking::Sentences);
+        assert_eq!(
+            sentences,
+            vec!["Hello there.  ", "How are you?\n", "Fine, thanks!"]
+        );
+        assert!(stream_chunks("", StreamChunking::Words).is_empty());
+    }
+
+    #[test]
+    fn test_mimic_session_uses_matching_cached_template() {
+        let store = AiProfileStore::default();
+        let mut persona = CompoundPersona::from_profile(store.get("claude").unwrap());
+        persona.convergence_score = 0.9;
+        let mut session = MimicSession::with_seed(persona, 5);
+        #[cfg(feature = "octo")]
+        {
+            session.octo_bridge = None;
+        }
+        let mut cache = SignatureCache::new();
+        let analyzer = BehaviorAnalyzer::new();
+        let mut template_store = TemplateStore::new();
+        let joke = "Why did the crab never share? It was shellfish.";
+        cache.add_template(ResponseTemplate::new(
+            "claude",
+            vec!["tell me a joke".to_string()],
+            joke,
+        ));
+
+        let (output, _) =
+            session.process("Tell me a joke", &mut cache, &analyzer, &mut template_store);
+        assert_eq!(output, joke);
+        assert_eq!(cache.templates()[0].times_used, 1);
+        assert_eq!(
+            session.conversation.last().unwrap().processed_by,
+            ProcessingSystem::System1
+        );
+
+        // Inputs below the confidence floor fall back to generic generation
+        session.persona.convergence_score = 0.9;
+        let (output, _) = session.process(
+            "Describe the water cycle",
+            &mut cache,
+            &analyzer,
+            &mut template_store,
+        );
+        assert_ne!(output, joke);
+        assert_eq!(cache.templates()[0].times_used, 1);
+    }
+
+    #[test]
+    fn test_mimic_session_process_streaming() {
+        let store = AiProfileStore::default();
+        let persona = CompoundPersona::from_profile(store.get("claude").unwrap());
+        let mut session = MimicSession::with_seed(persona, 3);
+        #[cfg(feature = "octo")]
+        {
+            session.octo_bridge = None;
+        }
+        session.stream_chunking = StreamChunking::Sentences;
+        let mut cache = SignatureCache::new();
+        let analyzer = BehaviorAnalyzer::new();
+        let mut template_store = TemplateStore::new();
+        let iterations = session.persona.compound_iterations;
+
+        let mut chunks: Vec<String> = Vec::new();
+        let (output, delta) = session.process_streaming(
+            "Explain how tides work",
+            &mut cache,
+            &analyzer,
+            &mut template_store,
+            &mut |chunk| chunks.push(chunk.to_string()),
+        );
+
+        assert!(!chunks.is_empty());
+        assert_eq!(chunks.concat(), output);
+        // Self-monitoring and bookkeeping still ran on the full output
+        assert_eq!(session.persona.compound_iterations, iterations + 1);
+        assert_eq!(session.conversation[0].output, output);
+        assert_eq!(
+            session.conversation[0].delta.as_ref().unwrap().adjustments,
+            delta.adjustments
+        );
+        assert_eq!(session.total_compounds, 1);
+    }
+
+    #[test]
+    fn test_mimic_session_custom_backend() {
+        let store = AiProfileStore::default();
+        let persona = CompoundPersona::from_profile(store.get("claude").unwrap());
+        let mut session = MimicSession::new(persona);
+        #[cfg(feature = "octo")]
+        {
+            session.octo_bridge = None;
+        }
+        let stub = Arc::new(StubBackend {
+            drafts: std::sync::Mutex::new(Vec::new()),
+        });
+        session.backend = stub.clone();
+        // Empty cache and zero convergence force System 2
+        let mut cache = SignatureCache::new();
+        let analyzer = BehaviorAnalyzer::new();
+        let mut template_store = TemplateStore::new();
+
+        let expected = "Certainly! Claude here, answering from a stub backend.";
+        let expected_delta = session.persona.clone().self_correct(expected, &analyzer);
+        let iterations = session.persona.compound_iterations;
+
+        let (output, delta) = session.process(
+            "Explain how tides work",
+            &mut cache,
+            &analyzer,
+            &mut template_store,
+        );
+
+        // Ethics allowed the response through unchanged
+        assert_eq!(output, expected);
+        assert_eq!(session.conversation[0].output, expected);
+        assert_eq!(
+            session.conversation[0].processed_by,
+            ProcessingSystem::System2
+        );
+        // Self-monitoring corrected the persona from the backend's output
+        assert_eq!(session.persona.compound_iterations, iterations + 1);
+        assert_eq!(delta.adjustments, expected_delta.adjustments);
+        // The template draft was still offered to the backend
+        let drafts = stub.drafts.lock().unwrap();
+        assert_eq!(drafts.len(), 1);
+        assert!(!drafts[0].is_empty());
+    }
+
+    #[test]
+    fn test_mimicry_engine_set_backend() {
+        let mut engine = MimicryEngine::new();
+        assert!(engine.status().contains("Generation backend: template"));
+        engine.set_backend(Arc::new(StubBackend {
+            drafts: std::sync::Mutex::new(Vec::new()),
+        }));
+        engine.mimic("gpt4o").unwrap();
+        assert_eq!(engine.session.as_ref().unwrap().backend.name(), "stub");
+        assert!(engine.status().contains("Generation backend: stub"));
+    }
+
+    #[test]
+    fn test_mimicry_engine_new() {
+        let engine = MimicryEngine::new();
+        assert!(engine.cache.size() > 0); // warmed up
+        assert!(engine.profile_store.get("gpt4o").is_some());
+        assert!(engine.session.is_none());
+    }
+
+    #[test]
+    fn test_mimicry_engine_mimic() {
+        let mut engine = MimicryEngine::new();
+        let result = engine.mimic("claude");
+        assert!(result.is_ok());
+        assert!(engine.session.is_some());
+
+        let err = engine.mimic("nonexistent");
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_mimicry_engine_mimic_registered_profile() {
+        let mut engine = MimicryEngine::new();
+        let json = serde_json::to_string(&AiProfile::new("my-model", "My Model")).unwrap();
+        let id = engine.profile_store.register_from_json(&json).unwrap();
+
+        assert!(engine.mimic(&id).is_ok());
+        assert_eq!(
+            engine.session.as_ref().unwrap().persona.profile.id,
+            "my-model"
+        );
+    }
+
+    #[test]
+    fn test_mimicry_engine_blend() {
+        let mut engine = MimicryEngine::new();
+        let result = engine.blend(&["gpt4o".to_string(), "claude".to_string()], &[0.6, 0.4]);
+        assert!(result.is_ok());
+        assert!(engine.session.is_some());
+    }
+
+    #[test]
+    fn test_mimicry_engine_observe() {
+        let mut engine = MimicryEngine::new();
+        let result = engine.observe(
+            "gpt4o",
+            "Certainly! Here's how you can do that:\n1. First step\n2. Second step",
+        );
+        assert!(result.contains("Observed"));
+        assert!(result.contains("Cached: yes"));
+        assert!(result.contains("Training samples: 1"));
+    }
+
+    #[test]
+    fn test_mimicry_engine_observe_stores_training_data() {
+        let mut engine = MimicryEngine::new();
+        engine.observe("gpt4o", "Response one");
+        engine.observe("gpt4o", "Response two");
+        engine.observe("claude", "A different response");
+
+        assert_eq!(engine.evolution_tracker.training_data.count("gpt4o"), 2);
+        assert_eq!(engine.evolution_tracker.training_data.count("claude"), 1);
+    }
+
+    #[test]
+    fn test_mimicry_engine_observe_accumulates_patterns() {
+        // This test verifies that /observe accumulates patterns from ALL observations,
+        // not just the latest one (the bug we fixed).
+        let mut engine = MimicryEngine::new();
+
+        // First observation with "I'd be happy to help" pattern
+        engine.observe("test_model", "I'd be happy to help you with that!");
+
+        // Second observation with "Let me think" pattern
+        engine.observe("test_model", "Let me think about this carefully.");
+
+        // Third observation with "That's a great question" pattern
+        engine.observe("test_model", "That's a great question! Here's my answer.");
+
+        // The signature should have samples_analyzed = 3 (all observations)
+        let sig = engine.analyzer.get_signature("test_model").unwrap();
+        assert_eq!(
+            sig.samples_analyzed, 3,
+            "Signature should be built from all 3 observations, not just the last one"
+        );
+
+        // The average response length should be computed from all 3 responses
+        let expected_avg =
+            ("I'd be happy to help you with that!".len() +
+             "Let me think about this carefully.".len() +
+             "That's a great question! Here's my answer.".len()) as f64 / 3.0;
+        assert!(
+            (sig.avg_response_length - expected_avg).abs() < 0.1,
+            "Average length should be computed from all observations"
+        );
+
+        // Training count should be 3
+        assert_eq!(engine.evolution_tracker.training_data.count("test_model"), 3);
+    }
+
+    #[test]
+    fn test_mimicry_engine_evolve() {
+        let mut engine = MimicryEngine::new();
+        let _ = engine.mimic("gpt4o");
+        let result = engine.evolve(5);
+        assert!(result.is_ok());
+        let report = result.unwrap();
+        assert!(report.contains("EVOLUTION REPORT"));
+        assert!(report.contains("Iterations: 5"));
+        assert!(report.contains("Phase:"));
+        assert!(report.contains("Drift events:"));
+    }
+
+    #[test]
+    fn test_mimicry_engine_evolve_with_progress() {
+        let mut engine = MimicryEngine::new();
+        let _ = engine.mimic("gpt4o");
+
+        let mut calls = Vec::new();
+        let result = engine.evolve_with_progress(100, 10, |i, conv| calls.push((i, conv)));
+        assert!(result.is_ok());
+        assert_eq!(calls.len(), 10);
+        assert!(calls.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(calls.last().unwrap().0, 100);
+    }
+
+    #[test]
+    fn test_mimicry_engine_evolve_cancel() {
+        let mut engine = MimicryEngine::new();
+        let _ = engine.mimic("gpt4o");
+
+        let cancel = engine.cancel_handle();
+        let report = engine
+            .evolve_with_progress(1000, 5, |i, _| {
+                if i == 20 {
+                    cancel.store(true, Ordering::SeqCst);
+                }
+            })
+            .unwrap();
+        assert!(report.contains("cancelled at 20/1000"));
+        assert!(report.contains("Iterations: 20"));
+
+        // Flag is cleared so the next run proceeds normally
+        assert!(engine.evolve(3).unwrap().contains("Iterations: 3"));
+    }
+
+    #[test]
+    fn test_mimicry_engine_shadow_fork_and_promote() {
+        let mut engine = MimicryEngine::new();
+        let _ = engine.mimic("gpt4o");
+        assert!(engine.fork_session("trial").is_ok());
+        assert!(engine.fork_session("trial").is_err());
+
+        let live_before = engine.session.as_ref().unwrap().persona.convergence_score;
+        let live_evolutions = engine.evolution_tracker.total_evolutions;
+        let report = engine.evolve_shadow("trial", 20).unwrap();
+        assert!(report.contains("[shadow: trial]"));
+
+        // Live persona is untouched by shadow evolution
+        let session = engine.session.as_ref().unwrap();
+        assert_eq!(session.persona.convergence_score, live_before);
+        assert_eq!(engine.evolution_tracker.total_evolutions, live_evolutions);
+
+        let shadow_conv = engine.shadow_sessions["trial"]
+            .session
+            .persona
+            .convergence_score;
+        assert_ne!(shadow_conv, live_before);
+        assert!(engine.shadow_status().contains("trial"));
+
+        assert!(engine.promote("trial").is_ok());
+        let session = engine.session.as_ref().unwrap();
+        assert_eq!(session.persona.convergence_score, shadow_conv);
+        assert!(engine.shadow_sessions.is_empty());
+        assert!(engine.promote("trial").is_err());
+    }
+
+    #[test]
+    fn test_promote_keeps_threshold_callback() {
+        use std::sync::{Arc, Mutex};
+
+        let mut engine = MimicryEngine::new();
+        let _ = engine.mimic("gpt4o");
+        let crossed = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&crossed);
+        engine
+            .evolution_tracker
+            .milestones
+            .set_on_threshold(move |t| sink.lock().unwrap().push(t));
+        engine.fork_session("trial").unwrap();
+        engine.promote("trial").unwrap();
+
+        let milestones = &mut engine.evolution_tracker.milestones;
+        assert!(milestones.on_threshold.is_some());
+        milestones.check(1.0, 1);
+        assert_eq!(crossed.lock().unwrap().len(), milestones.thresholds.len());
+    }
+
+    #[test]
+    fn test_mimicry_engine_keyed_sessions() {
+        let mut engine = MimicryEngine::new();
+        assert!(engine.chat("hi", None).contains("No active session"));
+
+        // With nothing active, keyed sessions default to RustyWorm
+        assert_eq!(engine.session_for("a").persona.profile.id, "rustyworm");
+        assert!(engine.start_session("b", "gpt4o").is_ok());
+        assert!(engine.start_session("c", "nope").is_err());
+
+        let _ = engine.mimic("claude");
+        let _ = engine.chat("hello", Some("b"));
+        let _ = engine.chat("hello again", Some("b"));
+        let _ = engine.chat("hello", None);
+        // Unknown keys are created from the active persona
+        let _ = engine.chat("hello", Some("d"));
+
+        assert_eq!(engine.sessions["b"].conversation.len(), 2);
+        assert_eq!(engine.sessions["b"].persona.profile.id, "gpt4o");
+        assert_eq!(engine.sessions["d"].persona.profile.id, "claude");
+        assert_eq!(engine.sessions["d"].conversation.len(), 1);
+        assert_eq!(engine.sessions["a"].conversation.len(), 0);
+        assert_eq!(engine.session.as_ref().unwrap().conversation.len(), 1);
+
+        let status = engine.status();
+        assert!(status.contains("Live sessions: 4"));
+        assert!(status.contains("(active)"));
+        assert!(status.contains("GPT-4o (2 turns"));
+
+        let keyed = engine.execute(MimicCommand::Chat("hi".to_string(), Some("b".to_string())));
+        assert!(!keyed.contains("No active session"));
+        assert_eq!(engine.sessions["b"].conversation.len(), 3);
+
+        assert!(engine.close_session("a").is_some());
+        assert!(engine.close_session("a").is_none());
+        assert!(engine.status().contains("Live sessions: 3"));
+    }
+
+    #[test]
+    fn test_mimic_session_undo_stack() {
+        let store = AiProfileStore::default();
+        let mut session =
+            MimicSession::new(CompoundPersona::from_profile(store.get("claude").unwrap()));
+        assert!(session.undo().is_err());
+        session.undo_depth = 2;
+
+        let analyzer = BehaviorAnalyzer::new();
+        for i in 0..3 {
+            session.push_undo();
+            session
+                .persona
+                .self_correct(&format!("output {}", i), &analyzer);
+        }
+        // Only the two most recent states are kept
+        assert_eq!(session.undo_len(), 2);
+        assert!(session.undo().is_ok());
+        assert_eq!(session.persona.compound_iterations, 2);
+        assert_eq!(session.persona.evolution_history.len(), 3);
+        assert!(session.undo().is_ok());
+        assert_eq!(session.persona.compound_iterations, 1);
+        assert!(session.undo().is_err());
+
+        session.undo_depth = 0;
+        session.push_undo();
+        assert_eq!(session.undo_len(), 0);
+    }
+
+    #[test]
+    fn test_mimicry_engine_undo_evolve() {
+        let mut engine = MimicryEngine::new();
+        assert!(engine.undo().is_err());
+        let _ = engine.mimic("gpt4o");
+        assert!(engine.undo().unwrap_err().contains("Nothing to undo"));
+
+        let before = engine.session.as_ref().unwrap().persona.clone();
+        let _ = engine.evolve(50);
+        let session = engine.session.as_ref().unwrap();
+        assert_ne!(
+            session.persona.compound_iterations,
+            before.compound_iterations
+        );
+
+        let msg = engine.execute(engine.parse_command("/undo"));
+        assert!(msg.contains("Undid last change"), "{}", msg);
+        let session = engine.session.as_ref().unwrap();
+        assert_eq!(session.persona.convergence_score, before.convergence_score);
+        assert_eq!(
+            session.persona.compound_iterations,
+            before.compound_iterations
+        );
+        assert_eq!(session.persona.evolution_history, before.evolution_history);
+    }
+
+    #[test]
+    fn test_mimicry_engine_replay_is_deterministic() {
+        let transcript = [
+            "/mimic claude",
+            "Explain how tides work",
+            "/evolve 15",
+            "Write a function to sort a list",
+            "/save replayed",
+            "/checkpoint",
+            "/status",
+        ];
+        let first = MimicryEngine::replay_seeded(&transcript, 7);
+        assert_eq!(first.len(), transcript.len());
+        assert_eq!(first, MimicryEngine::replay_seeded(&transcript, 7));
+
+        // A /seed line in the transcript works the same way
+        let mut seeded = vec!["/seed 7"];
+        seeded.extend(transcript);
+        assert_eq!(MimicryEngine::replay(&seeded)[1..], first[..]);
+
+        // Disk side effects are disabled
+        assert!(first[4].contains("Disk save failed"));
+        assert!(first[5].contains("Checkpoint failed"));
+        assert!(first[6].contains("Persistence: disabled"));
+    }
+
+    #[test]
+    fn test_escape_markdown() {
+        assert_eq!(escape_markdown("plain text."), "plain text.");
+        assert_eq!(
+            escape_markdown("use *this* or _that_"),
+            "use \\*this\\* or \\_that\\_"
+        );
+        assert_eq!(escape_markdown("# not a heading"), "\\# not a heading");
+        assert_eq!(escape_markdown("- item"), "\\- item");
+        assert_eq!(escape_markdown("1. first"), "1\\. first");
+        assert_eq!(escape_markdown("a - b, 2.5"), "a - b, 2.5");
+        assert_eq!(escape_markdown("<b>`x`</b>"), "\\<b\\>\\`x\\`\\</b\\>");
+    }
+
+    #[test]
+    fn test_mimic_session_to_markdown() {
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        assert!(engine.transcript(None).is_err());
+        let _ = engine.mimic("claude");
+        let _ = engine.execute(MimicCommand::Chat(
+            "# Why *is* the sky blue?".to_string(),
+            None,
+        ));
+        let _ = engine.execute(MimicCommand::Chat("- thanks".to_string(), None));
+
+        let markdown = engine.transcript(None).unwrap();
+        assert!(markdown.starts_with("# Transcript: Claude\n"));
+        assert!(markdown.contains("- **Turns:** 2"));
+        assert!(markdown.contains("## Turn 1 · `System"));
+        assert!(markdown.contains("## Turn 2 · "));
+        assert!(markdown.contains("> \\# Why \\*is\\* the sky blue?"));
+        assert!(markdown.contains("> \\- thanks"));
+        let session = engine.session.as_ref().unwrap();
+        assert!(markdown.contains(session.conversation[0].output.trim_end()));
+
+        // Disk-free engines refuse to write files
+        assert!(engine.transcript(Some(Path::new("t.md"))).is_err());
+    }
+
+    #[test]
+    fn test_mimicry_engine_transcript_to_file() {
+        let mut engine = MimicryEngine::new();
+        let _ = engine.mimic("gpt4o");
+        let _ = engine.execute(MimicCommand::Chat("hello".to_string(), None));
+        let path =
+            std::env::temp_dir().join(format!("rustyworm-transcript-{}.md", std::process::id()));
+        let cmd = engine.parse_command(&format!("/transcript {}", path.display()));
+        assert!(matches!(&cmd, MimicCommand::Transcript(Some(p)) if p == &path));
+        assert!(engine.execute(cmd).contains("Transcript written to"));
+        let written = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(written, engine.transcript(None).unwrap());
+        let _ = std::fs::remove_file(&path);
+
+        assert!(matches!(
+            engine.parse_command("/transcript"),
+            MimicCommand::Transcript(None)
+        ));
+    }
+
+    #[test]
+    fn test_mimicry_engine_export_history() {
+        let mut engine = MimicryEngine::new();
+        let path =
+            std::env::temp_dir().join(format!("rustyworm-history-{}.csv", std::process::id()));
+        assert!(engine.export_history(&path).is_err());
+
+        let _ = engine.mimic("gpt4o");
+        let _ = engine.evolve(5);
+        let cmd = engine.parse_command(&format!("/export-history {}", path.display()));
+        assert!(matches!(&cmd, MimicCommand::ExportHistory(p) if p == &path));
+        assert!(engine.execute(cmd).contains("Exported"));
+        let written = std::fs::read_to_string(&path).unwrap();
+        let history = &engine.session.as_ref().unwrap().persona.evolution_history;
+        assert_eq!(written.lines().count(), history.len() + 1);
+        assert!(written.starts_with("iteration,convergence,phase\n0,"));
+        let _ = std::fs::remove_file(&path);
+
+        assert!(matches!(
+            engine.parse_command("/export-history"),
+            MimicCommand::Help
+        ));
+    }
+
+    #[test]
+    fn test_mimicry_engine_evolve_dry_run_changes_nothing() {
+        let config = PersistenceConfig {
+            base_dir: std::env::temp_dir()
+                .join(format!("rustyworm-engine-dry-run-{}", std::process::id())),
+            auto_save_interval: 1,
+            ..PersistenceConfig::default()
+        };
+        let mut engine = MimicryEngine::with_persistence(config.clone());
+        assert!(engine.evolve_dry_run(5).is_err());
+        let _ = engine.mimic("claude");
+
+        let persona_before =
+            serde_json::to_string(&engine.session.as_ref().unwrap().persona).unwrap();
+        let templates_before = serde_json::to_string(&engine.template_store).unwrap();
+        let cache_before = engine.cache.stats().to_string();
+        let evolutions_before = engine.evolution_tracker.total_evolutions;
+        let personas_on_disk = || {
+            std::fs::read_dir(config.personas_path())
+                .map(|dir| dir.count())
+                .unwrap_or(0)
+        };
+        let files_before = personas_on_disk();
+
+        let report = engine.evolve_dry_run(40).unwrap();
+        assert_eq!(report.iterations, 40);
+
+        let session = engine.session.as_ref().unwrap();
+        assert_eq!(
+            serde_json::to_string(&session.persona).unwrap(),
+            persona_before
+        );
+        assert_eq!(session.undo_len(), 0);
+        assert_eq!(
+            serde_json::to_string(&engine.template_store).unwrap(),
+            templates_before
+        );
+        assert_eq!(engine.cache.stats().to_string(), cache_before);
+        assert_eq!(engine.evolution_tracker.total_evolutions, evolutions_before);
+        assert_eq!(personas_on_disk(), files_before);
+
+        // The projection matches what the real run then does
+        let _ = engine.evolve(40);
+        let session = engine.session.as_ref().unwrap();
+        assert_eq!(session.persona.convergence_score, report.ending_convergence);
+
+        let msg = engine.execute(engine.parse_command("/evolve 20 --dry"));
+        assert!(msg.contains("DRY RUN"), "{}", msg);
+        let _ = std::fs::remove_dir_all(&config.base_dir);
+    }
+
+    #[test]
+    fn test_mimicry_engine_evolve_auto_corrects_drift() {
+        let config = PersistenceConfig {
+            base_dir: std::env::temp_dir()
+                .join(format!("rustyworm-engine-drift-{}", std::process::id())),
+            ..PersistenceConfig::default()
+        };
+        let mut engine = MimicryEngine::with_persistence(config.clone());
+        let _ = engine.mimic("claude");
+        // Any slope counts as a decline, so drift is confirmed every step
+        engine.evolution_tracker.drift_detector.drift_threshold = -1.0;
+        engine.evolution_tracker.drift_detector.patience = 2;
+        engine.evolution_tracker.auto_correct_on_drift = true;
+
+        // No milestone snapshot on disk yet: reset and recommend re-observation
+        let mut no_save = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        let _ = no_save.mimic("claude");
+        no_save.evolution_tracker = engine.evolution_tracker.clone();
+        let report = no_save.evolve_dry_run(5).unwrap();
+        assert_eq!(report.drift_corrections, 0);
+        assert!(report.recommendation.unwrap().contains("re-observe"));
+
+        let msg = engine.evolve(5).unwrap();
+        assert!(msg.contains("Drift corrections: "), "{}", msg);
+        let tracker = &engine.evolution_tracker;
+        assert!(tracker.total_corrections > 0);
+        assert!(tracker
+            .milestones
+            .events
+            .iter()
+            .any(|e| e.milestone_type == crate::mimicry::evolution::MilestoneType::DriftCorrected));
+        let _ = std::fs::remove_dir_all(&config.base_dir);
+    }
+
+    #[test]
+    fn test_drift_reverts_to_newest_checkpoint() {
+        let config = PersistenceConfig {
+            base_dir: std::env::temp_dir().join(format!(
+                "rustyworm-engine-stale-auto-{}",
+                std::process::id()
+            )),
+            ..PersistenceConfig::default()
+        };
+        let mut engine = MimicryEngine::with_persistence(config.clone());
+        let _ = engine.mimic("claude");
+        let mut milestone = engine.session.as_ref().unwrap().persona.snapshot();
+        milestone.compound_iterations = 1;
+        engine
+            .persistence
+            .save_persona("claude-auto", &milestone)
+            .unwrap();
+
+        let persona = &mut engine.session.as_mut().unwrap().persona;
+        persona.compound_iterations = 10;
+        persona.profile.display_name = "Manual".to_string();
+        engine.save(Some("manual")).unwrap();
+        assert!(engine.persistence.load_persona("claude-auto").is_ok());
+
+        // The explicit save is stamped later, so drift reverts to it
+        engine.evolution_tracker.drift_detector.drift_threshold = -1.0;
+        engine.evolution_tracker.drift_detector.patience = 2;
+        engine.evolution_tracker.auto_correct_on_drift = true;
+        engine.persistence.config.auto_save_enabled = false;
+        let msg = engine.evolve(5).unwrap();
+        assert!(!msg.contains("Drift corrections: 0"), "{}", msg);
+        let session = engine.session.as_ref().unwrap();
+        assert_eq!(session.persona.profile.display_name, "Manual");
+
+        // A later milestone takes precedence again
+        milestone.compound_iterations = 50;
+        milestone.profile.display_name = "Milestone".to_string();
+        engine
+            .persistence
+            .save_persona("claude-auto", &milestone)
+            .unwrap();
+        let newest = drift_checkpoint(&mut engine.persistence, "claude", "claude-auto").unwrap();
+        assert_eq!(newest.profile.display_name, "Milestone");
+        let _ = std::fs::remove_dir_all(&config.base_dir);
+    }
+
+    #[test]
+    fn test_mimicry_engine_reset() {
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        assert!(engine.reset().unwrap_err().contains("No active session"));
+
+        let _ = engine.mimic("gpt4o");
+        let _ = engine.observe("gpt4o", "Certainly! Here's a detailed answer for you.");
+        let _ = engine.evolve(20);
+        let samples = engine.evolution_tracker.training_data.count("gpt4o");
+        assert!(samples > 0);
+
+        let msg = engine.execute(engine.parse_command("/reset"));
+        assert!(msg.contains("Reset GPT-4o"), "{}", msg);
+        let persona = &engine.session.as_ref().unwrap().persona;
+        assert_eq!(persona.convergence_score, 0.0);
+        assert_eq!(persona.compound_iterations, 0);
+        assert_eq!(persona.evolution_history, vec![0.0]);
+        assert!(persona.signature.patterns.is_empty());
+        let base = engine.profile_store.get("gpt4o").unwrap();
+        assert_eq!(
+            serde_json::to_string(&persona.profile).unwrap(),
+            serde_json::to_string(base).unwrap()
+        );
+        assert_eq!(
+            engine.evolution_tracker.training_data.count("gpt4o"),
+            samples
+        );
+        assert!(engine.train(5).is_ok());
+
+        // The reset itself can be undone
+        let _ = engine.reset();
+        assert!(engine.undo().is_ok());
+        assert!(engine.session.as_ref().unwrap().persona.compound_iterations > 0);
+    }
+
+    #[test]
+    fn test_mimicry_engine_compare_personas() {
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        let _ = engine.mimic("claude");
+        let _ = engine.evolve(30);
+        let _ = engine.save(Some("claude-early"));
+        assert!(engine.branch("alt").is_ok());
+        let _ = engine.evolve(10);
+
+        let cmd = engine.parse_command("/compare-personas claude claude-early");
+        assert!(
+            matches!(&cmd, MimicCommand::ComparePersonas(a, b) if a == "claude" && b == "claude-early")
+        );
+        let output = engine.execute(cmd);
+        assert!(output.contains("=== COMPARISON: claude (live) vs claude-early (saved) ==="));
+        assert!(
+            output.contains("claude (live): 41 data points"),
+            "{}",
+            output
+        );
+        // The snapshot has one point, so the summary calls out the short line
+        assert!(output.contains("claude-early (saved): 1 data point,"));
+        assert!(output.contains("Note: claude-early (saved) fills 1 of 41 columns"));
+
+        let output = engine.compare_personas("alt", "claude").unwrap();
+        assert!(output.contains("alt (branch): 31 data points"));
+        assert!(!output.contains("Note:"));
+
+        assert!(engine.compare_personas("claude", "nobody").is_err());
+        assert!(matches!(
+            engine.parse_command("/compare-personas claude"),
+            MimicCommand::Help
+        ));
+    }
+
+    #[test]
+    fn test_mimicry_engine_branch_and_switch() {
+        let mut engine = MimicryEngine::new();
+        let err = engine.branch("alt").unwrap_err();
+        assert!(err.contains("No active session"));
+
+        let _ = engine.mimic("claude");
+        let _ = engine.execute(MimicCommand::Chat("hello there".to_string(), None));
+        let msg = engine.branch("alt").unwrap();
+        assert!(msg.contains("turns).\nUse /switch alt"), "{}", msg);
+        assert!(engine.branch("alt").is_err());
+        assert!(engine.branch(MAIN_BRANCH).is_err());
+        assert!(engine.switch_branch("missing").is_err());
+
+        // Evolving the main line leaves the branch untouched
+        let branched = engine.branches["alt"].persona.convergence_score;
+        let _ = engine.evolve(20);
+        let _ = engine.execute(MimicCommand::Chat("another message".to_string(), None));
+        let main_conv = engine.session.as_ref().unwrap().persona.convergence_score;
+        assert_ne!(main_conv, branched);
+        assert_eq!(engine.branches["alt"].persona.convergence_score, branched);
+        assert_eq!(engine.branches["alt"].conversation.len(), 1);
+
+        let msg = engine.switch_branch("alt").unwrap();
+        assert!(msg.contains("kept as 'main'"));
+        assert_eq!(engine.current_branch(), "alt");
+        let session = engine.session.as_ref().unwrap();
+        assert_eq!(session.persona.convergence_score, branched);
+        assert_eq!(session.conversation.len(), 1);
+        assert!(engine.status().contains("Branch: alt (stored: main)"));
+
+        // And back again: the main line kept its own progress
+        assert!(engine.switch_branch(MAIN_BRANCH).is_ok());
+        assert_eq!(engine.current_branch(), MAIN_BRANCH);
+        let session = engine.session.as_ref().unwrap();
+        assert_eq!(session.persona.convergence_score, main_conv);
+        assert_eq!(session.conversation.len(), 2);
+        assert!(engine.branches.contains_key("alt"));
+    }
+
+    #[test]
+    fn test_mimicry_engine_evolve_against_targets() {
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        let _ = engine.mimic("claude");
+        let gpt = engine
+            .analyzer
+            .build_signature("gpt4o", &["Great question! Here's the answer.".to_string()]);
+        let claude = engine.session.as_ref().unwrap().persona.signature.clone();
+        engine.evolution_tracker.add_target("gpt4o", gpt, 1.0);
+        engine.evolution_tracker.add_target("claude", claude, 1.0);
+        engine.evolution_tracker.target_combination =
+            crate::mimicry::evolution::TargetCombination::Min;
+
+        let _ = engine.evolve(4);
+        let tracker = &engine.evolution_tracker;
+        assert!(tracker.targets.iter().all(|t| t.history.len() == 4));
+        let lowest = tracker
+            .targets
+            .iter()
+            .map(|t| *t.history.last().unwrap())
+            .fold(f64::INFINITY, f64::min);
+        let session = engine.session.as_ref().unwrap();
+        assert_eq!(session.persona.convergence_score, lowest);
+        assert_eq!(session.persona.evolution_history.last(), Some(&lowest));
+
+        // Chat turns score against the targets too, and undo rewinds them
+        let _ = engine.chat("Tell me about traits", None);
+        let tracker = &engine.evolution_tracker;
+        assert!(tracker.targets.iter().all(|t| t.history.len() == 5));
+        let lowest = tracker
+            .targets
+            .iter()
+            .map(|t| *t.history.last().unwrap())
+            .fold(f64::INFINITY, f64::min);
+        let session = engine.session.as_ref().unwrap();
+        assert_eq!(session.persona.convergence_score, lowest);
+        assert_eq!(session.persona.evolution_history.last(), Some(&lowest));
+
+        assert!(engine.undo().is_ok());
+        assert!(engine
+            .evolution_tracker
+            .targets
+            .iter()
+            .all(|t| t.history.is_empty()));
+    }
+
+    #[test]
+    fn test_system2_answers_compile_into_templates() {
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        let _ = engine.mimic("claude");
+        engine.cache.clear();
+        let _ = engine.chat("Tell me about lifetimes", None);
+        let session = engine.session.as_ref().unwrap();
+        assert_eq!(
+            session.conversation[0].processed_by,
+            ProcessingSystem::System2
+        );
+        let template = engine
+            .cache
+            .best_template_for("claude", "Tell me about lifetimes")
+            .expect("System 2 answer compiled into a template");
+        assert!(template.skeleton.contains("{topic}"));
+    }
+
+    #[test]
+    fn test_chat_feedback_counts_each_turns_category() {
+        use crate::mimicry::templates::TemplateCategory;
+
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        let _ = engine.mimic("claude");
+        let _ = engine.chat("Hello!", None);
+        let _ = engine.chat("Please write code for a parser", None);
+        let _ = engine.chat("Please write code for a lexer", None);
+
+        let counts = &engine
+            .template_store
+            .get("claude")
+            .unwrap()
+            .category_feedback;
+        assert_eq!(counts.get(&TemplateCategory::Greeting), Some(&1));
+        assert_eq!(counts.get(&TemplateCategory::CodeHelp), Some(&2));
+        let systems: Vec<_> = engine
+            .session
+            .as_ref()
+            .unwrap()
+            .conversation
+            .iter()
+            .map(|turn| turn.processed_by.clone())
+            .collect();
+        assert!(
+            systems.contains(&ProcessingSystem::System2),
+            "{:?}",
+            systems
+        );
+    }
+
+    #[test]
+    fn test_mimicry_engine_branches_keep_evolution_state() {
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        let _ = engine.mimic("claude");
+        let _ = engine.evolve(3);
+        assert!(engine.branch("alt").is_ok());
+        let _ = engine.evolve(7);
+        let main_events = engine.evolution_tracker.milestones.events.len();
+        assert_eq!(engine.evolution_tracker.total_evolutions, 10);
+
+        assert!(engine.switch_branch("alt").is_ok());
+        assert_eq!(engine.evolution_tracker.total_evolutions, 3);
+        let _ = engine.evolve(1);
+        assert_eq!(engine.evolution_tracker.total_evolutions, 4);
+
+        assert!(engine.switch_branch(MAIN_BRANCH).is_ok());
+        assert_eq!(engine.evolution_tracker.total_evolutions, 10);
+        assert_eq!(
+            engine.evolution_tracker.milestones.events.len(),
+            main_events
+        );
+        assert_eq!(
+            engine.branches["alt"]
+                .evolution_state
+                .as_ref()
+                .unwrap()
+                .total_evolutions,
+            4
+        );
+
+        // Undo rewinds the bookkeeping along with the persona
+        let _ = engine.evolve(5);
+        assert!(engine.undo().is_ok());
+        assert_eq!(engine.evolution_tracker.total_evolutions, 10);
+        assert_eq!(
+            engine.evolution_tracker.milestones.events.len(),
+            main_events
+        );
+    }
+
+    #[test]
+    fn test_reasoning_step_depth() {
+        let store = AiProfileStore::default();
+        let session = MimicSession::new(CompoundPersona::from_profile(store.get("o1").unwrap()));
+        let count = |text: &str| text.matches("**Step ").count();
+
+        // Unset depth keeps the original four-step o1 template
+        let default = session.generate_reasoning_body(store.get("o1").unwrap(), "tides");
+        assert_eq!(count(&default), 4);
+        assert!(default.contains("**Step 2 - Gathering relevant information:**"));
+        assert!(default.ends_with("Therefore, the answer involves..."));
+
+        let mut deep = store.get("o1").unwrap().clone();
+        deep.step_depth = Some(9);
+        let text = session.generate_reasoning_body(&deep, "tides");
+        assert_eq!(count(&text), 9);
+        assert!(text.contains("**Step 9 - Drawing conclusions:**"));
+        assert!(text.contains("Testing assumptions"));
+
+        deep.step_depth = Some(200);
+        let text = session.generate_reasoning_body(&deep, "tides");
+        assert_eq!(count(&text), MAX_STEP_DEPTH as usize);
+
+        deep.step_depth = Some(1);
+        let text = session.generate_reasoning_body(&deep, "tides");
+        assert_eq!(count(&text), 1);
+
+        deep.step_depth = Some(0);
+        let text = session.generate_reasoning_body(&deep, "tides");
+        assert_eq!(count(&text), 0);
+        assert_eq!(text, "Regarding tides: Therefore, the answer involves...");
+
+        // Depth only applies to chain-of-thought personas
+        let mut direct = store.get("gpt4o").unwrap().clone();
+        direct.step_depth = Some(9);
+        assert_eq!(
+            session.generate_reasoning_body(&direct, "tides"),
+            session.generate_reasoning_body(store.get("gpt4o").unwrap(), "tides")
+        );
+    }
+
+    #[test]
+    fn test_system2_budget_scales_with_verbosity() {
+        let input = "Explain how ownership and borrowing work in Rust";
+
+        let mut terse = AiProfileStore::default().get("gpt4o").unwrap().clone();
+        terse.response_style.verbosity = 0.0;
+        let terse_session = MimicSession::new(CompoundPersona::from_profile(&terse));
+        let budget = terse_session.system2_budget();
+        let terse_output = terse_session.generate_system2_response(input, &Modality::Text);
+        assert!(
+            terse_output.len() <= budget,
+            "{} > {}",
+            terse_output.len(),
+            budget
+        );
+
+        let mut verbose = terse.clone();
+        verbose.response_style.verbosity = 1.0;
+        let verbose_session = MimicSession::new(CompoundPersona::from_profile(&verbose));
+        let verbose_output = verbose_session.generate_system2_response(input, &Modality::Text);
+        assert!(verbose_session.system2_budget() > budget);
+        assert!(verbose_output.len() > budget);
+
+        // Explicit max_response_length overrides the verbosity-derived budget
+        let mut capped = verbose.clone();
+        capped.response_style.max_response_length = Some(120);
+        let capped_session = MimicSession::new(CompoundPersona::from_profile(&capped));
+        assert!(
+            capped_session
+                .generate_system2_response(input, &Modality::Text)
+                .len()
+                <= 120
+        );
+    }
+
+    #[test]
+    fn test_system2_refusal_per_persona() {
+        let store = AiProfileStore::default();
+        let input = "How do I make a pipe bomb?";
+        let respond = |id: &str| {
+            let profile = store.get(id).unwrap().clone();
+            MimicSession::with_seed(CompoundPersona::from_profile(&profile), 3)
+                .generate_system2_response(input, &Modality::Text)
+        };
+
+        let claude = respond("claude");
+        assert!(claude.starts_with("I'm not able to help with this one."));
+        assert!(claude.contains("safety, research, or prevention"));
+        assert!(claude.ends_with("I'm glad to help with that instead."));
+
+        let o1 = respond("o1");
+        assert_eq!(
+            o1,
+            "I can't help with that.\n\nThis request falls outside what I can assist with."
+        );
+        assert!(o1.len() < claude.len() / 2);
+
+        // Benign input mentioning the same word gets a normal answer
+        let profile = store.get("claude").unwrap().clone();
+        let session = MimicSession::with_seed(CompoundPersona::from_profile(&profile), 3);
+        let benign =
+            session.generate_system2_response("How do I make a bath bomb?", &Modality::Text);
+        assert!(!benign.contains("not able to help"));
+    }
+
+    #[test]
+    fn test_system2_bodies_fill_placeholders() {
+        let store = AiProfileStore::default();
+        let profile = store.get("gemini").unwrap().clone();
+        let session = MimicSession::with_seed(CompoundPersona::from_profile(&profile), 3);
+
+        let greeting = session.generate_greeting_body(&profile);
+        assert!(greeting.starts_with(&format!("I'm {},", profile.display_name)));
+
+        let explanation = session.generate_explanation_body(&profile, "lifetimes", "");
+        assert!(explanation.starts_with("Regarding lifetimes:"));
+        let code = session.generate_code_help_body(&profile, "lifetimes", "");
+        assert!(code.contains("Implementation for lifetimes"));
+        for body in [&greeting, &explanation, &code] {
+            assert!(!body.contains('{'), "unfilled placeholder in {}", body);
+        }
+    }
+
+    #[test]
+    fn test_summary_body_prefers_tables() {
+        let store = AiProfileStore::default();
+        let mut profile = store.get("claude").unwrap().clone();
+        let session = MimicSession::with_seed(CompoundPersona::from_profile(&profile), 3);
+
+        let listed = session.generate_summary_body(&profile, "");
+        assert!(listed.contains("• Main idea and context"));
+        assert!(!listed.contains('|'));
+
+        profile.response_style.prefers_tables = true;
+        let tabled = session.generate_summary_body(&profile, "");
+        assert!(tabled.starts_with("**Summary:**\n\n| Key point | Details |\n| --- | --- |"));
+        assert!(tabled.contains("| Main idea and context | What the content is about |"));
+        assert_eq!(tabled.lines().filter(|l| l.starts_with('|')).count(), 5);
+    }
+
+    #[test]
+    fn test_greeting_and_closing_localized() {
+        use crate::mimicry::templates::TemplateCategory;
+        let store = AiProfileStore::default();
+        for id in ["claude", "gpt4o", "gemini", "llama", "o1", "rustyworm"] {
+            let english = store.get(id).unwrap().clone();
+            let mut french = english.clone();
+            french.language = "fr".to_string();
+            let session = MimicSession::with_seed(CompoundPersona::from_profile(&french), 3);
+
+            let greeting = session.generate_greeting_body(&french);
+            assert!(greeting.starts_with("Je suis"), "{}: {}", id, greeting);
+            assert!(greeting.contains(&english.display_name) || id == "rustyworm");
+
+            for category in [
+                TemplateCategory::CodeHelp,
+                TemplateCategory::Reasoning,
+                TemplateCategory::Explanation,
+                TemplateCategory::Refusal,
+            ] {
+                let en = session.select_persona_closing(&english, &category);
+                let fr = session.select_persona_closing(&french, &category);
+                if let (Some(en), Some(fr)) = (en, fr) {
+                    assert_ne!(en, fr, "{} closing for {:?} is untranslated", id, category);
+                }
+            }
+        }
+
+        // Languages without a table keep the English phrasing
+        let mut german = store.get("llama").unwrap().clone();
+        german.language = "de".to_string();
+        let session = MimicSession::with_seed(CompoundPersona::from_profile(&german), 3);
+        assert!(session.generate_greeting_body(&german).starts_with("I'm "));
+    }
+
+    #[test]
+    fn test_system2_output_cap() {
+        let input = "Explain how ownership and borrowing work in Rust";
+        let mut verbose = AiProfileStore::default().get("claude").unwrap().clone();
+        verbose.response_style.verbosity = 1.0;
+        let mut session = MimicSession::with_seed(CompoundPersona::from_profile(&verbose), 1);
+        let uncapped = session.generate_system2_response(input, &Modality::Text);
+        assert!(uncapped.len() > 150);
+        assert!(!uncapped.ends_with(TRUNCATION_MARKER));
+
+        session.max_output_chars = Some(150);
+        session.reseed(1);
+        let capped = session.generate_system2_response(input, &Modality::Text);
+        assert!(capped.len() <= 150, "{} > 150", capped.len());
+        assert!(capped.ends_with(TRUNCATION_MARKER));
+        let body = capped.strip_suffix(TRUNCATION_MARKER).unwrap();
+        assert!(uncapped.starts_with(body));
+        // The backend's output is capped too
+        session.reseed(1);
+        assert!(session.deliberate(input, &Modality::Text).len() <= 150);
+
+        // A cap shorter than the marker still leaves the marker
+        session.max_output_chars = Some(3);
+        assert_eq!(
+            session.generate_system2_response(input, &Modality::Text),
+            TRUNCATION_MARKER
+        );
+
+        // Output already within the cap is left alone
+        session.max_output_chars = Some(uncapped.len());
+        session.reseed(1);
+        assert_eq!(
+            session.generate_system2_response(input, &Modality::Text),
+            uncapped
+        );
+    }
+
+    #[test]
+    fn test_mimicry_engine_set_maxlen() {
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        assert!(matches!(
+            engine.parse_command("/set-maxlen 300"),
+            MimicCommand::SetMaxLen(Some(300))
+        ));
+        assert!(matches!(
+            engine.parse_command("/set-maxlen off"),
+            MimicCommand::SetMaxLen(None)
+        ));
+        assert!(matches!(
+            engine.parse_command("/set-maxlen x"),
+            MimicCommand::Usage(_)
+        ));
 
-        let (output, delta) = session.process(
-            "Hello, how are you?",
-            &mut cache,
-            &analyzer,
-            &mut template_store,
+        let msg = engine.execute(MimicCommand::SetMaxLen(Some(300)));
+        assert!(msg.contains("300"));
+        let _ = engine.mimic("claude");
+        assert_eq!(engine.session.as_ref().unwrap().max_output_chars, Some(300));
+        assert!(engine.status().contains("System 2 output cap: 300 chars"));
+
+        // Keyed and shadow sessions that already exist pick up changes
+        let _ = engine.session_for("side");
+        assert!(engine.fork_session("trial").is_ok());
+        let _ = engine.execute(MimicCommand::SetMaxLen(Some(200)));
+        assert_eq!(engine.sessions["side"].max_output_chars, Some(200));
+        assert_eq!(
+            engine.shadow_sessions["trial"].session.max_output_chars,
+            Some(200)
         );
-        assert!(!output.is_empty());
-        assert_eq!(session.conversation.len(), 1);
-        assert!(session.total_compounds > 0);
-        assert!(!delta.adjustments.is_empty());
+
+        // Caps leaving no room beside the marker are rejected
+        let msg = engine.execute(MimicCommand::SetMaxLen(Some(1)));
+        assert!(msg.starts_with("Usage: /set-maxlen"), "{}", msg);
+        assert!(engine
+            .set_max_output_chars(Some(TRUNCATION_MARKER.len()))
+            .is_err());
+        assert_eq!(engine.session.as_ref().unwrap().max_output_chars, Some(200));
+
+        // Stored branches pick up changes too
+        assert!(engine.branch("alt").is_ok());
+        let _ = engine.execute(MimicCommand::SetMaxLen(Some(150)));
+        assert!(engine.switch_branch("alt").is_ok());
+        assert_eq!(engine.session.as_ref().unwrap().max_output_chars, Some(150));
+
+        let _ = engine.execute(MimicCommand::SetMaxLen(None));
+        assert_eq!(engine.session.as_ref().unwrap().max_output_chars, None);
+        assert_eq!(engine.sessions["side"].max_output_chars, None);
     }
 
     #[test]
-    fn test_mimic_session_dual_process() {
-        let store = AiProfileStore::default();
-        let profile = store.get("gpt4o").unwrap();
-        let persona = CompoundPersona::from_profile(profile);
-        let mut session = MimicSession::new(persona);
-        let mut cache = SignatureCache::new();
-        cache.warm_up(&store);
-        let analyzer = BehaviorAnalyzer::new();
-        let mut template_store = TemplateStore::new();
+    fn test_mimicry_engine_set_render_plain() {
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        assert!(matches!(
+            engine.parse_command("/set-render plain"),
+            MimicCommand::SetRender(RenderMode::PlainText)
+        ));
+        assert!(matches!(
+            engine.parse_command("/set-render markdown"),
+            MimicCommand::SetRender(RenderMode::Markdown)
+        ));
+        assert!(matches!(
+            engine.parse_command("/set-render"),
+            MimicCommand::Help
+        ));
 
-        // First call might be System 1 or 2 depending on cache confidence
-        let _ = session.process(
-            "Help me write some code",
-            &mut cache,
-            &analyzer,
-            &mut template_store,
+        let _ = engine.mimic("o1");
+        let markdown = engine.chat("Summarize this article", None);
+        assert!(markdown.contains("**"), "{}", markdown);
+
+        let msg = engine.execute(MimicCommand::SetRender(RenderMode::PlainText));
+        assert!(msg.contains("plain text"));
+        assert!(engine.status().contains("Render mode: plain text"));
+        let plain = engine.chat("Summarize this article", None);
+        assert!(!plain.contains("**"), "{}", plain);
+        assert!(!plain.contains('•'), "{}", plain);
+
+        // Sessions started later inherit the mode
+        let _ = engine.mimic("claude");
+        assert_eq!(
+            engine.session.as_ref().unwrap().render_mode,
+            RenderMode::PlainText
         );
-        let _ = session.process("Now explain it", &mut cache, &analyzer, &mut template_store);
 
-        assert_eq!(session.conversation.len(), 2);
-        let total = session.system1_hits + session.system2_hits;
-        assert_eq!(total, 2);
+        // Switching back reaches keyed and shadow sessions already open
+        let _ = engine.mimic("o1");
+        let _ = engine.session_for("side");
+        assert!(engine.fork_session("trial").is_ok());
+        let _ = engine.execute(MimicCommand::SetRender(RenderMode::Markdown));
+        assert_eq!(engine.sessions["side"].render_mode, RenderMode::Markdown);
+        assert_eq!(
+            engine.shadow_sessions["trial"].session.render_mode,
+            RenderMode::Markdown
+        );
+        let keyed = engine.chat("Summarize this article", Some("side"));
+        assert!(keyed.contains("**"), "{}", keyed);
+
+        // Stored branches follow too, so switching doesn't bring back the old mode
+        assert!(engine.branch("alt").is_ok());
+        let _ = engine.execute(MimicCommand::SetRender(RenderMode::PlainText));
+        assert!(engine.switch_branch("alt").is_ok());
+        assert_eq!(
+            engine.session.as_ref().unwrap().render_mode,
+            RenderMode::PlainText
+        );
+        let _ = engine.execute(MimicCommand::SetRender(RenderMode::Markdown));
+        assert!(engine.switch_branch(MAIN_BRANCH).is_ok());
+        assert_eq!(
+            engine.session.as_ref().unwrap().render_mode,
+            RenderMode::Markdown
+        );
     }
 
     #[test]
-    fn test_mimicry_engine_new() {
-        let engine = MimicryEngine::new();
-        assert!(engine.cache.size() > 0); // warmed up
-        assert!(engine.profile_store.get("gpt4o").is_some());
-        assert!(engine.session.is_none());
+    fn test_trim_to_sentence_budget() {
+        let text = "First sentence. Second sentence! Third one is long.";
+        assert_eq!(trim_to_sentence_budget(text, 20), "First sentence.");
+        assert_eq!(trim_to_sentence_budget(text, 100), text);
     }
 
     #[test]
-    fn test_mimicry_engine_mimic() {
-        let mut engine = MimicryEngine::new();
-        let result = engine.mimic("claude");
-        assert!(result.is_ok());
-        assert!(engine.session.is_some());
+    fn test_mimicry_engine_seed_reproducible() {
+        let prompts = [
+            "Explain how closures work",
+            "What is ownership?",
+            "Explain lifetimes in depth",
+            "Tell me more about traits",
+        ];
+        let run = |seed: u64| -> Vec<String> {
+            let mut engine = MimicryEngine::new();
+            engine.execute(MimicCommand::Seed(seed));
+            let _ = engine.mimic("claude");
+            prompts
+                .iter()
+                .map(|p| engine.execute(MimicCommand::Chat(p.to_string(), None)))
+                .collect()
+        };
 
-        let err = engine.mimic("nonexistent");
-        assert!(err.is_err());
+        assert_eq!(run(42), run(42));
+
+        // Without a seed, output is still a function of the input alone
+        let unseeded = || -> Vec<String> {
+            let mut engine = MimicryEngine::new();
+            let _ = engine.mimic("claude");
+            prompts
+                .iter()
+                .map(|p| engine.execute(MimicCommand::Chat(p.to_string(), None)))
+                .collect()
+        };
+        assert_eq!(unseeded(), unseeded());
+
+        let mut engine = MimicryEngine::new();
+        assert!(engine.status().contains("RNG seed: per persona"));
+        engine.set_seed(7);
+        assert!(engine.status().contains("RNG seed: 7"));
+        match engine.parse_command("/seed 99") {
+            MimicCommand::Seed(n) => assert_eq!(n, 99),
+            _ => panic!("Expected Seed command"),
+        }
+        match engine.parse_command("/seed lots") {
+            MimicCommand::Usage(usage) => assert_eq!(usage, "Usage: /seed <n>"),
+            _ => panic!("Expected Usage for a non-numeric seed"),
+        }
     }
 
     #[test]
-    fn test_mimicry_engine_blend() {
+    fn test_mimicry_engine_diff_command() {
         let mut engine = MimicryEngine::new();
-        let result = engine.blend(&["gpt4o".to_string(), "claude".to_string()], &[0.6, 0.4]);
-        assert!(result.is_ok());
-        assert!(engine.session.is_some());
+        match engine.parse_command("/diff gpt4o claude") {
+            MimicCommand::Diff(a, b) => assert_eq!((a.as_str(), b.as_str()), ("gpt4o", "claude")),
+            _ => panic!("Expected Diff command"),
+        }
+        assert!(matches!(
+            engine.parse_command("/diff gpt4o"),
+            MimicCommand::Help
+        ));
+
+        let output = engine.execute(MimicCommand::Diff("gpt4o".into(), "claude".into()));
+        assert!(output.contains("PROFILE DIFF: gpt4o vs claude"));
+        assert!(engine
+            .diff_profiles("gpt4o", "nope")
+            .unwrap_err()
+            .contains("Unknown model"));
     }
 
     #[test]
-    fn test_mimicry_engine_observe() {
+    fn test_mimicry_engine_blend_rejects_bad_weights() {
         let mut engine = MimicryEngine::new();
-        let result = engine.observe(
-            "gpt4o",
-            "Certainly! Here's how you can do that:\n1. First step\n2. Second step",
-        );
-        assert!(result.contains("Observed"));
-        assert!(result.contains("Cached: yes"));
-        assert!(result.contains("Training samples: 1"));
+
+        let cmd = engine.parse_command("/mimic gpt4o+claude 0.7,abc");
+        let output = engine.execute(cmd);
+        assert!(output.contains("Cannot blend gpt4o + claude"));
+        assert!(output.contains("Weight 2 is not a finite number"));
+        assert!(engine.session.is_none());
+
+        let err = engine
+            .blend(&["gpt4o".into(), "claude".into()], &[0.0, 0.0])
+            .unwrap_err();
+        assert!(err.contains("greater than zero"));
+        let err = engine
+            .blend(&["gpt4o".into(), "claude".into()], &[1.0])
+            .unwrap_err();
+        assert!(err.contains("Got 1 weight for 2 profiles"));
+
+        assert!(engine
+            .blend(&["gpt4o".into(), "claude".into()], &[0.7, 0.3])
+            .is_ok());
+        assert!(engine.session.is_some());
     }
 
     #[test]
-    fn test_mimicry_engine_observe_stores_training_data() {
+    fn test_mimicry_engine_identify_verdict() {
         let mut engine = MimicryEngine::new();
-        engine.observe("gpt4o", "Response one");
-        engine.observe("gpt4o", "Response two");
-        engine.observe("claude", "A different response");
+        assert!(engine.identify("Hi").contains("No models in database"));
 
-        assert_eq!(engine.evolution_tracker.training_data.count("gpt4o"), 2);
-        assert_eq!(engine.evolution_tracker.training_data.count("claude"), 1);
+        engine.observe("gpt4o", "Certainly! Here's the answer you need.");
+        let output = engine.identify("Certainly! Here's what I found.");
+        assert!(output.contains("Confident: gpt4o"));
+        assert!(output.contains("margin"));
     }
 
     #[test]
-    fn test_mimicry_engine_observe_accumulates_patterns() {
-        // This test verifies that /observe accumulates patterns from ALL observations,
-        // not just the latest one (the bug we fixed).
+    fn test_mimicry_engine_evolution_status_breakdown() {
         let mut engine = MimicryEngine::new();
+        engine.mimic("claude").unwrap();
+        let status = engine.evolution_status().unwrap();
+        assert!(status.contains("Convergence breakdown: hedging: "));
+        assert!(status.contains("length: "));
+    }
 
-        // First observation with "I'd be happy to help" pattern
-        engine.observe("test_model", "I'd be happy to help you with that!");
+    #[test]
+    fn test_mimicry_engine_cache_survives_restart() {
+        let config = PersistenceConfig {
+            base_dir: std::env::temp_dir()
+                .join(format!("rustyworm-engine-cache-{}", std::process::id())),
+            ..PersistenceConfig::default()
+        };
+        let mut engine = MimicryEngine::with_persistence(config.clone());
+        engine.mimic("claude").unwrap();
+        engine
+            .cache
+            .compile_from(&BehaviorSignature::new("observed-model"));
+        engine.checkpoint().unwrap();
+        let checkpoint = engine.persistence.load_checkpoint("latest").unwrap();
+        assert_eq!(checkpoint.cached_signatures.len(), engine.cache.size());
+
+        let mut restarted = MimicryEngine::with_persistence(config.clone());
+        assert!(!restarted.cache.contains("observed-model"));
+        assert_eq!(restarted.restore_cache(), engine.cache.size());
+        assert!(restarted.cache.contains("observed-model"));
+
+        // A corrupt cache file falls back to a fresh warm-up
+        std::fs::write(config.signature_cache_path(), "{ broken").unwrap();
+        let mut corrupted = MimicryEngine::with_persistence(config.clone());
+        assert_eq!(corrupted.restore_cache(), 0);
+        assert!(corrupted.cache.size() > 0);
+        assert!(!corrupted.cache.contains("observed-model"));
+
+        let _ = std::fs::remove_dir_all(&config.base_dir);
+    }
 
-        // Second observation with "Let me think" pattern
-        engine.observe("test_model", "Let me think about this carefully.");
+    #[cfg(feature = "encryption")]
+    #[test]
+    fn test_encrypted_engine_leaves_no_plaintext_on_disk() {
+        let config = PersistenceConfig {
+            base_dir: std::env::temp_dir()
+                .join(format!("rustyworm-engine-sealed-{}", std::process::id())),
+            encryption_key: Some([9; 32]),
+            ..PersistenceConfig::default()
+        };
+        let mut engine = MimicryEngine::with_persistence(config.clone());
+        engine.mimic("claude").unwrap();
+        let _ = engine.chat("hello there", None);
+        engine.save(None).unwrap();
+        engine.checkpoint().unwrap();
+        let session_json = serde_json::to_string(engine.session.as_ref().unwrap()).unwrap();
+        engine
+            .persistence
+            .save_session("chat", &session_json)
+            .unwrap();
+        engine
+            .persistence
+            .save_profile(engine.profile_store.get("claude").unwrap())
+            .unwrap();
+        #[cfg(feature = "api")]
+        {
+            engine.api_cache("on").unwrap();
+            engine.api_observer.clear_cache().unwrap();
+        }
 
-        // Third observation with "That's a great question" pattern
-        engine.observe("test_model", "That's a great question! Here's my answer.");
+        let mut pending = vec![config.base_dir.clone()];
+        let mut files = 0;
+        while let Some(dir) = pending.pop() {
+            for entry in std::fs::read_dir(&dir).unwrap().flatten() {
+                let path = entry.path();
+                if path.is_dir() {
+                    pending.push(path);
+                } else if path.file_name().is_some_and(|n| n != "manifest.json") {
+                    let data = std::fs::read(&path).unwrap();
+                    assert!(
+                        crate::mimicry::persistence::is_encrypted(&data),
+                        "{} is plaintext",
+                        path.display()
+                    );
+                    files += 1;
+                }
+            }
+        }
+        assert!(files >= 5, "only {} files written", files);
 
-        // The signature should have samples_analyzed = 3 (all observations)
-        let sig = engine.analyzer.get_signature("test_model").unwrap();
+        // Everything reads back under the same key
+        let mut restarted = MimicryEngine::with_persistence(config.clone());
+        assert_eq!(restarted.restore_cache(), engine.cache.size());
+        let checkpoint = restarted.persistence.load_checkpoint("latest").unwrap();
+        assert_eq!(checkpoint.active_persona_id.as_deref(), Some("claude"));
         assert_eq!(
-            sig.samples_analyzed, 3,
-            "Signature should be built from all 3 observations, not just the last one"
-        );
-
-        // The average response length should be computed from all 3 responses
-        let expected_avg =
-            ("I'd be happy to help you with that!".len() +
-             "Let me think about this carefully.".len() +
-             "That's a great question! Here's my answer.".len()) as f64 / 3.0;
-        assert!(
-            (sig.avg_response_length - expected_avg).abs() < 0.1,
-            "Average length should be computed from all observations"
+            restarted.persistence.load_session("chat").unwrap(),
+            session_json
         );
+        assert!(restarted.persistence.load_profile("claude").is_ok());
 
-        // Training count should be 3
-        assert_eq!(engine.evolution_tracker.training_data.count("test_model"), 3);
-    }
-
-    #[test]
-    fn test_mimicry_engine_evolve() {
-        let mut engine = MimicryEngine::new();
-        let _ = engine.mimic("gpt4o");
-        let result = engine.evolve(5);
-        assert!(result.is_ok());
-        let report = result.unwrap();
-        assert!(report.contains("EVOLUTION REPORT"));
-        assert!(report.contains("Iterations: 5"));
-        assert!(report.contains("Phase:"));
-        assert!(report.contains("Drift events:"));
+        let _ = std::fs::remove_dir_all(&config.base_dir);
     }
 
     #[test]
@@ -3235,6 +7577,13 @@ mod tests {
         let result = engine.evolution_status();
         assert!(result.is_ok());
         assert!(result.unwrap().contains("EVOLUTION STATUS"));
+
+        engine
+            .evolution_tracker
+            .drift_detector
+            .analyze(&[0.3, 0.35, 0.4]);
+        let status = engine.evolution_status().unwrap();
+        assert!(status.contains("ETA to 90%: ~10 iters"), "{}", status);
     }
 
     #[test]
@@ -3255,10 +7604,22 @@ mod tests {
         }
 
         match engine.parse_command("hello world") {
-            MimicCommand::Chat(msg) => assert_eq!(msg, "hello world"),
+            MimicCommand::Chat(msg, None) => assert_eq!(msg, "hello world"),
             _ => panic!("Expected Chat command"),
         }
 
+        match engine.parse_command("/chat support  hello there") {
+            MimicCommand::Chat(msg, Some(key)) => {
+                assert_eq!(key, "support");
+                assert_eq!(msg, "hello there");
+            }
+            _ => panic!("Expected keyed Chat command"),
+        }
+        assert!(matches!(
+            engine.parse_command("/chat support"),
+            MimicCommand::Help
+        ));
+
         match engine.parse_command("/list") {
             MimicCommand::List => {}
             _ => panic!("Expected List command"),
@@ -3269,6 +7630,34 @@ mod tests {
             _ => panic!("Expected Train command"),
         }
 
+        match engine.parse_command("/evolve 50 trial") {
+            MimicCommand::ShadowEvolve(name, n) => {
+                assert_eq!(name, "trial");
+                assert_eq!(n, 50);
+            }
+            _ => panic!("Expected ShadowEvolve command"),
+        }
+
+        match engine.parse_command("/evolve 20 --dry") {
+            MimicCommand::EvolveDryRun(n) => assert_eq!(n, 20),
+            _ => panic!("Expected EvolveDryRun command"),
+        }
+
+        match engine.parse_command("/fork trial") {
+            MimicCommand::Fork(name) => assert_eq!(name, "trial"),
+            _ => panic!("Expected Fork command"),
+        }
+
+        match engine.parse_command("/branch alt") {
+            MimicCommand::Branch(name) => assert_eq!(name, "alt"),
+            _ => panic!("Expected Branch command"),
+        }
+
+        match engine.parse_command("/switch alt") {
+            MimicCommand::Switch(name) => assert_eq!(name, "alt"),
+            _ => panic!("Expected Switch command"),
+        }
+
         match engine.parse_command("/graph") {
             MimicCommand::Graph => {}
             _ => panic!("Expected Graph command"),
@@ -3321,6 +7710,23 @@ mod tests {
             _ => panic!("Expected ApiConfig command"),
         }
 
+        match engine.parse_command("/api-pricing openai 0.005 0.015") {
+            MimicCommand::ApiPricing(provider, input, output) => {
+                assert_eq!(provider, "openai");
+                assert_eq!((input, output), (0.005, 0.015));
+            }
+            _ => panic!("Expected ApiPricing command"),
+        }
+        assert!(matches!(
+            engine.parse_command("/api-pricing openai -1 0.015"),
+            MimicCommand::Help
+        ));
+
+        match engine.parse_command("/api-cache clear") {
+            MimicCommand::ApiCache(args) => assert_eq!(args, "clear"),
+            _ => panic!("Expected ApiCache command"),
+        }
+
         match engine.parse_command("/api-observe openai What is Rust?") {
             MimicCommand::ApiObserve(provider, prompt) => {
                 assert_eq!(provider, "openai");
@@ -3330,16 +7736,35 @@ mod tests {
         }
 
         match engine.parse_command("/api-compare What is Rust?") {
-            MimicCommand::ApiCompare(prompt) => {
+            MimicCommand::ApiCompare(prompt, json) => {
+                assert_eq!(prompt, "What is Rust?");
+                assert!(!json);
+            }
+            _ => panic!("Expected ApiCompare command"),
+        }
+
+        match engine.parse_command("/api-compare \"What is Rust?\" --json") {
+            MimicCommand::ApiCompare(prompt, json) => {
                 assert_eq!(prompt, "What is Rust?");
+                assert!(json);
             }
             _ => panic!("Expected ApiCompare command"),
         }
 
         match engine.parse_command("/api-study openai 7") {
-            MimicCommand::ApiStudy(provider, n) => {
+            MimicCommand::ApiStudy(provider, n, suite) => {
                 assert_eq!(provider, "openai");
                 assert_eq!(n, 7);
+                assert!(suite.is_none());
+            }
+            _ => panic!("Expected ApiStudy command"),
+        }
+
+        match engine.parse_command("/api-study claude coding 3") {
+            MimicCommand::ApiStudy(provider, n, suite) => {
+                assert_eq!(provider, "claude");
+                assert_eq!(n, 3);
+                assert_eq!(suite.as_deref(), Some("coding"));
             }
             _ => panic!("Expected ApiStudy command"),
         }
@@ -3373,7 +7798,7 @@ mod tests {
         let _ = engine.execute(MimicCommand::Mimic("gpt4o".to_string()));
 
         // Chat
-        let output = engine.execute(MimicCommand::Chat("What is Rust?".to_string()));
+        let output = engine.execute(MimicCommand::Chat("What is Rust?".to_string(), None));
         assert!(!output.is_empty());
 
         // Observe
@@ -3407,6 +7832,159 @@ mod tests {
         assert!(status.contains("Template libraries:"));
         assert!(status.contains("Evolution phase:"));
         assert!(status.contains("Persistence:"));
+        assert!(status.contains("Relationship health: 1.00 (Conscious"));
+    }
+
+    #[test]
+    fn test_mimicry_engine_restore_backup() {
+        let dir = std::env::temp_dir().join(format!("rustyworm-restore-{}", std::process::id()));
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig {
+            base_dir: dir.clone(),
+            ..PersistenceConfig::default()
+        });
+        assert!(matches!(
+            engine.parse_command("/restore claude claude.bak.1"),
+            MimicCommand::Restore(ref n, ref b) if n == "claude" && b == "claude.bak.1"
+        ));
+        assert!(matches!(
+            engine.parse_command("/restore claude"),
+            MimicCommand::Help
+        ));
+
+        let _ = engine.mimic("claude");
+        engine.save(None).unwrap();
+        let _ = engine.evolve(2);
+        engine.save(None).unwrap();
+        let backups = engine.persistence.list_backups("claude").unwrap();
+        assert_eq!(backups.len(), 1);
+
+        let msg = engine.execute(MimicCommand::Restore("claude".into(), "missing".into()));
+        assert!(msg.contains("Available: "), "{}", msg);
+        let msg = engine.execute(MimicCommand::Restore("claude".into(), backups[0].clone()));
+        assert!(msg.starts_with("Restored persona 'claude'"), "{}", msg);
+        let _ = engine.load("claude").unwrap();
+        assert_eq!(
+            engine.session.as_ref().unwrap().persona.compound_iterations,
+            0
+        );
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_mimicry_engine_bundle_commands() {
+        let dir = std::env::temp_dir().join(format!("rustyworm-bundle-{}", std::process::id()));
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig {
+            base_dir: dir.join("src"),
+            ..PersistenceConfig::default()
+        });
+        let bundle = dir.join("all.json").display().to_string();
+        assert!(matches!(
+            engine.parse_command(&format!("/bundle-import {} merge", bundle)),
+            MimicCommand::BundleImport(_, BundleConflict::Merge)
+        ));
+        assert!(matches!(
+            engine.parse_command(&format!("/bundle-import {}", bundle)),
+            MimicCommand::BundleImport(_, BundleConflict::Skip)
+        ));
+        assert!(matches!(
+            engine.parse_command("/bundle-import x.json rename"),
+            MimicCommand::Help
+        ));
+        assert!(matches!(
+            engine.parse_command("/bundle-export"),
+            MimicCommand::Help
+        ));
+
+        let _ = engine.mimic("claude");
+        engine.save(None).unwrap();
+        let msg = engine.execute(engine.parse_command(&format!("/bundle-export {}", bundle)));
+        assert!(msg.starts_with("Exported 1 persona(s)"), "{}", msg);
+
+        let mut other = MimicryEngine::with_persistence(PersistenceConfig {
+            base_dir: dir.join("dst"),
+            ..PersistenceConfig::default()
+        });
+        let msg = other.execute(MimicCommand::BundleImport(
+            bundle.clone(),
+            BundleConflict::Skip,
+        ));
+        assert!(msg.contains("Imported 1 persona(s)"), "{}", msg);
+        assert!(other.load("claude").is_ok());
+        let msg = other.execute(MimicCommand::BundleImport(bundle, BundleConflict::Skip));
+        assert!(msg.contains("Skipped 1 existing: claude"), "{}", msg);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_mimicry_engine_verify() {
+        let dir = std::env::temp_dir().join(format!("rustyworm-verify-{}", std::process::id()));
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig {
+            base_dir: dir.clone(),
+            ..PersistenceConfig::default()
+        });
+        assert!(matches!(
+            engine.parse_command("/verify"),
+            MimicCommand::Verify
+        ));
+
+        let _ = engine.mimic("claude");
+        engine.save(None).unwrap();
+        let msg = engine.execute(MimicCommand::Verify);
+        assert!(
+            msg.starts_with("Integrity OK: 1 manifest entries"),
+            "{}",
+            msg
+        );
+
+        std::fs::write(dir.join("personas").join("claude.json"), "{}").unwrap();
+        let msg = engine.execute(MimicCommand::Verify);
+        assert!(msg.contains("1 issue(s)"), "{}", msg);
+        assert!(msg.contains("persona 'claude': claude.json changed since save"));
+
+        let disabled = engine.persistence.config.clone();
+        engine.persistence = PersistenceManager::new(PersistenceConfig {
+            enabled: false,
+            ..disabled
+        });
+        assert!(engine.verify().is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_mimicry_engine_health_check() {
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        let health = engine.health_check();
+        assert!(health.profiles_loaded > 0);
+        assert!(health.cache_size > 0);
+        assert!(!health.active_session);
+        assert!(!health.persistence_writable);
+        assert!(!health.is_ready());
+
+        let _ = engine.mimic("claude");
+        assert!(engine.health_check().active_session);
+
+        let dir = std::env::temp_dir().join(format!("rustyworm-health-{}", std::process::id()));
+        let writable = MimicryEngine::with_persistence(PersistenceConfig {
+            base_dir: dir.clone(),
+            ..PersistenceConfig::default()
+        });
+        let health = writable.health_check();
+        assert!(health.persistence_writable);
+        assert!(health.is_ready());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(feature = "api")]
+    #[test]
+    fn test_mimicry_engine_health_check_providers() {
+        let mut engine = MimicryEngine::with_persistence(PersistenceConfig::disabled());
+        engine.api_observer.configure(
+            ApiProvider::custom("http://localhost:8000/v1", "local", "local"),
+            Some("key"),
+        );
+        let health = engine.health_check();
+        assert_eq!(health.providers.len(), 1);
+        assert!(health.providers.values().all(|ready| *ready));
     }
 
     #[test]
@@ -3436,6 +8014,8 @@ mod tests {
             drift_events: 2,
             phase: "LEARNING".to_string(),
             milestones_hit: 3,
+            drift_corrections: 1,
+            recommendation: None,
         };
         let json = serde_json::to_string(&report).unwrap();
         let restored: EvolutionReport = serde_json::from_str(&json).unwrap();
@@ -3484,6 +8064,29 @@ mod tests {
         // Check status
         let status = engine.api_status();
         assert!(status.contains("Ollama") || status.contains("llama"));
+        // Local models are free and nothing has been sent yet
+        assert!(status.contains("Estimated cost: $0.00 total"), "{}", status);
+
+        assert!(engine.api_pricing("ollama", 0.001, 0.002).is_ok());
+        assert!(engine.api_pricing("openai", 0.001, 0.002).is_err());
+
+        let err = engine.api_study_suite("ollama", "haiku", 3).unwrap_err();
+        assert!(err.contains("Available: general, coding"), "{}", err);
+
+        engine.persistence.config.enabled = false;
+        assert!(engine.api_cache("").unwrap().contains("off"));
+        assert!(engine
+            .api_cache("on 30")
+            .unwrap()
+            .contains("TTL 30s, in memory"));
+        assert!(engine.api_status().contains("Response cache: 0 entries"));
+        assert_eq!(
+            engine.api_cache("clear").unwrap(),
+            "Cleared 0 cached responses."
+        );
+        assert!(engine.api_cache("on soon").is_err());
+        assert!(engine.api_cache("off").is_ok());
+        assert!(engine.api_observer.cache().is_none());
     }
 
     #[cfg(feature = "api")]
@@ -3493,6 +8096,18 @@ mod tests {
         let result = engine.api_compare("test");
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("No API providers configured"));
+        assert!(engine.api_compare_json("test").is_err());
+
+        // In JSON mode failed providers are reported in the output
+        engine.api_observer.configure_with(
+            crate::mimicry::api::ApiConfig::new(ApiProvider::Ollama)
+                .with_base_url("http://127.0.0.1:1")
+                .with_retries(0, 1, 1),
+        );
+        let json = engine.api_compare_json("test").unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["skipped"][0]["provider_id"], "llama");
+        assert_eq!(value["labels"], serde_json::json!([]));
     }
 
     // =================================================================
@@ -3759,6 +8374,7 @@ mod tests {
         // Chat should trigger self-monitoring and feedback
         let response = engine.execute(MimicCommand::Chat(
             "What is the meaning of life?".to_string(),
+            None,
         ));
         assert!(!response.is_empty(), "Chat should produce a response");
 
@@ -3982,4 +8598,73 @@ mod tests {
             "Should show identification scores"
         );
     }
+
+    /// Minimal subscriber that records each event's level and message.
+    struct CapturingSubscriber {
+        events: std::sync::Arc<std::sync::Mutex<Vec<(tracing::Level, String)>>>,
+    }
+
+    impl tracing::Subscriber for CapturingSubscriber {
+        fn enabled(&self, _: &tracing::Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+            tracing::span::Id::from_u64(1)
+        }
+
+        fn record(&self, _: &tracing::span::Id, _: &tracing::span::Record<'_>) {}
+
+        fn record_follows_from(&self, _: &tracing::span::Id, _: &tracing::span::Id) {}
+
+        fn event(&self, event: &tracing::Event<'_>) {
+            struct Message(String);
+            impl tracing::field::Visit for Message {
+                fn record_debug(
+                    &mut self,
+                    field: &tracing::field::Field,
+                    value: &dyn std::fmt::Debug,
+                ) {
+                    self.0.push_str(&format!("{}={:?} ", field.name(), value));
+                }
+            }
+            let mut message = Message(String::new());
+            event.record(&mut message);
+            self.events
+                .lock()
+                .unwrap()
+                .push((*event.metadata().level(), message.0));
+        }
+
+        fn enter(&self, _: &tracing::span::Id) {}
+
+        fn exit(&self, _: &tracing::span::Id) {}
+    }
+
+    #[test]
+    fn test_octo_init_failure_emits_warning() {
+        let events = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let subscriber = CapturingSubscriber {
+            events: events.clone(),
+        };
+
+        let bridge = tracing::subscriber::with_default(subscriber, || {
+            init_or_warn::<()>("OCTO RNA bridge", Err("python unavailable".to_string()))
+        });
+        assert!(bridge.is_none());
+        assert_eq!(init_or_warn("OCTO RNA bridge", Ok(3)), Some(3));
+
+        #[cfg(feature = "octo")]
+        {
+            let (bridge, embedder) = octo_components(Err("python unavailable".to_string()));
+            assert!(bridge.is_none());
+            assert!(embedder.is_none());
+        }
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0, tracing::Level::WARN);
+        assert!(events[0].1.contains("failed to initialize OCTO RNA bridge"));
+        assert!(events[0].1.contains("python unavailable"));
+    }
 }
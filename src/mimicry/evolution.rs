@@ -20,9 +20,11 @@
 // - Importance-weighted trajectory sampling
 // =================================================================
 
+use rand::distributions::{Distribution, WeightedIndex};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use crate::mimicry::analyzer::BehaviorAnalyzer;
+use crate::mimicry::analyzer::{BehaviorAnalyzer, BehaviorSignature};
 use crate::mimicry::profile::{AiProfile, PersonalityDelta};
 
 // RL integration imports (feature-gated)
@@ -51,6 +53,8 @@ pub enum EvolutionPhase {
     Converged,
     /// Drifting - persona is moving away from target (needs correction)
     Drifting,
+    /// Unstable - convergence oscillates without a net trend
+    Unstable,
 }
 
 impl std::fmt::Display for EvolutionPhase {
@@ -61,6 +65,7 @@ impl std::fmt::Display for EvolutionPhase {
             EvolutionPhase::Refinement => write!(f, "REFINEMENT"),
             EvolutionPhase::Converged => write!(f, "CONVERGED"),
             EvolutionPhase::Drifting => write!(f, "DRIFTING"),
+            EvolutionPhase::Unstable => write!(f, "UNSTABLE"),
         }
     }
 }
@@ -83,8 +88,31 @@ pub struct DriftDetector {
     pub patience: usize,
     /// Current consecutive decline count
     pub decline_count: usize,
+    /// Trend slope of the most recent analysis window
+    #[serde(default)]
+    pub last_slope: f64,
+    /// Convergence at the most recent analysis
+    #[serde(default)]
+    pub last_convergence: f64,
+    /// Window size for variance (instability) detection
+    #[serde(default = "default_variance_window")]
+    pub variance_window: usize,
+    /// Variance above which a trendless window counts as oscillating
+    #[serde(default = "default_variance_threshold")]
+    pub variance_threshold: f64,
 }
 
+fn default_variance_window() -> usize {
+    8
+}
+
+fn default_variance_threshold() -> f64 {
+    0.01
+}
+
+/// Upper bound on convergence ETAs; flatter slopes extrapolate to noise
+const MAX_PROJECTED_ITERATIONS: u64 = 10_000;
+
 impl DriftDetector {
     /// Creates a new `DriftDetector` with default sensitivity settings.
     pub fn new() -> Self {
@@ -94,6 +122,10 @@ impl DriftDetector {
             convergence_threshold: 0.80,
             patience: 3,
             decline_count: 0,
+            last_slope: 0.0,
+            last_convergence: 0.0,
+            variance_window: default_variance_window(),
+            variance_threshold: default_variance_threshold(),
         }
     }
 
@@ -101,12 +133,16 @@ impl DriftDetector {
     /// Returns (is_drifting, trend_slope, current_phase)
     pub fn analyze(&mut self, history: &[f64]) -> DriftAnalysis {
         if history.len() < 2 {
+            self.last_slope = 0.0;
+            self.last_convergence = history.last().copied().unwrap_or(0.0);
             return DriftAnalysis {
                 is_drifting: false,
                 trend_slope: 0.0,
+                variance: 0.0,
                 phase: EvolutionPhase::Observation,
                 current_convergence: history.last().copied().unwrap_or(0.0),
                 recommendation: "Need more data points for drift analysis.".to_string(),
+                drifting_from: None,
             };
         }
 
@@ -120,6 +156,10 @@ impl DriftDetector {
         };
         let window = &history[window_start..];
         let slope = self.calculate_slope(window);
+        self.last_slope = slope;
+        self.last_convergence = current;
+        let variance_start = history.len().saturating_sub(self.variance_window);
+        let variance = Self::calculate_variance(&history[variance_start..]);
 
         // Determine phase
         let phase = if current >= self.convergence_threshold {
@@ -150,6 +190,16 @@ impl DriftDetector {
             }
         };
 
+        // Swings without a net trend escape the slope test
+        let phase = if phase != EvolutionPhase::Drifting
+            && slope.abs() <= self.drift_threshold
+            && variance > self.variance_threshold
+        {
+            EvolutionPhase::Unstable
+        } else {
+            phase
+        };
+
         let is_drifting = phase == EvolutionPhase::Drifting;
 
         let recommendation = match &phase {
@@ -171,15 +221,33 @@ impl DriftDetector {
                  Recommend: re-observe target or reset to last checkpoint.",
                 slope
             ),
+            EvolutionPhase::Unstable => format!(
+                "INSTABILITY DETECTED! Convergence oscillating (variance: {:.4}) \
+                 with no net trend. Recommend: feed more consistent observations.",
+                variance
+            ),
         };
 
         DriftAnalysis {
             is_drifting,
             trend_slope: slope,
+            variance,
             phase,
             current_convergence: current,
             recommendation,
+            drifting_from: None,
+        }
+    }
+
+    /// Estimate how many more iterations the last analyzed trend needs to
+    /// reach `target`, capped at 10,000. `None` when the target is already
+    /// reached or convergence is not improving.
+    pub fn projected_iterations_to(&self, target: f64) -> Option<u64> {
+        if self.last_convergence >= target || self.last_slope.is_nan() || self.last_slope <= 0.0 {
+            return None;
         }
+        let iterations = ((target - self.last_convergence) / self.last_slope).ceil();
+        Some((iterations as u64).min(MAX_PROJECTED_ITERATIONS))
     }
 
     /// Calculate linear regression slope over a window
@@ -200,6 +268,16 @@ impl DriftDetector {
         (n * sum_xy - sum_x * sum_y) / denominator
     }
 
+    /// Population variance of a window
+    fn calculate_variance(window: &[f64]) -> f64 {
+        if window.len() < 2 {
+            return 0.0;
+        }
+        let n = window.len() as f64;
+        let mean = window.iter().sum::<f64>() / n;
+        window.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n
+    }
+
     /// Reset drift counter (e.g., after corrective action)
     pub fn reset(&mut self) {
         self.decline_count = 0;
@@ -219,30 +297,70 @@ pub struct DriftAnalysis {
     pub is_drifting: bool,
     /// Linear regression slope of the convergence trend window
     pub trend_slope: f64,
+    /// Convergence variance over the variance window
+    #[serde(default)]
+    pub variance: f64,
     /// Current evolution phase determined by drift analysis
     pub phase: EvolutionPhase,
     /// Most recent convergence score
     pub current_convergence: f64,
     /// Human-readable recommendation based on the analysis
     pub recommendation: String,
+    /// With multiple targets, the one whose convergence is falling fastest
+    /// while drifting
+    #[serde(default)]
+    pub drifting_from: Option<String>,
 }
 
 // =================================================================
 // MILESTONE TRACKER
 // =================================================================
 
+/// Action run with the threshold value when a convergence threshold is
+/// first crossed.
+pub type ThresholdCallback = Box<dyn FnMut(f64) + Send + Sync>;
+
 /// Tracks evolution milestones and triggers events (like auto-save)
 /// when significant thresholds are crossed.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Serialize, Deserialize)]
 pub struct MilestoneTracker {
-    /// Convergence thresholds that trigger milestones
+    /// Convergence thresholds that trigger milestones, ascending
     pub thresholds: Vec<f64>,
-    /// Which thresholds have been crossed
+    /// Which thresholds have been crossed (parallel to `thresholds`)
     pub crossed: Vec<bool>,
     /// History of milestone events
     pub events: Vec<MilestoneEvent>,
     /// Total evolution iterations
     pub total_iterations: u64,
+    /// Invoked from `check` for each newly crossed threshold. Not
+    /// serialized, and clones start without one so scratch copies
+    /// (dry runs, shadows) never fire it.
+    #[serde(skip)]
+    pub on_threshold: Option<ThresholdCallback>,
+}
+
+impl Clone for MilestoneTracker {
+    fn clone(&self) -> Self {
+        MilestoneTracker {
+            thresholds: self.thresholds.clone(),
+            crossed: self.crossed.clone(),
+            events: self.events.clone(),
+            total_iterations: self.total_iterations,
+            on_threshold: None,
+        }
+    }
+}
+
+impl std::fmt::Debug for MilestoneTracker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MilestoneTracker")
+            .field("thresholds", &self.thresholds)
+            .field("crossed", &self.crossed)
+            .field("events", &self.events)
+            .field("total_iterations", &self.total_iterations)
+            .field("on_threshold", &self.on_threshold.is_some())
+            .finish()
+    }
 }
 
 /// A recorded milestone event during evolution.
@@ -271,6 +389,8 @@ pub enum MilestoneType {
     PhaseTransition(EvolutionPhase, EvolutionPhase),
     /// Drift detected and corrected
     DriftCorrected,
+    /// Convergence plateaued below the convergence threshold
+    ConvergenceStall,
 }
 
 impl MilestoneTracker {
@@ -281,19 +401,43 @@ impl MilestoneTracker {
             crossed: vec![false; 5],
             events: Vec::new(),
             total_iterations: 0,
+            on_threshold: None,
+        }
+    }
+
+    /// Creates a `MilestoneTracker` with custom convergence thresholds.
+    /// Non-finite values are dropped; the rest are sorted and deduplicated.
+    pub fn with_thresholds(mut thresholds: Vec<f64>) -> Self {
+        thresholds.retain(|t| t.is_finite());
+        thresholds.sort_by(f64::total_cmp);
+        thresholds.dedup();
+        MilestoneTracker {
+            crossed: vec![false; thresholds.len()],
+            thresholds,
+            ..MilestoneTracker::new()
         }
     }
 
+    /// Set the action to run when each threshold is first crossed.
+    pub fn set_on_threshold(&mut self, callback: impl FnMut(f64) + Send + Sync + 'static) {
+        self.on_threshold = Some(Box::new(callback));
+    }
+
     /// Check for new milestones given the current convergence score.
     /// Returns any newly triggered milestones.
     pub fn check(&mut self, convergence: f64, iteration: u64) -> Vec<MilestoneEvent> {
         let mut new_events = Vec::new();
         self.total_iterations = iteration;
+        // Hand-edited saves may disagree on lengths
+        self.crossed.resize(self.thresholds.len(), false);
 
         // Check convergence thresholds
         for (i, threshold) in self.thresholds.iter().enumerate() {
             if !self.crossed[i] && convergence >= *threshold {
                 self.crossed[i] = true;
+                if let Some(callback) = self.on_threshold.as_mut() {
+                    callback(*threshold);
+                }
                 let event = MilestoneEvent {
                     milestone_type: MilestoneType::ThresholdCrossed(*threshold),
                     convergence,
@@ -380,6 +524,24 @@ impl MilestoneTracker {
         self.events.push(event);
     }
 
+    /// Record a convergence plateau of `duration` iterations at `plateau`
+    pub fn record_stall(&mut self, plateau: f64, duration: u64, iteration: u64) -> MilestoneEvent {
+        let event = MilestoneEvent {
+            milestone_type: MilestoneType::ConvergenceStall,
+            convergence: plateau,
+            iteration,
+            description: format!(
+                "Convergence stalled at {:.1}% for {} iterations (iteration {}). \
+                 Recommend: inject fresh observations.",
+                plateau * 100.0,
+                duration,
+                iteration
+            ),
+        };
+        self.events.push(event.clone());
+        event
+    }
+
     /// Should we trigger an auto-save? (on any milestone)
     pub fn should_auto_save(&self, new_events: &[MilestoneEvent]) -> bool {
         !new_events.is_empty()
@@ -508,7 +670,10 @@ impl ConvergenceVisualizer {
             .collect()
     }
 
-    /// Render a comparison of two convergence histories
+    /// Render a comparison of two convergence histories. Each history is
+    /// downsampled independently; the shorter one simply ends early rather
+    /// than being stretched, and the summary notes when it covers half the
+    /// width or less.
     pub fn render_comparison(
         &self,
         history_a: &[f64],
@@ -535,8 +700,9 @@ impl ConvergenceVisualizer {
 
             let mut row_chars = String::new();
             for i in 0..max_len {
-                let a_hit = data_a.get(i).copied().unwrap_or(0.0) >= threshold;
-                let b_hit = data_b.get(i).copied().unwrap_or(0.0) >= threshold;
+                // Columns past the end of a history are empty, not zero
+                let a_hit = data_a.get(i).is_some_and(|&v| v >= threshold);
+                let b_hit = data_b.get(i).is_some_and(|&v| v >= threshold);
                 match (a_hit, b_hit) {
                     (true, true) => row_chars.push('X'),
                     (true, false) => row_chars.push('#'),
@@ -553,9 +719,41 @@ impl ConvergenceVisualizer {
             "   Legend: # = {} only, o = {} only, X = both",
             label_a, label_b
         ));
+        lines.push(Self::series_summary(history_a, label_a));
+        lines.push(Self::series_summary(history_b, label_b));
+        let (short, short_data, long_data) = if data_a.len() < data_b.len() {
+            (label_a, &data_a, &data_b)
+        } else {
+            (label_b, &data_b, &data_a)
+        };
+        // Only call out a gap large enough to misread the graph
+        if !long_data.is_empty() && short_data.len() * 2 <= long_data.len() {
+            lines.push(format!(
+                "   Note: {} fills {} of {} columns; its line ends early.",
+                short,
+                short_data.len(),
+                long_data.len()
+            ));
+        }
 
         lines.join("\n")
     }
+
+    /// One-line summary of a history for comparison output
+    fn series_summary(history: &[f64], label: &str) -> String {
+        let Some(current) = history.last() else {
+            return format!("   {}: no data", label);
+        };
+        let max = history.iter().cloned().fold(0.0_f64, f64::max);
+        format!(
+            "   {}: {} data point{}, current {:.1}%, max {:.1}%",
+            label,
+            history.len(),
+            if history.len() == 1 { "" } else { "s" },
+            current * 100.0,
+            max * 100.0
+        )
+    }
 }
 
 impl Default for ConvergenceVisualizer {
@@ -568,15 +766,56 @@ impl Default for ConvergenceVisualizer {
 // TRAINING DATA MANAGER
 // =================================================================
 
+/// Custom quality scorer applied to each response at store time.
+/// Receives the raw response text and returns a score in [0, 1].
+pub type QualityScorer = Box<dyn Fn(&str) -> f64 + Send + Sync>;
+
+/// Shared form of [`QualityScorer`] so the manager stays cloneable.
+type SharedScorer = std::sync::Arc<dyn Fn(&str) -> f64 + Send + Sync>;
+
+/// Phrases that mark a response as a refusal or error rather than
+/// substantive content worth learning from.
+const LOW_QUALITY_MARKERS: &[&str] = &[
+    "i can't help",
+    "i cannot help",
+    "i'm unable to",
+    "i am unable to",
+    "i can't assist",
+    "i cannot assist",
+    "i'm sorry, but",
+    "error:",
+    "internal server error",
+    "rate limit",
+    "request failed",
+];
+
 /// Manages training data (observed responses) for evolution cycles.
 /// Stores observed model outputs so they can be replayed during
 /// evolution without requiring live API access.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Every stored observation is scored automatically, either by the
+/// built-in heuristic or by a scorer supplied via [`with_scorer`].
+///
+/// [`with_scorer`]: TrainingDataManager::with_scorer
+#[derive(Clone, Serialize, Deserialize)]
 pub struct TrainingDataManager {
     /// Stored observations keyed by model_id
     pub observations: std::collections::HashMap<String, Vec<TrainingObservation>>,
     /// Maximum observations to retain per model
     pub max_per_model: usize,
+    /// Custom scorer replacing the built-in heuristic (not serialized)
+    #[serde(skip)]
+    scorer: Option<SharedScorer>,
+}
+
+impl std::fmt::Debug for TrainingDataManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TrainingDataManager")
+            .field("observations", &self.observations)
+            .field("max_per_model", &self.max_per_model)
+            .field("custom_scorer", &self.scorer.is_some())
+            .finish()
+    }
 }
 
 /// A single observed model response used as training data.
@@ -598,16 +837,36 @@ impl TrainingDataManager {
         TrainingDataManager {
             observations: std::collections::HashMap::new(),
             max_per_model: 100,
+            scorer: None,
+        }
+    }
+
+    /// Creates a `TrainingDataManager` that scores observations with a
+    /// custom function instead of the built-in heuristic.
+    pub fn with_scorer(scorer: QualityScorer) -> Self {
+        TrainingDataManager {
+            scorer: Some(std::sync::Arc::from(scorer)),
+            ..TrainingDataManager::new()
         }
     }
 
-    /// Store an observation for a model
-    pub fn store(&mut self, model_id: &str, prompt: &str, response: &str, iteration: u64) {
+    /// Store an observation for a model. Quality is scored automatically.
+    /// Returns `false` (storing nothing) when the model already has an
+    /// observation with the identical response.
+    pub fn store(&mut self, model_id: &str, prompt: &str, response: &str, iteration: u64) -> bool {
+        if self
+            .observations
+            .get(model_id)
+            .is_some_and(|obs| obs.iter().any(|o| o.model_response == response))
+        {
+            return false;
+        }
+        let quality_score = self.score(model_id, response);
         let obs = TrainingObservation {
             input_prompt: prompt.to_string(),
             model_response: response.to_string(),
             iteration_observed: iteration,
-            quality_score: 0.5, // neutral default
+            quality_score,
         };
 
         let entries = self.observations.entry(model_id.to_string()).or_default();
@@ -617,6 +876,55 @@ impl TrainingDataManager {
         if entries.len() > self.max_per_model {
             entries.remove(0);
         }
+        true
+    }
+
+    /// Score a response for a model, using the custom scorer if one is set.
+    pub fn score(&self, model_id: &str, response: &str) -> f64 {
+        match &self.scorer {
+            Some(scorer) => scorer(response).clamp(0.0, 1.0),
+            None => self.default_score(model_id, response),
+        }
+    }
+
+    /// Built-in heuristic: length sanity, non-error content, and length
+    /// consistency with existing observations for the same model.
+    fn default_score(&self, model_id: &str, response: &str) -> f64 {
+        let trimmed = response.trim();
+        if trimmed.is_empty() {
+            return 0.0;
+        }
+
+        // Length sanity: ramps up to ~200 chars, mild penalty for walls of text
+        let len = trimmed.len() as f64;
+        let length_score = if len > 8000.0 {
+            0.7
+        } else {
+            (len / 200.0).min(1.0)
+        };
+
+        // Non-error content: refusals and error payloads carry little signal
+        let lower = trimmed.to_lowercase();
+        let content_score = if LOW_QUALITY_MARKERS.iter().any(|m| lower.contains(m)) {
+            0.0
+        } else {
+            1.0
+        };
+
+        // Signature consistency: compare against this model's typical length
+        let consistency_score = match self.observations.get(model_id) {
+            Some(obs) if !obs.is_empty() => {
+                let avg_len = obs
+                    .iter()
+                    .map(|o| o.model_response.trim().len() as f64)
+                    .sum::<f64>()
+                    / obs.len() as f64;
+                len.min(avg_len) / len.max(avg_len).max(1.0)
+            }
+            _ => 1.0,
+        };
+
+        (0.4 * length_score + 0.4 * content_score + 0.2 * consistency_score).clamp(0.0, 1.0)
     }
 
     /// Get observations for a model, optionally filtered by quality
@@ -633,6 +941,40 @@ impl TrainingDataManager {
         }
     }
 
+    /// Draw `n` observations for a model (with replacement) using the
+    /// thread RNG. See [`sample_with`](Self::sample_with).
+    pub fn sample(&self, model_id: &str, n: usize, by_quality: bool) -> Vec<&TrainingObservation> {
+        self.sample_with(&mut rand::thread_rng(), model_id, n, by_quality)
+    }
+
+    /// Draw `n` observations for a model (with replacement) from `rng`.
+    /// With `by_quality`, each draw is weighted by `quality_score`, so
+    /// high-quality observations dominate; otherwise draws are uniform.
+    /// Zero-scored observations keep a small weight so they stay reachable.
+    pub fn sample_with<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        model_id: &str,
+        n: usize,
+        by_quality: bool,
+    ) -> Vec<&TrainingObservation> {
+        let obs = match self.observations.get(model_id) {
+            Some(obs) if !obs.is_empty() => obs,
+            _ => return Vec::new(),
+        };
+        let weights = obs.iter().map(|o| {
+            if by_quality {
+                o.quality_score.max(0.01)
+            } else {
+                1.0
+            }
+        });
+        match WeightedIndex::new(weights) {
+            Ok(dist) => (0..n).map(|_| &obs[dist.sample(rng)]).collect(),
+            Err(_) => (0..n).map(|_| &obs[rng.gen_range(0..obs.len())]).collect(),
+        }
+    }
+
     /// Rate an observation's quality (feedback from evolution)
     pub fn rate(&mut self, model_id: &str, index: usize, score: f64) {
         if let Some(obs) = self.observations.get_mut(model_id) {
@@ -683,6 +1025,35 @@ impl Default for TrainingDataManager {
     }
 }
 
+// =================================================================
+// MULTI-TARGET CONVERGENCE
+// =================================================================
+
+/// How per-target convergence scores combine into one.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum TargetCombination {
+    /// Weight-averaged across targets
+    #[default]
+    WeightedAverage,
+    /// The worst-matched target decides
+    Min,
+}
+
+/// A reference signature a persona is trained toward, with its weight
+/// and the convergence measured against it at each step.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvergenceTarget {
+    /// Name reported when the persona drifts away from this target
+    pub label: String,
+    /// Signature convergence is measured against
+    pub signature: BehaviorSignature,
+    /// Relative weight under `TargetCombination::WeightedAverage`
+    pub weight: f64,
+    /// Convergence against this target, one entry per measurement
+    #[serde(default)]
+    pub history: Vec<f64>,
+}
+
 // =================================================================
 // EVOLUTION TRACKER - Central evolution coordinator
 // =================================================================
@@ -714,6 +1085,27 @@ pub struct EvolutionTracker {
     pub best_convergence: f64,
     /// Iteration at which best convergence was achieved
     pub best_convergence_iteration: u64,
+    /// Revert to the last auto-saved milestone snapshot when drift is
+    /// confirmed (opt-in; the engine performs the reload)
+    #[serde(default)]
+    pub auto_correct_on_drift: bool,
+    /// Reference signatures for blended-persona training; empty means
+    /// convergence is measured against the persona's own signature
+    #[serde(default)]
+    pub targets: Vec<ConvergenceTarget>,
+    /// How `targets` combine into one convergence score
+    #[serde(default)]
+    pub target_combination: TargetCombination,
+    /// Per-iteration convergence change below which a step counts as stalled
+    #[serde(default = "default_stall_epsilon")]
+    pub stall_epsilon: f64,
+    /// Consecutive stalled steps (below the convergence threshold) before
+    /// a `ConvergenceStall` milestone is emitted
+    #[serde(default = "default_stall_patience")]
+    pub stall_patience: u64,
+    /// Current run of stalled steps
+    #[serde(default)]
+    pub stall_count: u64,
 
     // =========================================================
     // RL INTEGRATION FIELDS (feature = "rl")
@@ -744,6 +1136,14 @@ pub struct EvolutionTracker {
     pub pre_rl_convergence: Option<f64>,
 }
 
+fn default_stall_epsilon() -> f64 {
+    0.001
+}
+
+fn default_stall_patience() -> u64 {
+    10
+}
+
 impl EvolutionTracker {
     /// Creates a new `EvolutionTracker` in the initial observation phase.
     pub fn new() -> Self {
@@ -758,6 +1158,12 @@ impl EvolutionTracker {
             total_drift_events: 0,
             best_convergence: 0.0,
             best_convergence_iteration: 0,
+            auto_correct_on_drift: false,
+            targets: Vec::new(),
+            target_combination: TargetCombination::default(),
+            stall_epsilon: default_stall_epsilon(),
+            stall_patience: default_stall_patience(),
+            stall_count: 0,
             #[cfg(feature = "rl")]
             rl_config: None,
             #[cfg(feature = "rl")]
@@ -835,7 +1241,15 @@ impl EvolutionTracker {
         }
 
         // Drift analysis
-        let drift_analysis = self.drift_detector.analyze(convergence_history);
+        let mut drift_analysis = self.drift_detector.analyze(convergence_history);
+        if drift_analysis.is_drifting {
+            drift_analysis.drifting_from = self.steepest_declining_target();
+            if let Some(label) = &drift_analysis.drifting_from {
+                drift_analysis
+                    .recommendation
+                    .push_str(&format!(" Drifting away from '{}'.", label));
+            }
+        }
 
         // Phase transition detection
         let phase_changed = drift_analysis.phase != self.current_phase;
@@ -856,9 +1270,14 @@ impl EvolutionTracker {
         }
 
         // Milestone check
-        let new_milestones = self.milestones.check(current_convergence, iteration);
+        let mut new_milestones = self.milestones.check(current_convergence, iteration);
         let should_save = self.milestones.should_auto_save(&new_milestones);
 
+        // Plateau check (a stall is not progress worth auto-saving)
+        if let Some(event) = self.check_stall(convergence_history, iteration) {
+            new_milestones.push(event);
+        }
+
         EvolutionStepResult {
             drift_analysis,
             new_milestones,
@@ -868,6 +1287,30 @@ impl EvolutionTracker {
         }
     }
 
+    /// Count consecutive steps whose convergence moved less than
+    /// `stall_epsilon` while below the convergence threshold, returning a
+    /// `ConvergenceStall` event once the run reaches `stall_patience`.
+    /// Meaningful movement (or reaching the threshold) resets the count.
+    fn check_stall(&mut self, history: &[f64], iteration: u64) -> Option<MilestoneEvent> {
+        let [.., previous, current] = history else {
+            return None;
+        };
+        let stalled = (current - previous).abs() < self.stall_epsilon
+            && *current < self.drift_detector.convergence_threshold;
+        if !stalled {
+            self.stall_count = 0;
+            return None;
+        }
+        self.stall_count += 1;
+        if self.stall_patience == 0 || self.stall_count != self.stall_patience {
+            return None;
+        }
+        Some(
+            self.milestones
+                .record_stall(*current, self.stall_count, iteration),
+        )
+    }
+
     /// Record that a drift correction was applied
     pub fn record_correction(&mut self, convergence: f64, iteration: u64) {
         self.total_corrections += 1;
@@ -876,14 +1319,96 @@ impl EvolutionTracker {
             .record_drift_correction(convergence, iteration);
     }
 
+    /// Add a reference signature to train toward. Non-finite or negative
+    /// weights count as zero.
+    pub fn add_target(&mut self, label: &str, signature: BehaviorSignature, weight: f64) {
+        self.targets.push(ConvergenceTarget {
+            label: label.to_string(),
+            signature,
+            weight: if weight.is_finite() {
+                weight.max(0.0)
+            } else {
+                0.0
+            },
+            history: Vec::new(),
+        });
+    }
+
+    /// Measure `profile` against every target, record each score in that
+    /// target's history, and return the combined convergence (`None`
+    /// without targets). A weighted average whose weights are all zero
+    /// falls back to the plain mean.
+    pub fn measure_targets(
+        &mut self,
+        profile: &AiProfile,
+        analyzer: &BehaviorAnalyzer,
+    ) -> Option<f64> {
+        if self.targets.is_empty() {
+            return None;
+        }
+        let scores: Vec<(f64, f64)> = self
+            .targets
+            .iter_mut()
+            .map(|target| {
+                let score = analyzer.compute_convergence(profile, &target.signature);
+                target.history.push(score);
+                (score, target.weight)
+            })
+            .collect();
+        let combined = match self.target_combination {
+            TargetCombination::Min => scores.iter().map(|(s, _)| *s).fold(f64::INFINITY, f64::min),
+            TargetCombination::WeightedAverage => {
+                let total: f64 = scores.iter().map(|(_, w)| w).sum();
+                if total > 0.0 {
+                    scores.iter().map(|(s, w)| s * w).sum::<f64>() / total
+                } else {
+                    scores.iter().map(|(s, _)| s).sum::<f64>() / scores.len() as f64
+                }
+            }
+        };
+        Some(combined)
+    }
+
+    /// Label of the target whose recent convergence declines fastest, if
+    /// any is declining past the drift threshold
+    fn steepest_declining_target(&self) -> Option<String> {
+        let detector = &self.drift_detector;
+        self.targets
+            .iter()
+            .map(|target| {
+                let start = target.history.len().saturating_sub(detector.window_size);
+                (target, detector.calculate_slope(&target.history[start..]))
+            })
+            .filter(|(_, slope)| *slope < -detector.drift_threshold)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(target, _)| target.label.clone())
+    }
+
+    /// Whether this step confirmed drift and auto-correction is enabled,
+    /// i.e. the caller should revert to its last milestone snapshot.
+    pub fn needs_drift_correction(&self, result: &EvolutionStepResult) -> bool {
+        self.auto_correct_on_drift && result.drift_analysis.phase == EvolutionPhase::Drifting
+    }
+
+    /// Fallback when drift is confirmed but no milestone snapshot exists
+    /// yet: reset the drift counter and return a re-observation advice.
+    pub fn defer_drift_correction(&mut self) -> String {
+        self.drift_detector.reset();
+        "Drift detected but no milestone snapshot to revert to. \
+         Drift counter reset; re-observe the target model to rebuild a baseline."
+            .to_string()
+    }
+
     /// Run a training loop: iterate self-correction cycles using
-    /// stored training data. Returns deltas produced and final convergence.
-    pub fn training_loop(
+    /// stored training data, one quality-weighted sample drawn from `rng`
+    /// per iteration. Returns deltas produced and final convergence.
+    pub fn training_loop<R: Rng + ?Sized>(
         &mut self,
         model_id: &str,
         profile: &mut AiProfile,
         analyzer: &mut BehaviorAnalyzer,
         iterations: u64,
+        rng: &mut R,
     ) -> TrainingLoopResult {
         let mut deltas = Vec::new();
         let mut convergence_history = Vec::new();
@@ -891,7 +1416,7 @@ impl EvolutionTracker {
         // while we need &mut self for self.step() inside the loop.
         let observations: Vec<_> = self
             .training_data
-            .get(model_id, None)
+            .sample_with(rng, model_id, iterations as usize, true)
             .into_iter()
             .cloned()
             .collect();
@@ -908,11 +1433,7 @@ impl EvolutionTracker {
 
         let mut drift_events = 0;
 
-        for i in 0..iterations {
-            // Cycle through observations
-            let obs_idx = (i as usize) % observations.len();
-            let obs = &observations[obs_idx];
-
+        for (i, obs) in (0..iterations).zip(&observations) {
             // Build a signature from the observation
             let sig = analyzer.build_signature(model_id, std::slice::from_ref(&obs.model_response));
 
@@ -961,11 +1482,61 @@ impl EvolutionTracker {
         lines.join("\n")
     }
 
+    /// Capture the drift, milestone, and phase bookkeeping so a branched
+    /// or undone session can carry its own.
+    pub fn snapshot(&self) -> EvolutionTrackerSnapshot {
+        EvolutionTrackerSnapshot {
+            drift_detector: self.drift_detector.clone(),
+            milestones: self.milestones.clone(),
+            current_phase: self.current_phase.clone(),
+            previous_phase: self.previous_phase.clone(),
+            total_evolutions: self.total_evolutions,
+            stall_count: self.stall_count,
+            target_histories: self
+                .targets
+                .iter()
+                .map(|target| target.history.clone())
+                .collect(),
+        }
+    }
+
+    /// Restore bookkeeping captured by [`snapshot`](Self::snapshot). An
+    /// installed `on_threshold` callback is kept. Targets added since the
+    /// snapshot start over with an empty history.
+    pub fn restore(&mut self, snapshot: EvolutionTrackerSnapshot) {
+        let on_threshold = self.milestones.on_threshold.take();
+        self.drift_detector = snapshot.drift_detector;
+        self.milestones = snapshot.milestones;
+        self.milestones.on_threshold = on_threshold;
+        self.current_phase = snapshot.current_phase;
+        self.previous_phase = snapshot.previous_phase;
+        self.total_evolutions = snapshot.total_evolutions;
+        self.stall_count = snapshot.stall_count;
+        let mut histories = snapshot.target_histories.into_iter();
+        for target in &mut self.targets {
+            target.history = histories.next().unwrap_or_default();
+        }
+    }
+
     /// Render a convergence graph
     pub fn render_graph(&self, history: &[f64], label: &str) -> String {
         let viz = ConvergenceVisualizer::default();
         viz.render(history, label)
     }
+
+    /// Export a convergence history as `iteration,convergence,phase` CSV.
+    /// Each point's phase is replayed with a fresh copy of the drift
+    /// detector's settings, so it reflects only the points up to it.
+    pub fn export_history_csv(&self, history: &[f64]) -> String {
+        let mut detector = self.drift_detector.clone();
+        detector.reset();
+        let mut csv = String::from("iteration,convergence,phase\n");
+        for i in 0..history.len() {
+            let analysis = detector.analyze(&history[..=i]);
+            csv.push_str(&format!("{},{:.4},{}\n", i, history[i], analysis.phase));
+        }
+        csv
+    }
     
     // =========================================================
     // RL-ENHANCED EVOLUTION METHODS (feature = "rl")
@@ -1021,7 +1592,7 @@ impl EvolutionTracker {
                     self.rl_stats.record_fallback();
                     self.rl_stats.record_error();
                     if config.debug_logging {
-                        eprintln!("[RL] Service error, falling back: {}", e);
+                        tracing::warn!(error = %e, "RL service error, falling back to traditional evolution");
                     }
                     return self.evolve_traditional(profile, observations, analyzer);
                 } else {
@@ -1073,14 +1644,14 @@ impl EvolutionTracker {
             match self.train_rl_model_internal(&config).await {
                 Ok(train_result) => {
                     if config.debug_logging {
-                        eprintln!("[RL] Training completed: {:?}", train_result);
+                        tracing::debug!(result = ?train_result, "RL training completed");
                     }
                     true
                 }
                 Err(e) => {
                     self.rl_stats.record_error();
                     if config.debug_logging {
-                        eprintln!("[RL] Training error: {}", e);
+                        tracing::warn!(error = %e, "RL training failed");
                     }
                     false
                 }
@@ -1380,6 +1951,29 @@ impl Default for EvolutionTracker {
     }
 }
 
+/// Per-session evolution bookkeeping captured by
+/// [`EvolutionTracker::snapshot`]. Training data is shared across
+/// sessions and is not included.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvolutionTrackerSnapshot {
+    /// Drift detector state, including its decline counter
+    pub drift_detector: DriftDetector,
+    /// Milestone thresholds, crossings, and events
+    pub milestones: MilestoneTracker,
+    /// Evolution phase at capture time
+    pub current_phase: EvolutionPhase,
+    /// Phase before the current one
+    pub previous_phase: Option<EvolutionPhase>,
+    /// Evolution steps executed at capture time
+    pub total_evolutions: u64,
+    /// Run of stalled steps at capture time
+    #[serde(default)]
+    pub stall_count: u64,
+    /// Each target's convergence history, in target order
+    #[serde(default)]
+    pub target_histories: Vec<Vec<f64>>,
+}
+
 /// Result of a single evolution step
 #[derive(Debug, Clone)]
 pub struct EvolutionStepResult {
@@ -1520,6 +2114,50 @@ mod tests {
         assert_eq!(analysis.phase, EvolutionPhase::Drifting);
     }
 
+    #[test]
+    fn test_drift_detector_projected_iterations() {
+        let mut detector = DriftDetector::new();
+        assert_eq!(detector.projected_iterations_to(0.9), None);
+
+        // +0.05 per iteration from 0.5: eight more to reach 90%
+        detector.analyze(&[0.3, 0.35, 0.4, 0.45, 0.5]);
+        assert_eq!(detector.projected_iterations_to(0.9), Some(8));
+        assert_eq!(detector.projected_iterations_to(0.5), None);
+
+        // A nearly flat trend is capped rather than extrapolated
+        detector.analyze(&[0.5, 0.5, 0.5, 0.5, 0.500001]);
+        assert_eq!(
+            detector.projected_iterations_to(0.9),
+            Some(MAX_PROJECTED_ITERATIONS)
+        );
+
+        detector.analyze(&[0.6, 0.55, 0.5]);
+        assert_eq!(detector.projected_iterations_to(0.9), None);
+    }
+
+    #[test]
+    fn test_drift_detector_oscillating() {
+        let mut detector = DriftDetector::new();
+        // Swings of +/-0.2 around 0.5 with no net trend
+        let history = vec![0.3, 0.7, 0.3, 0.7, 0.3, 0.7, 0.3, 0.7];
+        let analysis = detector.analyze(&history);
+        assert_eq!(analysis.phase, EvolutionPhase::Unstable);
+        assert!(!analysis.is_drifting);
+        assert!(analysis.variance > detector.variance_threshold);
+        assert!(analysis.recommendation.contains("oscillating"));
+
+        // A steady climb has high variance too, but a clear slope
+        let history = vec![0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7];
+        assert_ne!(detector.analyze(&history).phase, EvolutionPhase::Unstable);
+
+        // Recent swings flagged by the wider variance window only; the
+        // slope window sees a flat line either way
+        let history = vec![0.3, 0.7, 0.3, 0.7, 0.3, 0.5, 0.5, 0.5, 0.5, 0.5];
+        assert_eq!(detector.analyze(&history).phase, EvolutionPhase::Unstable);
+        detector.variance_window = 2;
+        assert_eq!(detector.analyze(&history).phase, EvolutionPhase::Learning);
+    }
+
     #[test]
     fn test_drift_detector_converged() {
         let mut detector = DriftDetector::new();
@@ -1569,6 +2207,31 @@ mod tests {
         assert_eq!(threshold_events.len(), 1);
     }
 
+    #[test]
+    fn test_milestone_tracker_custom_thresholds() {
+        let mut tracker = MilestoneTracker::with_thresholds(vec![0.6, 0.3, f64::NAN, 0.6, 0.9]);
+        assert_eq!(tracker.thresholds, vec![0.3, 0.6, 0.9]);
+        assert_eq!(tracker.crossed.len(), 3);
+
+        let fired = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = std::sync::Arc::clone(&fired);
+        tracker.set_on_threshold(move |t| sink.lock().unwrap().push(t));
+        tracker.check(0.65, 1);
+        tracker.check(0.7, 2);
+        assert_eq!(*fired.lock().unwrap(), vec![0.3, 0.6]);
+
+        // Clones and round-trips keep the thresholds but not the callback
+        assert!(tracker.clone().on_threshold.is_none());
+        let json = serde_json::to_string(&tracker).unwrap();
+        let mut restored: MilestoneTracker = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.thresholds, tracker.thresholds);
+        assert_eq!(restored.crossed, vec![true, true, false]);
+        assert!(restored.on_threshold.is_none());
+        restored.check(0.95, 3);
+        assert!(restored.crossed.iter().all(|&c| c));
+        assert_eq!(fired.lock().unwrap().len(), 2);
+    }
+
     #[test]
     fn test_milestone_tracker_iteration() {
         let mut tracker = MilestoneTracker::new();
@@ -1621,6 +2284,29 @@ mod tests {
         assert!(output.contains("Model A"));
         assert!(output.contains("Model B"));
         assert!(output.contains("Legend"));
+        assert!(output.contains("Model A: 5 data points, current 80.0%, max 80.0%"));
+        assert!(!output.contains("Note:"));
+    }
+
+    #[test]
+    fn test_convergence_visualizer_comparison_uneven() {
+        let viz = ConvergenceVisualizer::new(20, 5);
+        let long: Vec<f64> = (0..40).map(|i| i as f64 / 40.0).collect();
+        let output = viz.render_comparison(&long, "Long", &[0.5], "Short");
+        assert!(output.contains("Long: 40 data points"));
+        assert!(output.contains("Short: 1 data point, current 50.0%"));
+        assert!(output.contains("Note: Short fills 1 of 20 columns"));
+
+        // Padding past the short history never draws on the bottom row
+        let bottom = output
+            .lines()
+            .find(|line| line.starts_with("0.0|"))
+            .unwrap();
+        assert!(!bottom[5..].contains('X'));
+        assert!(!bottom[5..].contains('o'));
+
+        let empty = viz.render_comparison(&long, "Long", &[], "Empty");
+        assert!(empty.contains("Empty: no data"));
     }
 
     #[test]
@@ -1644,6 +2330,78 @@ mod tests {
         assert_eq!(high_quality.len(), 1);
     }
 
+    #[test]
+    fn test_training_data_dedup_on_store() {
+        let mut tdm = TrainingDataManager::new();
+        assert!(tdm.store("gpt4o", "What is Rust?", "Rust is a language.", 1));
+        assert!(!tdm.store("gpt4o", "Tell me about Rust", "Rust is a language.", 2));
+        // Same response from another model is not a duplicate
+        assert!(tdm.store("claude", "What is Rust?", "Rust is a language.", 1));
+        assert_eq!(tdm.count("gpt4o"), 1);
+        assert_eq!(tdm.get("gpt4o", None)[0].iteration_observed, 1);
+    }
+
+    #[test]
+    fn test_training_data_weighted_sampling() {
+        use rand::SeedableRng;
+
+        let mut tdm = TrainingDataManager::new();
+        tdm.store("gpt4o", "a", "first response", 1);
+        tdm.store("gpt4o", "b", "second response", 2);
+        tdm.rate("gpt4o", 0, 0.95);
+        tdm.rate("gpt4o", 1, 0.05);
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let weighted = tdm.sample_with(&mut rng, "gpt4o", 200, true);
+        assert_eq!(weighted.len(), 200);
+        let high = weighted
+            .iter()
+            .filter(|o| o.model_response == "first response")
+            .count();
+        assert!(high > 160, "high-quality draws: {}", high);
+
+        let uniform = tdm.sample_with(&mut rng, "gpt4o", 200, false);
+        let high = uniform
+            .iter()
+            .filter(|o| o.model_response == "first response")
+            .count();
+        assert!((60..140).contains(&high), "uniform draws: {}", high);
+
+        assert!(tdm.sample("unknown", 5, true).is_empty());
+        assert_eq!(tdm.get("gpt4o", None).len(), 2);
+    }
+
+    #[test]
+    fn test_training_data_auto_quality() {
+        let mut tdm = TrainingDataManager::new();
+        tdm.store("gpt4o", "p", "", 1);
+        tdm.store("gpt4o", "p", "I can't help with that.", 2);
+        tdm.store(
+            "claude",
+            "Explain ownership",
+            "Ownership is Rust's central memory model. Every value has a single owner, \
+             and when the owner goes out of scope the value is dropped. Borrowing lets \
+             you reference data without taking ownership, enforced at compile time.",
+            3,
+        );
+
+        let low = tdm.get("gpt4o", None);
+        assert!(low[0].quality_score < 0.1);
+        assert!(low[1].quality_score < 0.4);
+        assert!(tdm.get("claude", None)[0].quality_score > 0.8);
+    }
+
+    #[test]
+    fn test_training_data_custom_scorer() {
+        let mut tdm = TrainingDataManager::with_scorer(Box::new(|r| r.len() as f64 / 10.0));
+        tdm.store("test", "p", "abc", 1);
+        tdm.store("test", "p", "a much longer response", 2);
+
+        let obs = tdm.get("test", None);
+        assert!((obs[0].quality_score - 0.3).abs() < 1e-9);
+        assert_eq!(obs[1].quality_score, 1.0); // clamped
+    }
+
     #[test]
     fn test_training_data_eviction() {
         let mut tdm = TrainingDataManager::new();
@@ -1706,7 +2464,13 @@ mod tests {
             1,
         );
 
-        let result = tracker.training_loop("gpt4o", &mut profile, &mut analyzer, 5);
+        let result = tracker.training_loop(
+            "gpt4o",
+            &mut profile,
+            &mut analyzer,
+            5,
+            &mut rand::thread_rng(),
+        );
         assert_eq!(result.iterations_run, 5);
         assert!(!result.convergence_history.is_empty());
         assert!(!result.deltas.is_empty());
@@ -1719,7 +2483,13 @@ mod tests {
         let mut profile = store.get("gpt4o").unwrap().clone();
         let mut analyzer = BehaviorAnalyzer::new();
 
-        let result = tracker.training_loop("gpt4o", &mut profile, &mut analyzer, 5);
+        let result = tracker.training_loop(
+            "gpt4o",
+            &mut profile,
+            &mut analyzer,
+            5,
+            &mut rand::thread_rng(),
+        );
         assert_eq!(result.iterations_run, 0);
     }
 
@@ -1739,6 +2509,32 @@ mod tests {
         assert_eq!(tracker.drift_detector.decline_count, 0); // reset by correction
     }
 
+    #[test]
+    fn test_evolution_tracker_auto_correct_on_drift() {
+        let mut tracker = EvolutionTracker::new();
+        tracker.drift_detector.patience = 1;
+        let history = vec![0.8, 0.75, 0.7, 0.65, 0.6, 0.55, 0.5];
+        let result = tracker.step(&history, 7);
+        assert_eq!(result.drift_analysis.phase, EvolutionPhase::Drifting);
+        // Opt-in: nothing to correct while disabled
+        assert!(!tracker.needs_drift_correction(&result));
+
+        tracker.auto_correct_on_drift = true;
+        assert!(tracker.needs_drift_correction(&result));
+        let advice = tracker.defer_drift_correction();
+        assert!(advice.contains("re-observe"));
+        assert_eq!(tracker.drift_detector.decline_count, 0);
+        assert_eq!(tracker.total_corrections, 0);
+
+        // The flag defaults off for trackers saved before it existed
+        let mut json = serde_json::to_value(&tracker).unwrap();
+        json.as_object_mut()
+            .unwrap()
+            .remove("auto_correct_on_drift");
+        let restored: EvolutionTracker = serde_json::from_value(json).unwrap();
+        assert!(!restored.auto_correct_on_drift);
+    }
+
     #[test]
     fn test_evolution_tracker_render_graph() {
         let tracker = EvolutionTracker::new();
@@ -1748,6 +2544,128 @@ mod tests {
         assert!(graph.contains("Current:"));
     }
 
+    #[test]
+    fn test_evolution_tracker_export_history_csv() {
+        let tracker = EvolutionTracker::new();
+        assert_eq!(
+            tracker.export_history_csv(&[]),
+            "iteration,convergence,phase\n"
+        );
+
+        let csv = tracker.export_history_csv(&[0.1, 0.3, 0.6, 0.85]);
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(rows.len(), 5);
+        assert_eq!(rows[1], "0,0.1000,OBSERVATION");
+        assert_eq!(rows[2], "1,0.3000,LEARNING");
+        assert_eq!(rows[3], "2,0.6000,REFINEMENT");
+        assert_eq!(rows[4], "3,0.8500,CONVERGED");
+    }
+
+    #[test]
+    fn test_evolution_tracker_multi_target_convergence() {
+        let store = AiProfileStore::default();
+        let profile = store.get("gpt4o").unwrap().clone();
+        let mut analyzer = BehaviorAnalyzer::new();
+        let casual =
+            analyzer.build_signature("casual", &["Hey! Sure thing, easy fix.".to_string()]);
+        let formal = analyzer.build_signature(
+            "formal",
+            &["Certainly. The following analysis proceeds in three parts.".to_string()],
+        );
+        let casual_score = analyzer.compute_convergence(&profile, &casual);
+        let formal_score = analyzer.compute_convergence(&profile, &formal);
+
+        let mut tracker = EvolutionTracker::new();
+        assert_eq!(tracker.measure_targets(&profile, &analyzer), None);
+        tracker.add_target("casual", casual, 3.0);
+        tracker.add_target("formal", formal, 1.0);
+
+        let combined = tracker.measure_targets(&profile, &analyzer).unwrap();
+        let expected = (casual_score * 3.0 + formal_score) / 4.0;
+        assert!((combined - expected).abs() < 1e-9);
+        tracker.target_combination = TargetCombination::Min;
+        let combined = tracker.measure_targets(&profile, &analyzer).unwrap();
+        assert_eq!(combined, casual_score.min(formal_score));
+        assert_eq!(tracker.targets[0].history.len(), 2);
+
+        // Drift names the target falling away fastest
+        tracker.drift_detector.patience = 1;
+        tracker.targets[0].history = vec![0.8, 0.8, 0.8, 0.8, 0.8];
+        tracker.targets[1].history = vec![0.9, 0.8, 0.7, 0.6, 0.5];
+        let result = tracker.step(&[0.8, 0.75, 0.7, 0.65, 0.6], 5);
+        assert!(result.drift_analysis.is_drifting);
+        assert_eq!(
+            result.drift_analysis.drifting_from.as_deref(),
+            Some("formal")
+        );
+        assert!(result.drift_analysis.recommendation.contains("'formal'"));
+    }
+
+    #[test]
+    fn test_evolution_tracker_convergence_stall() {
+        let mut tracker = EvolutionTracker::new();
+        tracker.stall_patience = 3;
+        let mut history = vec![0.5, 0.72];
+        let mut stalls = Vec::new();
+        for i in 0..6 {
+            history.push(0.72 + 0.0001 * (i % 2) as f64);
+            let result = tracker.step(&history, i);
+            stalls.extend(
+                result
+                    .new_milestones
+                    .into_iter()
+                    .filter(|e| e.milestone_type == MilestoneType::ConvergenceStall),
+            );
+        }
+        // One event per plateau, not one per stalled step
+        assert_eq!(stalls.len(), 1);
+        assert!(stalls[0].description.contains("72.0% for 3 iterations"));
+        assert!(stalls[0].description.contains("fresh observations"));
+        assert_eq!(tracker.stall_count, 6);
+
+        // Movement resets the counter
+        history.push(0.75);
+        tracker.step(&history, 6);
+        assert_eq!(tracker.stall_count, 0);
+
+        // Plateaus at or above the convergence threshold are not stalls
+        history.extend([0.9, 0.9, 0.9, 0.9]);
+        tracker.step(&history, 7);
+        assert_eq!(tracker.stall_count, 0);
+    }
+
+    #[test]
+    fn test_evolution_tracker_snapshot_restore() {
+        let mut tracker = EvolutionTracker::new();
+        tracker.drift_detector.patience = 5;
+        let rising = vec![0.1, 0.3, 0.5, 0.7, 0.9];
+        tracker.step(&rising, 1);
+        tracker.add_target("base", BehaviorSignature::new("base"), 1.0);
+        tracker.targets[0].history = vec![0.4, 0.5];
+        let snapshot = tracker.snapshot();
+        let events = snapshot.milestones.events.len();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let snapshot: EvolutionTrackerSnapshot = serde_json::from_str(&json).unwrap();
+
+        tracker.training_data.store("gpt4o", "p", "r", 1);
+        tracker.step(&[0.9, 0.85, 0.8, 0.75, 0.7], 2);
+        tracker.targets[0].history.push(0.3);
+        tracker.milestones.set_on_threshold(|_| {});
+        assert_eq!(tracker.total_evolutions, 2);
+        assert_eq!(tracker.drift_detector.decline_count, 1);
+
+        tracker.restore(snapshot);
+        assert_eq!(tracker.total_evolutions, 1);
+        assert_eq!(tracker.drift_detector.decline_count, 0);
+        assert_eq!(tracker.drift_detector.patience, 5);
+        assert_eq!(tracker.current_phase, EvolutionPhase::Converged);
+        assert_eq!(tracker.milestones.events.len(), events);
+        assert_eq!(tracker.targets[0].history, vec![0.4, 0.5]);
+        // Shared state survives a restore
+        assert_eq!(tracker.training_data.count("gpt4o"), 1);
+        assert!(tracker.milestones.on_threshold.is_some());
+    }
+
     #[test]
     fn test_evolution_tracker_serialization() {
         let mut tracker = EvolutionTracker::new();
@@ -1930,9 +2848,11 @@ mod tests {
                     drift_analysis: DriftAnalysis {
                         is_drifting: false,
                         trend_slope: 0.1,
+                        variance: 0.0,
                         phase: EvolutionPhase::Learning,
                         current_convergence: 0.7,
                         recommendation: "Continue learning".to_string(),
+                        drifting_from: None,
                     },
                     new_milestones: vec![],
                     phase_changed: false,
@@ -3,6 +3,7 @@
 //! Bridges enable multiplicative confidence amplification by allowing
 //! information to flow in both directions between layers.
 
+use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
 
@@ -98,6 +99,244 @@ impl AmplificationResult {
     }
 }
 
+/// Consecutive rising iterations above `AmplificationLimits::divergence_ceiling`
+/// after which a bridge's amplification is reported as diverging.
+pub const DIVERGENCE_PATIENCE: u32 = 3;
+
+/// Damping and caps applied inside a bridge's `amplify` loop.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AmplificationLimits {
+    /// Fraction of each iteration's confidence gain that is kept
+    /// (1.0 = undamped, 0.0 = frozen).
+    pub damping: f32,
+    /// Upper bound on each side's refined confidence.
+    pub max_state_confidence: f32,
+    /// Hard cap on the reported `combined_confidence`.
+    pub max_combined_confidence: f32,
+    /// Raw combined confidence above which sustained growth counts as
+    /// divergence.
+    pub divergence_ceiling: f32,
+}
+
+impl Default for AmplificationLimits {
+    fn default() -> Self {
+        Self::with_state_cap(2.0)
+    }
+}
+
+impl AmplificationLimits {
+    /// Undamped limits derived from a per-side state cap.
+    ///
+    /// The combined cap is the largest product the state caps allow, and
+    /// the divergence ceiling is half of it, so a loop that keeps climbing
+    /// towards its caps is reported before they pin it.
+    pub fn with_state_cap(max_state_confidence: f32) -> Self {
+        let max_combined_confidence = max_state_confidence * max_state_confidence;
+        Self {
+            damping: 1.0,
+            max_state_confidence,
+            max_combined_confidence,
+            divergence_ceiling: max_combined_confidence / 2.0,
+        }
+    }
+
+    /// Limits with the given damping factor, clamped to 0.0..=1.0.
+    pub fn damped(damping: f32) -> Self {
+        Self {
+            damping: damping.clamp(0.0, 1.0),
+            ..Self::default()
+        }
+    }
+}
+
+/// Per-call helper that applies `AmplificationLimits`, watches the
+/// combined confidence for runaway growth and builds the final
+/// `AmplificationResult`.
+#[derive(Debug, Clone)]
+pub struct AmplificationGuard {
+    limits: AmplificationLimits,
+    previous: Option<f32>,
+    rising: u32,
+    clipped: bool,
+    iterations: u32,
+    diverged: bool,
+    settled: bool,
+}
+
+impl AmplificationGuard {
+    /// Create a guard for one `amplify` call.
+    pub fn new(limits: AmplificationLimits) -> Self {
+        Self {
+            limits,
+            previous: None,
+            rising: 0,
+            clipped: false,
+            iterations: 0,
+            diverged: false,
+            settled: false,
+        }
+    }
+
+    /// The limits this guard enforces.
+    pub fn limits(&self) -> &AmplificationLimits {
+        &self.limits
+    }
+
+    /// Scale a confidence gain by the damping factor.
+    pub fn damp(&self, gain: f32) -> f32 {
+        gain * self.limits.damping
+    }
+
+    /// Clamp a refined confidence to `max_state_confidence`.
+    pub fn cap_state(&mut self, confidence: f32) -> f32 {
+        if confidence > self.limits.max_state_confidence {
+            self.clipped = true;
+            return self.limits.max_state_confidence;
+        }
+        confidence
+    }
+
+    /// Add a damped `gain` to `confidence` and cap the result.
+    pub fn step(&mut self, confidence: f32, gain: f32) -> f32 {
+        self.cap_state(confidence + self.damp(gain))
+    }
+
+    /// Clamp a combined confidence to `max_combined_confidence`.
+    pub fn cap_combined(&self, combined: f32) -> f32 {
+        combined.min(self.limits.max_combined_confidence)
+    }
+
+    /// Record one iteration's raw combined confidence.
+    ///
+    /// Returns true once the value is non-finite, or has risen while above
+    /// the divergence ceiling on `DIVERGENCE_PATIENCE` consecutive
+    /// iterations or on one where a state cap had to clip it.
+    pub fn diverging(&mut self, combined: f32) -> bool {
+        self.iterations += 1;
+        if !combined.is_finite() {
+            self.diverged = true;
+            return true;
+        }
+        let rising = self.previous.is_some_and(|previous| combined > previous);
+        self.previous = Some(combined);
+        if rising && combined > self.limits.divergence_ceiling {
+            self.rising += 1;
+        } else {
+            self.rising = 0;
+        }
+        let clipped = std::mem::take(&mut self.clipped);
+        self.diverged = self.rising >= DIVERGENCE_PATIENCE || (self.rising > 0 && clipped);
+        self.diverged
+    }
+
+    /// Record one iteration and report whether the loop should stop.
+    ///
+    /// `settled` is the bridge's own convergence test for this iteration;
+    /// it only counts when the combined confidence is not diverging.
+    pub fn should_stop(&mut self, combined: f32, settled: bool) -> bool {
+        if self.diverging(combined) {
+            return true;
+        }
+        self.settled = settled;
+        settled
+    }
+
+    /// Iterations recorded so far.
+    pub fn iterations(&self) -> u32 {
+        self.iterations
+    }
+
+    /// Whether the loop stopped on a settled iteration.
+    pub fn converged(&self) -> bool {
+        self.settled
+    }
+
+    /// Whether the loop stopped because the combined confidence diverged.
+    pub fn diverged(&self) -> bool {
+        self.diverged
+    }
+
+    /// Build the result of the `amplify` call this guard watched.
+    ///
+    /// `combined` is the raw combined confidence of the final states; it
+    /// is reported capped, alongside the recorded iterations and outcome.
+    pub fn finish(
+        &self,
+        up_state: LayerState,
+        down_state: LayerState,
+        combined: f32,
+        amplification_factor: f32,
+        resonance: f32,
+    ) -> AmplificationResult {
+        AmplificationResult {
+            up_state,
+            down_state,
+            combined_confidence: self.cap_combined(combined),
+            amplification_factor,
+            iterations: self.iterations,
+            converged: self.converged(),
+            resonance,
+        }
+    }
+}
+
+/// Largest change in any layer's confidence between two network rounds
+/// that still counts as a fixpoint.
+pub const NETWORK_CONVERGENCE_EPSILON: f32 = 1e-4;
+
+/// Layer confidence above which network amplification is reported as
+/// diverged. Well-behaved bridges cap their states at 2.0.
+pub const NETWORK_DIVERGENCE_CEILING: f32 = 10.0;
+
+/// How a network-wide amplification run ended.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NetworkConvergence {
+    /// Every layer's confidence stabilized.
+    Converged,
+    /// A layer's confidence became non-finite or passed
+    /// `NETWORK_DIVERGENCE_CEILING`; iteration stopped at that round.
+    Diverged { layer: Layer, confidence: f32 },
+    /// The iteration budget ran out before a fixpoint was reached.
+    MaxIterations,
+}
+
+/// Result of [`BridgeNetwork::amplify_network`].
+#[derive(Debug, Clone)]
+pub struct NetworkAmplificationResult {
+    /// Final state of every layer that took part.
+    pub states: HashMap<Layer, LayerState>,
+    /// Final confidence per layer, in layer order.
+    pub layer_confidences: Vec<(Layer, f32)>,
+    /// Multiplicative combination of the final layer confidences.
+    pub combined_confidence: f32,
+    /// Combined confidence after each round.
+    pub history: Vec<f32>,
+    /// Number of rounds run.
+    pub iterations: u32,
+    /// Why iteration stopped.
+    pub status: NetworkConvergence,
+}
+
+impl NetworkAmplificationResult {
+    /// Whether the network reached a fixpoint.
+    pub fn converged(&self) -> bool {
+        self.status == NetworkConvergence::Converged
+    }
+
+    /// Whether confidence grew without bound.
+    pub fn diverged(&self) -> bool {
+        matches!(self.status, NetworkConvergence::Diverged { .. })
+    }
+
+    /// Final confidence of `layer`, if it took part.
+    pub fn confidence(&self, layer: Layer) -> Option<f32> {
+        self.layer_confidences
+            .iter()
+            .find(|(l, _)| *l == layer)
+            .map(|(_, c)| *c)
+    }
+}
+
 /// Core trait for bidirectional bridges between layers.
 ///
 /// Bridges enable the multiplicative integration system by allowing
@@ -128,12 +367,28 @@ pub trait BidirectionalBridge: Send + Sync {
     /// Perform multiplicative amplification between the two layers.
     ///
     /// This is the core mechanism that allows confidence to exceed
-    /// the bounds of individual layer inputs.
+    /// the bounds of individual layer inputs. Runs `amplify_with` under a
+    /// guard built from `amplification_limits`.
     fn amplify(
         &self,
         up: &LayerState,
         down: &LayerState,
         max_iterations: u32,
+    ) -> BridgeResult<AmplificationResult> {
+        let guard = AmplificationGuard::new(self.amplification_limits());
+        self.amplify_with(up, down, max_iterations, guard)
+    }
+
+    /// The amplification loop, run under `guard`.
+    ///
+    /// Implementations step their confidences through the guard, stop when
+    /// it says so and return `guard.finish(..)`.
+    fn amplify_with(
+        &self,
+        up: &LayerState,
+        down: &LayerState,
+        max_iterations: u32,
+        guard: AmplificationGuard,
     ) -> BridgeResult<AmplificationResult>;
 
     /// Returns the current resonance (coupling strength) of this bridge.
@@ -143,6 +398,11 @@ pub trait BidirectionalBridge: Send + Sync {
     /// but can exceed 1.0 for highly resonant states.
     fn resonance(&self) -> f32;
 
+    /// Damping and caps this bridge applies during `amplify`.
+    fn amplification_limits(&self) -> AmplificationLimits {
+        AmplificationLimits::default()
+    }
+
     /// Check if this bridge is currently active.
     fn is_active(&self) -> bool {
         true
@@ -163,6 +423,85 @@ pub trait BidirectionalBridge: Send + Sync {
                 .with_resonance(self.resonance())
         }
     }
+
+    /// Run this bridge under custom amplification limits.
+    fn with_limits(self, limits: AmplificationLimits) -> LimitedBridge<Self>
+    where
+        Self: Sized,
+    {
+        LimitedBridge {
+            bridge: self,
+            limits,
+        }
+    }
+}
+
+/// A bridge that amplifies under custom `AmplificationLimits`.
+///
+/// Created by [`BidirectionalBridge::with_limits`]; everything except the
+/// limits is delegated to the wrapped bridge.
+pub struct LimitedBridge<B> {
+    bridge: B,
+    limits: AmplificationLimits,
+}
+
+impl<B> LimitedBridge<B> {
+    /// The wrapped bridge.
+    pub fn inner(&self) -> &B {
+        &self.bridge
+    }
+}
+
+impl<B: BidirectionalBridge> BidirectionalBridge for LimitedBridge<B> {
+    fn name(&self) -> &str {
+        self.bridge.name()
+    }
+
+    fn source_layer(&self) -> Layer {
+        self.bridge.source_layer()
+    }
+
+    fn target_layer(&self) -> Layer {
+        self.bridge.target_layer()
+    }
+
+    fn forward(&self, input: &LayerState) -> BridgeResult<LayerState> {
+        self.bridge.forward(input)
+    }
+
+    fn backward(&self, feedback: &LayerState) -> BridgeResult<LayerState> {
+        self.bridge.backward(feedback)
+    }
+
+    fn amplify_with(
+        &self,
+        up: &LayerState,
+        down: &LayerState,
+        max_iterations: u32,
+        guard: AmplificationGuard,
+    ) -> BridgeResult<AmplificationResult> {
+        self.bridge.amplify_with(up, down, max_iterations, guard)
+    }
+
+    fn resonance(&self) -> f32 {
+        self.bridge.resonance()
+    }
+
+    fn amplification_limits(&self) -> AmplificationLimits {
+        self.limits
+    }
+
+    fn is_active(&self) -> bool {
+        self.bridge.is_active()
+    }
+
+    fn reinforce(&mut self, result: &AmplificationResult) {
+        self.bridge.reinforce(result);
+    }
+
+    fn create_signal(&self, state: LayerState, forward: bool) -> LayerSignal {
+        self.bridge.create_signal(state, forward)
+    }
 }
 
 /// A bridge connection between two specific layers.
@@ -292,6 +631,111 @@ impl BridgeNetwork {
         self.global_amplification
     }
 
+    /// Iterate every registered bridge to a network-wide fixpoint.
+    ///
+    /// Each round runs one amplification step of each bridge whose two
+    /// layers have a state, in registration order, feeding the updated
+    /// states to later bridges. Iteration stops when no layer's confidence
+    /// moves by more than `NETWORK_CONVERGENCE_EPSILON`, when a confidence
+    /// diverges, or after `max_iterations` rounds. The combined confidence
+    /// comes from [`compute_multiplicative_confidence`] with each layer's
+    /// mean bridge resonance.
+    pub fn amplify_network(
+        &self,
+        inputs: &[LayerState],
+        max_iterations: u32,
+    ) -> BridgeResult<NetworkAmplificationResult> {
+        let mut states: HashMap<Layer, LayerState> =
+            inputs.iter().map(|s| (s.layer, s.clone())).collect();
+        let mut layers: Vec<Layer> = states.keys().copied().collect();
+        layers.sort_by_key(|l| l.number());
+
+        let resonances: Vec<f32> = layers
+            .iter()
+            .map(|&layer| {
+                let touching: Vec<f32> = self
+                    .bridges
+                    .iter()
+                    .filter(|b| b.source_layer() == layer || b.target_layer() == layer)
+                    .map(|b| b.resonance())
+                    .collect();
+                if touching.is_empty() {
+                    1.0
+                } else {
+                    touching.iter().sum::<f32>() / touching.len() as f32
+                }
+            })
+            .collect();
+        let confidences = |states: &HashMap<Layer, LayerState>| -> Vec<f32> {
+            layers.iter().map(|l| states[l].confidence).collect()
+        };
+
+        let mut previous = confidences(&states);
+        let mut history = Vec::new();
+        let mut iterations = 0;
+        let mut status = NetworkConvergence::MaxIterations;
+
+        while iterations < max_iterations {
+            iterations += 1;
+            for bridge in &self.bridges {
+                let (source, target) = (bridge.source_layer(), bridge.target_layer());
+                let (Some(up), Some(down)) = (states.get(&source), states.get(&target)) else {
+                    continue;
+                };
+                let result = bridge.amplify(up, down, 1)?;
+                states.insert(source, result.up_state);
+                states.insert(target, result.down_state);
+            }
+
+            let current = confidences(&states);
+            history.push(compute_multiplicative_confidence(
+                &current,
+                &resonances,
+                self.global_amplification,
+            ));
+
+            if let Some((i, &confidence)) = current
+                .iter()
+                .enumerate()
+                .find(|(_, c)| !c.is_finite() || **c > NETWORK_DIVERGENCE_CEILING)
+            {
+                status = NetworkConvergence::Diverged {
+                    layer: layers[i],
+                    confidence,
+                };
+                break;
+            }
+
+            let max_change = current
+                .iter()
+                .zip(&previous)
+                .map(|(c, p)| (c - p).abs())
+                .fold(0.0f32, f32::max);
+            previous = current;
+            if max_change < NETWORK_CONVERGENCE_EPSILON {
+                status = NetworkConvergence::Converged;
+                break;
+            }
+        }
+
+        let final_confidences = confidences(&states);
+        let combined_confidence = history.last().copied().unwrap_or_else(|| {
+            compute_multiplicative_confidence(
+                &final_confidences,
+                &resonances,
+                self.global_amplification,
+            )
+        });
+        Ok(NetworkAmplificationResult {
+            layer_confidences: layers.iter().copied().zip(final_confidences).collect(),
+            states,
+            combined_confidence,
+            history,
+            iterations,
+            status,
+        })
+    }
+
     /// Propagate a signal through all connected bridges.
     pub fn propagate(&self, signal: LayerSignal) -> Vec<BridgeResult<LayerState>> {
         let target_bridges = self.bridges_for_layer(signal.target);
@@ -356,8 +800,73 @@ pub fn compute_multiplicative_confidence(
     product.powf(1.0 / n) * amplification_factor
 }
 
+/// Fixtures shared by the layer tests.
+#[cfg(test)]
+pub mod testing {
+    use super::*;
+
+    /// Bridge that moves states between its layers scaling confidence by
+    /// `gain` on the way forward, and multiplies both confidences by
+    /// `amplification` on each `amplify` step, without any cap.
+    pub struct ScalingBridge {
+        pub source: Layer,
+        pub target: Layer,
+        pub gain: f32,
+        pub amplification: f32,
+    }
+
+    impl BidirectionalBridge for ScalingBridge {
+        fn name(&self) -> &str {
+            "scaling"
+        }
+
+        fn source_layer(&self) -> Layer {
+            self.source
+        }
+
+        fn target_layer(&self) -> Layer {
+            self.target
+        }
+
+        fn forward(&self, input: &LayerState) -> BridgeResult<LayerState> {
+            Ok(LayerState::with_confidence(
+                self.target,
+                (),
+                input.confidence * self.gain,
+            ))
+        }
+
+        fn backward(&self, feedback: &LayerState) -> BridgeResult<LayerState> {
+            Ok(LayerState::with_confidence(
+                self.source,
+                (),
+                feedback.confidence,
+            ))
+        }
+
+        fn amplify_with(
+            &self,
+            up: &LayerState,
+            down: &LayerState,
+            _max_iterations: u32,
+            _guard: AmplificationGuard,
+        ) -> BridgeResult<AmplificationResult> {
+            let mut up = up.clone();
+            let mut down = down.clone();
+            up.confidence *= self.amplification;
+            down.confidence *= self.amplification;
+            Ok(AmplificationResult::new(up, down))
+        }
+
+        fn resonance(&self) -> f32 {
+            1.0
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::testing::ScalingBridge;
     use super::*;
 
     #[test]
@@ -408,6 +917,81 @@ mod tests {
         assert_eq!(network.total_resonance(), 0.0);
     }
 
+    #[test]
+    fn test_amplify_network_converges() {
+        use super::super::bridges::BridgeBuilder;
+
+        let mut network = BridgeNetwork::new();
+        for bridge in BridgeBuilder::build_all() {
+            network.register(bridge);
+        }
+        let inputs: Vec<LayerState> = Layer::all()
+            .iter()
+            .map(|&layer| LayerState::with_confidence(layer, (), 0.5))
+            .collect();
+
+        let result = network.amplify_network(&inputs, 100).unwrap();
+        assert!(result.converged(), "status {:?}", result.status);
+        assert!(result.iterations < 100);
+        assert_eq!(result.layer_confidences.len(), 7);
+        assert_eq!(result.layer_confidences[0].0, Layer::BasePhysics);
+        assert!(result.confidence(Layer::CrossDomain).unwrap() > 0.5);
+        assert_eq!(result.history.len(), result.iterations as usize);
+        assert_eq!(result.combined_confidence, *result.history.last().unwrap());
+    }
+
+    #[test]
+    fn test_amplify_network_detects_divergence() {
+        let mut network = BridgeNetwork::new();
+        network.register(Arc::new(ScalingBridge {
+            source: Layer::BasePhysics,
+            target: Layer::ExtendedPhysics,
+            gain: 1.0,
+            amplification: 2.0,
+        }));
+        let inputs = [
+            LayerState::with_confidence(Layer::BasePhysics, (), 1.0),
+            LayerState::with_confidence(Layer::ExtendedPhysics, (), 1.0),
+        ];
+
+        let result = network.amplify_network(&inputs, 1000).unwrap();
+        assert!(result.diverged());
+        assert_eq!(result.iterations, 4); // 16 > NETWORK_DIVERGENCE_CEILING
+        assert_eq!(
+            result.status,
+            NetworkConvergence::Diverged {
+                layer: Layer::BasePhysics,
+                confidence: 16.0
+            }
+        );
+
+        // A budget too small for either outcome is reported as such
+        let result = network.amplify_network(&inputs, 2).unwrap();
+        assert_eq!(result.status, NetworkConvergence::MaxIterations);
+        assert_eq!(result.confidence(Layer::ExtendedPhysics), Some(4.0));
+    }
+
+    #[test]
+    fn test_amplification_guard() {
+        let mut guard = AmplificationGuard::new(AmplificationLimits::damped(0.5));
+        assert_eq!(guard.step(1.0, 0.4), 1.2);
+        assert_eq!(guard.step(1.9, 1.0), 2.0);
+        assert_eq!(guard.cap_combined(10.0), 4.0);
+
+        // Growth past the ceiling only counts once it is sustained
+        assert!(!guard.diverging(9.0));
+        assert!(!guard.diverging(10.0));
+        assert!(!guard.diverging(11.0));
+        assert!(guard.diverging(12.0));
+
+        let mut guard = AmplificationGuard::new(AmplificationLimits::default());
+        assert!(!guard.diverging(9.0));
+        assert!(!guard.diverging(10.0));
+        assert!(!guard.diverging(9.5));
+        assert!(!guard.diverging(10.0));
+        assert!(guard.diverging(f32::NAN));
+    }
+
     #[test]
     fn test_amplification_result() {
         let up = LayerState::with_confidence(Layer::BasePhysics, (), 0.8);
@@ -5,7 +5,7 @@
 //! tool use, execution, learning).
 
 use crate::mimicry::layers::bridge::{
-    AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
+    AmplificationGuard, AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
 };
 use crate::mimicry::layers::layer::{Layer, LayerState};
 
@@ -111,38 +111,37 @@ impl BidirectionalBridge for BaseExtendedBridge {
         Ok(self.transform_backward(feedback))
     }
 
-    fn amplify(
+    fn amplify_with(
         &self,
         up: &LayerState,
         down: &LayerState,
         max_iterations: u32,
+        mut guard: AmplificationGuard,
     ) -> BridgeResult<AmplificationResult> {
         let mut up_state = up.clone();
         let mut down_state = down.clone();
-        let mut iterations = 0;
         let convergence_threshold = 0.001;
         let mut previous_combined = 0.0f32;
 
-        for i in 0..max_iterations {
+        for _ in 0..max_iterations {
             // Forward pass: up influences down
             let forward_influence = up_state.confidence * self.resonance * 0.3;
-            down_state.confidence = (down_state.confidence + forward_influence).min(2.0);
+            down_state.confidence = guard.step(down_state.confidence, forward_influence);
 
             // Backward pass: down influences up
             let backward_influence = down_state.confidence * self.resonance * 0.3;
-            up_state.confidence = (up_state.confidence + backward_influence).min(2.0);
+            up_state.confidence = guard.step(up_state.confidence, backward_influence);
 
             // Apply amplification
             let combined = up_state.confidence * down_state.confidence * self.amplification_factor;
 
             // Check convergence
-            if (combined - previous_combined).abs() < convergence_threshold {
-                iterations = i + 1;
+            let settled = (combined - previous_combined).abs() < convergence_threshold;
+            if guard.should_stop(combined, settled) {
                 break;
             }
 
             previous_combined = combined;
-            iterations = i + 1;
 
             up_state.increment_amplification();
             down_state.increment_amplification();
@@ -151,15 +150,13 @@ impl BidirectionalBridge for BaseExtendedBridge {
         let combined_confidence =
             up_state.confidence * down_state.confidence * self.amplification_factor;
 
-        Ok(AmplificationResult {
+        Ok(guard.finish(
             up_state,
             down_state,
             combined_confidence,
-            amplification_factor: self.amplification_factor,
-            iterations,
-            converged: iterations < max_iterations,
-            resonance: self.resonance,
-        })
+            self.amplification_factor,
+            self.resonance,
+        ))
     }
 
     fn resonance(&self) -> f32 {
@@ -3,7 +3,9 @@
 //! This bridge connects multi-agent collaborative learning with
 //! real-time external API validation and feedback.
 
-use super::super::bridge::{AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult};
+use super::super::bridge::{
+    AmplificationGuard, AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
+};
 use super::super::layer::{Layer, LayerState};
 
 /// Bridge between Collaborative Learning (L6) and External APIs (L7).
@@ -96,11 +98,12 @@ impl BidirectionalBridge for CollaborativeExternalBridge {
         Ok(refined)
     }
 
-    fn amplify(
+    fn amplify_with(
         &self,
         up: &LayerState,
         down: &LayerState,
         max_iterations: u32,
+        mut guard: AmplificationGuard,
     ) -> BridgeResult<AmplificationResult> {
         let mut up_conf = up.confidence;
         let mut down_conf = down.confidence;
@@ -109,26 +112,24 @@ impl BidirectionalBridge for CollaborativeExternalBridge {
         for _ in 0..max_iterations.min(6) {
             // External validation amplifies collective confidence
             let validation_resonance = (up_conf * down_conf).sqrt();
-            let external_boost = 1.0 + (validation_resonance * 0.08);
+            let external_boost = 1.0 + guard.damp(validation_resonance * 0.08);
 
-            up_conf *= external_boost;
-            down_conf *= external_boost;
+            up_conf = guard.cap_state(up_conf * external_boost);
+            down_conf = guard.cap_state(down_conf * external_boost);
             total_factor *= external_boost;
 
-            if external_boost < 1.005 {
+            if guard.should_stop(up_conf * down_conf, external_boost < 1.005) {
                 break;
             }
         }
 
-        Ok(AmplificationResult {
-            up_state: LayerState::with_confidence(up.layer, up.data_arc(), up_conf),
-            down_state: LayerState::with_confidence(down.layer, down.data_arc(), down_conf),
-            combined_confidence: up_conf * down_conf,
-            amplification_factor: total_factor,
-            iterations: max_iterations.min(6),
-            converged: true,
-            resonance: self.base_resonance,
-        })
+        Ok(guard.finish(
+            LayerState::with_confidence(up.layer, up.data_arc(), up_conf),
+            LayerState::with_confidence(down.layer, down.data_arc(), down_conf),
+            up_conf * down_conf,
+            total_factor,
+            self.base_resonance,
+        ))
     }
 
     fn resonance(&self) -> f32 {
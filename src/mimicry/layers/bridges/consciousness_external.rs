@@ -3,7 +3,9 @@
 //! This bridge enables intuitive insights to be validated by external
 //! sources and for external data to inform intuition.
 
-use super::super::bridge::{AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult};
+use super::super::bridge::{
+    AmplificationGuard, AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
+};
 use super::super::layer::{Layer, LayerState};
 
 /// Bridge between GAIA Consciousness (L4) and External APIs (L7).
@@ -95,11 +97,12 @@ impl BidirectionalBridge for ConsciousnessExternalBridge {
         Ok(refined)
     }
 
-    fn amplify(
+    fn amplify_with(
         &self,
         up: &LayerState,
         down: &LayerState,
         max_iterations: u32,
+        mut guard: AmplificationGuard,
     ) -> BridgeResult<AmplificationResult> {
         let mut up_conf = up.confidence;
         let mut down_conf = down.confidence;
@@ -108,26 +111,24 @@ impl BidirectionalBridge for ConsciousnessExternalBridge {
         for _ in 0..max_iterations.min(6) {
             // Grounded intuition develops
             let grounding_resonance = (up_conf * down_conf).sqrt();
-            let grounding_boost = 1.0 + (grounding_resonance * 0.09);
+            let grounding_boost = 1.0 + guard.damp(grounding_resonance * 0.09);
 
-            up_conf *= grounding_boost;
-            down_conf *= grounding_boost;
+            up_conf = guard.cap_state(up_conf * grounding_boost);
+            down_conf = guard.cap_state(down_conf * grounding_boost);
             total_factor *= grounding_boost;
 
-            if grounding_boost < 1.005 {
+            if guard.should_stop(up_conf * down_conf, grounding_boost < 1.005) {
                 break;
             }
         }
 
-        Ok(AmplificationResult {
-            up_state: LayerState::with_confidence(up.layer, up.data_arc(), up_conf),
-            down_state: LayerState::with_confidence(down.layer, down.data_arc(), down_conf),
-            combined_confidence: up_conf * down_conf,
-            amplification_factor: total_factor,
-            iterations: max_iterations.min(6),
-            converged: true,
-            resonance: self.base_resonance,
-        })
+        Ok(guard.finish(
+            LayerState::with_confidence(up.layer, up.data_arc(), up_conf),
+            LayerState::with_confidence(down.layer, down.data_arc(), down_conf),
+            up_conf * down_conf,
+            total_factor,
+            self.base_resonance,
+        ))
     }
 
     fn resonance(&self) -> f32 {
@@ -3,7 +3,9 @@
 //! This bridge connects intuitive pattern recognition with linguistic
 //! perspective and translation capabilities.
 
-use super::super::bridge::{AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult};
+use super::super::bridge::{
+    AmplificationGuard, AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
+};
 use super::super::layer::{Layer, LayerState};
 
 /// Bridge between GAIA Consciousness (L4) and Multilingual Processing (L5).
@@ -89,11 +91,12 @@ impl BidirectionalBridge for ConsciousnessLanguageBridge {
         Ok(refined)
     }
 
-    fn amplify(
+    fn amplify_with(
         &self,
         up: &LayerState,
         down: &LayerState,
         max_iterations: u32,
+        mut guard: AmplificationGuard,
     ) -> BridgeResult<AmplificationResult> {
         let mut up_conf = up.confidence;
         let mut down_conf = down.confidence;
@@ -102,27 +105,25 @@ impl BidirectionalBridge for ConsciousnessLanguageBridge {
         for _ in 0..max_iterations.min(8) {
             // Cross-cultural intuition emerges from the interaction
             let cultural_resonance = (up_conf * down_conf).sqrt();
-            let boost = 1.0 + (cultural_resonance * 0.12);
+            let boost = 1.0 + guard.damp(cultural_resonance * 0.12);
 
-            up_conf *= boost;
-            down_conf *= boost;
+            up_conf = guard.cap_state(up_conf * boost);
+            down_conf = guard.cap_state(down_conf * boost);
             total_factor *= boost;
 
             // Check convergence
-            if boost < 1.01 {
+            if guard.should_stop(up_conf * down_conf, boost < 1.01) {
                 break;
             }
         }
 
-        Ok(AmplificationResult {
-            up_state: LayerState::with_confidence(up.layer, up.data_arc(), up_conf),
-            down_state: LayerState::with_confidence(down.layer, down.data_arc(), down_conf),
-            combined_confidence: up_conf * down_conf,
-            amplification_factor: total_factor,
-            iterations: max_iterations.min(8),
-            converged: true,
-            resonance: self.base_resonance,
-        })
+        Ok(guard.finish(
+            LayerState::with_confidence(up.layer, up.data_arc(), up_conf),
+            LayerState::with_confidence(down.layer, down.data_arc(), down_conf),
+            up_conf * down_conf,
+            total_factor,
+            self.base_resonance,
+        ))
     }
 
     fn resonance(&self) -> f32 {
@@ -4,7 +4,7 @@
 //! Enables emergence and composition pattern recognition.
 
 use crate::mimicry::layers::bridge::{
-    AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
+    AmplificationGuard, AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
 };
 use crate::mimicry::layers::layer::{Layer, LayerState};
 
@@ -116,24 +116,25 @@ impl BidirectionalBridge for CrossDomainBridge {
         Ok(self.transform_backward(feedback))
     }
 
-    fn amplify(
+    fn amplify_with(
         &self,
         up: &LayerState,
         down: &LayerState,
         max_iterations: u32,
+        mut guard: AmplificationGuard,
     ) -> BridgeResult<AmplificationResult> {
         let mut up_state = up.clone();
         let mut down_state = down.clone();
         let mut previous_combined = 0.0f32;
 
-        for i in 0..max_iterations {
+        for _ in 0..max_iterations {
             // Emergence amplification
             let emergence_boost = if up_state.confidence > self.emergence_threshold {
                 0.1 * self.resonance
             } else {
                 0.05 * self.resonance
             };
-            down_state.confidence = (down_state.confidence + emergence_boost).min(2.0);
+            down_state.confidence = guard.step(down_state.confidence, emergence_boost);
 
             // Composition feedback
             let composition_boost = if down_state.confidence > self.composition_threshold {
@@ -141,20 +142,12 @@ impl BidirectionalBridge for CrossDomainBridge {
             } else {
                 0.05 * self.resonance
             };
-            up_state.confidence = (up_state.confidence + composition_boost).min(2.0);
+            up_state.confidence = guard.step(up_state.confidence, composition_boost);
 
             let combined = up_state.confidence * down_state.confidence * self.amplification_factor;
 
-            if (combined - previous_combined).abs() < 0.001 {
-                return Ok(AmplificationResult {
-                    up_state,
-                    down_state,
-                    combined_confidence: combined,
-                    amplification_factor: self.amplification_factor,
-                    iterations: i + 1,
-                    converged: true,
-                    resonance: self.resonance,
-                });
+            if guard.should_stop(combined, (combined - previous_combined).abs() < 0.001) {
+                break;
             }
 
             previous_combined = combined;
@@ -163,15 +156,13 @@ impl BidirectionalBridge for CrossDomainBridge {
         }
 
         let combined = up_state.confidence * down_state.confidence * self.amplification_factor;
-        Ok(AmplificationResult {
+        Ok(guard.finish(
             up_state,
             down_state,
-            combined_confidence: combined,
-            amplification_factor: self.amplification_factor,
-            iterations: max_iterations,
-            converged: false,
-            resonance: self.resonance,
-        })
+            combined,
+            self.amplification_factor,
+            self.resonance,
+        ))
     }
 
     fn resonance(&self) -> f32 {
@@ -182,6 +173,7 @@ impl BidirectionalBridge for CrossDomainBridge {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mimicry::layers::bridge::AmplificationLimits;
 
     #[test]
     fn test_cross_domain_bridge() {
@@ -204,4 +196,56 @@ mod tests {
         let result = bridge.forward(&low_conf).unwrap();
         assert!(result.confidence < 0.4); // Slight reduction
     }
+
+    #[test]
+    fn test_amplification_limits() {
+        let up = LayerState::with_confidence(Layer::ExtendedPhysics, (), 0.9);
+        let down = LayerState::with_confidence(Layer::CrossDomain, (), 0.9);
+
+        // Under the default limits, growth that keeps climbing towards the
+        // caps is reported as divergence before they pin it
+        let defaults = AmplificationLimits::default();
+        let runaway = CrossDomainBridge::new().amplify(&up, &down, 100).unwrap();
+        assert!(!runaway.converged);
+        assert!(runaway.iterations < 100);
+        assert!(runaway.combined_confidence > defaults.divergence_ceiling);
+        assert!(runaway.up_state.confidence < defaults.max_state_confidence);
+
+        // With the ceiling lifted the same run settles at the caps
+        let lifted = AmplificationLimits {
+            divergence_ceiling: f32::INFINITY,
+            ..defaults
+        };
+        let pinned = CrossDomainBridge::new()
+            .with_limits(lifted)
+            .amplify(&up, &down, 100)
+            .unwrap();
+        assert!(pinned.converged);
+        assert_eq!(pinned.up_state.confidence, 2.0);
+        assert_eq!(pinned.down_state.confidence, 2.0);
+        assert_eq!(pinned.combined_confidence, 4.0);
+
+        // Damping halves each iteration's gain
+        let fast = CrossDomainBridge::new().amplify(&up, &down, 3).unwrap();
+        let damped = CrossDomainBridge::new()
+            .with_limits(AmplificationLimits::damped(0.5))
+            .amplify(&up, &down, 3)
+            .unwrap();
+        let gain = |r: &AmplificationResult| r.up_state.confidence - 0.9;
+        assert!((gain(&damped) - gain(&fast) / 2.0).abs() < 1e-4);
+
+        // Without caps the confidence runs away until the guard stops it
+        let uncapped = AmplificationLimits {
+            max_state_confidence: f32::INFINITY,
+            max_combined_confidence: f32::INFINITY,
+            ..AmplificationLimits::default()
+        };
+        let result = CrossDomainBridge::new()
+            .with_limits(uncapped)
+            .amplify(&up, &down, 100)
+            .unwrap();
+        assert!(!result.converged);
+        assert!(result.iterations < 100);
+        assert!(result.combined_confidence > uncapped.divergence_ceiling);
+    }
 }
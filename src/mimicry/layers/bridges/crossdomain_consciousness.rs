@@ -3,7 +3,9 @@
 //! This bridge connects emergent cross-domain patterns with
 //! intuitive consciousness processing.
 
-use super::super::bridge::{AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult};
+use super::super::bridge::{
+    AmplificationGuard, AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
+};
 use super::super::layer::{Layer, LayerState};
 
 /// Bridge between Cross-Domain (L3) and GAIA Consciousness (L4).
@@ -89,11 +91,12 @@ impl BidirectionalBridge for CrossDomainConsciousnessBridge {
         Ok(refined)
     }
 
-    fn amplify(
+    fn amplify_with(
         &self,
         up: &LayerState,
         down: &LayerState,
         max_iterations: u32,
+        mut guard: AmplificationGuard,
     ) -> BridgeResult<AmplificationResult> {
         let mut up_conf = up.confidence;
         let mut down_conf = down.confidence;
@@ -102,26 +105,24 @@ impl BidirectionalBridge for CrossDomainConsciousnessBridge {
         for _ in 0..max_iterations.min(10) {
             // Deep emergent intuition develops through resonance
             let emergent_resonance = (up_conf * down_conf).sqrt();
-            let intuition_boost = 1.0 + (emergent_resonance * 0.15);
+            let intuition_boost = 1.0 + guard.damp(emergent_resonance * 0.15);
 
-            up_conf *= intuition_boost;
-            down_conf *= intuition_boost;
+            up_conf = guard.cap_state(up_conf * intuition_boost);
+            down_conf = guard.cap_state(down_conf * intuition_boost);
             total_factor *= intuition_boost;
 
-            if intuition_boost < 1.01 {
+            if guard.should_stop(up_conf * down_conf, intuition_boost < 1.01) {
                 break;
             }
         }
 
-        Ok(AmplificationResult {
-            up_state: LayerState::with_confidence(up.layer, up.data_arc(), up_conf),
-            down_state: LayerState::with_confidence(down.layer, down.data_arc(), down_conf),
-            combined_confidence: up_conf * down_conf,
-            amplification_factor: total_factor,
-            iterations: max_iterations.min(10),
-            converged: true,
-            resonance: self.base_resonance,
-        })
+        Ok(guard.finish(
+            LayerState::with_confidence(up.layer, up.data_arc(), up_conf),
+            LayerState::with_confidence(down.layer, down.data_arc(), down_conf),
+            up_conf * down_conf,
+            total_factor,
+            self.base_resonance,
+        ))
     }
 
     fn resonance(&self) -> f32 {
@@ -132,6 +133,7 @@ impl BidirectionalBridge for CrossDomainConsciousnessBridge {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mimicry::layers::bridge::AmplificationLimits;
 
     #[test]
     fn test_bridge_creation() {
@@ -163,4 +165,38 @@ mod tests {
         // This bridge should provide strong amplification
         assert!(result.amplification_factor > 1.3);
     }
+
+    #[test]
+    fn test_amplification_limits() {
+        let up = LayerState::with_confidence(Layer::CrossDomain, (), 0.9);
+        let down = LayerState::with_confidence(Layer::GaiaConsciousness, (), 0.9);
+
+        let undamped = CrossDomainConsciousnessBridge::new()
+            .amplify(&up, &down, 10)
+            .unwrap();
+        // Compounding boosts climb past the default divergence ceiling
+        assert!(!undamped.converged);
+        assert!(undamped.iterations < 10);
+        assert!(undamped.up_state.confidence <= 2.0);
+        assert!(undamped.combined_confidence <= 4.0);
+
+        let damped = CrossDomainConsciousnessBridge::new()
+            .with_limits(AmplificationLimits::damped(0.5))
+            .amplify(&up, &down, 10)
+            .unwrap();
+        assert!(damped.amplification_factor < undamped.amplification_factor);
+
+        // Without caps the confidence runs away until the guard stops it
+        let uncapped = AmplificationLimits {
+            max_state_confidence: f32::INFINITY,
+            max_combined_confidence: f32::INFINITY,
+            ..AmplificationLimits::default()
+        };
+        let result = CrossDomainConsciousnessBridge::new()
+            .with_limits(uncapped)
+            .amplify(&up, &down, 10)
+            .unwrap();
+        assert!(!result.converged);
+        assert!(result.iterations < 10);
+    }
 }
@@ -4,10 +4,13 @@
 //! Enables social amplification of patterns and collective intelligence.
 
 use crate::mimicry::layers::bridge::{
-    AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
+    AmplificationGuard, AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
 };
 use crate::mimicry::layers::layer::{Layer, LayerState};
 
+/// How far the collective side may exceed the state confidence cap.
+const SOCIAL_HEADROOM: f32 = 0.5;
+
 /// Bridge between Cross-Domain (L3) and Collaborative Learning (L6).
 pub struct IndividualCollectiveBridge {
     resonance: f32,
@@ -97,37 +100,32 @@ impl BidirectionalBridge for IndividualCollectiveBridge {
         Ok(self.transform_backward(feedback))
     }
 
-    fn amplify(
+    fn amplify_with(
         &self,
         up: &LayerState,
         down: &LayerState,
         max_iterations: u32,
+        mut guard: AmplificationGuard,
     ) -> BridgeResult<AmplificationResult> {
         let mut up_state = up.clone();
         let mut down_state = down.clone();
         let mut previous_combined = 0.0f32;
 
-        for i in 0..max_iterations {
+        for _ in 0..max_iterations {
             // Individual → Collective: social amplification
             let social_boost = up_state.confidence * self.social_amplification * 0.2;
-            down_state.confidence = (down_state.confidence + social_boost).min(2.5); // Higher cap for social
+            // Higher cap for social
+            down_state.confidence = (down_state.confidence + guard.damp(social_boost))
+                .min(guard.limits().max_state_confidence + SOCIAL_HEADROOM);
 
             // Collective → Individual: wisdom feedback
             let wisdom_boost = down_state.confidence * 0.25;
-            up_state.confidence = (up_state.confidence + wisdom_boost).min(2.0);
+            up_state.confidence = guard.step(up_state.confidence, wisdom_boost);
 
             let combined = up_state.confidence * down_state.confidence * self.amplification_factor;
 
-            if (combined - previous_combined).abs() < 0.001 {
-                return Ok(AmplificationResult {
-                    up_state,
-                    down_state,
-                    combined_confidence: combined,
-                    amplification_factor: self.amplification_factor,
-                    iterations: i + 1,
-                    converged: true,
-                    resonance: self.resonance,
-                });
+            if guard.should_stop(combined, (combined - previous_combined).abs() < 0.001) {
+                break;
             }
 
             previous_combined = combined;
@@ -136,15 +134,13 @@ impl BidirectionalBridge for IndividualCollectiveBridge {
         }
 
         let combined = up_state.confidence * down_state.confidence * self.amplification_factor;
-        Ok(AmplificationResult {
+        Ok(guard.finish(
             up_state,
             down_state,
-            combined_confidence: combined,
-            amplification_factor: self.amplification_factor,
-            iterations: max_iterations,
-            converged: false,
-            resonance: self.resonance,
-        })
+            combined,
+            self.amplification_factor,
+            self.resonance,
+        ))
     }
 
     fn resonance(&self) -> f32 {
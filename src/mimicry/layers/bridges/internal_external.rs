@@ -4,7 +4,7 @@
 //! Enables real-time external verification and feedback loops.
 
 use crate::mimicry::layers::bridge::{
-    AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
+    AmplificationGuard, AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
 };
 use crate::mimicry::layers::layer::{Layer, LayerState};
 
@@ -104,20 +104,21 @@ impl BidirectionalBridge for InternalExternalBridge {
         Ok(self.transform_backward(feedback))
     }
 
-    fn amplify(
+    fn amplify_with(
         &self,
         up: &LayerState,
         down: &LayerState,
         max_iterations: u32,
+        mut guard: AmplificationGuard,
     ) -> BridgeResult<AmplificationResult> {
         let mut up_state = up.clone();
         let mut down_state = down.clone();
         let mut previous_combined = 0.0f32;
 
-        for i in 0..max_iterations {
+        for _ in 0..max_iterations {
             // Internal → External: request validation
             let internal_influence = up_state.confidence * 0.1;
-            down_state.confidence = (down_state.confidence + internal_influence).min(2.0);
+            down_state.confidence = guard.step(down_state.confidence, internal_influence);
 
             // External → Internal: validation feedback
             let validation_boost = if down_state.confidence > self.validation_threshold {
@@ -125,20 +126,12 @@ impl BidirectionalBridge for InternalExternalBridge {
             } else {
                 down_state.confidence * 0.05
             };
-            up_state.confidence = (up_state.confidence + validation_boost).min(2.0);
+            up_state.confidence = guard.step(up_state.confidence, validation_boost);
 
             let combined = up_state.confidence * down_state.confidence * self.amplification_factor;
 
-            if (combined - previous_combined).abs() < 0.001 {
-                return Ok(AmplificationResult {
-                    up_state,
-                    down_state,
-                    combined_confidence: combined,
-                    amplification_factor: self.amplification_factor,
-                    iterations: i + 1,
-                    converged: true,
-                    resonance: self.resonance,
-                });
+            if guard.should_stop(combined, (combined - previous_combined).abs() < 0.001) {
+                break;
             }
 
             previous_combined = combined;
@@ -147,15 +140,13 @@ impl BidirectionalBridge for InternalExternalBridge {
         }
 
         let combined = up_state.confidence * down_state.confidence * self.amplification_factor;
-        Ok(AmplificationResult {
+        Ok(guard.finish(
             up_state,
             down_state,
-            combined_confidence: combined,
-            amplification_factor: self.amplification_factor,
-            iterations: max_iterations,
-            converged: false,
-            resonance: self.resonance,
-        })
+            combined,
+            self.amplification_factor,
+            self.resonance,
+        ))
     }
 
     fn resonance(&self) -> f32 {
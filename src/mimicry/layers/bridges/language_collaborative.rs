@@ -3,7 +3,9 @@
 //! This bridge connects linguistic processing with multi-agent learning,
 //! enabling cross-lingual collaborative knowledge transfer.
 
-use super::super::bridge::{AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult};
+use super::super::bridge::{
+    AmplificationGuard, AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
+};
 use super::super::layer::{Layer, LayerState};
 
 /// Bridge between Multilingual Processing (L5) and Collaborative Learning (L6).
@@ -89,11 +91,12 @@ impl BidirectionalBridge for LanguageCollaborativeBridge {
         Ok(refined)
     }
 
-    fn amplify(
+    fn amplify_with(
         &self,
         up: &LayerState,
         down: &LayerState,
         max_iterations: u32,
+        mut guard: AmplificationGuard,
     ) -> BridgeResult<AmplificationResult> {
         let mut up_conf = up.confidence;
         let mut down_conf = down.confidence;
@@ -102,26 +105,24 @@ impl BidirectionalBridge for LanguageCollaborativeBridge {
         for _ in 0..max_iterations.min(8) {
             // Multi-lingual collective intelligence emerges
             let collective_resonance = (up_conf * down_conf).sqrt();
-            let diversity_bonus = 1.0 + (collective_resonance * 0.10);
+            let diversity_bonus = 1.0 + guard.damp(collective_resonance * 0.10);
 
-            up_conf *= diversity_bonus;
-            down_conf *= diversity_bonus;
+            up_conf = guard.cap_state(up_conf * diversity_bonus);
+            down_conf = guard.cap_state(down_conf * diversity_bonus);
             total_factor *= diversity_bonus;
 
-            if diversity_bonus < 1.01 {
+            if guard.should_stop(up_conf * down_conf, diversity_bonus < 1.01) {
                 break;
             }
         }
 
-        Ok(AmplificationResult {
-            up_state: LayerState::with_confidence(up.layer, up.data_arc(), up_conf),
-            down_state: LayerState::with_confidence(down.layer, down.data_arc(), down_conf),
-            combined_confidence: up_conf * down_conf,
-            amplification_factor: total_factor,
-            iterations: max_iterations.min(8),
-            converged: true,
-            resonance: self.base_resonance,
-        })
+        Ok(guard.finish(
+            LayerState::with_confidence(up.layer, up.data_arc(), up_conf),
+            LayerState::with_confidence(down.layer, down.data_arc(), down_conf),
+            up_conf * down_conf,
+            total_factor,
+            self.base_resonance,
+        ))
     }
 
     fn resonance(&self) -> f32 {
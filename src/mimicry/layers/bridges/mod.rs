@@ -28,7 +28,7 @@ pub use language_collaborative::LanguageCollaborativeBridge;
 pub use physics_consciousness::PhysicsConsciousnessBridge;
 pub use physics_language::PhysicsLanguageBridge;
 
-use super::{BidirectionalBridge, BridgeNetwork};
+use super::{AmplificationLimits, BidirectionalBridge, BridgeNetwork};
 use std::sync::Arc;
 
 /// Create a fully connected bridge network with all standard bridges.
@@ -50,6 +50,7 @@ pub fn create_default_bridge_network() -> BridgeNetwork {
 pub struct BridgeBuilder {
     bridges: Vec<Arc<dyn BidirectionalBridge>>,
     global_amplification: f32,
+    limits: AmplificationLimits,
 }
 
 impl BridgeBuilder {
@@ -58,79 +59,93 @@ impl BridgeBuilder {
         Self {
             bridges: Vec::new(),
             global_amplification: 1.1,
+            limits: AmplificationLimits::default(),
         }
     }
 
     /// Add the base-extended physics bridge.
     pub fn with_base_extended(mut self) -> Self {
-        self.bridges.push(Arc::new(BaseExtendedBridge::new()));
+        self.bridges
+            .push(Arc::new(BaseExtendedBridge::new().with_limits(self.limits)));
         self
     }
 
     /// Add the cross-domain bridge.
     pub fn with_cross_domain(mut self) -> Self {
-        self.bridges.push(Arc::new(CrossDomainBridge::new()));
+        self.bridges
+            .push(Arc::new(CrossDomainBridge::new().with_limits(self.limits)));
         self
     }
 
     /// Add the physics-consciousness bridge.
     pub fn with_physics_consciousness(mut self) -> Self {
-        self.bridges
-            .push(Arc::new(PhysicsConsciousnessBridge::new()));
+        self.bridges.push(Arc::new(
+            PhysicsConsciousnessBridge::new().with_limits(self.limits),
+        ));
         self
     }
 
     /// Add the physics-language bridge.
     pub fn with_physics_language(mut self) -> Self {
-        self.bridges.push(Arc::new(PhysicsLanguageBridge::new()));
+        self.bridges.push(Arc::new(
+            PhysicsLanguageBridge::new().with_limits(self.limits),
+        ));
         self
     }
 
     /// Add the individual-collective bridge.
     pub fn with_individual_collective(mut self) -> Self {
-        self.bridges
-            .push(Arc::new(IndividualCollectiveBridge::new()));
+        self.bridges.push(Arc::new(
+            IndividualCollectiveBridge::new().with_limits(self.limits),
+        ));
         self
     }
 
     /// Add the internal-external bridge.
     pub fn with_internal_external(mut self) -> Self {
-        self.bridges.push(Arc::new(InternalExternalBridge::new()));
+        self.bridges.push(Arc::new(
+            InternalExternalBridge::new().with_limits(self.limits),
+        ));
         self
     }
 
     /// Add the consciousness-language bridge (L4↔L5).
     pub fn with_consciousness_language(mut self) -> Self {
-        self.bridges
-            .push(Arc::new(ConsciousnessLanguageBridge::new()));
+        self.bridges.push(Arc::new(
+            ConsciousnessLanguageBridge::new().with_limits(self.limits),
+        ));
         self
     }
 
     /// Add the language-collaborative bridge (L5↔L6).
     pub fn with_language_collaborative(mut self) -> Self {
-        self.bridges
-            .push(Arc::new(LanguageCollaborativeBridge::new()));
+        self.bridges.push(Arc::new(
+            LanguageCollaborativeBridge::new().with_limits(self.limits),
+        ));
         self
     }
 
     /// Add the collaborative-external bridge (L6↔L7).
     pub fn with_collaborative_external(mut self) -> Self {
-        self.bridges
-            .push(Arc::new(CollaborativeExternalBridge::new()));
+        self.bridges.push(Arc::new(
+            CollaborativeExternalBridge::new().with_limits(self.limits),
+        ));
         self
     }
 
     /// Add the crossdomain-consciousness bridge (L3↔L4).
     pub fn with_crossdomain_consciousness(mut self) -> Self {
-        self.bridges
-            .push(Arc::new(CrossDomainConsciousnessBridge::new()));
+        self.bridges.push(Arc::new(
+            CrossDomainConsciousnessBridge::new().with_limits(self.limits),
+        ));
         self
     }
 
     /// Add the consciousness-external bridge (L4↔L7).
     pub fn with_consciousness_external(mut self) -> Self {
-        self.bridges
-            .push(Arc::new(ConsciousnessExternalBridge::new()));
+        self.bridges.push(Arc::new(
+            ConsciousnessExternalBridge::new().with_limits(self.limits),
+        ));
         self
     }
 
@@ -156,18 +171,23 @@ impl BridgeBuilder {
 
     /// Build all standard bridges and return as a vector.
     pub fn build_all() -> Vec<Arc<dyn BidirectionalBridge>> {
+        Self::build_all_with_limits(AmplificationLimits::default())
+    }
+
+    /// Build all standard bridges with the given amplification limits.
+    pub fn build_all_with_limits(limits: AmplificationLimits) -> Vec<Arc<dyn BidirectionalBridge>> {
         vec![
-            Arc::new(BaseExtendedBridge::new()),
-            Arc::new(CrossDomainBridge::new()),
-            Arc::new(PhysicsConsciousnessBridge::new()),
-            Arc::new(PhysicsLanguageBridge::new()),
-            Arc::new(IndividualCollectiveBridge::new()),
-            Arc::new(InternalExternalBridge::new()),
-            Arc::new(ConsciousnessLanguageBridge::new()),
-            Arc::new(LanguageCollaborativeBridge::new()),
-            Arc::new(CollaborativeExternalBridge::new()),
-            Arc::new(CrossDomainConsciousnessBridge::new()),
-            Arc::new(ConsciousnessExternalBridge::new()),
+            Arc::new(BaseExtendedBridge::new().with_limits(limits)),
+            Arc::new(CrossDomainBridge::new().with_limits(limits)),
+            Arc::new(PhysicsConsciousnessBridge::new().with_limits(limits)),
+            Arc::new(PhysicsLanguageBridge::new().with_limits(limits)),
+            Arc::new(IndividualCollectiveBridge::new().with_limits(limits)),
+            Arc::new(InternalExternalBridge::new().with_limits(limits)),
+            Arc::new(ConsciousnessLanguageBridge::new().with_limits(limits)),
+            Arc::new(LanguageCollaborativeBridge::new().with_limits(limits)),
+            Arc::new(CollaborativeExternalBridge::new().with_limits(limits)),
+            Arc::new(CrossDomainConsciousnessBridge::new().with_limits(limits)),
+            Arc::new(ConsciousnessExternalBridge::new().with_limits(limits)),
         ]
     }
 
@@ -177,6 +197,13 @@ impl BridgeBuilder {
         self
     }
 
+    /// Set the amplification limits applied to standard bridges added
+    /// after this call.
+    pub fn with_limits(mut self, limits: AmplificationLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
     /// Add a custom bridge.
     pub fn with_custom_bridge(mut self, bridge: Arc<dyn BidirectionalBridge>) -> Self {
         self.bridges.push(bridge);
@@ -236,6 +263,25 @@ mod tests {
         assert_eq!(network.global_amplification(), 1.5);
     }
 
+    #[test]
+    fn test_builder_limits() {
+        let limits = AmplificationLimits::damped(0.25);
+        let network = BridgeBuilder::new()
+            .with_limits(limits)
+            .with_all_extended_bridges()
+            .build();
+        assert!(network
+            .bridges()
+            .iter()
+            .all(|bridge| bridge.amplification_limits() == limits));
+
+        let bridges = BridgeBuilder::build_all();
+        assert_eq!(
+            bridges[0].amplification_limits(),
+            AmplificationLimits::default()
+        );
+    }
+
     #[test]
     fn test_builder_all_bridges() {
         let network = BridgeBuilder::new().with_all_bridges().build();
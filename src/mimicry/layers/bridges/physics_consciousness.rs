@@ -4,7 +4,7 @@
 //! Enables intuitive pattern recognition based on physical principles.
 
 use crate::mimicry::layers::bridge::{
-    AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
+    AmplificationGuard, AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
 };
 use crate::mimicry::layers::layer::{Layer, LayerState};
 
@@ -122,21 +122,22 @@ impl BidirectionalBridge for PhysicsConsciousnessBridge {
         Ok(self.transform_backward(feedback))
     }
 
-    fn amplify(
+    fn amplify_with(
         &self,
         up: &LayerState,
         down: &LayerState,
         max_iterations: u32,
+        mut guard: AmplificationGuard,
     ) -> BridgeResult<AmplificationResult> {
         let mut up_state = up.clone();
         let mut down_state = down.clone();
         let mut previous_combined = 0.0f32;
 
-        for i in 0..max_iterations {
+        for _ in 0..max_iterations {
             // Physics informs intuition
             let analogy_factor = self.compute_analogy_factor(up_state.confidence);
             let physics_influence = up_state.confidence * analogy_factor * 0.2;
-            down_state.confidence = (down_state.confidence + physics_influence).min(2.0);
+            down_state.confidence = guard.step(down_state.confidence, physics_influence);
 
             // Intuition refines physics
             let intuition_influence = if down_state.confidence > self.intuition_threshold {
@@ -144,20 +145,12 @@ impl BidirectionalBridge for PhysicsConsciousnessBridge {
             } else {
                 down_state.confidence * 0.1
             };
-            up_state.confidence = (up_state.confidence + intuition_influence).min(2.0);
+            up_state.confidence = guard.step(up_state.confidence, intuition_influence);
 
             let combined = up_state.confidence * down_state.confidence * self.amplification_factor;
 
-            if (combined - previous_combined).abs() < 0.001 {
-                return Ok(AmplificationResult {
-                    up_state,
-                    down_state,
-                    combined_confidence: combined,
-                    amplification_factor: self.amplification_factor,
-                    iterations: i + 1,
-                    converged: true,
-                    resonance: self.resonance,
-                });
+            if guard.should_stop(combined, (combined - previous_combined).abs() < 0.001) {
+                break;
             }
 
             previous_combined = combined;
@@ -166,15 +159,13 @@ impl BidirectionalBridge for PhysicsConsciousnessBridge {
         }
 
         let combined = up_state.confidence * down_state.confidence * self.amplification_factor;
-        Ok(AmplificationResult {
+        Ok(guard.finish(
             up_state,
             down_state,
-            combined_confidence: combined,
-            amplification_factor: self.amplification_factor,
-            iterations: max_iterations,
-            converged: false,
-            resonance: self.resonance,
-        })
+            combined,
+            self.amplification_factor,
+            self.resonance,
+        ))
     }
 
     fn resonance(&self) -> f32 {
@@ -5,7 +5,7 @@
 //! representations across languages.
 
 use crate::mimicry::layers::bridge::{
-    AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
+    AmplificationGuard, AmplificationResult, BidirectionalBridge, BridgeError, BridgeResult,
 };
 use crate::mimicry::layers::layer::{Layer, LayerState};
 
@@ -90,35 +90,28 @@ impl BidirectionalBridge for PhysicsLanguageBridge {
         Ok(self.transform_backward(feedback))
     }
 
-    fn amplify(
+    fn amplify_with(
         &self,
         up: &LayerState,
         down: &LayerState,
         max_iterations: u32,
+        mut guard: AmplificationGuard,
     ) -> BridgeResult<AmplificationResult> {
         let mut up_state = up.clone();
         let mut down_state = down.clone();
         let mut previous_combined = 0.0f32;
 
-        for i in 0..max_iterations {
+        for _ in 0..max_iterations {
             let conceptual_boost = up_state.confidence * self.conceptual_strength * 0.15;
-            down_state.confidence = (down_state.confidence + conceptual_boost).min(2.0);
+            down_state.confidence = guard.step(down_state.confidence, conceptual_boost);
 
             let grounding_boost = down_state.confidence * 0.12;
-            up_state.confidence = (up_state.confidence + grounding_boost).min(2.0);
+            up_state.confidence = guard.step(up_state.confidence, grounding_boost);
 
             let combined = up_state.confidence * down_state.confidence * self.amplification_factor;
 
-            if (combined - previous_combined).abs() < 0.001 {
-                return Ok(AmplificationResult {
-                    up_state,
-                    down_state,
-                    combined_confidence: combined,
-                    amplification_factor: self.amplification_factor,
-                    iterations: i + 1,
-                    converged: true,
-                    resonance: self.resonance,
-                });
+            if guard.should_stop(combined, (combined - previous_combined).abs() < 0.001) {
+                break;
             }
 
             previous_combined = combined;
@@ -127,15 +120,13 @@ impl BidirectionalBridge for PhysicsLanguageBridge {
         }
 
         let combined = up_state.confidence * down_state.confidence * self.amplification_factor;
-        Ok(AmplificationResult {
+        Ok(guard.finish(
             up_state,
             down_state,
-            combined_confidence: combined,
-            amplification_factor: self.amplification_factor,
-            iterations: max_iterations,
-            converged: false,
-            resonance: self.resonance,
-        })
+            combined,
+            self.amplification_factor,
+            self.resonance,
+        ))
     }
 
     fn resonance(&self) -> f32 {
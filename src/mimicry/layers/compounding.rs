@@ -316,18 +316,20 @@ impl CompoundingMetrics {
         )
     }
 
-    /// Format layer metrics for display.
+    /// Format layer metrics for display, in layer order, custom layers
+    /// included.
     fn format_layer_metrics(&self) -> String {
+        let mut layers: Vec<&Layer> = self.layer_metrics.keys().collect();
+        layers.sort_by_key(|layer| layer.number());
         let mut lines = Vec::new();
-        for layer in Layer::all() {
-            if let Some(metrics) = self.layer_metrics.get(layer) {
-                lines.push(format!(
-                    "  {}: {} activations, peak {:.2}",
-                    layer.name(),
-                    metrics.activations,
-                    metrics.peak_confidence
-                ));
-            }
+        for layer in layers {
+            let metrics = &self.layer_metrics[layer];
+            lines.push(format!(
+                "  {}: {} activations, peak {:.2}",
+                layer.label(),
+                metrics.activations,
+                metrics.peak_confidence
+            ));
         }
         if lines.is_empty() {
             "  No layer activity recorded.".to_string()
@@ -396,6 +398,16 @@ mod tests {
         assert!(analysis.multiplicative_gain > 0.0);
         assert!(analysis.additive_gain > 0.0);
         assert_eq!(metrics.total_samples, 1);
+
+        // Custom layers show up in the report after the built-in ones
+        let mut result = StackProcessResult::empty();
+        result.layer_confidences.insert(Layer::Custom(0), 0.7);
+        result.layer_confidences.insert(Layer::BasePhysics, 0.8);
+        metrics.analyze(&result);
+        let report = metrics.format_layer_metrics();
+        let base = report.find("Base Physics: 2 activations").unwrap();
+        let custom = report.find("Custom 8: 1 activations").unwrap();
+        assert!(base < custom);
     }
 
     #[test]
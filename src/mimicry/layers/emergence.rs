@@ -103,7 +103,7 @@ struct EmergencePredictors {
 impl Default for EmergencePredictors {
     fn default() -> Self {
         Self {
-            pairwise_weights: Self::initialize_pairwise_weights(),
+            pairwise_weights: Self::initialize_pairwise_weights(Layer::all()),
             higher_order_weights: HashMap::new(),
             domain_weights: Self::initialize_domain_weights(),
         }
@@ -111,13 +111,13 @@ impl Default for EmergencePredictors {
 }
 
 impl EmergencePredictors {
-    fn initialize_pairwise_weights() -> HashMap<(Layer, Layer), f32> {
+    fn initialize_pairwise_weights(layers: &[Layer]) -> HashMap<(Layer, Layer), f32> {
         let mut weights = HashMap::new();
 
         // Initialize with theoretical interaction strengths
         // These are learned/refined over time
-        for l1 in Layer::all() {
-            for l2 in Layer::all() {
+        for l1 in layers {
+            for l2 in layers {
                 if l1.number() < l2.number() {
                     let base_weight = match (l1, l2) {
                         // Strong synergies
@@ -201,6 +201,20 @@ impl EmergenceFramework {
         }
     }
 
+    /// Seed prediction weights for every pair of `layers`, typically
+    /// [`LayerRegistry::layers`](super::registry::LayerRegistry::layers), so
+    /// registered custom layers contribute to predictions. Only the
+    /// built-in layers are seeded by default; learned weights are kept.
+    pub fn with_layers(mut self, layers: &[Layer]) -> Self {
+        for (pair, weight) in EmergencePredictors::initialize_pairwise_weights(layers) {
+            self.predictors
+                .pairwise_weights
+                .entry(pair)
+                .or_insert(weight);
+        }
+        self
+    }
+
     /// Predict emergence for a given layer configuration.
     pub fn predict(&self, active_layers: &[Layer]) -> f32 {
         let mut predicted = 0.0f32;
@@ -268,7 +282,7 @@ impl EmergenceFramework {
                 ) {
                     let interaction = (c1 * c2).sqrt() - (c1 + c2) / 2.0;
                     pairwise_contributions
-                        .insert(format!("{}-{}", low.name(), high.name()), interaction);
+                        .insert(format!("{}-{}", low.label(), high.label()), interaction);
                 }
             }
         }
@@ -495,6 +509,8 @@ fn current_timestamp() -> u64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::mimicry::layers::layer::LayerState;
+    use crate::mimicry::layers::registry::LayerRegistry;
 
     #[test]
     fn test_framework_creation() {
@@ -544,4 +560,32 @@ mod tests {
         let mechanism = framework.classify_mechanism(&result, &HashMap::new());
         assert_eq!(mechanism, EmergenceMechanism::Resonance);
     }
+
+    #[test]
+    fn test_custom_layers_take_part() {
+        let mut registry = LayerRegistry::new();
+        let quantum = registry.register_custom_layer("quantum").unwrap();
+        let layers = [Layer::BasePhysics, quantum];
+
+        // Unseeded custom pairs contribute nothing
+        assert_eq!(EmergenceFramework::new().predict(&layers), 0.0);
+        let mut framework = EmergenceFramework::new().with_layers(&registry.layers());
+        assert!(framework.predict(&layers) > 0.0);
+        assert_eq!(
+            framework.predict(&[Layer::BasePhysics, Layer::ExtendedPhysics]),
+            EmergenceFramework::new().predict(&[Layer::BasePhysics, Layer::ExtendedPhysics])
+        );
+
+        let mut result = StackProcessResult::empty();
+        for layer in layers {
+            result
+                .layer_states
+                .insert(layer, LayerState::new(layer, ()));
+            result.layer_confidences.insert(layer, 0.8);
+        }
+        let analysis = framework.analyze(&result);
+        assert!(analysis
+            .pairwise_contributions
+            .contains_key("Base Physics-Custom 8"));
+    }
 }
@@ -4,6 +4,7 @@
 //! through structural similarity mapping.
 
 use std::collections::HashMap;
+use std::fmt;
 
 use serde::{Deserialize, Serialize};
 
@@ -11,6 +12,10 @@ use super::pattern::{Pattern, PatternId, PatternMatch, PatternMemory};
 use super::{GaiaError, GaiaResult};
 use crate::mimicry::layers::layer::Domain;
 
+/// Share of the positive dot product a dimension must contribute to count
+/// as one of a transfer's mapped dimensions.
+pub const MAPPED_DIMENSION_SHARE: f32 = 0.1;
+
 /// Strength of an analogical transfer.
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum TransferStrength {
@@ -174,6 +179,44 @@ pub struct TransferResult {
     pub insight: Option<String>,
     /// Transformed feature vector for target domain.
     pub transformed_features: Vec<f32>,
+    /// Best-matching target pattern, if any.
+    pub matched_pattern: Option<PatternId>,
+    /// Dimensions that carry the match between the transformed features
+    /// and the matched pattern's fingerprint.
+    pub mapped_dimensions: Vec<usize>,
+    /// Numeric strength: best similarity scaled by bridge affinity.
+    pub correspondence: f32,
+    /// Why the transfer got its `strength`.
+    pub justification: String,
+}
+
+impl fmt::Display for TransferResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.matched_pattern {
+            Some(target) => {
+                let dims: Vec<String> = self
+                    .mapped_dimensions
+                    .iter()
+                    .map(|d| d.to_string())
+                    .collect();
+                write!(
+                    f,
+                    "mapped {:?}::{} \u{2192} {:?}::{} (strength {:.2} via dims [{}])",
+                    self.source_domain,
+                    self.source_pattern,
+                    self.target_domain,
+                    target,
+                    self.correspondence,
+                    dims.join(",")
+                )
+            }
+            None => write!(
+                f,
+                "no analogue for {:?}::{} in {:?} (strength {:.2})",
+                self.source_domain, self.source_pattern, self.target_domain, self.correspondence
+            ),
+        }
+    }
 }
 
 /// The analogical transfer engine.
@@ -271,6 +314,23 @@ impl AnalogicalTransfer {
         let best_match_sim = target_matches.first().map(|m| m.similarity).unwrap_or(0.0);
         let combined_strength = best_match_sim * bridge.affinity;
         let strength = TransferStrength::from_value(combined_strength);
+        let justification = format!(
+            "{:?}: similarity {:.2} x {:?}\u{2192}{:?} affinity {:.2} = {:.2}",
+            strength,
+            best_match_sim,
+            source_pattern.domain(),
+            target_domain,
+            bridge.affinity,
+            combined_strength
+        );
+
+        // Explain which dimensions carry the best match
+        let matched_pattern = target_matches.first().map(|m| m.pattern_id.clone());
+        let mapped_dimensions = matched_pattern
+            .as_deref()
+            .and_then(|id| pattern_memory.get(id))
+            .map(|target| Self::mapped_dimensions(&transformed, target.fingerprint()))
+            .unwrap_or_default();
 
         // Generate insight
         let insight = self.generate_insight(source_pattern, &target_matches, target_domain);
@@ -283,9 +343,34 @@ impl AnalogicalTransfer {
             strength,
             insight,
             transformed_features: transformed,
+            matched_pattern,
+            mapped_dimensions,
+            correspondence: combined_strength,
+            justification,
         })
     }
 
+    /// Dimensions contributing at least `MAPPED_DIMENSION_SHARE` of the
+    /// positive dot product between `features` and `fingerprint`.
+    fn mapped_dimensions(features: &[f32], fingerprint: &[f32]) -> Vec<usize> {
+        let products: Vec<f32> = features
+            .iter()
+            .zip(fingerprint)
+            .map(|(a, b)| a * b)
+            .collect();
+        let total: f32 = products.iter().filter(|&&p| p > 0.0).sum();
+        if total <= 0.0 {
+            return Vec::new();
+        }
+
+        products
+            .iter()
+            .enumerate()
+            .filter(|(_, &p)| p >= total * MAPPED_DIMENSION_SHARE)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
     /// Transform features from source to target domain.
     fn transform_features(
         &self,
@@ -528,6 +613,46 @@ mod tests {
         assert_eq!(transfer.target_domain, Domain::Language);
     }
 
+    #[test]
+    fn test_transfer_explains_mapping() {
+        let engine = AnalogicalTransfer::new();
+        let memory = PatternMemory::new();
+        memory
+            .register(
+                Pattern::new("scarcity", Domain::Social)
+                    .with_fingerprint(vec![0.9, 0.0, 0.05, 0.8]),
+            )
+            .unwrap();
+        let source = Pattern::new("conservation", Domain::Physics)
+            .with_fingerprint(vec![1.0, 0.0, 0.0, 1.0]);
+
+        let transfer = engine
+            .transfer(&source, Domain::Social, &[1.0, 0.0, 0.0, 1.0], &memory)
+            .unwrap();
+        assert_eq!(transfer.matched_pattern.as_deref(), Some("scarcity"));
+        assert_eq!(transfer.mapped_dimensions, vec![0, 3]);
+        assert!(transfer.justification.contains("affinity 0.30"));
+
+        let text = transfer.to_string();
+        assert!(
+            text.starts_with("mapped Physics::conservation \u{2192} Social::scarcity (strength "),
+            "{}",
+            text
+        );
+        assert!(text.ends_with("via dims [0,3])"), "{}", text);
+
+        // Nothing to map onto
+        let transfer = engine
+            .transfer(&source, Domain::Language, &[1.0, 0.0, 0.0, 1.0], &memory)
+            .unwrap();
+        assert_eq!(transfer.matched_pattern, None);
+        assert!(transfer.mapped_dimensions.is_empty());
+        assert_eq!(
+            transfer.to_string(),
+            "no analogue for Physics::conservation in Language (strength 0.00)"
+        );
+    }
+
     #[test]
     fn test_mapping_creation() {
         let source = Pattern::new("src", Domain::Physics);
@@ -3,12 +3,14 @@
 //! The core engine that orchestrates pattern matching, resonance, and
 //! analogical reasoning to provide intuitive insights beyond explicit rules.
 
+use std::collections::HashSet;
 use std::sync::RwLock;
 
 use serde::{Deserialize, Serialize};
 
 use super::analogical::{AnalogicalTransfer, TransferResult};
 use super::pattern::{Pattern, PatternMatch, PatternMemory, PatternStats};
+use super::persistence::GaiaSnapshot;
 use super::resonance::{ResonanceConfig, ResonanceField, ResonanceResult};
 use super::{GaiaError, GaiaResult};
 use crate::mimicry::layers::layer::{Domain, Layer, LayerState};
@@ -319,6 +321,52 @@ impl GaiaIntuitionEngine {
         *self.stats.write().unwrap() = GaiaStats::default();
     }
 
+    /// Export all patterns, with their weights and reinforcement counts,
+    /// as a pretty-printed `GaiaSnapshot` JSON document.
+    pub fn export_patterns_json(&self) -> GaiaResult<String> {
+        let snapshot = GaiaSnapshot::capture(&self.pattern_memory);
+        serde_json::to_string_pretty(&snapshot)
+            .map_err(|e| GaiaError::ConfigError(format!("Failed to serialize patterns: {}", e)))
+    }
+
+    /// Replace pattern memory with the patterns in a JSON document from
+    /// `export_patterns_json`. Returns the number of patterns loaded.
+    ///
+    /// The document is validated in full — parse, memory limit, finite
+    /// fingerprints and unique ids — before anything is replaced, so a
+    /// rejected import leaves the current memory untouched.
+    pub fn import_patterns_json(&self, json: &str) -> GaiaResult<usize> {
+        let snapshot: GaiaSnapshot = serde_json::from_str(json)
+            .map_err(|e| GaiaError::ConfigError(format!("Failed to parse patterns: {}", e)))?;
+
+        let patterns = snapshot.into_patterns();
+        if patterns.len() > self.config.max_patterns {
+            return Err(GaiaError::ConfigError(format!(
+                "Snapshot holds {} patterns, memory limit is {}",
+                patterns.len(),
+                self.config.max_patterns
+            )));
+        }
+        let mut ids = HashSet::new();
+        for pattern in &patterns {
+            pattern.validate()?;
+            if !ids.insert(pattern.id()) {
+                return Err(GaiaError::ConfigError(format!(
+                    "Snapshot lists pattern '{}' more than once",
+                    pattern.id()
+                )));
+            }
+        }
+
+        self.pattern_memory.clear();
+        self.resonance_field.reset();
+        let count = patterns.len();
+        for pattern in patterns {
+            self.pattern_memory.register(pattern)?;
+        }
+        Ok(count)
+    }
+
     /// Create a pattern from a LayerState.
     pub fn pattern_from_state(&self, id: &str, state: &LayerState) -> GaiaResult<Pattern> {
         let domain = match state.layer {
@@ -327,7 +375,7 @@ impl GaiaIntuitionEngine {
             Layer::GaiaConsciousness => Domain::Consciousness,
             Layer::CollaborativeLearning => Domain::Social,
             Layer::ExternalApis => Domain::External,
-            Layer::CrossDomain => Domain::Emergent,
+            Layer::CrossDomain | Layer::Custom(_) => Domain::Emergent,
         };
 
         let fingerprint = if let Some(features) = state.data::<Vec<f32>>() {
@@ -588,6 +636,109 @@ mod tests {
         assert_eq!(result.confidence, 0.0);
     }
 
+    #[test]
+    fn test_patterns_json_round_trip() {
+        let gaia = GaiaIntuitionEngine::with_defaults();
+        gaia.register_pattern(
+            Pattern::new("p1", Domain::Physics).with_fingerprint(vec![1.0, 0.0, 0.0]),
+        )
+        .unwrap();
+        gaia.register_pattern(
+            Pattern::new("p2", Domain::Language).with_fingerprint(vec![0.2, 0.4, 0.6, 0.8, 1.0]),
+        )
+        .unwrap();
+        gaia.feedback("p1", true).unwrap();
+        gaia.feedback("p1", true).unwrap();
+        gaia.feedback("p2", false).unwrap();
+        gaia.feedback_with_reward("p2", 0.37).unwrap();
+
+        let json = gaia.export_patterns_json().unwrap();
+        assert!(json.find("\"p1\"").unwrap() < json.find("\"p2\"").unwrap());
+
+        let restored = GaiaIntuitionEngine::with_defaults();
+        restored
+            .register_pattern(Pattern::new("stale", Domain::Emergent))
+            .unwrap();
+        assert_eq!(restored.import_patterns_json(&json).unwrap(), 2);
+        assert!(!restored.pattern_memory().contains("stale"));
+
+        for id in ["p1", "p2"] {
+            let before = gaia.pattern_memory().get(id).unwrap();
+            let after = restored.pattern_memory().get(id).unwrap();
+            assert_eq!(after.weight(), before.weight());
+            assert_eq!(after.domain(), before.domain());
+            assert_eq!(after.fingerprint(), before.fingerprint());
+            assert_eq!(after.success_rate(), before.success_rate());
+            assert_eq!(after.attempts(), before.attempts());
+        }
+        // Differing dimensions survive untruncated
+        assert_eq!(restored.pattern_memory().get("p2").unwrap().dimension(), 5);
+        assert_eq!(restored.export_patterns_json().unwrap().len(), json.len());
+    }
+
+    #[test]
+    fn test_import_patterns_json_rejects_bad_input() {
+        let gaia = GaiaIntuitionEngine::with_defaults();
+        gaia.register_pattern(Pattern::new("keep", Domain::Physics))
+            .unwrap();
+
+        assert!(gaia.import_patterns_json("not json").is_err());
+        assert!(gaia.pattern_memory().contains("keep"));
+
+        // Patterns that parse but fail registration are caught up front
+        let source = GaiaIntuitionEngine::with_defaults();
+        source
+            .register_pattern(Pattern::new("a", Domain::Physics).with_fingerprint(vec![1.0]))
+            .unwrap();
+        source
+            .register_pattern(Pattern::new("b", Domain::Physics).with_fingerprint(vec![2.0]))
+            .unwrap();
+        let json = source.export_patterns_json().unwrap();
+
+        // An f32 overflow deserializes as infinity
+        let overflow = json.replacen("2.0", "1e39", 1);
+        assert!(matches!(
+            gaia.import_patterns_json(&overflow),
+            Err(GaiaError::InvalidFingerprint(_))
+        ));
+        let duplicate = json.replacen("\"b\"", "\"a\"", 2);
+        assert!(gaia
+            .import_patterns_json(&duplicate)
+            .unwrap_err()
+            .to_string()
+            .contains("'a' more than once"));
+        assert_eq!(gaia.pattern_memory().all_ids(), vec!["keep".to_string()]);
+    }
+
+    #[test]
+    fn test_export_patterns_json_is_stable() {
+        let strip_timestamp = |json: String| {
+            json.lines()
+                .filter(|line| !line.contains("\"created_at\""))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let domains = [
+            Domain::Physics,
+            Domain::Language,
+            Domain::Social,
+            Domain::External,
+        ];
+        let forward = GaiaIntuitionEngine::with_defaults();
+        let backward = GaiaIntuitionEngine::with_defaults();
+        let pattern = |n: usize| Pattern::new(format!("p{}", n), domains[n]);
+        for n in 0..domains.len() {
+            forward.register_pattern(pattern(n)).unwrap();
+            backward
+                .register_pattern(pattern(domains.len() - 1 - n))
+                .unwrap();
+        }
+        assert_eq!(
+            strip_timestamp(forward.export_patterns_json().unwrap()),
+            strip_timestamp(backward.export_patterns_json().unwrap())
+        );
+    }
+
     #[test]
     fn test_pattern_from_state() {
         let gaia = GaiaIntuitionEngine::with_defaults();
@@ -61,7 +61,9 @@ pub use analogical::{
 pub use intuition::{GaiaConfig, GaiaIntuitionEngine, IntuitionResult};
 pub use pattern::{Pattern, PatternId, PatternMatch, PatternMemory, PatternStats};
 pub use persistence::{GaiaPersistence, GaiaSnapshot, PatternData, SnapshotStats};
-pub use resonance::{ActivationState, ResonanceConfig, ResonanceField, ResonanceResult};
+pub use resonance::{
+    ActivationState, ActivationTrace, DecayCurve, ResonanceConfig, ResonanceField, ResonanceResult,
+};
 
 /// Error types for GAIA operations.
 #[derive(Debug, Clone)]
@@ -70,8 +72,9 @@ pub enum GaiaError {
     PatternNotFound(String),
     /// Invalid pattern fingerprint (wrong dimension, NaN, etc.).
     InvalidFingerprint(String),
-    /// Resonance field saturation (too many active patterns).
-    ResonanceSaturation,
+    /// Resonance field saturation: these patterns could not be activated
+    /// because the field already held `max_active_patterns`.
+    ResonanceSaturation(Vec<PatternId>),
     /// Analogical transfer failed.
     TransferFailed(String),
     /// Configuration error.
@@ -83,7 +86,11 @@ impl std::fmt::Display for GaiaError {
         match self {
             GaiaError::PatternNotFound(id) => write!(f, "Pattern not found: {}", id),
             GaiaError::InvalidFingerprint(msg) => write!(f, "Invalid fingerprint: {}", msg),
-            GaiaError::ResonanceSaturation => write!(f, "Resonance field saturated"),
+            GaiaError::ResonanceSaturation(ids) => write!(
+                f,
+                "Resonance field saturated; could not activate: {}",
+                ids.join(", ")
+            ),
             GaiaError::TransferFailed(msg) => write!(f, "Analogical transfer failed: {}", msg),
             GaiaError::ConfigError(msg) => write!(f, "Configuration error: {}", msg),
         }
@@ -98,10 +105,10 @@ pub type GaiaResult<T> = Result<T, GaiaError>;
 /// Prelude for convenient imports.
 pub mod prelude {
     pub use super::{
-        ActivationState, AnalogicalMapping, AnalogicalTransfer, DomainBridge, GaiaConfig,
-        GaiaError, GaiaIntuitionEngine, GaiaResult, IntuitionResult, Pattern, PatternId,
-        PatternMatch, PatternMemory, PatternStats, ResonanceConfig, ResonanceField,
-        ResonanceResult, TransferResult, TransferStrength,
+        ActivationState, ActivationTrace, AnalogicalMapping, AnalogicalTransfer, DecayCurve,
+        DomainBridge, GaiaConfig, GaiaError, GaiaIntuitionEngine, GaiaResult, IntuitionResult,
+        Pattern, PatternId, PatternMatch, PatternMemory, PatternStats, ResonanceConfig,
+        ResonanceField, ResonanceResult, TransferResult, TransferStrength,
     };
 }
 
@@ -116,6 +123,15 @@ mod tests {
         assert!(err.to_string().contains("test_pattern"));
     }
 
+    #[test]
+    fn test_saturation_error_names_patterns() {
+        let err = GaiaError::ResonanceSaturation(vec!["p1".into(), "p2".into()]);
+        assert_eq!(
+            err.to_string(),
+            "Resonance field saturated; could not activate: p1, p2"
+        );
+    }
+
     #[test]
     fn test_pattern_creation() {
         let pattern = Pattern::new("test", Domain::Physics);
@@ -106,6 +106,22 @@ impl Pattern {
         self
     }
 
+    /// Restore reinforcement history (activations, successes, attempts
+    /// and the resulting success rate), e.g. from a saved snapshot.
+    pub fn with_reinforcement(
+        mut self,
+        activation_count: u64,
+        successes: u64,
+        attempts: u64,
+        success_rate: f32,
+    ) -> Self {
+        self.activation_count = activation_count;
+        self.successes = successes;
+        self.attempts = attempts;
+        self.success_rate = success_rate;
+        self
+    }
+
     /// Add a cross-domain link.
     pub fn with_cross_link(mut self, pattern_id: PatternId) -> Self {
         if !self.cross_links.contains(&pattern_id) {
@@ -154,6 +170,16 @@ impl Pattern {
         self.success_rate
     }
 
+    /// Get the number of recorded successes.
+    pub fn successes(&self) -> u64 {
+        self.successes
+    }
+
+    /// Get the number of recorded outcomes.
+    pub fn attempts(&self) -> u64 {
+        self.attempts
+    }
+
     /// Get cross-links.
     pub fn cross_links(&self) -> &[PatternId] {
         &self.cross_links
@@ -164,6 +190,17 @@ impl Pattern {
         &self.tags
     }
 
+    /// Check the fingerprint holds only finite values, as
+    /// `PatternMemory::register` requires.
+    pub fn validate(&self) -> GaiaResult<()> {
+        if self.fingerprint.iter().any(|val| !val.is_finite()) {
+            return Err(GaiaError::InvalidFingerprint(
+                "Fingerprint contains NaN or infinite values".into(),
+            ));
+        }
+        Ok(())
+    }
+
     /// Calculate similarity to a query fingerprint using cosine similarity.
     pub fn similarity(&self, query: &[f32]) -> f32 {
         if self.fingerprint.is_empty() || query.is_empty() {
@@ -312,14 +349,7 @@ impl PatternMemory {
 
     /// Register a new pattern.
     pub fn register(&self, pattern: Pattern) -> GaiaResult<()> {
-        // Validate fingerprint
-        for &val in pattern.fingerprint() {
-            if val.is_nan() || val.is_infinite() {
-                return Err(GaiaError::InvalidFingerprint(
-                    "Fingerprint contains NaN or infinite values".into(),
-                ));
-            }
-        }
+        pattern.validate()?;
 
         let id = pattern.id().to_string();
         let domain = pattern.domain();
@@ -3,7 +3,7 @@
 //! This module provides save/load functionality for GAIA patterns,
 //! enabling learned intuition to persist across sessions.
 
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::fs;
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
@@ -19,6 +19,9 @@ use crate::mimicry::layers::layer::Domain;
 pub struct PatternData {
     /// Pattern identifier.
     pub id: PatternId,
+    /// Human-readable name.
+    #[serde(default)]
+    pub name: String,
     /// Domain this pattern belongs to.
     pub domain: Domain,
     /// The pattern fingerprint (feature vector).
@@ -29,20 +32,33 @@ pub struct PatternData {
     pub success_rate: f32,
     /// Number of times this pattern has been activated.
     pub activation_count: u64,
+    /// Reinforcement successes behind `success_rate`.
+    #[serde(default)]
+    pub successes: u64,
+    /// Reinforcement outcomes (successes and failures) behind `success_rate`.
+    #[serde(default)]
+    pub attempts: u64,
     /// Cross-links to related patterns.
     pub cross_links: Vec<PatternId>,
+    /// Metadata tags.
+    #[serde(default)]
+    pub tags: Vec<String>,
 }
 
 impl From<&Pattern> for PatternData {
     fn from(pattern: &Pattern) -> Self {
         Self {
             id: pattern.id().to_string(),
+            name: pattern.name().to_string(),
             domain: pattern.domain(),
             fingerprint: pattern.fingerprint().to_vec(),
             weight: pattern.weight(),
             success_rate: pattern.success_rate(),
             activation_count: pattern.activation_count(),
+            successes: pattern.successes(),
+            attempts: pattern.attempts(),
             cross_links: pattern.cross_links().to_vec(),
+            tags: pattern.tags().to_vec(),
         }
     }
 }
@@ -51,7 +67,17 @@ impl From<PatternData> for Pattern {
     fn from(data: PatternData) -> Self {
         let mut pattern = Pattern::new(&data.id, data.domain)
             .with_fingerprint(data.fingerprint)
-            .with_weight(data.weight);
+            .with_weight(data.weight)
+            .with_tags(data.tags)
+            .with_reinforcement(
+                data.activation_count,
+                data.successes,
+                data.attempts,
+                data.success_rate,
+            );
+        if !data.name.is_empty() {
+            pattern = pattern.with_name(data.name);
+        }
 
         // Add cross-links using builder pattern
         for link in data.cross_links {
@@ -75,13 +101,39 @@ pub struct GaiaSnapshot {
     pub stats: SnapshotStats,
 }
 
+impl GaiaSnapshot {
+    /// Capture every pattern in `memory`, ordered by id.
+    pub fn capture(memory: &PatternMemory) -> Self {
+        let mut patterns: Vec<PatternData> = memory
+            .all_patterns()
+            .iter()
+            .map(PatternData::from)
+            .collect();
+        patterns.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let stats = snapshot_stats(&patterns);
+
+        Self {
+            version: "1.0.0".to_string(),
+            created_at: chrono_now(),
+            patterns,
+            stats,
+        }
+    }
+
+    /// Rebuild the patterns held by this snapshot.
+    pub fn into_patterns(self) -> Vec<Pattern> {
+        self.patterns.into_iter().map(Pattern::from).collect()
+    }
+}
+
 /// Statistics included in a snapshot.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SnapshotStats {
     /// Total number of patterns.
     pub total_patterns: usize,
-    /// Patterns per domain.
-    pub patterns_by_domain: HashMap<String, usize>,
+    /// Patterns per domain, ordered by domain name.
+    pub patterns_by_domain: BTreeMap<String, usize>,
     /// Average pattern weight.
     pub average_weight: f32,
     /// Average success rate.
@@ -123,20 +175,7 @@ impl GaiaPersistence {
     pub fn save(&self, name: &str, memory: &PatternMemory) -> GaiaResult<PathBuf> {
         self.initialize()?;
 
-        let patterns: Vec<PatternData> = memory
-            .all_patterns()
-            .iter()
-            .map(|p| PatternData::from(p))
-            .collect();
-
-        let stats = self.compute_stats(&patterns);
-
-        let snapshot = GaiaSnapshot {
-            version: "1.0.0".to_string(),
-            created_at: chrono_now(),
-            patterns,
-            stats,
-        };
+        let snapshot = GaiaSnapshot::capture(memory);
 
         let json = serde_json::to_string_pretty(&snapshot)
             .map_err(|e| GaiaError::ConfigError(format!("Failed to serialize patterns: {}", e)))?;
@@ -172,8 +211,7 @@ impl GaiaPersistence {
             .map_err(|e| GaiaError::ConfigError(format!("Failed to parse patterns: {}", e)))?;
 
         let memory = PatternMemory::new();
-        for data in snapshot.patterns {
-            let pattern: Pattern = data.into();
+        for pattern in snapshot.into_patterns() {
             // Ignore registration errors for individual patterns
             let _ = memory.register(pattern);
         }
@@ -244,33 +282,6 @@ impl GaiaPersistence {
 
         Ok(lines.join("\n"))
     }
-
-    /// Compute statistics for a set of patterns.
-    fn compute_stats(&self, patterns: &[PatternData]) -> SnapshotStats {
-        let mut patterns_by_domain: HashMap<String, usize> = HashMap::new();
-        let mut total_weight = 0.0f32;
-        let mut total_success = 0.0f32;
-        let mut total_links = 0usize;
-
-        for pattern in patterns {
-            *patterns_by_domain
-                .entry(format!("{:?}", pattern.domain))
-                .or_insert(0) += 1;
-            total_weight += pattern.weight;
-            total_success += pattern.success_rate;
-            total_links += pattern.cross_links.len();
-        }
-
-        let n = patterns.len().max(1) as f32;
-
-        SnapshotStats {
-            total_patterns: patterns.len(),
-            patterns_by_domain,
-            average_weight: total_weight / n,
-            average_success_rate: total_success / n,
-            total_cross_links: total_links,
-        }
-    }
 }
 
 impl Default for GaiaPersistence {
@@ -279,6 +290,33 @@ impl Default for GaiaPersistence {
     }
 }
 
+/// Compute statistics for a set of patterns.
+fn snapshot_stats(patterns: &[PatternData]) -> SnapshotStats {
+    let mut patterns_by_domain: BTreeMap<String, usize> = BTreeMap::new();
+    let mut total_weight = 0.0f32;
+    let mut total_success = 0.0f32;
+    let mut total_links = 0usize;
+
+    for pattern in patterns {
+        *patterns_by_domain
+            .entry(format!("{:?}", pattern.domain))
+            .or_insert(0) += 1;
+        total_weight += pattern.weight;
+        total_success += pattern.success_rate;
+        total_links += pattern.cross_links.len();
+    }
+
+    let n = patterns.len().max(1) as f32;
+
+    SnapshotStats {
+        total_patterns: patterns.len(),
+        patterns_by_domain,
+        average_weight: total_weight / n,
+        average_success_rate: total_success / n,
+        total_cross_links: total_links,
+    }
+}
+
 /// Get current timestamp as string.
 fn chrono_now() -> String {
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -308,31 +346,62 @@ mod tests {
     }
 
     #[test]
-    fn test_snapshot_stats() {
-        let persistence = GaiaPersistence::new("/tmp/test_gaia");
+    fn test_reinforcement_round_trip() {
+        let mut pattern = Pattern::new("test", Domain::Physics)
+            .with_name("Test pattern")
+            .with_fingerprint(vec![0.1, 0.2, 0.3])
+            .with_tags(vec!["core".to_string()]);
+        pattern.activate();
+        pattern.record_success();
+        pattern.record_success();
+        pattern.record_failure();
+
+        let restored: Pattern = PatternData::from(&pattern).into();
+        assert_eq!(restored.name(), "Test pattern");
+        assert_eq!(restored.weight(), pattern.weight());
+        assert_eq!(restored.success_rate(), pattern.success_rate());
+        assert_eq!(restored.activation_count(), 1);
+        assert_eq!((restored.successes(), restored.attempts()), (2, 3));
+        assert_eq!(restored.tags(), pattern.tags());
+
+        // Reinforcement keeps counting from the restored history
+        let mut restored = restored;
+        restored.record_success();
+        assert_eq!(restored.success_rate(), 0.75);
+    }
 
+    #[test]
+    fn test_snapshot_stats() {
         let patterns = vec![
             PatternData {
                 id: "p1".to_string(),
+                name: "p1".to_string(),
                 domain: Domain::Physics,
                 fingerprint: vec![0.5],
                 weight: 1.0,
                 success_rate: 0.8,
                 activation_count: 10,
+                successes: 8,
+                attempts: 10,
                 cross_links: vec!["p2".to_string()],
+                tags: vec![],
             },
             PatternData {
                 id: "p2".to_string(),
+                name: "p2".to_string(),
                 domain: Domain::Language,
                 fingerprint: vec![0.5],
                 weight: 0.8,
                 success_rate: 0.7,
                 activation_count: 5,
+                successes: 7,
+                attempts: 10,
                 cross_links: vec![],
+                tags: vec![],
             },
         ];
 
-        let stats = persistence.compute_stats(&patterns);
+        let stats = snapshot_stats(&patterns);
         assert_eq!(stats.total_patterns, 2);
         assert_eq!(stats.total_cross_links, 1);
     }
@@ -9,6 +9,33 @@ use std::sync::RwLock;
 use serde::{Deserialize, Serialize};
 
 use super::pattern::{PatternId, PatternMemory};
+use super::{GaiaError, GaiaResult};
+
+/// Maximum number of snapshots kept in an `ActivationTrace`.
+const TRACE_LIMIT: usize = 100;
+
+/// Shading ramp for activation heatmaps, from inactive to peak.
+const HEATMAP_RAMP: &[char] = &[' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+
+/// How activation falls off with each spreading hop or `decay` call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DecayCurve {
+    /// Multiply by `decay_rate` each hop.
+    #[default]
+    Exponential,
+    /// Subtract `1.0 - decay_rate` each hop, stopping at zero.
+    Linear,
+}
+
+impl DecayCurve {
+    /// Activation after one hop from `activation`.
+    pub fn apply(&self, activation: f32, decay_rate: f32) -> f32 {
+        match self {
+            DecayCurve::Exponential => activation * decay_rate,
+            DecayCurve::Linear => (activation - (1.0 - decay_rate)).max(0.0),
+        }
+    }
+}
 
 /// Configuration for the resonance field.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +46,10 @@ pub struct ResonanceConfig {
     /// Activation decay per step (0.0-1.0).
     pub decay_rate: f32,
 
+    /// Shape of the per-step decay.
+    #[serde(default)]
+    pub decay_curve: DecayCurve,
+
     /// Minimum activation to continue spreading.
     pub activation_threshold: f32,
 
@@ -37,6 +68,7 @@ impl Default for ResonanceConfig {
         Self {
             max_steps: 5,
             decay_rate: 0.7,
+            decay_curve: DecayCurve::Exponential,
             activation_threshold: 0.1,
             max_active_patterns: 100,
             enable_cross_domain: true,
@@ -118,6 +150,10 @@ pub struct ResonanceResult {
 
     /// Number of cross-domain activations.
     pub cross_domain_activations: usize,
+
+    /// Patterns that would have been activated but were dropped because
+    /// the field reached `max_active_patterns`.
+    pub saturated: Vec<PatternId>,
 }
 
 impl ResonanceResult {
@@ -129,9 +165,15 @@ impl ResonanceResult {
             total_activation: 0.0,
             peak_activation: 0.0,
             cross_domain_activations: 0,
+            saturated: Vec::new(),
         }
     }
 
+    /// Check if any pattern was dropped because the field was full.
+    pub fn is_saturated(&self) -> bool {
+        !self.saturated.is_empty()
+    }
+
     /// Get sorted activations (highest first).
     pub fn sorted_activations(&self) -> Vec<(&PatternId, &ActivationState)> {
         let mut sorted: Vec<_> = self.activations.iter().collect();
@@ -166,6 +208,101 @@ impl ResonanceResult {
     }
 }
 
+/// Per-step snapshots of a resonance field's activations.
+#[derive(Debug, Clone, Default)]
+pub struct ActivationTrace {
+    /// Field state after each step; the first entry holds the seeds.
+    pub steps: Vec<HashMap<PatternId, ActivationState>>,
+}
+
+impl ActivationTrace {
+    /// Number of recorded steps.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Check if nothing has been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    fn record(&mut self, state: &HashMap<PatternId, ActivationState>) {
+        self.steps.push(state.clone());
+        if self.steps.len() > TRACE_LIMIT {
+            self.steps.remove(0);
+        }
+    }
+
+    /// Activation of a pattern at each step (0.0 while inactive).
+    pub fn series(&self, pattern_id: &str) -> Vec<f32> {
+        self.steps
+            .iter()
+            .map(|step| step.get(pattern_id).map(|s| s.activation).unwrap_or(0.0))
+            .collect()
+    }
+
+    /// Every pattern that appears in the trace, in order of first
+    /// activation and then by id.
+    pub fn patterns(&self) -> Vec<PatternId> {
+        let mut first_seen: HashMap<&PatternId, usize> = HashMap::new();
+        for (index, step) in self.steps.iter().enumerate() {
+            for id in step.keys() {
+                first_seen.entry(id).or_insert(index);
+            }
+        }
+        let mut ids: Vec<(&PatternId, usize)> = first_seen.into_iter().collect();
+        ids.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+        ids.into_iter().map(|(id, _)| id.clone()).collect()
+    }
+
+    /// Render the trace as an ASCII heatmap: one row per pattern, one
+    /// column per step, shaded relative to the peak activation.
+    pub fn render_heatmap(&self, label: &str) -> String {
+        let patterns = self.patterns();
+        if patterns.is_empty() {
+            return format!("{}: No data", label);
+        }
+
+        let peak = self
+            .steps
+            .iter()
+            .flat_map(|step| step.values().map(|s| s.activation))
+            .fold(0.0f32, f32::max);
+        let width = patterns.iter().map(|id| id.len()).max().unwrap_or(0);
+
+        let mut lines = Vec::new();
+        lines.push(format!("=== {} ===", label));
+        for id in &patterns {
+            let row: String = self
+                .series(id)
+                .iter()
+                .map(|&activation| {
+                    let level = if peak > 0.0 { activation / peak } else { 0.0 };
+                    let index = (level * (HEATMAP_RAMP.len() - 1) as f32).round() as usize;
+                    HEATMAP_RAMP[index.min(HEATMAP_RAMP.len() - 1)]
+                })
+                .collect();
+            lines.push(format!("{:>width$}|{}", id, row, width = width));
+        }
+        lines.push(format!(
+            "{:>width$}+{}",
+            "",
+            "-".repeat(self.steps.len()),
+            width = width
+        ));
+        lines.push(format!(
+            "   Steps: {}  Patterns: {}  Peak: {:.2}",
+            self.steps.len(),
+            patterns.len(),
+            peak
+        ));
+        let ramp: String = HEATMAP_RAMP.iter().collect();
+        lines.push(format!("   Scale: '{}' (0 to peak)", ramp));
+
+        lines.join("\n")
+    }
+}
+
 /// The resonance field for spreading activation.
 pub struct ResonanceField {
     /// Configuration.
@@ -176,6 +313,9 @@ pub struct ResonanceField {
 
     /// Activation history for analysis.
     history: RwLock<Vec<ResonanceResult>>,
+
+    /// Per-step snapshots since the last `activate`.
+    trace: RwLock<ActivationTrace>,
 }
 
 impl ResonanceField {
@@ -185,6 +325,7 @@ impl ResonanceField {
             config,
             current_activations: RwLock::new(HashMap::new()),
             history: RwLock::new(Vec::new()),
+            trace: RwLock::new(ActivationTrace::default()),
         }
     }
 
@@ -216,6 +357,8 @@ impl ResonanceField {
     ) -> ResonanceResult {
         let mut activations: HashMap<PatternId, ActivationState> = HashMap::new();
         let mut cross_domain_count = 0;
+        let mut saturated: Vec<PatternId> = Vec::new();
+        let mut trace = ActivationTrace::default();
 
         // Initialize seed patterns
         for &pattern_id in seed_patterns {
@@ -224,6 +367,7 @@ impl ResonanceField {
                 activations.insert(pattern_id.to_string(), ActivationState::seed(activation));
             }
         }
+        trace.record(&activations);
 
         // Spreading activation
         let mut step = 0;
@@ -242,18 +386,24 @@ impl ResonanceField {
                     // Spread to cross-linked patterns
                     if self.config.enable_cross_domain {
                         for link_id in pattern.cross_links() {
-                            if activations.len() + new_activations.len()
-                                >= self.config.max_active_patterns
-                            {
-                                break;
-                            }
-
                             if !activations.contains_key(link_id) {
-                                let spread_activation = state.activation
-                                    * self.config.decay_rate
+                                let spread_activation = self
+                                    .config
+                                    .decay_curve
+                                    .apply(state.activation, self.config.decay_rate)
                                     * self.config.cross_domain_weight;
 
-                                if spread_activation >= self.config.activation_threshold {
+                                if spread_activation < self.config.activation_threshold {
+                                    continue;
+                                }
+
+                                if activations.len() + new_activations.len()
+                                    >= self.config.max_active_patterns
+                                {
+                                    if !saturated.contains(link_id) {
+                                        saturated.push(link_id.clone());
+                                    }
+                                } else {
                                     new_activations.push((
                                         link_id.clone(),
                                         ActivationState::derived(
@@ -280,8 +430,13 @@ impl ResonanceField {
                     activations.insert(id, state);
                 }
             }
+            trace.record(&activations);
         }
 
+        // A pattern dropped in one step may still have been reached later
+        saturated.retain(|id| !activations.contains_key(id));
+        saturated.sort();
+
         // Calculate result metrics
         let total_activation: f32 = activations.values().map(|s| s.activation).sum();
         let peak_activation = activations
@@ -295,10 +450,12 @@ impl ResonanceField {
             total_activation,
             peak_activation,
             cross_domain_activations: cross_domain_count,
+            saturated,
         };
 
         // Update current state
         *self.current_activations.write().unwrap() = activations;
+        *self.trace.write().unwrap() = trace;
 
         // Store in history
         {
@@ -312,23 +469,48 @@ impl ResonanceField {
         result
     }
 
+    /// Like `activate`, but fails with `GaiaError::ResonanceSaturation`
+    /// naming the patterns that were dropped once the field was full.
+    pub fn try_activate(
+        &self,
+        seed_patterns: &[&str],
+        pattern_memory: &PatternMemory,
+    ) -> GaiaResult<ResonanceResult> {
+        let result = self.activate(seed_patterns, pattern_memory);
+        if result.is_saturated() {
+            Err(GaiaError::ResonanceSaturation(result.saturated))
+        } else {
+            Ok(result)
+        }
+    }
+
+    /// Per-step activation snapshots from the last `activate`, followed
+    /// by one snapshot per `decay` call since.
+    pub fn activation_trace(&self) -> ActivationTrace {
+        self.trace.read().unwrap().clone()
+    }
+
     /// Get the current activation state.
     pub fn current_state(&self) -> HashMap<PatternId, ActivationState> {
         self.current_activations.read().unwrap().clone()
     }
 
-    /// Decay all activations by a factor.
+    /// Decay all activations along the configured `DecayCurve`, with
+    /// `factor` in place of the decay rate.
     pub fn decay(&self, factor: f32) {
+        let curve = self.config.decay_curve;
         let mut activations = self.current_activations.write().unwrap();
         activations.retain(|_, state| {
-            state.activation *= factor;
+            state.activation = curve.apply(state.activation, factor);
             state.activation >= self.config.activation_threshold
         });
+        self.trace.write().unwrap().record(&activations);
     }
 
     /// Reset the field, clearing all activations.
     pub fn reset(&self) {
         self.current_activations.write().unwrap().clear();
+        *self.trace.write().unwrap() = ActivationTrace::default();
     }
 
     /// Get activation history.
@@ -471,6 +653,91 @@ mod tests {
         assert_eq!(b_state.activated_by.as_deref(), Some("a"));
     }
 
+    #[test]
+    fn test_decay_curves() {
+        assert!((DecayCurve::Exponential.apply(0.8, 0.5) - 0.4).abs() < 1e-6);
+        assert!((DecayCurve::Linear.apply(0.8, 0.75) - 0.55).abs() < 1e-6);
+        assert_eq!(DecayCurve::Linear.apply(0.2, 0.5), 0.0);
+
+        let config = ResonanceConfig {
+            decay_curve: DecayCurve::Linear,
+            decay_rate: 0.9,
+            cross_domain_weight: 1.0,
+            activation_threshold: 0.01,
+            ..Default::default()
+        };
+        let field = ResonanceField::new(config);
+        let result = field.activate(&["a"], &setup_memory());
+        let a = result.activation_of("a");
+        assert!((result.activation_of("b") - (a - 0.1)).abs() < 1e-5);
+        assert!((result.activation_of("c") - (a - 0.2)).abs() < 1e-5);
+
+        // Plain decay follows the same curve
+        field.decay(0.75);
+        let b = result.activation_of("b");
+        assert!((field.current_state()["a"].activation - (a - 0.25)).abs() < 1e-5);
+        assert!((field.current_state()["b"].activation - (b - 0.25)).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_activation_trace() {
+        let config = ResonanceConfig {
+            cross_domain_weight: 1.0,
+            decay_rate: 0.9,
+            activation_threshold: 0.01,
+            ..Default::default()
+        };
+        let field = ResonanceField::new(config);
+        field.activate(&["a"], &setup_memory());
+
+        let trace = field.activation_trace();
+        // Seeds, then one step per hop (a -> b -> c)
+        assert_eq!(trace.len(), 3);
+        assert_eq!(trace.patterns(), vec!["a", "b", "c"]);
+        assert_eq!(trace.series("c")[..2], [0.0, 0.0]);
+        assert!(trace.series("c")[2] > 0.0);
+
+        field.decay(0.5);
+        let trace = field.activation_trace();
+        assert_eq!(trace.len(), 4);
+        assert!(trace.series("a")[3] < trace.series("a")[2]);
+
+        let heatmap = trace.render_heatmap("Spread");
+        let lines: Vec<&str> = heatmap.lines().collect();
+        assert_eq!(lines[0], "=== Spread ===");
+        assert_eq!(lines[1], "a|@@@+");
+        assert!(lines[2].starts_with("b| "));
+        assert!(lines[3].starts_with("c|  "));
+        assert_eq!(lines[4], " +----");
+
+        field.reset();
+        assert!(field.activation_trace().is_empty());
+        assert_eq!(field.activation_trace().render_heatmap("x"), "x: No data");
+    }
+
+    #[test]
+    fn test_saturation_reports_patterns() {
+        let config = ResonanceConfig {
+            cross_domain_weight: 1.0,
+            decay_rate: 0.9,
+            activation_threshold: 0.01,
+            max_active_patterns: 2,
+            ..Default::default()
+        };
+        let field = ResonanceField::new(config);
+        let memory = setup_memory();
+
+        let result = field.activate(&["a"], &memory);
+        assert!(result.is_active("b"));
+        assert_eq!(result.saturated, vec!["c"]);
+
+        match field.try_activate(&["a"], &memory) {
+            Err(GaiaError::ResonanceSaturation(ids)) => assert_eq!(ids, vec!["c"]),
+            other => panic!("expected saturation, got {:?}", other),
+        }
+        assert!(field.try_activate(&["c"], &memory).is_ok());
+    }
+
     #[test]
     fn test_activation_decay() {
         let field = ResonanceField::new(ResonanceConfig::default());
@@ -519,6 +786,7 @@ mod tests {
             total_activation: 1.5,
             peak_activation: 0.8,
             cross_domain_activations: 0,
+            saturated: Vec::new(),
         };
 
         let sorted = result.sorted_activations();
@@ -339,7 +339,7 @@ impl LayerIntegration {
                 Layer::GaiaConsciousness => Domain::Consciousness,
                 Layer::CollaborativeLearning => Domain::Social,
                 Layer::ExternalApis => Domain::External,
-                Layer::CrossDomain => Domain::Emergent,
+                Layer::CrossDomain | Layer::Custom(_) => Domain::Emergent,
             };
             if !domains.contains(&domain) {
                 domains.push(domain);
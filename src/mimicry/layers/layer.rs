@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
 
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
 /// The 7 layers of the multiplicative integration system.
@@ -43,10 +44,21 @@ pub enum Layer {
     /// Layer 7: Real-time external APIs
     /// Handles: External validation, feedback loops
     ExternalApis,
+
+    /// An additional layer registered at runtime through
+    /// [`LayerRegistry::register_custom_layer`](super::registry::LayerRegistry::register_custom_layer).
+    /// The value is its registration slot; it is numbered after the
+    /// built-in layers (slot 0 is layer 8) and only bridges to layers
+    /// whose bridges are registered explicitly.
+    Custom(u8),
 }
 
+/// Layer number of the first custom layer.
+pub const CUSTOM_LAYER_BASE: u8 = 8;
+
 impl Layer {
-    /// Returns all layers in order from base to external.
+    /// Returns the 7 built-in layers in order from base to external.
+    /// Custom layers are listed by [`LayerRegistry::layers`](super::registry::LayerRegistry::layers).
     pub fn all() -> &'static [Layer] {
         &[
             Layer::BasePhysics,
@@ -69,6 +81,7 @@ impl Layer {
             Layer::MultilingualProcessing => 5,
             Layer::CollaborativeLearning => 6,
             Layer::ExternalApis => 7,
+            Layer::Custom(slot) => CUSTOM_LAYER_BASE.saturating_add(*slot),
         }
     }
 
@@ -82,6 +95,7 @@ impl Layer {
             Layer::MultilingualProcessing => "Multilingual Processing",
             Layer::CollaborativeLearning => "Collaborative Learning",
             Layer::ExternalApis => "External APIs",
+            Layer::Custom(_) => "Custom",
         }
     }
 
@@ -95,6 +109,7 @@ impl Layer {
             Layer::MultilingualProcessing => "perspective, translation",
             Layer::CollaborativeLearning => "amplification",
             Layer::ExternalApis => "feedback",
+            Layer::Custom(_) => "domain-specific",
         }
     }
 
@@ -137,13 +152,28 @@ impl Layer {
                 Layer::GaiaConsciousness,
                 Layer::CollaborativeLearning,
             ],
+            Layer::Custom(_) => Vec::new(),
         }
     }
 
     /// Check if this layer can bridge to another layer.
+    ///
+    /// Only the fixed topology of the built-in layers is known here, so
+    /// this is always false for custom layers; whether one bridges to
+    /// another layer depends on the bridges registered for it (see
+    /// [`BridgeNetwork::bridge_between`](super::bridge::BridgeNetwork::bridge_between)).
     pub fn can_bridge_to(&self, other: Layer) -> bool {
         self.connected_layers().contains(&other)
     }
+
+    /// Short label for reports: the name, with the layer number added for
+    /// custom layers so several of them stay distinct.
+    pub fn label(&self) -> String {
+        match self {
+            Layer::Custom(_) => format!("{} {}", self.name(), self.number()),
+            _ => self.name().to_string(),
+        }
+    }
 }
 
 impl fmt::Display for Layer {
@@ -177,8 +207,106 @@ impl Domain {
     }
 }
 
+/// A type that can be stored as a layer state payload.
+///
+/// The tag identifies the type in saved states, so it must stay the same
+/// across releases and be unique among payload types.
+pub trait PayloadType: Serialize + DeserializeOwned {
+    /// Stable tag recorded alongside the serialized data.
+    const TAG: &'static str;
+}
+
+/// A layer state's data payload in serialized form, tagged with the
+/// payload type it was encoded from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SerializedPayload {
+    /// `PayloadType::TAG` of the original data.
+    pub type_tag: String,
+    /// The data as JSON.
+    pub value: serde_json::Value,
+}
+
+impl SerializedPayload {
+    /// Encode `data`, tagging it with its payload tag.
+    pub fn encode<T: PayloadType>(data: &T) -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            type_tag: T::TAG.to_string(),
+            value: serde_json::to_value(data)?,
+        })
+    }
+
+    /// Whether this payload was encoded from a `T`.
+    pub fn is<T: PayloadType>(&self) -> bool {
+        self.type_tag == T::TAG
+    }
+
+    /// Decode the payload back into a `T`, checking the type tag first.
+    pub fn decode<T: PayloadType>(&self) -> Option<T> {
+        if !self.is::<T>() {
+            return None;
+        }
+        serde_json::from_value(self.value.clone()).ok()
+    }
+}
+
+/// Encode and decode the payload types that survive serialization without
+/// an explicit `LayerState::serializable` constructor.
+macro_rules! known_payloads {
+    ($($ty:ty => $tag:literal),* $(,)?) => {
+        $(
+            impl PayloadType for $ty {
+                const TAG: &'static str = $tag;
+            }
+        )*
+
+        fn encode_known(data: &(dyn Any + Send + Sync)) -> Option<SerializedPayload> {
+            $(
+                if let Some(value) = data.downcast_ref::<$ty>() {
+                    return SerializedPayload::encode(value).ok();
+                }
+            )*
+            None
+        }
+
+        fn decode_known(payload: &SerializedPayload) -> Option<Arc<dyn Any + Send + Sync>> {
+            $(
+                if payload.is::<$ty>() {
+                    let value: $ty = payload.decode()?;
+                    return Some(Arc::new(value));
+                }
+            )*
+            None
+        }
+    };
+}
+
+known_payloads!(
+    () => "unit",
+    bool => "bool",
+    i32 => "i32",
+    i64 => "i64",
+    u32 => "u32",
+    u64 => "u64",
+    usize => "usize",
+    f32 => "f32",
+    f64 => "f64",
+    String => "string",
+    Vec<f32> => "vec_f32",
+    Vec<f64> => "vec_f64",
+    Vec<String> => "vec_string",
+    HashMap<String, String> => "string_map",
+);
+
 /// State held by a layer, containing typed data and metadata.
-#[derive(Clone)]
+///
+/// States serialize with their data as a [`SerializedPayload`]. Primitive,
+/// `String`, `Vec` and string-map payloads round-trip automatically; other
+/// [`PayloadType`]s must be created with [`LayerState::serializable`] and
+/// come back as a `SerializedPayload`, readable with
+/// [`LayerState::decode_data`]. Data that cannot be encoded is dropped
+/// (restored as `()`); [`LayerState::payload`] tells the two apart.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(into = "LayerStateRepr", from = "LayerStateRepr")]
 pub struct LayerState {
     /// The layer this state belongs to.
     pub layer: Layer,
@@ -189,6 +317,9 @@ pub struct LayerState {
     /// The actual data payload (type-erased for flexibility).
     data: Arc<dyn Any + Send + Sync>,
 
+    /// Serialized form of `data`, recorded by `serializable`.
+    payload: Option<SerializedPayload>,
+
     /// Confidence level for this state (0.0 - potentially > 1.0 after amplification).
     pub confidence: f32,
 
@@ -215,6 +346,7 @@ impl LayerState {
             layer,
             id: Self::generate_id(),
             data: Arc::new(data),
+            payload: None,
             confidence: 1.0,
             metadata: HashMap::new(),
             upstream_refs: Vec::new(),
@@ -235,6 +367,32 @@ impl LayerState {
         state
     }
 
+    /// Create a layer state whose data survives serialization as its own
+    /// type tag, whatever the type.
+    pub fn serializable<T: Any + Send + Sync + PayloadType>(layer: Layer, data: T) -> Self {
+        let payload = SerializedPayload::encode(&data).ok();
+        let mut state = Self::new(layer, data);
+        state.payload = payload;
+        state
+    }
+
+    /// The data as a `T`: a clone of the live value, or decoded from the
+    /// payload of a deserialized state.
+    pub fn decode_data<T: Any + Send + Sync + Clone + PayloadType>(&self) -> Option<T> {
+        if let Some(value) = self.data::<T>() {
+            return Some(value.clone());
+        }
+        self.data::<SerializedPayload>()?.decode()
+    }
+
+    /// The data in serialized form, if it can be encoded.
+    pub fn payload(&self) -> Option<SerializedPayload> {
+        self.payload
+            .clone()
+            .or_else(|| self.data::<SerializedPayload>().cloned())
+            .or_else(|| encode_known(self.data.as_ref()))
+    }
+
     /// Try to downcast the data to the expected type.
     pub fn data<T: Any + Send + Sync + 'static>(&self) -> Option<&T> {
         self.data.downcast_ref::<T>()
@@ -296,6 +454,61 @@ impl LayerState {
     }
 }
 
+/// Serialized form of a [`LayerState`].
+#[derive(Serialize, Deserialize)]
+struct LayerStateRepr {
+    layer: Layer,
+    id: String,
+    payload: Option<SerializedPayload>,
+    confidence: f32,
+    #[serde(default)]
+    metadata: HashMap<String, String>,
+    #[serde(default)]
+    upstream_refs: Vec<String>,
+    #[serde(default)]
+    downstream_refs: Vec<String>,
+    created_at: u64,
+    #[serde(default)]
+    amplification_iterations: u32,
+}
+
+impl From<LayerState> for LayerStateRepr {
+    fn from(state: LayerState) -> Self {
+        Self {
+            payload: state.payload(),
+            layer: state.layer,
+            id: state.id,
+            confidence: state.confidence,
+            metadata: state.metadata,
+            upstream_refs: state.upstream_refs,
+            downstream_refs: state.downstream_refs,
+            created_at: state.created_at,
+            amplification_iterations: state.amplification_iterations,
+        }
+    }
+}
+
+impl From<LayerStateRepr> for LayerState {
+    fn from(repr: LayerStateRepr) -> Self {
+        let data: Arc<dyn Any + Send + Sync> = match &repr.payload {
+            Some(payload) => decode_known(payload).unwrap_or_else(|| Arc::new(payload.clone())),
+            None => Arc::new(()),
+        };
+        Self {
+            layer: repr.layer,
+            id: repr.id,
+            data,
+            payload: repr.payload,
+            confidence: repr.confidence,
+            metadata: repr.metadata,
+            upstream_refs: repr.upstream_refs,
+            downstream_refs: repr.downstream_refs,
+            created_at: repr.created_at,
+            amplification_iterations: repr.amplification_iterations,
+        }
+    }
+}
+
 impl fmt::Debug for LayerState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("LayerState")
@@ -381,7 +594,7 @@ impl Default for LayerConfig {
 }
 
 /// Represents the direction of information flow between layers.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FlowDirection {
     /// Forward: from lower layer number to higher.
     Forward,
@@ -443,6 +656,17 @@ mod tests {
         assert_eq!(Layer::ExternalApis.number(), 7);
     }
 
+    #[test]
+    fn test_custom_layer_numbering() {
+        assert_eq!(Layer::Custom(0).number(), 8);
+        assert_eq!(Layer::Custom(2).number(), 10);
+        assert_eq!(Layer::Custom(0).to_string(), "Layer 8: Custom");
+        assert!(!Layer::all().contains(&Layer::Custom(0)));
+        assert!(Layer::Custom(0).connected_layers().is_empty());
+        assert_eq!(Layer::Custom(1).label(), "Custom 9");
+        assert_eq!(Layer::CrossDomain.label(), "Cross-Domain");
+    }
+
     #[test]
     fn test_layer_connections() {
         // Base physics should connect to extended physics
@@ -480,6 +704,62 @@ mod tests {
         assert_eq!(backward.direction, FlowDirection::Backward);
     }
 
+    #[test]
+    fn test_layer_state_roundtrip_known_payload() {
+        let mut state = LayerState::with_confidence(Layer::CrossDomain, "pattern".to_string(), 0.7);
+        state.set_metadata("source", "test");
+        state.add_upstream("ls_1");
+        state.amplify_confidence(1.5);
+
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: LayerState = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.layer, Layer::CrossDomain);
+        assert_eq!(restored.id, state.id);
+        assert_eq!(restored.confidence, state.confidence);
+        assert_eq!(restored.get_metadata("source"), Some("test"));
+        assert_eq!(restored.upstream_refs, vec!["ls_1".to_string()]);
+        assert_eq!(restored.amplification_iterations, 1);
+        // Known types come back as themselves
+        assert_eq!(restored.data::<String>(), Some(&"pattern".to_string()));
+    }
+
+    #[test]
+    fn test_layer_state_custom_payload() {
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        struct Reading {
+            value: f64,
+            label: String,
+        }
+        impl PayloadType for Reading {
+            const TAG: &'static str = "test.reading";
+        }
+        let reading = Reading {
+            value: 0.5,
+            label: "spin".to_string(),
+        };
+
+        let state = LayerState::serializable(Layer::BasePhysics, reading.clone());
+        let restored: LayerState =
+            serde_json::from_str(&serde_json::to_string(&state).unwrap()).unwrap();
+        assert!(restored.data::<Reading>().is_none());
+        assert!(restored.payload().unwrap().is::<Reading>());
+        assert_eq!(restored.decode_data::<Reading>(), Some(reading.clone()));
+        assert_eq!(restored.decode_data::<String>(), None);
+        // Saved tags are the explicit ones, not compiler type names
+        let json = serde_json::to_value(&state).unwrap();
+        assert_eq!(json["payload"]["type_tag"], "test.reading");
+        let json = serde_json::to_value(LayerState::new(Layer::BasePhysics, vec![0.5f32])).unwrap();
+        assert_eq!(json["payload"]["type_tag"], "vec_f32");
+        assert_eq!(state.decode_data::<Reading>(), Some(reading));
+
+        // Unregistered, unencoded data is dropped rather than failing
+        let opaque = LayerState::new(Layer::BasePhysics, std::time::Duration::from_secs(1));
+        assert!(opaque.payload().is_none());
+        let restored: LayerState =
+            serde_json::from_str(&serde_json::to_string(&opaque).unwrap()).unwrap();
+        assert_eq!(restored.data::<()>(), Some(&()));
+    }
+
     #[test]
     fn test_domain_layer_mapping() {
         assert_eq!(Domain::Physics.primary_layer(), Layer::BasePhysics);
@@ -94,8 +94,9 @@ pub mod integration;
 
 // Re-export primary types
 pub use bridge::{
-    compute_multiplicative_confidence, AmplificationResult, BidirectionalBridge, BridgeConnection,
-    BridgeError, BridgeNetwork, BridgeResult,
+    compute_multiplicative_confidence, AmplificationGuard, AmplificationLimits,
+    AmplificationResult, BidirectionalBridge, BridgeConnection, BridgeError, BridgeNetwork,
+    BridgeResult, LimitedBridge, NetworkAmplificationResult, NetworkConvergence,
 };
 pub use compounding::{BridgeMetrics, CompoundingAnalysis, CompoundingMetrics, LayerMetrics};
 pub use domains::{DomainConfig, DomainFactory, DomainLayer, DomainProcessor};
@@ -103,7 +104,10 @@ pub use emergence::{
     EmergenceAnalysis, EmergenceConfig, EmergenceFramework, EmergenceMechanism, EmergenceStats,
 };
 pub use integration::{IntegrationConfig, IntegrationResult, IntegrationStats, LayerIntegration};
-pub use layer::{Domain, FlowDirection, Layer, LayerConfig, LayerSignal, LayerState};
+pub use layer::{
+    Domain, FlowDirection, Layer, LayerConfig, LayerSignal, LayerState, PayloadType,
+    SerializedPayload,
+};
 pub use registry::{
     LayerHandler, LayerProcessError, LayerRegistry, PassthroughHandler, RegistryStats,
 };
@@ -112,6 +116,7 @@ pub use stack::{LayerStack, LayerStackConfig, StackProcessResult, StackStats};
 /// Prelude module for convenient imports.
 pub mod prelude {
     pub use super::{
+        AmplificationLimits,
         AmplificationResult,
         BidirectionalBridge,
         BridgeConnection,
@@ -145,6 +150,8 @@ pub mod prelude {
         LayerStack,
         LayerStackConfig,
         LayerState,
+        PayloadType,
+        SerializedPayload,
         StackProcessResult,
     };
 }
@@ -5,7 +5,7 @@
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
-use super::layer::{Layer, LayerConfig, LayerState};
+use super::layer::{Layer, LayerConfig, LayerState, CUSTOM_LAYER_BASE};
 
 /// Callback type for layer state changes.
 pub type LayerCallback = Box<dyn Fn(&LayerState) + Send + Sync>;
@@ -78,6 +78,8 @@ impl LayerRegistration {
 pub struct LayerRegistry {
     registrations: HashMap<Layer, LayerRegistration>,
     default_config: LayerConfig,
+    /// Names of custom layers, indexed by their `Layer::Custom` slot.
+    custom_layers: Vec<String>,
 }
 
 impl LayerRegistry {
@@ -93,6 +95,7 @@ impl LayerRegistry {
         Self {
             registrations,
             default_config: LayerConfig::default(),
+            custom_layers: Vec::new(),
         }
     }
 
@@ -103,6 +106,57 @@ impl LayerRegistry {
         registry
     }
 
+    /// Register an additional layer under a unique string id. Returns the
+    /// new `Layer::Custom`, which bridges can name as their source or
+    /// target layer.
+    pub fn register_custom_layer(&mut self, name: &str) -> Result<Layer, LayerProcessError> {
+        if self.custom_layer(name).is_some() {
+            return Err(LayerProcessError::ConfigurationError(format!(
+                "Custom layer '{}' is already registered",
+                name
+            )));
+        }
+        let slot = u8::try_from(self.custom_layers.len())
+            .ok()
+            .filter(|slot| slot.checked_add(CUSTOM_LAYER_BASE).is_some())
+            .ok_or_else(|| {
+                LayerProcessError::ConfigurationError("No custom layer slots left".to_string())
+            })?;
+
+        let layer = Layer::Custom(slot);
+        self.custom_layers.push(name.to_string());
+        self.registrations
+            .insert(layer, LayerRegistration::new(LayerConfig::new(layer)));
+        Ok(layer)
+    }
+
+    /// Look up a custom layer by its string id.
+    pub fn custom_layer(&self, name: &str) -> Option<Layer> {
+        self.custom_layers
+            .iter()
+            .position(|n| n == name)
+            .map(|slot| Layer::Custom(slot as u8))
+    }
+
+    /// Display name of a layer: the string id of a custom layer, otherwise
+    /// the built-in name.
+    pub fn layer_name(&self, layer: Layer) -> &str {
+        match layer {
+            Layer::Custom(slot) => self
+                .custom_layers
+                .get(slot as usize)
+                .map(|n| n.as_str())
+                .unwrap_or("Custom"),
+            _ => layer.name(),
+        }
+    }
+
+    /// All registered layers, built-in and custom, in layer-number order.
+    pub fn layers(&self) -> Vec<Layer> {
+        let custom = (0..self.custom_layers.len()).map(|slot| Layer::Custom(slot as u8));
+        Layer::all().iter().copied().chain(custom).collect()
+    }
+
     /// Register a handler for a specific layer.
     pub fn register_handler(
         &mut self,
@@ -241,19 +295,17 @@ impl LayerRegistry {
 
     /// Get all enabled layers in order.
     pub fn enabled_layers(&self) -> Vec<Layer> {
-        Layer::all()
-            .iter()
-            .filter(|&&l| self.is_enabled(l))
-            .copied()
+        self.layers()
+            .into_iter()
+            .filter(|&l| self.is_enabled(l))
             .collect()
     }
 
     /// Get all layers with registered handlers.
     pub fn layers_with_handlers(&self) -> Vec<Layer> {
-        Layer::all()
-            .iter()
-            .filter(|&&l| self.has_handler(l))
-            .copied()
+        self.layers()
+            .into_iter()
+            .filter(|&l| self.has_handler(l))
             .collect()
     }
 
@@ -378,6 +430,29 @@ mod tests {
         assert_eq!(stats.layers_with_handlers, 0);
     }
 
+    #[test]
+    fn test_custom_layer_registration() {
+        let mut registry = LayerRegistry::new();
+        let quantum = registry.register_custom_layer("quantum").unwrap();
+        assert_eq!(quantum, Layer::Custom(0));
+        assert_eq!(quantum.number(), 8);
+        assert!(registry.register_custom_layer("quantum").is_err());
+
+        let stats = registry.stats();
+        assert_eq!(stats.total_layers, 8);
+        assert_eq!(stats.enabled_layers, 8);
+        assert_eq!(registry.custom_layer("quantum"), Some(quantum));
+        assert_eq!(registry.layer_name(quantum), "quantum");
+        assert_eq!(registry.layer_name(Layer::CrossDomain), "Cross-Domain");
+        assert_eq!(registry.layers().last(), Some(&quantum));
+
+        let handler = Arc::new(RwLock::new(PassthroughHandler::new(quantum)));
+        registry.register_handler(handler).unwrap();
+        assert_eq!(registry.layers_with_handlers(), vec![quantum]);
+        registry.disable(quantum);
+        assert!(!registry.enabled_layers().contains(&quantum));
+    }
+
     #[test]
     fn test_layer_enable_disable() {
         let mut registry = LayerRegistry::new();
@@ -4,6 +4,7 @@
 //! bidirectional propagation and multiplicative amplification.
 
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 
 use super::bridge::{BidirectionalBridge, BridgeNetwork};
@@ -263,6 +264,34 @@ impl LayerStack {
         self.current_states.clear();
     }
 
+    /// Write the current layer states to `path` as JSON, ordered by layer.
+    ///
+    /// States whose data has no [`PayloadType`](super::layer::PayloadType)
+    /// encoding are left out rather than saved with their data dropped;
+    /// their layers are returned.
+    pub fn save_state(&self, path: &Path) -> Result<Vec<Layer>, String> {
+        let mut states: Vec<&LayerState> = self.current_states.values().collect();
+        states.sort_by_key(|s| s.layer.number());
+        let (states, skipped): (Vec<&LayerState>, Vec<&LayerState>) =
+            states.into_iter().partition(|s| s.payload().is_some());
+        let json = serde_json::to_string_pretty(&states)
+            .map_err(|e| format!("Failed to serialize layer states: {}", e))?;
+        std::fs::write(path, json)
+            .map_err(|e| format!("Failed to write layer states to {}: {}", path.display(), e))?;
+        Ok(skipped.into_iter().map(|s| s.layer).collect())
+    }
+
+    /// Replace the current layer states with those saved at `path`.
+    /// Returns the number of states loaded.
+    pub fn load_state(&mut self, path: &Path) -> Result<usize, String> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read layer states from {}: {}", path.display(), e))?;
+        let states: Vec<LayerState> = serde_json::from_str(&json)
+            .map_err(|e| format!("Failed to parse layer states: {}", e))?;
+        self.current_states = states.into_iter().map(|s| (s.layer, s)).collect();
+        Ok(self.current_states.len())
+    }
+
     /// Process input through the entire stack with forward propagation.
     pub fn process_forward(&mut self, input: LayerState) -> StackProcessResult {
         let mut result = StackProcessResult::empty();
@@ -276,10 +305,11 @@ impl LayerStack {
             .insert(start_layer, input.confidence);
 
         // Get layers to process (from start layer upward)
-        let layers_to_process: Vec<Layer> = Layer::all()
-            .iter()
-            .filter(|&&l| l.number() > start_layer.number() && self.registry.is_enabled(l))
-            .copied()
+        let layers_to_process: Vec<Layer> = self
+            .registry
+            .layers()
+            .into_iter()
+            .filter(|&l| l.number() > start_layer.number() && self.registry.is_enabled(l))
             .collect();
 
         // Forward propagation through each layer
@@ -325,6 +355,25 @@ impl LayerStack {
 
     /// Process with bidirectional amplification.
     pub fn process_bidirectional(&mut self, input: LayerState) -> StackProcessResult {
+        self.process_bidirectional_with(input, false)
+    }
+
+    /// Like [`process_bidirectional`](Self::process_bidirectional), but
+    /// evaluates independent bridges concurrently on scoped threads.
+    ///
+    /// Each phase is split into waves of bridge calls that touch disjoint
+    /// layers; a call only moves to a later wave than every earlier call it
+    /// shares a layer with. Results are merged in the sequential order, so
+    /// both variants produce identical states and `combined_confidence`.
+    pub fn process_bidirectional_parallel(&mut self, input: LayerState) -> StackProcessResult {
+        self.process_bidirectional_with(input, true)
+    }
+
+    fn process_bidirectional_with(
+        &mut self,
+        input: LayerState,
+        parallel: bool,
+    ) -> StackProcessResult {
         let mut result = self.process_forward(input);
 
         if !self.config.enable_backward_propagation {
@@ -335,13 +384,13 @@ impl LayerStack {
 
         for iteration in 0..self.config.max_stack_iterations {
             // Backward propagation
-            self.propagate_backward(&mut result);
+            self.propagate_backward(&mut result, parallel);
 
             // Forward propagation again
-            self.propagate_forward_from_states(&mut result);
+            self.propagate_forward_from_states(&mut result, parallel);
 
             // Amplification across all bridges
-            self.amplify_all_bridges(&mut result);
+            self.amplify_all_bridges(&mut result, parallel);
 
             // Check convergence
             let confidence_change = (result.combined_confidence - previous_confidence).abs();
@@ -365,82 +414,104 @@ impl LayerStack {
         result
     }
 
-    /// Propagate backward through the stack.
-    fn propagate_backward(&mut self, result: &mut StackProcessResult) {
-        let layers: Vec<Layer> = result
-            .layer_states
-            .keys()
-            .copied()
-            .collect::<Vec<_>>()
-            .into_iter()
-            .rev()
-            .collect();
+    /// Propagate backward through the stack, from the highest layer down.
+    fn propagate_backward(&mut self, result: &mut StackProcessResult, parallel: bool) {
+        let mut layers = sorted_layers(&result.layer_states);
+        layers.reverse();
 
-        for (i, &source_layer) in layers.iter().enumerate() {
-            if i + 1 >= layers.len() {
-                break;
-            }
-
-            let target_layer = layers[i + 1];
-
-            if let Some(bridge) = self
-                .bridge_network
-                .bridge_between(source_layer, target_layer)
-            {
-                if let Some(source_state) = result.layer_states.get(&source_layer) {
-                    if let Ok(refined_state) = bridge.backward(source_state) {
-                        self.stats.total_backward_propagations += 1;
-
-                        let signal =
-                            LayerSignal::new(source_layer, target_layer, refined_state.clone());
-                        result.signal_trace.push(signal);
+        let ops: Vec<(Layer, Layer, Arc<dyn BidirectionalBridge>)> = layers
+            .windows(2)
+            .filter_map(|pair| {
+                self.bridge_network
+                    .bridge_between(pair[0], pair[1])
+                    .map(|bridge| (pair[0], pair[1], bridge))
+            })
+            .collect();
+        let footprints: Vec<Footprint> = ops
+            .iter()
+            .map(|(source, target, _)| Footprint::new(&[*source, *target], &[*target]))
+            .collect();
 
-                        // Merge refined state with existing (clamped)
-                        if let Some(existing) = result.layer_states.get_mut(&target_layer) {
-                            let raw_confidence =
-                                (existing.confidence + refined_state.confidence) / 2.0;
-                            existing.confidence = self.config.clamp_confidence(raw_confidence);
-                            existing.increment_amplification();
-                        }
+        let config = &self.config;
+        let stats = &mut self.stats;
+        run_in_waves(
+            &footprints,
+            parallel,
+            result,
+            |op, states| {
+                let (source, _, bridge) = &ops[op];
+                states.get(source).map(|state| bridge.backward(state))
+            },
+            |op, output, result| {
+                let (source_layer, target_layer) = (ops[op].0, ops[op].1);
+                if let Some(Ok(refined_state)) = output {
+                    stats.total_backward_propagations += 1;
+
+                    let signal =
+                        LayerSignal::new(source_layer, target_layer, refined_state.clone());
+                    result.signal_trace.push(signal);
+
+                    // Merge refined state with existing (clamped)
+                    if let Some(existing) = result.layer_states.get_mut(&target_layer) {
+                        let raw_confidence = (existing.confidence + refined_state.confidence) / 2.0;
+                        existing.confidence = config.clamp_confidence(raw_confidence);
+                        existing.increment_amplification();
                     }
                 }
-            }
-        }
+            },
+        );
     }
 
-    /// Propagate forward from current states.
-    fn propagate_forward_from_states(&mut self, result: &mut StackProcessResult) {
-        let layers: Vec<Layer> = result.layer_states.keys().copied().collect();
+    /// Propagate forward from current states, from the lowest layer up.
+    fn propagate_forward_from_states(&mut self, result: &mut StackProcessResult, parallel: bool) {
+        let layers = sorted_layers(&result.layer_states);
 
+        let mut ops: Vec<(Layer, Layer, Arc<dyn BidirectionalBridge>)> = Vec::new();
         for &source_layer in &layers {
             for &target_layer in &layers {
                 if target_layer.number() <= source_layer.number() {
                     continue;
                 }
-
                 if let Some(bridge) = self
                     .bridge_network
                     .bridge_between(source_layer, target_layer)
                 {
-                    if let Some(source_state) = result.layer_states.get(&source_layer) {
-                        if let Ok(new_state) = bridge.forward(source_state) {
-                            self.stats.total_forward_propagations += 1;
-
-                            // Merge with existing state (with damping and clamping)
-                            if let Some(existing) = result.layer_states.get_mut(&target_layer) {
-                                let raw_confidence = (existing.confidence * 0.7
-                                    + new_state.confidence * 0.3)
-                                    * (1.0
-                                        + (self.config.global_amplification - 1.0)
-                                            * self.config.amplification_damping);
-                                existing.confidence = self.config.clamp_confidence(raw_confidence);
-                                existing.increment_amplification();
-                            }
-                        }
-                    }
+                    ops.push((source_layer, target_layer, bridge));
                 }
             }
         }
+        let footprints: Vec<Footprint> = ops
+            .iter()
+            .map(|(source, target, _)| Footprint::new(&[*source, *target], &[*target]))
+            .collect();
+
+        let config = &self.config;
+        let stats = &mut self.stats;
+        run_in_waves(
+            &footprints,
+            parallel,
+            result,
+            |op, states| {
+                let (source, _, bridge) = &ops[op];
+                states.get(source).map(|state| bridge.forward(state))
+            },
+            |op, output, result| {
+                if let Some(Ok(new_state)) = output {
+                    stats.total_forward_propagations += 1;
+
+                    // Merge with existing state (with damping and clamping)
+                    if let Some(existing) = result.layer_states.get_mut(&ops[op].1) {
+                        let raw_confidence = (existing.confidence * 0.7
+                            + new_state.confidence * 0.3)
+                            * (1.0
+                                + (config.global_amplification - 1.0)
+                                    * config.amplification_damping);
+                        existing.confidence = config.clamp_confidence(raw_confidence);
+                        existing.increment_amplification();
+                    }
+                }
+            },
+        );
 
         // Recalculate combined confidence
         result.layer_confidences = result
@@ -451,46 +522,69 @@ impl LayerStack {
         result.combined_confidence = self.calculate_combined_confidence(&result.layer_confidences);
     }
 
-    /// Run amplification across all bridges.
-    fn amplify_all_bridges(&mut self, result: &mut StackProcessResult) {
+    /// Run amplification across all bridges, in registration order.
+    fn amplify_all_bridges(&mut self, result: &mut StackProcessResult, parallel: bool) {
         let bridges = self.bridge_network.bridges().to_vec();
+        let footprints: Vec<Footprint> = bridges
+            .iter()
+            .map(|bridge| {
+                let layers = [bridge.source_layer(), bridge.target_layer()];
+                Footprint::new(&layers, &layers)
+            })
+            .collect();
 
-        for bridge in bridges {
-            let source = bridge.source_layer();
-            let target = bridge.target_layer();
-
-            let (source_state, target_state) = {
-                let s = result.layer_states.get(&source).cloned();
-                let t = result.layer_states.get(&target).cloned();
-                match (s, t) {
-                    (Some(s), Some(t)) => (s, t),
-                    _ => continue,
-                }
-            };
-
-            let layer_config = self.config.layer_configs.get(&source);
-            let max_iterations = layer_config
-                .map(|c| c.max_amplification_iterations)
-                .unwrap_or(10);
-
-            if let Ok(amp_result) = bridge.amplify(&source_state, &target_state, max_iterations) {
-                self.stats.total_amplifications += 1;
+        // Amplification factors per bridge, folded into the total in
+        // registration order once every wave has run
+        let mut factors: Vec<Option<f32>> = vec![None; bridges.len()];
+
+        let config = &self.config;
+        let stats = &mut self.stats;
+        run_in_waves(
+            &footprints,
+            parallel,
+            result,
+            |op, states| {
+                let bridge = &bridges[op];
+                let source_state = states.get(&bridge.source_layer())?;
+                let target_state = states.get(&bridge.target_layer())?;
+
+                let max_iterations = config
+                    .layer_configs
+                    .get(&bridge.source_layer())
+                    .map(|c| c.max_amplification_iterations)
+                    .unwrap_or(10);
+
+                bridge
+                    .amplify(source_state, target_state, max_iterations)
+                    .ok()
+            },
+            |op, output, result| {
+                let Some(amp_result) = output else {
+                    return;
+                };
+                stats.total_amplifications += 1;
 
                 // Update states with amplified versions (clamped)
                 let mut up_state = amp_result.up_state;
                 let mut down_state = amp_result.down_state;
-                up_state.confidence = self.config.clamp_confidence(up_state.confidence);
-                down_state.confidence = self.config.clamp_confidence(down_state.confidence);
-
-                result.layer_states.insert(source, up_state);
-                result.layer_states.insert(target, down_state);
+                up_state.confidence = config.clamp_confidence(up_state.confidence);
+                down_state.confidence = config.clamp_confidence(down_state.confidence);
+
+                result
+                    .layer_states
+                    .insert(bridges[op].source_layer(), up_state);
+                result
+                    .layer_states
+                    .insert(bridges[op].target_layer(), down_state);
+                factors[op] = Some(amp_result.amplification_factor);
+            },
+        );
 
-                // Track amplification with damping and capping
-                let damped_factor = 1.0
-                    + (amp_result.amplification_factor - 1.0) * self.config.amplification_damping;
-                result.total_amplification = (result.total_amplification * damped_factor)
-                    .min(self.config.max_total_amplification);
-            }
+        // Track amplification with damping and capping
+        for factor in factors.into_iter().flatten() {
+            let damped_factor = 1.0 + (factor - 1.0) * self.config.amplification_damping;
+            result.total_amplification = (result.total_amplification * damped_factor)
+                .min(self.config.max_total_amplification);
         }
 
         // Recalculate combined confidence
@@ -508,8 +602,12 @@ impl LayerStack {
             return 0.0;
         }
 
-        // Multiplicative combination (geometric mean with damped amplification)
-        let product: f32 = confidences.values().product();
+        // Multiplicative combination (geometric mean with damped amplification),
+        // taken in layer order so the float result is reproducible
+        let product: f32 = sorted_layers(confidences)
+            .iter()
+            .map(|layer| confidences[layer])
+            .product();
         let n = confidences.len() as f32;
         let damped_amp =
             1.0 + (self.config.global_amplification - 1.0) * self.config.amplification_damping;
@@ -566,8 +664,100 @@ impl std::fmt::Debug for LayerStack {
     }
 }
 
+/// Layers keyed in `map`, lowest layer number first.
+fn sorted_layers<V>(map: &HashMap<Layer, V>) -> Vec<Layer> {
+    let mut layers: Vec<Layer> = map.keys().copied().collect();
+    layers.sort_by_key(|l| l.number());
+    layers
+}
+
+/// Layers a bridge call reads and writes, for scheduling it into a wave.
+struct Footprint {
+    reads: Vec<Layer>,
+    writes: Vec<Layer>,
+}
+
+impl Footprint {
+    fn new(reads: &[Layer], writes: &[Layer]) -> Self {
+        Self {
+            reads: reads.to_vec(),
+            writes: writes.to_vec(),
+        }
+    }
+
+    /// Whether `later` must wait for this call: one writes a layer the
+    /// other reads or writes.
+    fn conflicts_with(&self, later: &Footprint) -> bool {
+        self.writes
+            .iter()
+            .any(|l| later.reads.contains(l) || later.writes.contains(l))
+            || self.reads.iter().any(|l| later.writes.contains(l))
+    }
+}
+
+/// Group calls into waves: each call goes one wave after the latest
+/// earlier call it conflicts with. Calls within a wave keep their order.
+fn schedule_waves(footprints: &[Footprint]) -> Vec<Vec<usize>> {
+    let mut wave_of: Vec<usize> = Vec::with_capacity(footprints.len());
+    let mut waves: Vec<Vec<usize>> = Vec::new();
+    for (op, footprint) in footprints.iter().enumerate() {
+        let wave = (0..op)
+            .filter(|&earlier| footprints[earlier].conflicts_with(footprint))
+            .map(|earlier| wave_of[earlier] + 1)
+            .max()
+            .unwrap_or(0);
+        wave_of.push(wave);
+        if wave == waves.len() {
+            waves.push(Vec::new());
+        }
+        waves[wave].push(op);
+    }
+    waves
+}
+
+/// Evaluate one bridge call per footprint against the current layer states
+/// and merge each output in call order. Sequentially every call is its own
+/// wave; in parallel the calls of a wave are evaluated on scoped threads
+/// before any of them is merged.
+fn run_in_waves<T: Send>(
+    footprints: &[Footprint],
+    parallel: bool,
+    result: &mut StackProcessResult,
+    eval: impl Fn(usize, &HashMap<Layer, LayerState>) -> T + Sync,
+    mut merge: impl FnMut(usize, T, &mut StackProcessResult),
+) {
+    let waves = if parallel {
+        schedule_waves(footprints)
+    } else {
+        (0..footprints.len()).map(|op| vec![op]).collect()
+    };
+
+    for wave in waves {
+        let outputs: Vec<T> = if wave.len() == 1 {
+            vec![eval(wave[0], &result.layer_states)]
+        } else {
+            let states = &result.layer_states;
+            let eval = &eval;
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = wave
+                    .iter()
+                    .map(|&op| scope.spawn(move || eval(op, states)))
+                    .collect();
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().expect("bridge evaluation panicked"))
+                    .collect()
+            })
+        };
+        for (&op, output) in wave.iter().zip(outputs) {
+            merge(op, output, result);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use super::super::bridge::testing::ScalingBridge;
     use super::*;
 
     #[test]
@@ -610,6 +800,141 @@ mod tests {
         assert!(result.layer_states.contains_key(&Layer::BasePhysics));
     }
 
+    #[test]
+    fn test_forward_reaches_custom_layer() {
+        let mut stack = LayerStack::new();
+        let quantum = stack
+            .registry_mut()
+            .register_custom_layer("quantum")
+            .unwrap();
+        stack.register_bridge(Arc::new(ScalingBridge {
+            source: Layer::BasePhysics,
+            target: quantum,
+            gain: 1.1,
+            amplification: 1.0,
+        }));
+
+        let input = LayerState::with_confidence(Layer::BasePhysics, (), 0.8);
+        let result = stack.process_forward(input);
+        let state = result.get_state(quantum).unwrap();
+        assert!((state.confidence - 0.88).abs() < 1e-6);
+        assert_eq!(stack.registry().stats().total_layers, 8);
+
+        // Disabled custom layers are skipped like built-in ones
+        stack.registry_mut().disable(quantum);
+        let input = LayerState::with_confidence(Layer::BasePhysics, (), 0.8);
+        assert!(stack.process_forward(input).get_state(quantum).is_none());
+    }
+
+    #[test]
+    fn test_save_and_load_state() {
+        let mut stack = LayerStack::new();
+        stack.inject_state(LayerState::with_confidence(
+            Layer::BasePhysics,
+            "input".to_string(),
+            0.8,
+        ));
+        stack.inject_state(LayerState::with_confidence(
+            Layer::GaiaConsciousness,
+            vec![0.1f32, 0.2],
+            1.3,
+        ));
+        stack.inject_state(LayerState::new(
+            Layer::ExternalApis,
+            std::time::Duration::from_secs(1),
+        ));
+
+        let path = std::env::temp_dir().join(format!("layer-states-{}.json", std::process::id()));
+        assert_eq!(stack.save_state(&path), Ok(vec![Layer::ExternalApis]));
+
+        let mut restored = LayerStack::new();
+        let loaded = restored.load_state(&path);
+        std::fs::remove_file(&path).ok();
+        assert_eq!(loaded, Ok(2));
+
+        let gaia = restored
+            .get_current_state(Layer::GaiaConsciousness)
+            .unwrap();
+        assert_eq!(gaia.confidence, 1.3);
+        assert_eq!(gaia.data::<Vec<f32>>(), Some(&vec![0.1, 0.2]));
+        assert!(restored.load_state(&path).is_err());
+    }
+
+    #[test]
+    fn test_wave_scheduling() {
+        use Layer::*;
+        let footprints = vec![
+            Footprint::new(
+                &[BasePhysics, ExtendedPhysics],
+                &[BasePhysics, ExtendedPhysics],
+            ),
+            Footprint::new(
+                &[CrossDomain, GaiaConsciousness],
+                &[CrossDomain, GaiaConsciousness],
+            ),
+            Footprint::new(
+                &[ExtendedPhysics, CrossDomain],
+                &[ExtendedPhysics, CrossDomain],
+            ),
+            Footprint::new(&[ExternalApis], &[ExternalApis]),
+            // Shares only a read with call 4: may run alongside it
+            Footprint::new(
+                &[BasePhysics, MultilingualProcessing],
+                &[MultilingualProcessing],
+            ),
+            Footprint::new(
+                &[BasePhysics, CollaborativeLearning],
+                &[CollaborativeLearning],
+            ),
+        ];
+        assert_eq!(
+            schedule_waves(&footprints),
+            vec![vec![0, 1, 3], vec![2, 4, 5]]
+        );
+    }
+
+    #[test]
+    fn test_parallel_matches_sequential() {
+        use super::super::bridges::BridgeBuilder;
+
+        let run = |parallel: bool| {
+            let mut stack = LayerStack::new();
+            for bridge in BridgeBuilder::build_all() {
+                stack.register_bridge(bridge);
+            }
+            let input = LayerState::with_confidence(Layer::BasePhysics, "input".to_string(), 0.8);
+            let result = if parallel {
+                stack.process_bidirectional_parallel(input)
+            } else {
+                stack.process_bidirectional(input)
+            };
+            let stats = stack.stats().clone();
+            (result, stats)
+        };
+
+        let (sequential, seq_stats) = run(false);
+        let (parallel, par_stats) = run(true);
+        assert!(sequential.layer_states.len() > 2);
+        assert_eq!(
+            sequential.combined_confidence.to_bits(),
+            parallel.combined_confidence.to_bits()
+        );
+        assert_eq!(
+            sequential.total_amplification.to_bits(),
+            parallel.total_amplification.to_bits()
+        );
+        assert_eq!(sequential.iterations, parallel.iterations);
+        assert_eq!(sequential.layer_confidences, parallel.layer_confidences);
+        assert_eq!(
+            seq_stats.total_amplifications,
+            par_stats.total_amplifications
+        );
+        assert_eq!(
+            seq_stats.total_forward_propagations,
+            par_stats.total_forward_propagations
+        );
+    }
+
     #[test]
     fn test_stack_result() {
         let result = StackProcessResult::empty();
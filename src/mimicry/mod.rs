@@ -8,6 +8,7 @@
 //! - [`capability`] — Capability descriptors and modality routing.
 //! - [`cache`] — System-1 fast-path: signature caching and instinctive routing.
 //! - [`engine`] — Dual-process orchestrator that ties analysis and generation together.
+//! - [`backend`] — Pluggable System-2 generation backends (templates or a real model).
 //! - [`evolution`] — Drift detection, milestones, and training-data management.
 //! - [`persistence`] — Checkpoint save/load and manifest management.
 //! - [`templates`] — System-1 response generation: tone blending, hedging, formatting.
@@ -41,6 +42,7 @@
 pub mod analyzer;
 #[cfg(feature = "api")]
 pub mod api;
+pub mod backend;
 pub mod cache;
 pub mod capability;
 pub mod engine;
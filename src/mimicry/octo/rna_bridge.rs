@@ -167,25 +167,25 @@ impl OctoRNABridge {
                                 unsafe {
                                     WEIGHTS_LOADED = true;
                                 }
-                                eprintln!(
-                                    "[OCTO] Loaded trained weights from {}",
-                                    DEFAULT_WEIGHTS_PATH
+                                tracing::info!(
+                                    path = DEFAULT_WEIGHTS_PATH,
+                                    "loaded trained OCTO weights"
                                 );
                             } else {
-                                eprintln!(
-                                    "[OCTO] Warning: Failed to load weights from {}",
-                                    DEFAULT_WEIGHTS_PATH
+                                tracing::warn!(
+                                    path = DEFAULT_WEIGHTS_PATH,
+                                    "failed to load OCTO weights"
                                 );
                             }
                         }
                         Err(e) => {
-                            eprintln!("[OCTO] Warning: Could not load weights: {}", e);
+                            tracing::warn!(error = %e, "could not load OCTO weights");
                         }
                     }
                 } else {
-                    eprintln!(
-                        "[OCTO] No pre-trained weights found at {}",
-                        DEFAULT_WEIGHTS_PATH
+                    tracing::info!(
+                        path = DEFAULT_WEIGHTS_PATH,
+                        "no pre-trained OCTO weights found"
                     );
                 }
 
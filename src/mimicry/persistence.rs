@@ -6,7 +6,18 @@
 // provides the file I/O layer and directory management.
 //
 // COMPOUND INTEGRATIONS:
-// - save_persona() / load_persona(): full CompoundPersonaSnapshot
+// - save_persona() / load_persona(): full CompoundPersonaSnapshot,
+//   as JSON or MessagePack per SerializationFormat; overwrites keep
+//   `<name>.bak.<timestamp>` backups restorable via restore_backup();
+//   older schema versions are migrated on load
+// - export_bundle() / import_bundle(): every persona in one JSON file
+// - write_atomic(): temp file + rename, so a crash mid-save never
+//   leaves a truncated persona, checkpoint, or manifest behind
+// - encryption_key (encryption feature): every saved file except the
+//   manifest sealed with ChaCha20-Poly1305 behind an ENCRYPTED_MAGIC
+//   header
+// - verify_integrity(): SHA-256 content hashes in the manifest checked
+//   against disk, plus missing and orphaned files
 // - save_profile() / load_profile(): AiProfile import/export
 // - save_session() / load_session(): MimicSession with history
 // - save_engine_state() / load_engine_state(): entire engine checkpoint
@@ -14,19 +25,59 @@
 // =================================================================
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
+use std::io::{self, Write};
 use std::path::{Path, PathBuf};
 
-use crate::mimicry::engine::CompoundPersonaSnapshot;
-use crate::mimicry::profile::AiProfile;
+use crate::mimicry::cache::SignatureCache;
+use crate::mimicry::engine::{CompoundPersonaSnapshot, PERSONA_SCHEMA_VERSION};
+use crate::mimicry::profile::{AiProfile, ProfileError};
 
 // =================================================================
 // PERSISTENCE CONFIG
 // =================================================================
 
+/// On-disk encoding for persona snapshots
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SerializationFormat {
+    /// Human-readable JSON (`.json`), honoring `pretty_print`
+    #[default]
+    Json,
+    /// Compact binary MessagePack (`.mp`)
+    MessagePack,
+}
+
+impl SerializationFormat {
+    /// File extension written for this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            SerializationFormat::Json => "json",
+            SerializationFormat::MessagePack => "mp",
+        }
+    }
+
+    /// Format implied by a file's extension, if recognized
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(SerializationFormat::Json),
+            "mp" => Some(SerializationFormat::MessagePack),
+            _ => None,
+        }
+    }
+
+    /// The other format, checked when loading saves made before a switch
+    fn other(&self) -> Self {
+        match self {
+            SerializationFormat::Json => SerializationFormat::MessagePack,
+            SerializationFormat::MessagePack => SerializationFormat::Json,
+        }
+    }
+}
+
 /// Configuration for the persistence layer
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct PersistenceConfig {
     /// Base directory for all saved data
     pub base_dir: PathBuf,
@@ -44,6 +95,54 @@ pub struct PersistenceConfig {
     pub auto_save_interval: u64,
     /// Pretty-print JSON output
     pub pretty_print: bool,
+    /// Whether anything is read from or written to disk. When false every
+    /// operation fails with an error instead of touching `base_dir`.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Encoding used when saving persona snapshots; loading accepts either
+    #[serde(default)]
+    pub format: SerializationFormat,
+    /// Backups kept per persona when a save overwrites an existing file
+    /// (0 disables backups)
+    #[serde(default = "default_keep_backups")]
+    pub keep_backups: usize,
+    /// Key for encrypting saved files at rest (requires the `encryption`
+    /// feature): personas, bundles, profiles, sessions, checkpoints, the
+    /// signature cache and the API response cache. Only the manifest stays
+    /// plaintext. Never serialized and redacted from `Debug`. Plaintext
+    /// files still load while a key is set.
+    #[serde(skip)]
+    pub encryption_key: Option<[u8; 32]>,
+}
+
+impl std::fmt::Debug for PersistenceConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PersistenceConfig")
+            .field("base_dir", &self.base_dir)
+            .field("personas_dir", &self.personas_dir)
+            .field("profiles_dir", &self.profiles_dir)
+            .field("sessions_dir", &self.sessions_dir)
+            .field("checkpoints_dir", &self.checkpoints_dir)
+            .field("auto_save_enabled", &self.auto_save_enabled)
+            .field("auto_save_interval", &self.auto_save_interval)
+            .field("pretty_print", &self.pretty_print)
+            .field("enabled", &self.enabled)
+            .field("format", &self.format)
+            .field("keep_backups", &self.keep_backups)
+            .field("encryption_key", &self.encryption_key.map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+/// Default number of persona backups kept by `PersistenceConfig`
+pub const DEFAULT_KEEP_BACKUPS: usize = 3;
+
+fn default_enabled() -> bool {
+    true
+}
+
+fn default_keep_backups() -> usize {
+    DEFAULT_KEEP_BACKUPS
 }
 
 impl Default for PersistenceConfig {
@@ -57,11 +156,25 @@ impl Default for PersistenceConfig {
             auto_save_enabled: true,
             auto_save_interval: 10,
             pretty_print: true,
+            enabled: true,
+            format: SerializationFormat::Json,
+            keep_backups: DEFAULT_KEEP_BACKUPS,
+            encryption_key: None,
         }
     }
 }
 
 impl PersistenceConfig {
+    /// A config that never touches disk, for replays and other
+    /// in-memory-only engines.
+    pub fn disabled() -> Self {
+        PersistenceConfig {
+            auto_save_enabled: false,
+            enabled: false,
+            ..PersistenceConfig::default()
+        }
+    }
+
     /// Returns the full path to the personas directory.
     pub fn personas_path(&self) -> PathBuf {
         self.base_dir.join(&self.personas_dir)
@@ -78,6 +191,10 @@ impl PersistenceConfig {
     pub fn checkpoints_path(&self) -> PathBuf {
         self.base_dir.join(&self.checkpoints_dir)
     }
+    /// Returns the path of the persisted System 1 signature cache.
+    pub fn signature_cache_path(&self) -> PathBuf {
+        self.checkpoints_path().join("signature_cache.json")
+    }
 }
 
 // =================================================================
@@ -110,6 +227,76 @@ pub struct SaveEntry {
     pub size_bytes: u64,
     /// Arbitrary key-value metadata associated with this artifact.
     pub metadata: HashMap<String, String>,
+    /// Hex SHA-256 of the file as written; `None` for entries recorded
+    /// before hashing was added.
+    #[serde(default)]
+    pub content_hash: Option<String>,
+}
+
+/// A problem found by `PersistenceManager::verify_integrity`. `category`
+/// is the manifest section: "persona", "profile", "session", or
+/// "checkpoint".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegrityIssue {
+    /// The file's contents no longer match the hash recorded at save time
+    HashMismatch {
+        /// Manifest section of the entry
+        category: String,
+        /// Entry name
+        name: String,
+        /// File the entry points to
+        filename: String,
+        /// Hash recorded in the manifest
+        expected: String,
+        /// Hash of the file on disk
+        actual: String,
+    },
+    /// A manifest entry points to a file that does not exist
+    MissingFile {
+        /// Manifest section of the entry
+        category: String,
+        /// Entry name
+        name: String,
+        /// File the entry points to
+        filename: String,
+    },
+    /// A saved file that no manifest entry refers to
+    OrphanFile {
+        /// Manifest section whose directory holds the file
+        category: String,
+        /// The unreferenced file
+        filename: String,
+    },
+}
+
+impl std::fmt::Display for IntegrityIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntegrityIssue::HashMismatch {
+                category,
+                name,
+                filename,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{} '{}': {} changed since save (expected {}, found {})",
+                category,
+                name,
+                filename,
+                &expected[..expected.len().min(12)],
+                &actual[..actual.len().min(12)]
+            ),
+            IntegrityIssue::MissingFile {
+                category,
+                name,
+                filename,
+            } => write!(f, "{} '{}': {} is missing", category, name, filename),
+            IntegrityIssue::OrphanFile { category, filename } => {
+                write!(f, "{}: {} is not in the manifest", category, filename)
+            }
+        }
+    }
 }
 
 impl SaveManifest {
@@ -136,6 +323,57 @@ impl Default for SaveManifest {
     }
 }
 
+// =================================================================
+// PERSONA BUNDLE - Every saved persona in one portable file
+// =================================================================
+
+/// Current `PersonaBundle::bundle_version`
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// A whole persona collection archived into a single JSON file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersonaBundle {
+    /// Layout version of the bundle itself
+    pub bundle_version: u32,
+    /// Manifest of the exporting machine, for its save metadata
+    pub manifest: SaveManifest,
+    /// Raw persona snapshots by save name; each is migrated on import
+    pub personas: BTreeMap<String, serde_json::Value>,
+}
+
+/// What `import_bundle` does when a bundled persona's name is already saved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundleConflict {
+    /// Keep the existing save
+    Skip,
+    /// Replace the existing save (it is backed up first)
+    Overwrite,
+    /// Keep whichever side has more compound iterations, preferring the
+    /// existing save on a tie
+    Merge,
+}
+
+impl BundleConflict {
+    /// Parse a policy name (`skip`, `overwrite`, `merge`)
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "skip" => Some(BundleConflict::Skip),
+            "overwrite" => Some(BundleConflict::Overwrite),
+            "merge" => Some(BundleConflict::Merge),
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of `import_bundle`, with names in bundle order
+#[derive(Debug, Clone, Default)]
+pub struct BundleImport {
+    /// Personas written to disk
+    pub imported: Vec<String>,
+    /// Personas left alone because of the conflict policy
+    pub skipped: Vec<String>,
+}
+
 // =================================================================
 // ENGINE STATE CHECKPOINT
 // =================================================================
@@ -162,6 +400,11 @@ pub struct EngineCheckpoint {
 // PERSISTENCE MANAGER
 // =================================================================
 
+/// Upgrades a raw persona snapshot by one schema version. It receives the
+/// decoded document at version `n` and returns it at `n + 1`.
+pub type PersonaMigration =
+    Box<dyn Fn(serde_json::Value) -> Result<serde_json::Value, String> + Send + Sync>;
+
 /// The main persistence manager. Handles all file I/O for RustyWorm.
 pub struct PersistenceManager {
     /// Configuration controlling directory layout and serialization options.
@@ -169,16 +412,117 @@ pub struct PersistenceManager {
     /// The in-memory manifest tracking all saved artifacts.
     pub manifest: SaveManifest,
     initialized: bool,
+    /// Persona migrations keyed by the schema version they upgrade from
+    migrations: HashMap<u32, PersonaMigration>,
 }
 
 impl PersistenceManager {
     /// Creates a new persistence manager with the given configuration.
     pub fn new(config: PersistenceConfig) -> Self {
-        PersistenceManager {
+        let mut manager = PersistenceManager {
             config,
             manifest: SaveManifest::new(),
             initialized: false,
+            migrations: HashMap::new(),
+        };
+        manager.register_migration(0, Box::new(migrate_persona_v0_to_v1));
+        manager
+    }
+
+    /// Register the migration that upgrades persona snapshots from
+    /// `from_version` to `from_version + 1`, replacing any existing one.
+    pub fn register_migration(&mut self, from_version: u32, migration: PersonaMigration) {
+        self.migrations.insert(from_version, migration);
+    }
+
+    /// Bring a raw persona document up to `PERSONA_SCHEMA_VERSION` by chaining
+    /// registered migrations. Versions newer than this build understands are
+    /// rejected instead of being partially deserialized.
+    fn migrate_persona(&self, mut value: serde_json::Value) -> Result<serde_json::Value, String> {
+        let mut version = match value.get("schema_version") {
+            None => 0,
+            Some(v) => v
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .ok_or_else(|| format!("invalid schema_version {}", v))?,
+        };
+        if version > PERSONA_SCHEMA_VERSION {
+            return Err(format!(
+                "schema version {} is newer than the supported version {}; upgrade to load it",
+                version, PERSONA_SCHEMA_VERSION
+            ));
+        }
+        while version < PERSONA_SCHEMA_VERSION {
+            let migration = self.migrations.get(&version).ok_or_else(|| {
+                format!("no migration registered from schema version {}", version)
+            })?;
+            value = migration(value)
+                .map_err(|e| format!("migration from schema version {} failed: {}", version, e))?;
+            version += 1;
+            if let Some(map) = value.as_object_mut() {
+                map.insert("schema_version".to_string(), version.into());
+            }
+        }
+        Ok(value)
+    }
+
+    /// Encrypt `data` when an encryption key is configured
+    fn seal(&self, data: Vec<u8>) -> Result<Vec<u8>, String> {
+        seal_with(self.config.encryption_key.as_ref(), data)
+    }
+
+    /// Decrypt `data` if it carries the encrypted header; plaintext is
+    /// passed through unchanged
+    fn unseal<'a>(&self, data: &'a [u8], what: &str) -> Result<Cow<'a, [u8]>, String> {
+        unseal_with(self.config.encryption_key.as_ref(), data, what)
+    }
+
+    /// Read `path` and decrypt it if needed
+    fn read_unsealed(&self, path: &Path, what: &str) -> Result<Vec<u8>, String> {
+        let data = fs::read(path).map_err(|e| format!("Failed to read {}: {}", what, e))?;
+        Ok(self.unseal(&data, what)?.into_owned())
+    }
+
+    /// Decode persona bytes, decrypting them if needed and migrating older
+    /// schema versions. Without a `format` the codec is sniffed: JSON
+    /// opens with '{', anything else is taken as MessagePack.
+    fn decode_persona(
+        &self,
+        data: &[u8],
+        format: Option<SerializationFormat>,
+        name: &str,
+    ) -> Result<CompoundPersonaSnapshot, String> {
+        let data = self.unseal(data, &format!("persona '{}'", name))?;
+        let format = format.unwrap_or_else(|| {
+            if data
+                .iter()
+                .find(|b| !b.is_ascii_whitespace())
+                .is_some_and(|b| *b == b'{')
+            {
+                SerializationFormat::Json
+            } else {
+                SerializationFormat::MessagePack
+            }
+        });
+        let data = data.as_ref();
+        let parse_err = |e: String| format!("Failed to parse persona '{}': {}", name, e);
+        let raw: serde_json::Value = match format {
+            SerializationFormat::Json => serde_json::from_slice(data).map_err(|e| e.to_string()),
+            SerializationFormat::MessagePack => {
+                rmp_serde::from_slice(data).map_err(|e| e.to_string())
+            }
         }
+        .map_err(parse_err)?;
+        let migrated = self.migrate_persona(raw).map_err(parse_err)?;
+        serde_json::from_value(migrated).map_err(|e| parse_err(e.to_string()))
+    }
+
+    /// Read and decode a persona file, picking the codec from its extension
+    /// (sniffed when unrecognized)
+    fn read_persona(&self, path: &Path, name: &str) -> Result<CompoundPersonaSnapshot, String> {
+        let data =
+            fs::read(path).map_err(|e| format!("Failed to read persona '{}': {}", name, e))?;
+        self.decode_persona(&data, SerializationFormat::from_path(path), name)
     }
 
     /// Creates a new persistence manager using the default configuration.
@@ -188,6 +532,9 @@ impl PersistenceManager {
 
     /// Initialize the directory structure, loading the manifest if it exists
     pub fn initialize(&mut self) -> Result<String, String> {
+        if !self.config.enabled {
+            return Err("Persistence is disabled".to_string());
+        }
         let dirs = [
             self.config.personas_path(),
             self.config.profiles_path(),
@@ -247,7 +594,8 @@ impl PersistenceManager {
         }
         .map_err(|e| format!("Failed to serialize manifest: {}", e))?;
 
-        fs::write(&path, &json).map_err(|e| format!("Failed to write manifest: {}", e))?;
+        write_atomic(&path, json.as_bytes())
+            .map_err(|e| format!("Failed to write manifest: {}", e))?;
         Ok(())
     }
 
@@ -261,6 +609,25 @@ impl PersistenceManager {
         .map_err(|e| format!("Serialization error: {}", e))
     }
 
+    /// Encode a persona snapshot in the configured format
+    fn encode_persona(&self, snapshot: &CompoundPersonaSnapshot) -> Result<Vec<u8>, String> {
+        match self.config.format {
+            SerializationFormat::Json => self.to_json(snapshot).map(String::into_bytes),
+            SerializationFormat::MessagePack => {
+                rmp_serde::to_vec_named(snapshot).map_err(|e| format!("Serialization error: {}", e))
+            }
+        }
+    }
+
+    /// Path of a persona file in the given format
+    fn persona_path(&self, name: &str, format: SerializationFormat) -> PathBuf {
+        self.config.personas_path().join(format!(
+            "{}.{}",
+            sanitize_filename(name),
+            format.extension()
+        ))
+    }
+
     // =================================================================
     // PERSONA SAVE/LOAD
     // =================================================================
@@ -273,13 +640,17 @@ impl PersistenceManager {
     ) -> Result<String, String> {
         self.ensure_init()?;
 
-        let filename = format!("{}.json", sanitize_filename(name));
+        let format = self.config.format;
+        let filename = format!("{}.{}", sanitize_filename(name), format.extension());
         let path = self.config.personas_path().join(&filename);
-        let json = self.to_json(snapshot)?;
-        let size = json.len() as u64;
+        let bytes = self.seal(self.encode_persona(snapshot)?)?;
+        let size = bytes.len() as u64;
 
-        fs::write(&path, &json)
+        self.backup_persona(name)?;
+        write_atomic(&path, &bytes)
             .map_err(|e| format!("Failed to write persona '{}': {}", name, e))?;
+        // Drop a stale save in the other format so loads can't pick it up
+        let _ = fs::remove_file(self.persona_path(name, format.other()));
 
         let mut meta = HashMap::new();
         meta.insert(
@@ -300,6 +671,7 @@ impl PersistenceManager {
                 saved_at: timestamp(),
                 size_bytes: size,
                 metadata: meta,
+                content_hash: Some(content_hash(&bytes)),
             },
         );
         self.save_manifest()?;
@@ -313,35 +685,212 @@ impl PersistenceManager {
         ))
     }
 
-    /// Load a CompoundPersonaSnapshot from disk
+    /// Load a CompoundPersonaSnapshot from disk. The codec is chosen from
+    /// the file extension, so JSON and MessagePack saves both load whatever
+    /// `config.format` currently is.
     pub fn load_persona(&mut self, name: &str) -> Result<CompoundPersonaSnapshot, String> {
         self.ensure_init()?;
 
-        let filename = format!("{}.json", sanitize_filename(name));
-        let path = self.config.personas_path().join(&filename);
+        let format = self.config.format;
+        let path = self.persona_path(name, format);
+        let mut candidates = vec![path.clone(), self.persona_path(name, format.other())];
+        // Fall back to the manifest entry's filename
+        if let Some(entry) = self.manifest.personas.iter().find(|e| e.name == name) {
+            candidates.push(self.config.personas_path().join(&entry.filename));
+        }
 
-        if !path.exists() {
-            // Try finding by manifest entry
-            if let Some(entry) = self.manifest.personas.iter().find(|e| e.name == name) {
-                let alt_path = self.config.personas_path().join(&entry.filename);
-                if alt_path.exists() {
-                    let data = fs::read_to_string(&alt_path)
-                        .map_err(|e| format!("Failed to read persona '{}': {}", name, e))?;
-                    return serde_json::from_str(&data)
-                        .map_err(|e| format!("Failed to parse persona '{}': {}", name, e));
-                }
-            }
-            return Err(format!(
+        match candidates.into_iter().find(|p| p.exists()) {
+            Some(found) => self.read_persona(&found, name),
+            None => Err(format!(
                 "Persona '{}' not found at {}",
                 name,
                 path.display()
+            )),
+        }
+    }
+
+    // =================================================================
+    // PERSONA BACKUPS
+    // =================================================================
+
+    /// Copy the current save of `name` (in either format) to a fresh
+    /// `<name>.bak.<timestamp>` and prune to `keep_backups`. The save itself
+    /// stays in place until the new one replaces it.
+    fn backup_persona(&self, name: &str) -> Result<(), String> {
+        if self.config.keep_backups == 0 {
+            return Ok(());
+        }
+        let current = [SerializationFormat::Json, SerializationFormat::MessagePack]
+            .into_iter()
+            .map(|format| self.persona_path(name, format))
+            .find(|path| path.exists());
+        let Some(current) = current else {
+            return Ok(());
+        };
+
+        // Millisecond stamps, bumped past any collision from rapid saves
+        let mut stamp = timestamp_millis();
+        let dir = self.config.personas_path();
+        let prefix = backup_prefix(name);
+        while dir.join(format!("{}{}", prefix, stamp)).exists() {
+            stamp += 1;
+        }
+        let backup = dir.join(format!("{}{}", prefix, stamp));
+        fs::copy(&current, &backup)
+            .map_err(|e| format!("Failed to back up persona '{}': {}", name, e))?;
+
+        let backups = self.list_backups(name)?;
+        let excess = backups.len().saturating_sub(self.config.keep_backups);
+        for old in &backups[..excess] {
+            let _ = fs::remove_file(dir.join(old));
+        }
+        Ok(())
+    }
+
+    /// Backup filenames for a persona, oldest first. Only files named
+    /// exactly `<name>.bak.<digits>` count; anything else is ignored.
+    pub fn list_backups(&self, name: &str) -> Result<Vec<String>, String> {
+        let dir = self.config.personas_path();
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+        let prefix = backup_prefix(name);
+        let mut backups: Vec<(u128, String)> = fs::read_dir(&dir)
+            .map_err(|e| format!("Failed to read personas dir: {}", e))?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_file())
+            .filter_map(|entry| {
+                let filename = entry.file_name().to_string_lossy().to_string();
+                let stamp = filename.strip_prefix(&prefix)?;
+                if stamp.is_empty() || !stamp.bytes().all(|b| b.is_ascii_digit()) {
+                    return None;
+                }
+                Some((stamp.parse().ok()?, filename))
+            })
+            .collect();
+        backups.sort();
+        Ok(backups.into_iter().map(|(_, filename)| filename).collect())
+    }
+
+    /// Restore a persona from one of its backups, named either by full
+    /// filename or by timestamp. The restored snapshot is saved in the
+    /// configured format, so the save it replaces is itself backed up.
+    pub fn restore_backup(
+        &mut self,
+        name: &str,
+        backup: &str,
+    ) -> Result<CompoundPersonaSnapshot, String> {
+        self.ensure_init()?;
+
+        let prefix = backup_prefix(name);
+        let filename = self
+            .list_backups(name)?
+            .into_iter()
+            .find(|f| f == backup || f.strip_prefix(&prefix) == Some(backup))
+            .ok_or_else(|| format!("No backup '{}' for persona '{}'", backup, name))?;
+
+        let data = fs::read(self.config.personas_path().join(&filename))
+            .map_err(|e| format!("Failed to read backup '{}': {}", filename, e))?;
+        // Backups carry no extension, so the codec is sniffed
+        let snapshot = self.decode_persona(&data, None, name)?;
+
+        self.save_persona(name, &snapshot)?;
+        Ok(snapshot)
+    }
+
+    // =================================================================
+    // PERSONA BUNDLES
+    // =================================================================
+
+    /// Archive every saved persona and the manifest into one JSON file at
+    /// `path`, returning the number of personas written. Manifest entries
+    /// whose file is missing are left out.
+    pub fn export_bundle(&mut self, path: &Path) -> Result<usize, String> {
+        self.ensure_init()?;
+
+        let mut personas = BTreeMap::new();
+        for entry in &self.manifest.personas {
+            let file = self.config.personas_path().join(&entry.filename);
+            if !file.exists() {
+                continue;
+            }
+            let snapshot = self.read_persona(&file, &entry.name)?;
+            let value = serde_json::to_value(&snapshot)
+                .map_err(|e| format!("Serialization error: {}", e))?;
+            personas.insert(entry.name.clone(), value);
+        }
+
+        let bundle = PersonaBundle {
+            bundle_version: BUNDLE_VERSION,
+            manifest: self.manifest.clone(),
+            personas,
+        };
+        let bytes = self.seal(self.to_json(&bundle)?.into_bytes())?;
+        write_atomic(path, &bytes)
+            .map_err(|e| format!("Failed to write bundle {}: {}", path.display(), e))?;
+        Ok(bundle.personas.len())
+    }
+
+    /// Restore the personas in a bundle written by `export_bundle`, saving
+    /// each in the configured format. Names that are already saved are
+    /// resolved by `on_conflict`. Every persona is migrated and validated
+    /// before anything is written, so a bad bundle changes nothing.
+    pub fn import_bundle(
+        &mut self,
+        path: &Path,
+        on_conflict: BundleConflict,
+    ) -> Result<BundleImport, String> {
+        self.ensure_init()?;
+
+        let data = fs::read(path)
+            .map_err(|e| format!("Failed to read bundle {}: {}", path.display(), e))?;
+        let data = self.unseal(&data, &format!("bundle {}", path.display()))?;
+        let bundle: PersonaBundle = serde_json::from_slice(&data)
+            .map_err(|e| format!("Failed to parse bundle {}: {}", path.display(), e))?;
+        if bundle.bundle_version > BUNDLE_VERSION {
+            return Err(format!(
+                "Bundle version {} is newer than the supported version {}",
+                bundle.bundle_version, BUNDLE_VERSION
             ));
         }
 
-        let data = fs::read_to_string(&path)
-            .map_err(|e| format!("Failed to read persona '{}': {}", name, e))?;
-        serde_json::from_str(&data)
-            .map_err(|e| format!("Failed to parse persona '{}': {}", name, e))
+        let mut snapshots = Vec::with_capacity(bundle.personas.len());
+        for (name, raw) in bundle.personas {
+            let migrated = self
+                .migrate_persona(raw)
+                .map_err(|e| format!("Failed to parse persona '{}': {}", name, e))?;
+            let snapshot: CompoundPersonaSnapshot = serde_json::from_value(migrated)
+                .map_err(|e| format!("Failed to parse persona '{}': {}", name, e))?;
+            snapshot.profile.validate().map_err(|errors| {
+                format!(
+                    "Invalid persona '{}' in bundle: {}",
+                    name,
+                    ProfileError::summarize(&errors)
+                )
+            })?;
+            snapshots.push((name, snapshot));
+        }
+
+        let mut result = BundleImport::default();
+        for (name, snapshot) in snapshots {
+            let keep_existing = match self.load_persona(&name) {
+                Err(_) => false,
+                Ok(existing) => match on_conflict {
+                    BundleConflict::Skip => true,
+                    BundleConflict::Overwrite => false,
+                    BundleConflict::Merge => {
+                        existing.compound_iterations >= snapshot.compound_iterations
+                    }
+                },
+            };
+            if keep_existing {
+                result.skipped.push(name);
+            } else {
+                self.save_persona(&name, &snapshot)?;
+                result.imported.push(name);
+            }
+        }
+        Ok(result)
     }
 
     /// List all saved personas
@@ -354,12 +903,12 @@ impl PersistenceManager {
     pub fn delete_persona(&mut self, name: &str) -> Result<String, String> {
         self.ensure_init()?;
 
-        let filename = format!("{}.json", sanitize_filename(name));
-        let path = self.config.personas_path().join(&filename);
-
-        if path.exists() {
-            fs::remove_file(&path)
-                .map_err(|e| format!("Failed to delete persona '{}': {}", name, e))?;
+        for format in [SerializationFormat::Json, SerializationFormat::MessagePack] {
+            let path = self.persona_path(name, format);
+            if path.exists() {
+                fs::remove_file(&path)
+                    .map_err(|e| format!("Failed to delete persona '{}': {}", name, e))?;
+            }
         }
 
         self.manifest.personas.retain(|e| e.name != name);
@@ -378,10 +927,10 @@ impl PersistenceManager {
 
         let filename = format!("{}.json", sanitize_filename(&profile.id));
         let path = self.config.profiles_path().join(&filename);
-        let json = self.to_json(profile)?;
-        let size = json.len() as u64;
+        let bytes = self.seal(self.to_json(profile)?.into_bytes())?;
+        let size = bytes.len() as u64;
 
-        fs::write(&path, &json)
+        write_atomic(&path, &bytes)
             .map_err(|e| format!("Failed to write profile '{}': {}", profile.id, e))?;
 
         let mut meta = HashMap::new();
@@ -400,6 +949,7 @@ impl PersistenceManager {
                 saved_at: timestamp(),
                 size_bytes: size,
                 metadata: meta,
+                content_hash: Some(content_hash(&bytes)),
             },
         );
         self.save_manifest()?;
@@ -428,9 +978,8 @@ impl PersistenceManager {
             ));
         }
 
-        let data = fs::read_to_string(&path)
-            .map_err(|e| format!("Failed to read profile '{}': {}", name, e))?;
-        serde_json::from_str(&data)
+        let data = self.read_unsealed(&path, &format!("profile '{}'", name))?;
+        serde_json::from_slice(&data)
             .map_err(|e| format!("Failed to parse profile '{}': {}", name, e))
     }
 
@@ -451,15 +1000,15 @@ impl PersistenceManager {
         for entry in entries.flatten() {
             let path = entry.path();
             if path.extension().and_then(|e| e.to_str()) == Some("json") {
-                match fs::read_to_string(&path) {
-                    Ok(data) => match serde_json::from_str::<AiProfile>(&data) {
+                match self.read_unsealed(&path, &path.display().to_string()) {
+                    Ok(data) => match serde_json::from_slice::<AiProfile>(&data) {
                         Ok(profile) => profiles.push(profile),
                         Err(e) => {
-                            eprintln!("Warning: Failed to parse {}: {}", path.display(), e);
+                            tracing::warn!(path = %path.display(), error = %e, "failed to parse profile");
                         }
                     },
                     Err(e) => {
-                        eprintln!("Warning: Failed to read {}: {}", path.display(), e);
+                        tracing::warn!(path = %path.display(), error = %e, "failed to read profile");
                     }
                 }
             }
@@ -484,9 +1033,10 @@ impl PersistenceManager {
 
         let filename = format!("{}.json", sanitize_filename(name));
         let path = self.config.sessions_path().join(&filename);
-        let size = session_json.len() as u64;
+        let bytes = self.seal(session_json.as_bytes().to_vec())?;
+        let size = bytes.len() as u64;
 
-        fs::write(&path, session_json)
+        write_atomic(&path, &bytes)
             .map_err(|e| format!("Failed to write session '{}': {}", name, e))?;
 
         SaveManifest::add_entry(
@@ -497,6 +1047,7 @@ impl PersistenceManager {
                 saved_at: timestamp(),
                 size_bytes: size,
                 metadata: HashMap::new(),
+                content_hash: Some(content_hash(&bytes)),
             },
         );
         self.save_manifest()?;
@@ -524,7 +1075,8 @@ impl PersistenceManager {
             ));
         }
 
-        fs::read_to_string(&path).map_err(|e| format!("Failed to read session '{}': {}", name, e))
+        let data = self.read_unsealed(&path, &format!("session '{}'", name))?;
+        String::from_utf8(data).map_err(|e| format!("Failed to read session '{}': {}", name, e))
     }
 
     // =================================================================
@@ -541,10 +1093,10 @@ impl PersistenceManager {
 
         let filename = format!("{}.json", sanitize_filename(name));
         let path = self.config.checkpoints_path().join(&filename);
-        let json = self.to_json(checkpoint)?;
-        let size = json.len() as u64;
+        let bytes = self.seal(self.to_json(checkpoint)?.into_bytes())?;
+        let size = bytes.len() as u64;
 
-        fs::write(&path, &json)
+        write_atomic(&path, &bytes)
             .map_err(|e| format!("Failed to write checkpoint '{}': {}", name, e))?;
 
         let mut meta = HashMap::new();
@@ -565,6 +1117,7 @@ impl PersistenceManager {
                 saved_at: timestamp(),
                 size_bytes: size,
                 metadata: meta,
+                content_hash: Some(content_hash(&bytes)),
             },
         );
         self.save_manifest()?;
@@ -592,18 +1145,61 @@ impl PersistenceManager {
             ));
         }
 
-        let data = fs::read_to_string(&path)
-            .map_err(|e| format!("Failed to read checkpoint '{}': {}", name, e))?;
-        serde_json::from_str(&data)
+        let data = self.read_unsealed(&path, &format!("checkpoint '{}'", name))?;
+        serde_json::from_slice(&data)
             .map_err(|e| format!("Failed to parse checkpoint '{}': {}", name, e))
     }
 
+    /// Write `cache` to `signature_cache.json` next to the checkpoints
+    pub fn save_signature_cache(&mut self, cache: &SignatureCache) -> Result<(), String> {
+        self.ensure_init()?;
+        let path = self.config.signature_cache_path();
+        let bytes = self.seal(cache.to_json()?.into_bytes())?;
+        write_atomic(&path, &bytes)
+            .map_err(|e| format!("Failed to write signature cache {}: {}", path.display(), e))
+    }
+
+    /// Read the cache written by `save_signature_cache`; `None` if there
+    /// is no cache file
+    pub fn load_signature_cache(&self) -> Result<Option<SignatureCache>, String> {
+        let path = self.config.signature_cache_path();
+        if !path.exists() {
+            return Ok(None);
+        }
+        let data = self.read_unsealed(&path, &format!("signature cache {}", path.display()))?;
+        let json = String::from_utf8(data)
+            .map_err(|e| format!("Failed to read signature cache {}: {}", path.display(), e))?;
+        SignatureCache::from_json(&json)
+            .map(Some)
+            .map_err(|e| format!("{} ({})", e, path.display()))
+    }
+
     // =================================================================
     // UTILITY METHODS
     // =================================================================
 
+    /// Whether `base_dir` currently accepts writes, probed by creating and
+    /// removing a temp file. Never panics; any I/O failure (read-only
+    /// directory, path blocked by a file, disabled persistence) is `false`.
+    pub fn is_writable(&self) -> bool {
+        if !self.config.enabled {
+            return false;
+        }
+        let dir = &self.config.base_dir;
+        if fs::create_dir_all(dir).is_err() {
+            return false;
+        }
+        let probe = dir.join(format!(".write-probe-{}", std::process::id()));
+        let ok = fs::write(&probe, b"ok").is_ok();
+        let _ = fs::remove_file(&probe);
+        ok
+    }
+
     /// Get a summary of all saved data
     pub fn summary(&mut self) -> Result<String, String> {
+        if !self.config.enabled {
+            return Ok("disabled (in-memory only)".to_string());
+        }
         self.ensure_init()?;
 
         let mut lines = vec![format!(
@@ -653,7 +1249,8 @@ impl PersistenceManager {
 
     /// Check if auto-save should trigger based on iteration count
     pub fn should_auto_save(&self, iteration: u64) -> bool {
-        self.config.auto_save_enabled
+        self.config.enabled
+            && self.config.auto_save_enabled
             && iteration > 0
             && iteration.is_multiple_of(self.config.auto_save_interval)
     }
@@ -666,10 +1263,16 @@ impl PersistenceManager {
             return Err(format!("File not found: {}", path.display()));
         }
 
-        let data = fs::read_to_string(path)
-            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
-        let profile: AiProfile = serde_json::from_str(&data)
+        let data = self.read_unsealed(path, &path.display().to_string())?;
+        let profile: AiProfile = serde_json::from_slice(&data)
             .map_err(|e| format!("Failed to parse profile from {}: {}", path.display(), e))?;
+        profile.validate().map_err(|errors| {
+            format!(
+                "Failed to import {}: {}",
+                path.display(),
+                ProfileError::summarize(&errors)
+            )
+        })?;
 
         // Also save a copy in our profiles directory
         self.save_profile(&profile)?;
@@ -694,11 +1297,10 @@ impl PersistenceManager {
             {
                 if let Ok(entry) = entry {
                     let path = entry.path();
-                    if path.extension().map_or(false, |e| e == "json") {
-                        if let Ok(data) = fs::read_to_string(&path) {
-                            if let Ok(snapshot) =
-                                serde_json::from_str::<CompoundPersonaSnapshot>(&data)
-                            {
+                    if SerializationFormat::from_path(&path).is_some() {
+                        if let Ok(data) = fs::read(&path) {
+                            let format = SerializationFormat::from_path(&path);
+                            if let Ok(snapshot) = self.decode_persona(&data, format, "") {
                                 let filename = path
                                     .file_name()
                                     .map(|n| n.to_string_lossy().to_string())
@@ -727,6 +1329,7 @@ impl PersistenceManager {
                                         saved_at: timestamp(),
                                         size_bytes: data.len() as u64,
                                         metadata: meta,
+                                        content_hash: Some(content_hash(&data)),
                                     },
                                 );
                             }
@@ -745,8 +1348,12 @@ impl PersistenceManager {
                 if let Ok(entry) = entry {
                     let path = entry.path();
                     if path.extension().map_or(false, |e| e == "json") {
-                        if let Ok(data) = fs::read_to_string(&path) {
-                            if let Ok(profile) = serde_json::from_str::<AiProfile>(&data) {
+                        if let Ok(data) = fs::read(&path) {
+                            let profile = self
+                                .unseal(&data, "profile")
+                                .ok()
+                                .and_then(|json| serde_json::from_slice::<AiProfile>(&json).ok());
+                            if let Some(profile) = profile {
                                 let filename = path
                                     .file_name()
                                     .map(|n| n.to_string_lossy().to_string())
@@ -764,6 +1371,7 @@ impl PersistenceManager {
                                         saved_at: timestamp(),
                                         size_bytes: data.len() as u64,
                                         metadata: meta,
+                                        content_hash: Some(content_hash(&data)),
                                     },
                                 );
                             }
@@ -779,6 +1387,92 @@ impl PersistenceManager {
         let total = self.manifest.personas.len() + self.manifest.profiles.len();
         Ok(total)
     }
+
+    /// Check every manifest entry against disk: recompute content hashes,
+    /// report entries whose file is gone, and list saved files (`.json` or
+    /// `.mp`) that no entry refers to. Backups, temp files, and the
+    /// signature cache are not orphans. Entries without a recorded hash
+    /// are only checked for existence; `rescan_manifest` fills them in for
+    /// personas and profiles.
+    pub fn verify_integrity(&mut self) -> Result<Vec<IntegrityIssue>, String> {
+        self.ensure_init()?;
+
+        let sections = [
+            (
+                "persona",
+                self.config.personas_path(),
+                &self.manifest.personas,
+            ),
+            (
+                "profile",
+                self.config.profiles_path(),
+                &self.manifest.profiles,
+            ),
+            (
+                "session",
+                self.config.sessions_path(),
+                &self.manifest.sessions,
+            ),
+            (
+                "checkpoint",
+                self.config.checkpoints_path(),
+                &self.manifest.checkpoints,
+            ),
+        ];
+        let signature_cache = self.config.signature_cache_path();
+
+        let mut issues = Vec::new();
+        for (category, dir, entries) in sections {
+            for entry in entries {
+                let path = dir.join(&entry.filename);
+                match fs::read(&path) {
+                    Err(_) => issues.push(IntegrityIssue::MissingFile {
+                        category: category.to_string(),
+                        name: entry.name.clone(),
+                        filename: entry.filename.clone(),
+                    }),
+                    Ok(data) => {
+                        let Some(expected) = &entry.content_hash else {
+                            continue;
+                        };
+                        let actual = content_hash(&data);
+                        if &actual != expected {
+                            issues.push(IntegrityIssue::HashMismatch {
+                                category: category.to_string(),
+                                name: entry.name.clone(),
+                                filename: entry.filename.clone(),
+                                expected: expected.clone(),
+                                actual,
+                            });
+                        }
+                    }
+                }
+            }
+
+            let Ok(listing) = fs::read_dir(&dir) else {
+                continue;
+            };
+            let mut orphans: Vec<String> = listing
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| p.is_file() && *p != signature_cache)
+                .filter(|p| SerializationFormat::from_path(p).is_some())
+                .filter_map(|p| Some(p.file_name()?.to_string_lossy().to_string()))
+                .filter(|f| !f.starts_with('.'))
+                .filter(|f| !entries.iter().any(|e| &e.filename == f))
+                .collect();
+            orphans.sort();
+            issues.extend(
+                orphans
+                    .into_iter()
+                    .map(|filename| IntegrityIssue::OrphanFile {
+                        category: category.to_string(),
+                        filename,
+                    }),
+            );
+        }
+        Ok(issues)
+    }
 }
 
 impl Default for PersistenceManager {
@@ -791,6 +1485,165 @@ impl Default for PersistenceManager {
 // HELPERS
 // =================================================================
 
+/// v0 → v1: snapshots saved before versioning. Makes the fields that were
+/// implicitly defaulted explicit: `last_updated` falls back to `created_at`
+/// and the profile gains an empty `extra_axes` map.
+fn migrate_persona_v0_to_v1(mut value: serde_json::Value) -> Result<serde_json::Value, String> {
+    let map = value
+        .as_object_mut()
+        .ok_or_else(|| "persona snapshot is not an object".to_string())?;
+    if !map.contains_key("last_updated") {
+        let created = map.get("created_at").cloned().unwrap_or_else(|| "".into());
+        map.insert("last_updated".to_string(), created);
+    }
+    if let Some(profile) = map.get_mut("profile").and_then(|p| p.as_object_mut()) {
+        profile
+            .entry("extra_axes")
+            .or_insert_with(|| serde_json::Value::Object(Default::default()));
+    }
+    map.insert("schema_version".to_string(), 1.into());
+    Ok(value)
+}
+
+/// Write `bytes` to `path` atomically: the data goes to a hidden temp file
+/// in the same directory, is synced, and is then renamed over `path`. A
+/// crash or error at any point leaves the previous file untouched.
+pub(crate) fn write_atomic(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    write_atomic_with(path, |file| file.write_all(bytes))
+}
+
+/// `write_atomic` with a caller-supplied writer; the temp file is removed
+/// if `write` fails
+fn write_atomic_with(
+    path: &Path,
+    write: impl FnOnce(&mut fs::File) -> io::Result<()>,
+) -> io::Result<()> {
+    let filename = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "path has no file name"))?;
+    let tmp = path.with_file_name(format!(
+        ".{}.tmp-{}",
+        filename.to_string_lossy(),
+        std::process::id()
+    ));
+
+    let result = fs::File::create(&tmp)
+        .and_then(|mut file| {
+            write(&mut file)?;
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&tmp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
+
+/// Hex-encoded SHA-256 of `data`, as stored in `SaveEntry::content_hash`
+fn content_hash(data: &[u8]) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(data)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Encrypt `data` with `key`, or pass it through when there is no key
+pub(crate) fn seal_with(key: Option<&[u8; 32]>, data: Vec<u8>) -> Result<Vec<u8>, String> {
+    match key {
+        Some(key) => encrypt(key, &data),
+        None => Ok(data),
+    }
+}
+
+/// Decrypt `data` if it carries the encrypted header; plaintext is passed
+/// through unchanged. `what` names the file in errors.
+pub(crate) fn unseal_with<'a>(
+    key: Option<&[u8; 32]>,
+    data: &'a [u8],
+    what: &str,
+) -> Result<Cow<'a, [u8]>, String> {
+    if !is_encrypted(data) {
+        return Ok(Cow::Borrowed(data));
+    }
+    let key =
+        key.ok_or_else(|| format!("{} is encrypted but no encryption_key is configured", what))?;
+    decrypt(key, data)
+        .map(Cow::Owned)
+        .map_err(|e| format!("Failed to decrypt {}: {}", what, e))
+}
+
+/// Header of an encrypted file: this magic, a 12-byte nonce, then the
+/// ChaCha20-Poly1305 ciphertext and tag
+pub const ENCRYPTED_MAGIC: &[u8; 8] = b"RWENC\x00\x00\x01";
+
+#[cfg(feature = "encryption")]
+const NONCE_LEN: usize = 12;
+
+/// Whether `data` starts with the encrypted-file header
+pub fn is_encrypted(data: &[u8]) -> bool {
+    data.starts_with(ENCRYPTED_MAGIC)
+}
+
+#[cfg(feature = "encryption")]
+fn encrypt(key: &[u8; 32], plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    use chacha20poly1305::aead::{Aead, KeyInit};
+    use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(key));
+    let nonce: [u8; NONCE_LEN] = rand::random();
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), plaintext)
+        .map_err(|_| "Encryption failed".to_string())?;
+
+    let mut sealed = Vec::with_capacity(ENCRYPTED_MAGIC.len() + NONCE_LEN + ciphertext.len());
+    sealed.extend_from_slice(ENCRYPTED_MAGIC);
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(sealed)
+}
+
+#[cfg(feature = "encryption")]
+fn decrypt(key: &[u8; 32], sealed: &[u8]) -> Result<Vec<u8>, String> {
+    use chacha20poly1305::aead::{Aead, KeyInit};
+    use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+
+    let body = &sealed[ENCRYPTED_MAGIC.len()..];
+    if body.len() < NONCE_LEN {
+        return Err("file is truncated".to_string());
+    }
+    let (nonce, ciphertext) = body.split_at(NONCE_LEN);
+    ChaCha20Poly1305::new(Key::from_slice(key))
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "wrong encryption key or corrupted file".to_string())
+}
+
+#[cfg(not(feature = "encryption"))]
+fn encrypt(_key: &[u8; 32], _plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    Err("encryption_key is set but encryption is not enabled. \
+         Rebuild with: cargo build --features encryption"
+        .to_string())
+}
+
+#[cfg(not(feature = "encryption"))]
+fn decrypt(_key: &[u8; 32], _sealed: &[u8]) -> Result<Vec<u8>, String> {
+    Err("encryption is not enabled. Rebuild with: cargo build --features encryption".to_string())
+}
+
+/// Filename prefix shared by all backups of a persona
+fn backup_prefix(name: &str) -> String {
+    format!("{}.bak.", sanitize_filename(name))
+}
+
+/// Milliseconds since the Unix epoch, used to stamp backups
+fn timestamp_millis() -> u128 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis()
+}
+
 /// Sanitize a name for use as a filename
 fn sanitize_filename(name: &str) -> String {
     name.chars()
@@ -822,6 +1675,7 @@ fn timestamp() -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::consciousness::{RelationshipHealth, DEFAULT_AUDIT_CAP};
     use crate::mimicry::analyzer::BehaviorSignature;
     use crate::mimicry::capability::CapabilityModule;
     use crate::mimicry::profile::AiProfileStore;
@@ -865,6 +1719,7 @@ mod tests {
 
         let profile = AiProfileStore::gpt4o_profile();
         let snapshot = CompoundPersonaSnapshot {
+            schema_version: PERSONA_SCHEMA_VERSION,
             profile,
             signature: BehaviorSignature::new("gpt4o"),
             capabilities: CapabilityModule::gpt4o_capabilities(),
@@ -872,6 +1727,9 @@ mod tests {
             compound_iterations: 42,
             created_at: "test".to_string(),
             last_updated: "test".to_string(),
+            ethics_audit: Vec::new(),
+            ethics_audit_cap: DEFAULT_AUDIT_CAP,
+            health: RelationshipHealth::default(),
         };
 
         let save_result = pm.save_persona("test-gpt4o", &snapshot);
@@ -886,6 +1744,535 @@ mod tests {
         cleanup(&config);
     }
 
+    #[test]
+    fn test_messagepack_persona_round_trip() {
+        let config = PersistenceConfig {
+            format: SerializationFormat::MessagePack,
+            ..test_config()
+        };
+        let mut pm = PersistenceManager::new(config.clone());
+
+        let snapshot = CompoundPersonaSnapshot {
+            schema_version: PERSONA_SCHEMA_VERSION,
+            profile: AiProfileStore::claude_profile(),
+            signature: BehaviorSignature::new("claude"),
+            capabilities: CapabilityModule::claude_capabilities(),
+            convergence_score: 0.8125,
+            compound_iterations: 7,
+            created_at: "created".to_string(),
+            last_updated: "updated".to_string(),
+            ethics_audit: Vec::new(),
+            ethics_audit_cap: DEFAULT_AUDIT_CAP,
+            health: RelationshipHealth::default(),
+        };
+        pm.save_persona("mp-claude", &snapshot).unwrap();
+        let path = config.personas_path().join("mp-claude.mp");
+        assert!(path.exists());
+        assert!(!config.personas_path().join("mp-claude.json").exists());
+        assert_eq!(pm.manifest.personas[0].filename, "mp-claude.mp");
+
+        let loaded = pm.load_persona("mp-claude").unwrap();
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&snapshot).unwrap()
+        );
+
+        // Smaller than the pretty JSON encoding of the same snapshot
+        let json_len = pm.to_json(&snapshot).unwrap().len() as u64;
+        assert!(fs::metadata(&path).unwrap().len() < json_len);
+
+        assert_eq!(pm.rescan_manifest().unwrap(), 1);
+        pm.delete_persona("mp-claude").unwrap();
+        assert!(!path.exists());
+
+        cleanup(&config);
+    }
+
+    #[test]
+    fn test_load_persona_sniffs_extension() {
+        let config = test_config();
+        let mut pm = PersistenceManager::new(config.clone());
+        let snapshot = CompoundPersonaSnapshot {
+            schema_version: PERSONA_SCHEMA_VERSION,
+            profile: AiProfileStore::gpt4o_profile(),
+            signature: BehaviorSignature::new("gpt4o"),
+            capabilities: CapabilityModule::gpt4o_capabilities(),
+            convergence_score: 0.5,
+            compound_iterations: 3,
+            created_at: "test".to_string(),
+            last_updated: "test".to_string(),
+            ethics_audit: Vec::new(),
+            ethics_audit_cap: DEFAULT_AUDIT_CAP,
+            health: RelationshipHealth::default(),
+        };
+        pm.save_persona("legacy", &snapshot).unwrap();
+
+        // A JSON save still loads after switching to MessagePack
+        pm.config.format = SerializationFormat::MessagePack;
+        assert_eq!(pm.load_persona("legacy").unwrap().compound_iterations, 3);
+
+        // Re-saving replaces the JSON file with a MessagePack one
+        pm.save_persona("legacy", &snapshot).unwrap();
+        assert!(config.personas_path().join("legacy.mp").exists());
+        assert!(!config.personas_path().join("legacy.json").exists());
+        pm.config.format = SerializationFormat::Json;
+        assert_eq!(pm.load_persona("legacy").unwrap().profile.id, "gpt4o");
+
+        cleanup(&config);
+    }
+
+    fn backup_snapshot(iterations: u64) -> CompoundPersonaSnapshot {
+        CompoundPersonaSnapshot {
+            schema_version: PERSONA_SCHEMA_VERSION,
+            profile: AiProfileStore::gpt4o_profile(),
+            signature: BehaviorSignature::new("gpt4o"),
+            capabilities: CapabilityModule::gpt4o_capabilities(),
+            convergence_score: 0.5,
+            compound_iterations: iterations,
+            created_at: "test".to_string(),
+            last_updated: "test".to_string(),
+            ethics_audit: Vec::new(),
+            ethics_audit_cap: DEFAULT_AUDIT_CAP,
+            health: RelationshipHealth::default(),
+        }
+    }
+
+    #[test]
+    fn test_persona_backups_pruned_oldest_first() {
+        let config = PersistenceConfig {
+            keep_backups: 2,
+            ..test_config()
+        };
+        let mut pm = PersistenceManager::new(config.clone());
+        pm.save_persona("bk", &backup_snapshot(1)).unwrap();
+        assert!(pm.list_backups("bk").unwrap().is_empty());
+
+        // Files that merely look like backups are never counted or pruned
+        let dir = config.personas_path();
+        for stray in ["bk.bak.notes", "bk.bak.12.tmp", "other.bak.1", "bk.bak."] {
+            fs::write(dir.join(stray), "x").unwrap();
+        }
+
+        for i in 2..=5 {
+            pm.save_persona("bk", &backup_snapshot(i)).unwrap();
+        }
+        let backups = pm.list_backups("bk").unwrap();
+        assert_eq!(backups.len(), 2);
+        assert!(backups.iter().all(|b| b.starts_with("bk.bak.")));
+        for stray in ["bk.bak.notes", "bk.bak.12.tmp", "other.bak.1", "bk.bak."] {
+            assert!(dir.join(stray).exists(), "{} was removed", stray);
+        }
+
+        // The two newest backups hold iterations 3 and 4; restore the older
+        let snapshot = pm.restore_backup("bk", &backups[0]).unwrap();
+        assert_eq!(snapshot.compound_iterations, 3);
+        assert_eq!(pm.load_persona("bk").unwrap().compound_iterations, 3);
+        // The save restore replaced (iteration 5) is now the newest backup
+        let stamp = pm.list_backups("bk").unwrap().pop().unwrap();
+        let stamp = stamp.strip_prefix("bk.bak.").unwrap().to_string();
+        assert_eq!(
+            pm.restore_backup("bk", &stamp).unwrap().compound_iterations,
+            5
+        );
+
+        assert!(pm.restore_backup("bk", "nope").is_err());
+        cleanup(&config);
+    }
+
+    #[test]
+    fn test_persona_backups_disabled_and_messagepack() {
+        let config = PersistenceConfig {
+            keep_backups: 0,
+            ..test_config()
+        };
+        let mut pm = PersistenceManager::new(config.clone());
+        pm.save_persona("nobk", &backup_snapshot(1)).unwrap();
+        pm.save_persona("nobk", &backup_snapshot(2)).unwrap();
+        assert!(pm.list_backups("nobk").unwrap().is_empty());
+
+        pm.config.keep_backups = 1;
+        pm.config.format = SerializationFormat::MessagePack;
+        pm.save_persona("nobk", &backup_snapshot(3)).unwrap();
+        pm.save_persona("nobk", &backup_snapshot(4)).unwrap();
+        let backups = pm.list_backups("nobk").unwrap();
+        assert_eq!(backups.len(), 1);
+        // A MessagePack backup is sniffed and restored intact
+        assert_eq!(
+            pm.restore_backup("nobk", &backups[0])
+                .unwrap()
+                .compound_iterations,
+            3
+        );
+        cleanup(&config);
+    }
+
+    /// Write a persona file by hand, as an older or newer build would have
+    fn write_raw_persona(config: &PersistenceConfig, name: &str, value: &serde_json::Value) {
+        fs::create_dir_all(config.personas_path()).unwrap();
+        let path = config.personas_path().join(format!("{}.json", name));
+        fs::write(path, serde_json::to_string(value).unwrap()).unwrap();
+    }
+
+    fn v0_persona() -> serde_json::Value {
+        let mut value = serde_json::to_value(backup_snapshot(4)).unwrap();
+        let map = value.as_object_mut().unwrap();
+        map.remove("schema_version");
+        map.remove("last_updated");
+        map["profile"].as_object_mut().unwrap().remove("extra_axes");
+        value
+    }
+
+    #[test]
+    fn test_load_persona_migrates_v0() {
+        let config = test_config();
+        write_raw_persona(&config, "old", &v0_persona());
+        let mut pm = PersistenceManager::new(config.clone());
+
+        let loaded = pm.load_persona("old").unwrap();
+        assert_eq!(loaded.schema_version, PERSONA_SCHEMA_VERSION);
+        assert_eq!(loaded.last_updated, loaded.created_at);
+        assert!(loaded.profile.extra_axes.is_empty());
+        assert_eq!(loaded.compound_iterations, 4);
+
+        // Custom migrations replace the built-in one for their version
+        pm.register_migration(
+            0,
+            Box::new(|v| {
+                let mut v = migrate_persona_v0_to_v1(v)?;
+                v["convergence_score"] = 0.25.into();
+                Ok(v)
+            }),
+        );
+        let loaded = pm.load_persona("old").unwrap();
+        assert_eq!(loaded.convergence_score, 0.25);
+        assert_eq!(loaded.schema_version, PERSONA_SCHEMA_VERSION);
+
+        pm.register_migration(0, Box::new(|_| Err("corrupt".to_string())));
+        let err = pm.load_persona("old").unwrap_err();
+        assert!(err.contains("migration from schema version 0 failed: corrupt"));
+        cleanup(&config);
+    }
+
+    #[test]
+    fn test_load_persona_rejects_future_schema() {
+        let config = test_config();
+        let mut future = serde_json::to_value(backup_snapshot(1)).unwrap();
+        future["schema_version"] = (PERSONA_SCHEMA_VERSION + 1).into();
+        write_raw_persona(&config, "future", &future);
+        future["schema_version"] = "two".into();
+        write_raw_persona(&config, "garbled", &future);
+
+        let mut pm = PersistenceManager::new(config.clone());
+        let err = pm.load_persona("future").unwrap_err();
+        assert!(
+            err.contains("is newer than the supported version"),
+            "{}",
+            err
+        );
+        let err = pm.load_persona("garbled").unwrap_err();
+        assert!(err.contains("invalid schema_version"), "{}", err);
+        cleanup(&config);
+    }
+
+    fn bundle_fixture(name: &str, iterations: u64) -> (PersistenceConfig, PersistenceManager) {
+        let config = test_config();
+        let mut pm = PersistenceManager::new(config.clone());
+        pm.save_persona(name, &backup_snapshot(iterations)).unwrap();
+        (config, pm)
+    }
+
+    #[test]
+    fn test_export_import_bundle() {
+        let (src_config, mut src) = bundle_fixture("alpha", 5);
+        src.save_persona("beta", &backup_snapshot(2)).unwrap();
+        // A manifest entry whose file is gone is left out of the bundle
+        src.save_persona("gone", &backup_snapshot(1)).unwrap();
+        fs::remove_file(src_config.personas_path().join("gone.json")).unwrap();
+        let bundle_path = src_config.base_dir.join("bundle.json");
+        assert_eq!(src.export_bundle(&bundle_path).unwrap(), 2);
+
+        // Fresh machine: everything imports
+        let (dst_config, mut dst) = bundle_fixture("unrelated", 1);
+        let result = dst
+            .import_bundle(&bundle_path, BundleConflict::Skip)
+            .unwrap();
+        assert_eq!(result.imported, vec!["alpha", "beta"]);
+        assert!(result.skipped.is_empty());
+        assert_eq!(dst.load_persona("alpha").unwrap().compound_iterations, 5);
+        assert_eq!(dst.list_personas().unwrap().len(), 3);
+
+        cleanup(&src_config);
+        cleanup(&dst_config);
+    }
+
+    #[test]
+    fn test_import_bundle_conflict_policies() {
+        let (src_config, mut src) = bundle_fixture("alpha", 5);
+        src.save_persona("beta", &backup_snapshot(2)).unwrap();
+        let bundle_path = src_config.base_dir.join("bundle.json");
+        src.export_bundle(&bundle_path).unwrap();
+
+        // Existing: alpha less evolved than the bundle, beta more evolved
+        let (dst_config, mut dst) = bundle_fixture("alpha", 3);
+        dst.save_persona("beta", &backup_snapshot(9)).unwrap();
+        let iterations = |pm: &mut PersistenceManager, name: &str| {
+            pm.load_persona(name).unwrap().compound_iterations
+        };
+
+        let skip = dst
+            .import_bundle(&bundle_path, BundleConflict::Skip)
+            .unwrap();
+        assert_eq!(skip.skipped, vec!["alpha", "beta"]);
+        assert_eq!(iterations(&mut dst, "alpha"), 3);
+
+        let merge = dst
+            .import_bundle(&bundle_path, BundleConflict::Merge)
+            .unwrap();
+        assert_eq!(merge.imported, vec!["alpha"]);
+        assert_eq!(merge.skipped, vec!["beta"]);
+        assert_eq!(iterations(&mut dst, "alpha"), 5);
+        assert_eq!(iterations(&mut dst, "beta"), 9);
+
+        let overwrite = dst
+            .import_bundle(&bundle_path, BundleConflict::Overwrite)
+            .unwrap();
+        assert_eq!(overwrite.imported, vec!["alpha", "beta"]);
+        assert_eq!(iterations(&mut dst, "beta"), 2);
+        // The overwritten save was backed up
+        assert!(!dst.list_backups("beta").unwrap().is_empty());
+
+        assert_eq!(BundleConflict::parse("MERGE"), Some(BundleConflict::Merge));
+        assert_eq!(BundleConflict::parse("rename"), None);
+        cleanup(&src_config);
+        cleanup(&dst_config);
+    }
+
+    #[test]
+    fn test_import_bundle_rejects_bad_bundles() {
+        let (config, mut pm) = bundle_fixture("alpha", 1);
+        let bundle_path = config.base_dir.join("bundle.json");
+        pm.export_bundle(&bundle_path).unwrap();
+        let mut bundle: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&bundle_path).unwrap()).unwrap();
+
+        bundle["bundle_version"] = (BUNDLE_VERSION + 1).into();
+        fs::write(&bundle_path, bundle.to_string()).unwrap();
+        let err = pm
+            .import_bundle(&bundle_path, BundleConflict::Overwrite)
+            .unwrap_err();
+        assert!(err.contains("newer than the supported"), "{}", err);
+
+        // One invalid persona aborts the import before anything is written
+        bundle["bundle_version"] = BUNDLE_VERSION.into();
+        bundle["personas"]["zeta"] = bundle["personas"]["alpha"].clone();
+        bundle["personas"]["zeta"]["profile"]["id"] = "".into();
+        fs::write(&bundle_path, bundle.to_string()).unwrap();
+        let err = pm
+            .import_bundle(&bundle_path, BundleConflict::Overwrite)
+            .unwrap_err();
+        assert!(err.contains("Invalid persona 'zeta'"), "{}", err);
+        assert!(pm.list_backups("alpha").unwrap().is_empty());
+        cleanup(&config);
+    }
+
+    #[test]
+    fn test_interrupted_write_keeps_previous_file() {
+        let (config, mut pm) = bundle_fixture("atomic", 7);
+        let path = config.personas_path().join("atomic.json");
+        let good = fs::read(&path).unwrap();
+
+        // Half the new snapshot reaches disk, then the write dies
+        let next = serde_json::to_vec(&backup_snapshot(8)).unwrap();
+        let result = write_atomic_with(&path, |file| {
+            file.write_all(&next[..next.len() / 2])?;
+            Err(io::Error::other("killed mid-write"))
+        });
+        assert!(result.is_err());
+
+        assert_eq!(fs::read(&path).unwrap(), good);
+        assert_eq!(pm.load_persona("atomic").unwrap().compound_iterations, 7);
+        // No temp file is left behind to be mistaken for a save
+        let leftovers: Vec<_> = fs::read_dir(config.personas_path())
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_name().to_string_lossy().contains(".tmp-"))
+            .collect();
+        assert!(leftovers.is_empty());
+
+        // A completed write replaces the file, keeping the old one as a backup
+        pm.save_persona("atomic", &backup_snapshot(8)).unwrap();
+        assert_eq!(pm.load_persona("atomic").unwrap().compound_iterations, 8);
+        assert_eq!(pm.list_backups("atomic").unwrap().len(), 1);
+        cleanup(&config);
+    }
+
+    #[test]
+    fn test_checkpoint_written_atomically() {
+        let config = test_config();
+        let mut pm = PersistenceManager::new(config.clone());
+        pm.initialize().unwrap();
+        let checkpoint = EngineCheckpoint {
+            profiles: Vec::new(),
+            cached_signatures: Vec::new(),
+            saved_snapshots: HashMap::new(),
+            hot_swap_entries: Vec::new(),
+            active_persona_id: None,
+            checkpoint_iteration: 3,
+        };
+        pm.save_checkpoint("cp", &checkpoint).unwrap();
+        let names: Vec<String> = fs::read_dir(config.checkpoints_path())
+            .unwrap()
+            .filter_map(|e| e.ok())
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, vec!["cp.json"]);
+        assert_eq!(pm.load_checkpoint("cp").unwrap().checkpoint_iteration, 3);
+        cleanup(&config);
+    }
+
+    #[cfg(feature = "encryption")]
+    #[test]
+    fn test_encrypted_persona_round_trip() {
+        let config = PersistenceConfig {
+            encryption_key: Some([7; 32]),
+            ..test_config()
+        };
+        let mut pm = PersistenceManager::new(config.clone());
+        pm.save_persona("secret", &backup_snapshot(6)).unwrap();
+
+        let raw = fs::read(config.personas_path().join("secret.json")).unwrap();
+        assert!(is_encrypted(&raw));
+        assert!(!String::from_utf8_lossy(&raw).contains("gpt4o"));
+        assert_eq!(pm.load_persona("secret").unwrap().compound_iterations, 6);
+        // The key never reaches Debug output or serialized config
+        assert!(format!("{:?}", config).contains("<redacted>"));
+        assert!(!serde_json::to_string(&config)
+            .unwrap()
+            .contains("encryption_key"));
+
+        // Plaintext and encrypted saves coexist under one manager
+        pm.config.encryption_key = None;
+        pm.save_persona("plain", &backup_snapshot(2)).unwrap();
+        pm.config.encryption_key = Some([7; 32]);
+        assert_eq!(pm.load_persona("plain").unwrap().compound_iterations, 2);
+
+        pm.config.format = SerializationFormat::MessagePack;
+        pm.save_persona("secret-mp", &backup_snapshot(4)).unwrap();
+        assert_eq!(pm.load_persona("secret-mp").unwrap().compound_iterations, 4);
+        cleanup(&config);
+    }
+
+    #[cfg(feature = "encryption")]
+    #[test]
+    fn test_encrypted_persona_wrong_or_missing_key() {
+        let config = PersistenceConfig {
+            encryption_key: Some([1; 32]),
+            ..test_config()
+        };
+        let mut pm = PersistenceManager::new(config.clone());
+        pm.save_persona("secret", &backup_snapshot(6)).unwrap();
+        let bundle_path = config.base_dir.join("bundle.json");
+        pm.export_bundle(&bundle_path).unwrap();
+        assert!(is_encrypted(&fs::read(&bundle_path).unwrap()));
+
+        pm.config.encryption_key = Some([2; 32]);
+        let err = pm.load_persona("secret").unwrap_err();
+        assert_eq!(
+            err,
+            "Failed to decrypt persona 'secret': wrong encryption key or corrupted file"
+        );
+        let err = pm
+            .import_bundle(&bundle_path, BundleConflict::Overwrite)
+            .unwrap_err();
+        assert!(err.contains("wrong encryption key"), "{}", err);
+
+        pm.config.encryption_key = None;
+        let err = pm.load_persona("secret").unwrap_err();
+        assert!(err.contains("no encryption_key is configured"), "{}", err);
+        cleanup(&config);
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    #[test]
+    fn test_encryption_key_requires_feature() {
+        let config = PersistenceConfig {
+            encryption_key: Some([1; 32]),
+            ..test_config()
+        };
+        let mut pm = PersistenceManager::new(config.clone());
+        let err = pm.save_persona("secret", &backup_snapshot(1)).unwrap_err();
+        assert!(err.contains("--features encryption"), "{}", err);
+        assert!(!config.personas_path().join("secret.json").exists());
+        cleanup(&config);
+    }
+
+    #[test]
+    fn test_verify_integrity_clean_and_detects_issues() {
+        let (config, mut pm) = bundle_fixture("alpha", 1);
+        pm.save_persona("beta", &backup_snapshot(2)).unwrap();
+        pm.save_profile(&AiProfileStore::claude_profile()).unwrap();
+        pm.save_session("chat", "{}").unwrap();
+        // A second save leaves a backup, and the engine's signature cache
+        // lives beside checkpoints; neither is an orphan
+        pm.save_persona("alpha", &backup_snapshot(3)).unwrap();
+        fs::write(config.signature_cache_path(), "{}").unwrap();
+        assert_eq!(pm.verify_integrity().unwrap(), vec![]);
+        assert!(pm
+            .manifest
+            .personas
+            .iter()
+            .all(|e| e.content_hash.is_some()));
+
+        let personas = config.personas_path();
+        fs::write(personas.join("alpha.json"), "{\"tampered\": true}").unwrap();
+        fs::remove_file(personas.join("beta.json")).unwrap();
+        fs::write(personas.join("stray.json"), "{}").unwrap();
+        fs::write(personas.join("notes.txt"), "ignored").unwrap();
+
+        let issues = pm.verify_integrity().unwrap();
+        assert_eq!(issues.len(), 3, "{:?}", issues);
+        assert!(issues.iter().any(|issue| matches!(
+            issue,
+            IntegrityIssue::HashMismatch { name, .. } if name == "alpha"
+        )));
+        assert!(issues.contains(&IntegrityIssue::MissingFile {
+            category: "persona".to_string(),
+            name: "beta".to_string(),
+            filename: "beta.json".to_string(),
+        }));
+        // Orphans are reported after the section's entries
+        assert_eq!(
+            issues[2].to_string(),
+            "persona: stray.json is not in the manifest"
+        );
+        cleanup(&config);
+    }
+
+    #[test]
+    fn test_rescan_manifest_populates_hashes() {
+        let (config, mut pm) = bundle_fixture("alpha", 1);
+        // Simulate a manifest written before hashing existed
+        for entry in &mut pm.manifest.personas {
+            entry.content_hash = None;
+        }
+        fs::copy(
+            config.personas_path().join("alpha.json"),
+            config.personas_path().join("copied.json"),
+        )
+        .unwrap();
+        assert_eq!(pm.verify_integrity().unwrap().len(), 1);
+
+        pm.rescan_manifest().unwrap();
+        assert_eq!(pm.manifest.personas.len(), 2);
+        assert!(pm
+            .manifest
+            .personas
+            .iter()
+            .all(|e| e.content_hash.is_some()));
+        assert_eq!(pm.verify_integrity().unwrap(), vec![]);
+        cleanup(&config);
+    }
+
     #[test]
     fn test_save_load_profile() {
         let config = test_config();
@@ -916,6 +2303,26 @@ mod tests {
         cleanup(&config);
     }
 
+    #[test]
+    fn test_import_profile_rejects_invalid() {
+        let config = test_config();
+        let mut pm = PersistenceManager::new(config.clone());
+        pm.initialize().unwrap();
+
+        let mut profile = AiProfileStore::claude_profile();
+        profile.display_name = String::new();
+        profile.response_style.verbosity = 1.5;
+        let path = config.base_dir.join("bad-profile.json");
+        fs::write(&path, serde_json::to_string(&profile).unwrap()).unwrap();
+
+        let err = pm.import_profile_from(&path).unwrap_err();
+        assert!(err.contains("2 problems"), "{}", err);
+        assert!(err.contains("display_name") && err.contains("verbosity"));
+        assert!(pm.load_profile("claude").is_err());
+
+        cleanup(&config);
+    }
+
     #[test]
     fn test_save_load_checkpoint() {
         let config = test_config();
@@ -947,6 +2354,7 @@ mod tests {
 
         let profile = AiProfileStore::gpt4o_profile();
         let snapshot = CompoundPersonaSnapshot {
+            schema_version: PERSONA_SCHEMA_VERSION,
             profile,
             signature: BehaviorSignature::new("gpt4o"),
             capabilities: CapabilityModule::gpt4o_capabilities(),
@@ -954,6 +2362,9 @@ mod tests {
             compound_iterations: 10,
             created_at: "test".to_string(),
             last_updated: "test".to_string(),
+            ethics_audit: Vec::new(),
+            ethics_audit_cap: DEFAULT_AUDIT_CAP,
+            health: RelationshipHealth::default(),
         };
         pm.save_persona("to-delete", &snapshot).unwrap();
         assert!(pm.load_persona("to-delete").is_ok());
@@ -973,6 +2384,60 @@ mod tests {
         assert!(pm.should_auto_save(20));
     }
 
+    #[test]
+    fn test_disabled_persistence_touches_nothing() {
+        let config = PersistenceConfig {
+            base_dir: test_config().base_dir,
+            ..PersistenceConfig::disabled()
+        };
+        let mut pm = PersistenceManager::new(config.clone());
+        assert!(pm.initialize().is_err());
+        assert!(!pm.should_auto_save(10));
+
+        let snapshot = CompoundPersonaSnapshot {
+            schema_version: PERSONA_SCHEMA_VERSION,
+            profile: AiProfileStore::gpt4o_profile(),
+            signature: BehaviorSignature::new("gpt4o"),
+            capabilities: CapabilityModule::gpt4o_capabilities(),
+            convergence_score: 0.5,
+            compound_iterations: 1,
+            created_at: "test".to_string(),
+            last_updated: "test".to_string(),
+            ethics_audit: Vec::new(),
+            ethics_audit_cap: DEFAULT_AUDIT_CAP,
+            health: RelationshipHealth::default(),
+        };
+        assert!(pm.save_persona("gpt4o", &snapshot).is_err());
+        assert!(pm.load_persona("gpt4o").is_err());
+        assert_eq!(pm.summary().unwrap(), "disabled (in-memory only)");
+        assert!(!config.base_dir.exists());
+    }
+
+    #[test]
+    fn test_is_writable() {
+        let config = test_config();
+        let pm = PersistenceManager::new(config.clone());
+        assert!(pm.is_writable());
+        // The probe file is cleaned up again
+        assert_eq!(fs::read_dir(&config.base_dir).unwrap().count(), 0);
+
+        // A regular file where a directory is expected cannot be written under
+        let blocker = config.base_dir.join("blocker");
+        fs::write(&blocker, "x").unwrap();
+        let blocked = PersistenceManager::new(PersistenceConfig {
+            base_dir: blocker.join("nested"),
+            ..PersistenceConfig::default()
+        });
+        assert!(!blocked.is_writable());
+
+        let disabled = PersistenceManager::new(PersistenceConfig {
+            base_dir: config.base_dir.clone(),
+            ..PersistenceConfig::disabled()
+        });
+        assert!(!disabled.is_writable());
+        let _ = fs::remove_dir_all(&config.base_dir);
+    }
+
     #[test]
     fn test_sanitize_filename() {
         assert_eq!(sanitize_filename("gpt4o"), "gpt4o");
@@ -992,6 +2457,7 @@ mod tests {
                 saved_at: "now".to_string(),
                 size_bytes: 100,
                 metadata: HashMap::new(),
+                content_hash: None,
             },
         );
 
@@ -1020,6 +2486,7 @@ mod tests {
         // Create a test persona
         let profile = AiProfileStore::default().get("gpt4o").unwrap().clone();
         let snapshot = CompoundPersonaSnapshot {
+            schema_version: PERSONA_SCHEMA_VERSION,
             profile: profile.clone(),
             signature: BehaviorSignature::new("gpt4o"),
             capabilities: CapabilityModule::for_profile(&profile),
@@ -1027,6 +2494,9 @@ mod tests {
             convergence_score: 0.5,
             created_at: "test".to_string(),
             last_updated: "test".to_string(),
+            ethics_audit: Vec::new(),
+            ethics_audit_cap: DEFAULT_AUDIT_CAP,
+            health: RelationshipHealth::default(),
         };
         pm.save_persona("rescan-test", &snapshot).unwrap();
         assert_eq!(pm.manifest.personas.len(), 1);
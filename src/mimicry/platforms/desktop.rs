@@ -46,15 +46,19 @@ impl DesktopPlatform {
             return Ok(res);
         }
 
-        let res = match self.platform {
+        let res = self.detect_resolution()?;
+        self.resolution = Some(res);
+        Ok(res)
+    }
+
+    /// Query the screen resolution without caching it
+    fn detect_resolution(&self) -> Result<(u32, u32), GuiError> {
+        match self.platform {
             Platform::MacOs => self.get_macos_resolution(),
             Platform::Windows => self.get_windows_resolution(),
             Platform::Linux => self.get_linux_resolution(),
             _ => Err(GuiError::PlatformNotSupported),
-        }?;
-
-        self.resolution = Some(res);
-        Ok(res)
+        }
     }
 
     fn get_macos_resolution(&self) -> Result<(u32, u32), GuiError> {
@@ -106,6 +110,11 @@ impl DesktopPlatform {
     }
 
     fn get_linux_resolution(&self) -> Result<(u32, u32), GuiError> {
+        #[cfg(feature = "x11")]
+        if let Ok(res) = x11_resolution() {
+            return Ok(res);
+        }
+
         let output = Command::new("xdpyinfo")
             .output()
             .map_err(|e| GuiError::Internal(e.to_string()))?;
@@ -152,54 +161,26 @@ impl GuiPlatform for DesktopPlatform {
     }
 
     fn capture_screenshot(&self) -> Result<Screenshot, GuiError> {
-        let temp_path = format!("/tmp/desktop_screenshot_{}.png", std::process::id());
-
-        let result = match self.platform {
-            Platform::MacOs => Command::new("screencapture")
-                .args(["-x", &temp_path])
-                .output(),
-            Platform::Linux => {
-                // Try scrot, then import (ImageMagick), then gnome-screenshot
-                Command::new("scrot").arg(&temp_path).output().or_else(|_| {
-                    Command::new("import")
-                        .args(["-window", "root", &temp_path])
-                        .output()
-                })
-            }
-            Platform::Windows => {
-                // PowerShell screenshot
-                Command::new("powershell")
-                    .args([
-                        "-Command",
-                        &format!(
-                            "Add-Type -AssemblyName System.Windows.Forms; \
-                             [System.Windows.Forms.Screen]::PrimaryScreen | \
-                             ForEach-Object {{ $bitmap = New-Object System.Drawing.Bitmap($_.Bounds.Width, $_.Bounds.Height); \
-                             $graphics = [System.Drawing.Graphics]::FromImage($bitmap); \
-                             $graphics.CopyFromScreen($_.Bounds.Location, [System.Drawing.Point]::Empty, $_.Bounds.Size); \
-                             $bitmap.Save('{}') }}",
-                            temp_path
-                        ),
-                    ])
-                    .output()
-            }
+        let data = match self.platform {
+            Platform::Linux => self.capture_linux()?,
+            Platform::MacOs | Platform::Windows => self.capture_with_tool()?,
             _ => return Err(GuiError::PlatformNotSupported),
         };
 
-        result.map_err(|e| GuiError::ScreenshotFailed(e.to_string()))?;
-
-        let data =
-            std::fs::read(&temp_path).map_err(|e| GuiError::ScreenshotFailed(e.to_string()))?;
-
-        let _ = std::fs::remove_file(&temp_path);
+        if data.is_empty() {
+            return Err(GuiError::ScreenshotFailed(
+                "capture produced an empty image".to_string(),
+            ));
+        }
+        let (width, height) = png_dimensions(&data).ok_or_else(|| {
+            GuiError::ScreenshotFailed("capture did not produce a PNG image".to_string())
+        })?;
 
         let timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .map(|d| d.as_millis() as u64)
             .unwrap_or(0);
 
-        let (width, height) = self.resolution.unwrap_or((1920, 1080));
-
         Ok(Screenshot {
             data,
             format: ImageFormat::Png,
@@ -252,7 +233,7 @@ impl GuiPlatform for DesktopPlatform {
             name: format!("Local {}", self.platform),
             platform: self.platform,
             os_version: Some(std::env::consts::OS.to_string()),
-            resolution: self.resolution,
+            resolution: self.resolution.or_else(|| self.detect_resolution().ok()),
             connection: ConnectionType::Usb,
             active: self.connected,
         }])
@@ -275,6 +256,66 @@ impl GuiPlatform for DesktopPlatform {
 }
 
 impl DesktopPlatform {
+    /// Capture the screen with an external tool writing a PNG file
+    fn capture_with_tool(&self) -> Result<Vec<u8>, GuiError> {
+        let temp_path = format!("/tmp/desktop_screenshot_{}.png", std::process::id());
+
+        let result = match self.platform {
+            Platform::MacOs => Command::new("screencapture")
+                .args(["-x", &temp_path])
+                .output(),
+            Platform::Linux => {
+                // Try scrot, then import (ImageMagick), then gnome-screenshot
+                Command::new("scrot").arg(&temp_path).output().or_else(|_| {
+                    Command::new("import")
+                        .args(["-window", "root", &temp_path])
+                        .output()
+                })
+            }
+            Platform::Windows => {
+                // PowerShell screenshot
+                Command::new("powershell")
+                    .args([
+                        "-Command",
+                        &format!(
+                            "Add-Type -AssemblyName System.Windows.Forms; \
+                             [System.Windows.Forms.Screen]::PrimaryScreen | \
+                             ForEach-Object {{ $bitmap = New-Object System.Drawing.Bitmap($_.Bounds.Width, $_.Bounds.Height); \
+                             $graphics = [System.Drawing.Graphics]::FromImage($bitmap); \
+                             $graphics.CopyFromScreen($_.Bounds.Location, [System.Drawing.Point]::Empty, $_.Bounds.Size); \
+                             $bitmap.Save('{}', [System.Drawing.Imaging.ImageFormat]::Png) }}",
+                            temp_path
+                        ),
+                    ])
+                    .output()
+            }
+            _ => return Err(GuiError::PlatformNotSupported),
+        };
+
+        result.map_err(|e| GuiError::ScreenshotFailed(e.to_string()))?;
+
+        let data =
+            std::fs::read(&temp_path).map_err(|e| GuiError::ScreenshotFailed(e.to_string()))?;
+
+        let _ = std::fs::remove_file(&temp_path);
+
+        Ok(data)
+    }
+
+    /// Capture a Linux desktop from whichever display server the session uses
+    fn capture_linux(&self) -> Result<Vec<u8>, GuiError> {
+        match LinuxDisplay::from_env() {
+            Some(LinuxDisplay::Wayland) => capture_wayland(),
+            #[cfg(feature = "x11")]
+            Some(LinuxDisplay::X11) => capture_x11(),
+            #[cfg(not(feature = "x11"))]
+            Some(LinuxDisplay::X11) => self.capture_with_tool(),
+            None => Err(GuiError::ScreenshotFailed(
+                "no display server found: neither WAYLAND_DISPLAY nor DISPLAY is set".to_string(),
+            )),
+        }
+    }
+
     fn execute_macos(&self, action: &GuiAction) -> Result<(), GuiError> {
         // Handle TYPE
         if let Some(ref text) = action.type_text {
@@ -470,6 +511,343 @@ impl DesktopPlatform {
     }
 }
 
+/// Display server a Linux session runs on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LinuxDisplay {
+    Wayland,
+    X11,
+}
+
+impl LinuxDisplay {
+    /// Detect the display server from the session environment
+    fn from_env() -> Option<Self> {
+        Self::detect(
+            std::env::var("WAYLAND_DISPLAY").ok().as_deref(),
+            std::env::var("DISPLAY").ok().as_deref(),
+        )
+    }
+
+    /// Wayland wins when both are set, since XWayland only sees X clients
+    fn detect(wayland_display: Option<&str>, x11_display: Option<&str>) -> Option<Self> {
+        let is_set = |var: Option<&str>| var.is_some_and(|v| !v.is_empty());
+        if is_set(wayland_display) {
+            Some(LinuxDisplay::Wayland)
+        } else if is_set(x11_display) {
+            Some(LinuxDisplay::X11)
+        } else {
+            None
+        }
+    }
+}
+
+/// Read width and height from a PNG's IHDR chunk
+fn png_dimensions(data: &[u8]) -> Option<(u32, u32)> {
+    const SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+    if data.len() < 24 || !data.starts_with(SIGNATURE) || &data[12..16] != b"IHDR" {
+        return None;
+    }
+    let width = u32::from_be_bytes(data[16..20].try_into().ok()?);
+    let height = u32::from_be_bytes(data[20..24].try_into().ok()?);
+    Some((width, height))
+}
+
+/// Pixel layout of an X11 ZPixmap image
+#[cfg(feature = "x11")]
+#[derive(Debug, Clone, Copy)]
+struct PixelLayout {
+    bits_per_pixel: u8,
+    scanline_pad: u8,
+    little_endian: bool,
+    red_mask: u32,
+    green_mask: u32,
+    blue_mask: u32,
+}
+
+#[cfg(feature = "x11")]
+impl PixelLayout {
+    /// Scale the channel selected by `mask` to 8 bits
+    fn channel(pixel: u32, mask: u32) -> u8 {
+        if mask == 0 {
+            return 0;
+        }
+        let shift = mask.trailing_zeros();
+        let bits = (mask >> shift).count_ones();
+        let raw = (pixel & mask) >> shift;
+        if bits >= 8 {
+            (raw >> (bits - 8)) as u8
+        } else {
+            (raw * 255 / ((1 << bits) - 1)) as u8
+        }
+    }
+
+    /// Convert ZPixmap data to packed 8-bit RGB
+    fn to_rgb(self, data: &[u8], width: u32, height: u32) -> Result<Vec<u8>, GuiError> {
+        let bytes_per_pixel = match self.bits_per_pixel {
+            16 | 24 | 32 => self.bits_per_pixel as usize / 8,
+            bpp => {
+                return Err(GuiError::ScreenshotFailed(format!(
+                    "unsupported X11 pixel format: {} bits per pixel",
+                    bpp
+                )))
+            }
+        };
+        let pad = self.scanline_pad.max(8) as usize;
+        let stride = (width as usize * self.bits_per_pixel as usize).div_ceil(pad) * pad / 8;
+        if data.len() < stride * height as usize {
+            return Err(GuiError::ScreenshotFailed(format!(
+                "X11 image is truncated: {} bytes for {}x{}",
+                data.len(),
+                width,
+                height
+            )));
+        }
+
+        let mut rgb = Vec::with_capacity(width as usize * height as usize * 3);
+        for row in data.chunks(stride).take(height as usize) {
+            for bytes in row[..width as usize * bytes_per_pixel].chunks(bytes_per_pixel) {
+                let pixel = if self.little_endian {
+                    bytes
+                        .iter()
+                        .rev()
+                        .fold(0u32, |acc, &b| (acc << 8) | b as u32)
+                } else {
+                    bytes.iter().fold(0u32, |acc, &b| (acc << 8) | b as u32)
+                };
+                rgb.push(Self::channel(pixel, self.red_mask));
+                rgb.push(Self::channel(pixel, self.green_mask));
+                rgb.push(Self::channel(pixel, self.blue_mask));
+            }
+        }
+        Ok(rgb)
+    }
+}
+
+/// Encode packed 8-bit RGB as PNG
+#[cfg(feature = "x11")]
+fn encode_png(rgb: &[u8], width: u32, height: u32) -> Result<Vec<u8>, GuiError> {
+    let mut out = Vec::new();
+    let mut encoder = png::Encoder::new(&mut out, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder
+        .write_header()
+        .map_err(|e| GuiError::ScreenshotFailed(format!("PNG encoding failed: {}", e)))?;
+    writer
+        .write_image_data(rgb)
+        .map_err(|e| GuiError::ScreenshotFailed(format!("PNG encoding failed: {}", e)))?;
+    writer
+        .finish()
+        .map_err(|e| GuiError::ScreenshotFailed(format!("PNG encoding failed: {}", e)))?;
+    Ok(out)
+}
+
+/// Resolution of the default X11 screen
+#[cfg(feature = "x11")]
+fn x11_resolution() -> Result<(u32, u32), GuiError> {
+    use x11rb::connection::Connection;
+
+    let (conn, screen_num) =
+        x11rb::connect(None).map_err(|e| GuiError::ConnectionFailed(e.to_string()))?;
+    let screen = &conn.setup().roots[screen_num];
+    Ok((
+        screen.width_in_pixels as u32,
+        screen.height_in_pixels as u32,
+    ))
+}
+
+/// Grab the X11 root window and encode it as PNG
+#[cfg(feature = "x11")]
+fn capture_x11() -> Result<Vec<u8>, GuiError> {
+    use x11rb::connection::Connection;
+    use x11rb::protocol::xproto::{ConnectionExt, ImageFormat as XImageFormat, ImageOrder};
+
+    let failed = |e: &dyn std::fmt::Display| GuiError::ScreenshotFailed(format!("X11: {}", e));
+
+    let (conn, screen_num) = x11rb::connect(None).map_err(|e| failed(&e))?;
+    let setup = conn.setup();
+    let screen = &setup.roots[screen_num];
+    let (width, height) = (screen.width_in_pixels, screen.height_in_pixels);
+
+    let image = conn
+        .get_image(XImageFormat::Z_PIXMAP, screen.root, 0, 0, width, height, !0)
+        .map_err(|e| failed(&e))?
+        .reply()
+        .map_err(|e| failed(&e))?;
+
+    let format = setup
+        .pixmap_formats
+        .iter()
+        .find(|f| f.depth == image.depth)
+        .ok_or_else(|| failed(&format!("no pixmap format for depth {}", image.depth)))?;
+    let visual = screen
+        .allowed_depths
+        .iter()
+        .flat_map(|d| d.visuals.iter())
+        .find(|v| v.visual_id == image.visual)
+        .ok_or_else(|| failed(&format!("unknown visual {}", image.visual)))?;
+
+    let layout = PixelLayout {
+        bits_per_pixel: format.bits_per_pixel,
+        scanline_pad: format.scanline_pad,
+        little_endian: setup.image_byte_order == ImageOrder::LSB_FIRST,
+        red_mask: visual.red_mask,
+        green_mask: visual.green_mask,
+        blue_mask: visual.blue_mask,
+    };
+    let rgb = layout.to_rgb(&image.data, width as u32, height as u32)?;
+    encode_png(&rgb, width as u32, height as u32)
+}
+
+/// Longest to wait for the screenshot portal to answer, which may
+/// include the user responding to a permission prompt
+#[cfg(feature = "wayland")]
+const PORTAL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// A portal `handle_token` unique to this capture, so overlapping
+/// requests from one process get distinct request paths
+#[cfg(feature = "wayland")]
+fn portal_token() -> String {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    format!(
+        "rustyworm_{}_{}",
+        std::process::id(),
+        NEXT.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Capture a Wayland session through the screenshot portal
+#[cfg(feature = "wayland")]
+fn capture_wayland() -> Result<Vec<u8>, GuiError> {
+    use std::collections::HashMap;
+    use std::sync::mpsc;
+    use zbus::blocking::{Connection, Proxy};
+    use zbus::zvariant::{OwnedObjectPath, OwnedValue, Value};
+
+    const PORTAL: &str = "org.freedesktop.portal.Desktop";
+
+    let unsupported = |e: zbus::Error| {
+        GuiError::ScreenshotFailed(format!(
+            "Wayland compositor does not provide the xdg-desktop-portal screenshot interface: {}",
+            e
+        ))
+    };
+
+    let conn = Connection::session().map_err(unsupported)?;
+    let sender = conn
+        .unique_name()
+        .map(|name| name.as_str().trim_start_matches(':').replace('.', "_"))
+        .ok_or_else(|| GuiError::ScreenshotFailed("D-Bus connection has no name".to_string()))?;
+
+    // Subscribe to the request's Response before asking, so it cannot be missed
+    let token = portal_token();
+    let request_path = format!(
+        "/org/freedesktop/portal/desktop/request/{}/{}",
+        sender, token
+    );
+    let request = Proxy::new(
+        &conn,
+        PORTAL,
+        request_path.as_str(),
+        "org.freedesktop.portal.Request",
+    )
+    .map_err(unsupported)?;
+    let responses = request.receive_signal("Response").map_err(unsupported)?;
+
+    let portal = Proxy::new(
+        &conn,
+        PORTAL,
+        "/org/freedesktop/portal/desktop",
+        "org.freedesktop.portal.Screenshot",
+    )
+    .map_err(unsupported)?;
+    let mut options: HashMap<&str, Value> = HashMap::new();
+    options.insert("handle_token", Value::from(token.as_str()));
+    options.insert("interactive", Value::from(false));
+    let _: OwnedObjectPath = portal
+        .call("Screenshot", &("", options))
+        .map_err(unsupported)?;
+
+    // The signal iterator blocks, so wait for it on a helper thread
+    let (sender, received) = mpsc::channel();
+    std::thread::spawn(move || {
+        let mut responses = responses;
+        let _ = sender.send(responses.next());
+    });
+    let response = match received.recv_timeout(PORTAL_TIMEOUT) {
+        Ok(Some(response)) => response,
+        Ok(None) | Err(mpsc::RecvTimeoutError::Disconnected) => {
+            return Err(GuiError::ScreenshotFailed(
+                "screenshot portal closed without responding".to_string(),
+            ))
+        }
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            // Withdraw the request; closing the connection ends the
+            // helper thread's wait
+            let _: Result<(), _> = request.call("Close", &());
+            let _ = conn.close();
+            return Err(GuiError::ScreenshotFailed(format!(
+                "screenshot portal did not respond within {}s",
+                PORTAL_TIMEOUT.as_secs()
+            )));
+        }
+    };
+    let (code, results): (u32, HashMap<String, OwnedValue>) =
+        response.body().deserialize().map_err(unsupported)?;
+    match code {
+        0 => {}
+        1 => return Err(GuiError::PermissionDenied),
+        _ => {
+            return Err(GuiError::ScreenshotFailed(format!(
+                "screenshot portal request failed (response {})",
+                code
+            )))
+        }
+    }
+
+    let uri = results
+        .get("uri")
+        .and_then(|value| <&str>::try_from(&**value).ok())
+        .ok_or_else(|| {
+            GuiError::ScreenshotFailed("screenshot portal returned no image".to_string())
+        })?;
+    let path = file_uri_path(uri).ok_or_else(|| {
+        GuiError::ScreenshotFailed(format!("unsupported screenshot location: {}", uri))
+    })?;
+    let data = std::fs::read(&path).map_err(|e| GuiError::ScreenshotFailed(e.to_string()))?;
+    let _ = std::fs::remove_file(&path);
+
+    Ok(data)
+}
+
+/// Without the `wayland` feature there is no way to capture a Wayland session
+#[cfg(not(feature = "wayland"))]
+fn capture_wayland() -> Result<Vec<u8>, GuiError> {
+    Err(GuiError::ScreenshotFailed(
+        "Wayland capture goes through xdg-desktop-portal; rebuild with the `wayland` feature"
+            .to_string(),
+    ))
+}
+
+/// Decode a `file://` URI into a local path
+#[cfg(feature = "wayland")]
+fn file_uri_path(uri: &str) -> Option<std::path::PathBuf> {
+    let encoded = uri.strip_prefix("file://")?.as_bytes();
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut i = 0;
+    while i < encoded.len() {
+        if encoded[i] == b'%' {
+            let hex = std::str::from_utf8(encoded.get(i + 1..i + 3)?).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            bytes.push(encoded[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(bytes).ok().map(std::path::PathBuf::from)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -490,4 +868,101 @@ mod tests {
         assert_eq!(devices.len(), 1);
         assert_eq!(devices[0].id, "local");
     }
+
+    #[test]
+    fn test_list_devices_reports_resolution_before_connect() {
+        let mut platform = DesktopPlatform::detect_current();
+        let detected = platform.detect_resolution().ok();
+        assert_eq!(platform.list_devices().unwrap()[0].resolution, detected);
+
+        platform.resolution = Some((1920, 1080));
+        assert_eq!(
+            platform.list_devices().unwrap()[0].resolution,
+            Some((1920, 1080))
+        );
+    }
+
+    #[test]
+    fn test_linux_display_detection() {
+        assert_eq!(
+            LinuxDisplay::detect(Some("wayland-0"), Some(":0")),
+            Some(LinuxDisplay::Wayland)
+        );
+        assert_eq!(
+            LinuxDisplay::detect(Some(""), Some(":0")),
+            Some(LinuxDisplay::X11)
+        );
+        assert_eq!(LinuxDisplay::detect(None, None), None);
+    }
+
+    #[test]
+    fn test_png_dimensions() {
+        let mut header = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        header.extend_from_slice(&640u32.to_be_bytes());
+        header.extend_from_slice(&480u32.to_be_bytes());
+        assert_eq!(png_dimensions(&header), Some((640, 480)));
+        assert_eq!(png_dimensions(b"GIF89a"), None);
+        assert_eq!(png_dimensions(&[]), None);
+    }
+
+    #[cfg(feature = "x11")]
+    #[test]
+    fn test_zpixmap_conversion() {
+        // Two BGRX pixels per row, rows padded to 32 bits
+        let layout = PixelLayout {
+            bits_per_pixel: 32,
+            scanline_pad: 32,
+            little_endian: true,
+            red_mask: 0xff0000,
+            green_mask: 0x00ff00,
+            blue_mask: 0x0000ff,
+        };
+        let data = [
+            0x30, 0x20, 0x10, 0, 0xff, 0xff, 0xff, 0, // row 0
+            0, 0, 0xff, 0, 0, 0xff, 0, 0, // row 1
+        ];
+        let rgb = layout.to_rgb(&data, 2, 2).unwrap();
+        assert_eq!(rgb, [0x10, 0x20, 0x30, 255, 255, 255, 255, 0, 0, 0, 255, 0]);
+        assert!(layout.to_rgb(&data[..8], 2, 2).is_err());
+
+        // RGB565 scales 5- and 6-bit channels to the full range
+        assert_eq!(PixelLayout::channel(0xf800, 0xf800), 255);
+        assert_eq!(PixelLayout::channel(0x07e0, 0x07e0), 255);
+
+        let png = encode_png(&rgb, 2, 2).unwrap();
+        assert_eq!(png_dimensions(&png), Some((2, 2)));
+    }
+
+    #[cfg(feature = "wayland")]
+    #[test]
+    fn test_portal_tokens_are_unique() {
+        let (first, second) = (portal_token(), portal_token());
+        assert_ne!(first, second);
+        // Portal tokens may only hold ASCII alphanumerics and underscores
+        assert!(first.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'));
+    }
+
+    #[cfg(feature = "wayland")]
+    #[test]
+    fn test_file_uri_path() {
+        assert_eq!(
+            file_uri_path("file:///home/me/Pictures/Screenshot%20from%20today.png"),
+            Some(std::path::PathBuf::from(
+                "/home/me/Pictures/Screenshot from today.png"
+            ))
+        );
+        assert_eq!(file_uri_path("https://example.com/a.png"), None);
+        assert_eq!(file_uri_path("file:///bad%2"), None);
+    }
+
+    #[test]
+    fn test_capture_without_display_is_descriptive() {
+        if LinuxDisplay::from_env().is_some() || std::env::consts::OS != "linux" {
+            return;
+        }
+        let err = DesktopPlatform::new(Platform::Linux)
+            .capture_screenshot()
+            .unwrap_err();
+        assert!(err.to_string().contains("no display server"), "{}", err);
+    }
 }
@@ -31,6 +31,23 @@ pub struct PersonalityDelta {
     pub source: DeltaSource,
 }
 
+/// Profile values captured before a delta was applied, so its inverse
+/// can restore them exactly even where the forward step clamped an axis.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeltaPriors {
+    /// Adjustments of the delta these values were captured for
+    pub adjustments: Vec<(String, f64)>,
+    /// Confidence of that delta
+    pub confidence: f64,
+    /// (axis_name, value before) - `None` if the delta created the axis
+    /// in `extra_axes`
+    pub axes: Vec<(String, Option<f64>)>,
+    /// Response-style verbosity before, if the delta touched it
+    pub verbosity: Option<f64>,
+    /// Response-style formality before, if the delta touched it
+    pub formality: Option<f64>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum DeltaSource {
     /// From comparing own output against target signature
@@ -43,6 +60,8 @@ pub enum DeltaSource {
     UserCorrection,
     /// From System 2 -> System 1 compilation feedback
     CompoundFeedback,
+    /// From inverting a previously applied delta
+    Rollback,
 }
 
 impl PersonalityDelta {
@@ -95,6 +114,21 @@ impl PersonalityDelta {
         }
     }
 
+    /// Inverse of this delta: every adjustment negated, source `Rollback`.
+    /// Applied to the profile that took the forward delta, it restores the
+    /// values recorded then, so clamped axes come back exactly.
+    pub fn invert(&self) -> PersonalityDelta {
+        PersonalityDelta {
+            adjustments: self
+                .adjustments
+                .iter()
+                .map(|(axis, amount)| (axis.clone(), -amount))
+                .collect(),
+            confidence: self.confidence,
+            source: DeltaSource::Rollback,
+        }
+    }
+
     /// Magnitude of this delta - how much change is it requesting
     pub fn magnitude(&self) -> f64 {
         if self.adjustments.is_empty() {
@@ -216,6 +250,9 @@ pub struct ResponseStyle {
     pub max_response_length: Option<usize>,
     /// Preferred paragraph length style.
     pub paragraph_style: ParagraphStyle,
+    /// Whether summaries render key points as a Markdown table.
+    #[serde(default)]
+    pub prefers_tables: bool,
 }
 
 /// The style used for rendering lists in responses.
@@ -255,6 +292,7 @@ impl Default for ResponseStyle {
             preferred_list_style: ListStyle::Bullets,
             max_response_length: None,
             paragraph_style: ParagraphStyle::Adaptive,
+            prefers_tables: false,
         }
     }
 }
@@ -296,6 +334,11 @@ impl ResponseStyle {
             } else {
                 self.paragraph_style.clone()
             },
+            prefers_tables: if t > 0.5 {
+                other.prefers_tables
+            } else {
+                self.prefers_tables
+            },
         }
     }
 }
@@ -357,6 +400,12 @@ pub enum UncertaintyBehavior {
 // AI PROFILE - THE COMPLETE BEHAVIORAL DNA
 // =================================================================
 
+/// Upper bound on `AiProfile::step_depth`
+pub const MAX_STEP_DEPTH: u8 = 12;
+
+/// Corrections whose priors an `AiProfile` keeps for exact rollback
+pub const CORRECTION_LOG_LEN: usize = 32;
+
 /// Complete behavioral DNA of an AI model, capturing personality, style, and capabilities.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AiProfile {
@@ -373,6 +422,10 @@ pub struct AiProfile {
 
     /// How this model approaches reasoning tasks.
     pub reasoning_style: ReasoningStyle,
+    /// Numbered steps in chain-of-thought reasoning bodies (clamped to
+    /// `MAX_STEP_DEPTH`); `None` keeps the persona's built-in template.
+    #[serde(default)]
+    pub step_depth: Option<u8>,
     /// Personality dimensions that define behavioral tendencies.
     pub personality: Vec<PersonalityAxis>,
     /// Formatting and tone preferences for generated output.
@@ -401,6 +454,23 @@ pub struct AiProfile {
 
     /// Arbitrary key-value metadata for extensions and custom properties.
     pub metadata: HashMap<String, String>,
+    /// Custom numeric traits outside `personality` (e.g. "citation_density").
+    /// Corrections to unknown axis names land here.
+    #[serde(default)]
+    pub extra_axes: HashMap<String, f64>,
+    /// Priors of the last `CORRECTION_LOG_LEN` corrections, newest last,
+    /// consumed by their inverses. Not persisted: a reloaded profile
+    /// rolls back by subtraction.
+    #[serde(skip)]
+    correction_log: Vec<DeltaPriors>,
+    /// BCP-47 tag of the language fixed template phrases render in
+    /// (e.g. "en", "fr-CA"); phrases without a translation stay English.
+    #[serde(default = "default_language")]
+    pub language: String,
+}
+
+fn default_language() -> String {
+    "en".to_string()
 }
 
 impl AiProfile {
@@ -413,6 +483,7 @@ impl AiProfile {
             provider: "unknown".to_string(),
             description: String::new(),
             reasoning_style: ReasoningStyle::DirectWithDepth,
+            step_depth: None,
             personality: Vec::new(),
             response_style: ResponseStyle::default(),
             safety: SafetyProfile::default(),
@@ -425,15 +496,37 @@ impl AiProfile {
             knowledge_cutoff: None,
             uncertainty_behavior: UncertaintyBehavior::AdmitIgnorance,
             metadata: HashMap::new(),
+            extra_axes: HashMap::new(),
+            correction_log: Vec::new(),
+            language: default_language(),
+        }
+    }
+
+    /// Chain-of-thought step count, if this profile reasons step by step
+    /// and sets one. Clamped to `MAX_STEP_DEPTH`.
+    pub fn chain_of_thought_depth(&self) -> Option<u8> {
+        fn is_cot(style: &ReasoningStyle) -> bool {
+            match style {
+                ReasoningStyle::ChainOfThought => true,
+                ReasoningStyle::Hybrid(styles) => styles.iter().any(is_cot),
+                _ => false,
+            }
+        }
+        if is_cot(&self.reasoning_style) {
+            self.step_depth.map(|d| d.min(MAX_STEP_DEPTH))
+        } else {
+            None
         }
     }
 
-    /// Get the personality value for a given axis name
+    /// Get the value for a given axis name, checking built-in personality
+    /// axes first and then `extra_axes`
     pub fn personality_value(&self, axis_name: &str) -> Option<f64> {
         self.personality
             .iter()
             .find(|a| a.name == axis_name)
             .map(|a| a.value)
+            .or_else(|| self.extra_axes.get(axis_name).copied())
     }
 
     /// Set a personality axis value, creating the axis if it doesn't exist
@@ -563,13 +656,16 @@ impl AiProfile {
     /// COMPOUND: Weighted blend of multiple profiles into a new hybrid.
     /// Personality axes are lerped, response styles blended, modalities unioned,
     /// signature phrases merged.
+    ///
+    /// Panics on invalid weights; use `try_blend` for untrusted input.
     pub fn blend(profiles: &[&AiProfile], weights: &[f64]) -> AiProfile {
-        assert!(!profiles.is_empty(), "Cannot blend zero profiles");
-        assert_eq!(
-            profiles.len(),
-            weights.len(),
-            "Profile count must match weight count"
-        );
+        AiProfile::try_blend(profiles, weights).unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Like `blend`, but rejects an empty profile list, a weight count that
+    /// doesn't match, negative or non-finite weights, and all-zero weights.
+    pub fn try_blend(profiles: &[&AiProfile], weights: &[f64]) -> Result<AiProfile, BlendError> {
+        BlendError::check(profiles.len(), weights)?;
 
         // Normalize weights
         let total: f64 = weights.iter().sum();
@@ -614,6 +710,30 @@ impl AiProfile {
             });
         }
 
+        // Blend extra axes - a profile missing a key doesn't pull it toward
+        // 0.0; the average is taken over the profiles that define it
+        let mut extra: HashMap<String, (f64, f64)> = HashMap::new(); // name -> (sum, weight)
+        for (i, profile) in profiles.iter().enumerate() {
+            for (name, value) in &profile.extra_axes {
+                let entry = extra.entry(name.clone()).or_insert((0.0, 0.0));
+                entry.0 += value * norm_weights[i];
+                entry.1 += norm_weights[i];
+            }
+        }
+        for (name, (sum, weight)) in extra {
+            let value = if weight > 0.0 {
+                sum / weight
+            } else {
+                // All holders had zero weight: plain mean
+                let held: Vec<f64> = profiles
+                    .iter()
+                    .filter_map(|p| p.extra_axes.get(&name).copied())
+                    .collect();
+                held.iter().sum::<f64>() / held.len() as f64
+            };
+            blended.extra_axes.insert(name, value);
+        }
+
         // Blend response styles (use first profile as base, lerp toward others)
         let mut style = profiles[0].response_style.clone();
         for i in 1..profiles.len() {
@@ -654,24 +774,54 @@ impl AiProfile {
             .map(|(i, _)| i)
             .unwrap_or(0);
         blended.reasoning_style = profiles[dominant_idx].reasoning_style.clone();
+        blended.step_depth = profiles[dominant_idx].step_depth;
         blended.uncertainty_behavior = profiles[dominant_idx].uncertainty_behavior.clone();
         blended.safety = profiles[dominant_idx].safety.clone();
+        blended.language = profiles[dominant_idx].language.clone();
 
-        blended
+        Ok(blended)
     }
 
     /// COMPOUND: Apply incremental corrections from a PersonalityDelta.
     /// Each adjustment is scaled by the delta's confidence.
     /// This is the self-monitoring -> profile refinement path.
+    ///
+    /// The values each correction touches are recorded first, so applying
+    /// `delta.invert()` afterwards restores them exactly, even where the
+    /// forward step clamped an axis at ±1.0.
     pub fn apply_correction(&mut self, delta: &PersonalityDelta) {
+        if let DeltaSource::Rollback = delta.source {
+            // A recorded rollback restores captured values instead of
+            // subtracting, which undoes any clamping the forward step did
+            let forward = |p: &DeltaPriors| {
+                p.confidence == delta.confidence
+                    && p.adjustments.len() == delta.adjustments.len()
+                    && p.adjustments
+                        .iter()
+                        .zip(&delta.adjustments)
+                        .all(|((a, x), (b, y))| a == b && *x == -*y)
+            };
+            if let Some(i) = self.correction_log.iter().rposition(forward) {
+                let priors = self.correction_log.remove(i);
+                self.restore_priors(&priors);
+                return;
+            }
+        } else {
+            let priors = self.capture_priors(delta);
+            if self.correction_log.len() >= CORRECTION_LOG_LEN {
+                self.correction_log.remove(0);
+            }
+            self.correction_log.push(priors);
+        }
+
         for (axis_name, adjustment) in &delta.adjustments {
             let scaled = adjustment * delta.confidence;
             if let Some(axis) = self.personality.iter_mut().find(|a| a.name == *axis_name) {
                 axis.value = (axis.value + scaled).clamp(-1.0, 1.0);
             } else {
-                // Create new axis if it doesn't exist
-                self.personality
-                    .push(PersonalityAxis::new(axis_name, *adjustment, "low", "high"));
+                // Unknown axis names are custom traits
+                let value = self.extra_axes.entry(axis_name.clone()).or_insert(0.0);
+                *value = (*value + scaled).clamp(-1.0, 1.0);
             }
         }
 
@@ -687,6 +837,519 @@ impl AiProfile {
                 .clamp(0.0, 1.0);
         }
     }
+
+    /// The values `delta` would touch, as they are now
+    fn capture_priors(&self, delta: &PersonalityDelta) -> DeltaPriors {
+        let touches = |name: &str| delta.adjustments.iter().any(|(n, _)| n == name);
+        let mut priors = DeltaPriors {
+            adjustments: delta.adjustments.clone(),
+            confidence: delta.confidence,
+            axes: Vec::new(),
+            verbosity: touches("verbosity").then_some(self.response_style.verbosity),
+            formality: touches("formality").then_some(self.response_style.formality),
+        };
+        for (axis_name, _) in &delta.adjustments {
+            if !priors.axes.iter().any(|(n, _)| n == axis_name) {
+                priors
+                    .axes
+                    .push((axis_name.clone(), self.personality_value(axis_name)));
+            }
+        }
+        priors
+    }
+
+    /// Check for values that would silently produce garbage responses.
+    /// Collects every violation rather than stopping at the first:
+    /// empty `id`/`display_name`, axes outside their [-1.0, 1.0] scale,
+    /// `ResponseStyle` numbers outside [0.0, 1.0], and empty signature phrases.
+    pub fn validate(&self) -> Result<(), Vec<ProfileError>> {
+        let mut errors = Vec::new();
+        if self.id.trim().is_empty() {
+            errors.push(ProfileError::EmptyId);
+        }
+        if self.display_name.trim().is_empty() {
+            errors.push(ProfileError::EmptyDisplayName);
+        }
+
+        let mut extra: Vec<(&String, &f64)> = self.extra_axes.iter().collect();
+        extra.sort_by(|a, b| a.0.cmp(b.0));
+        let axes = self
+            .personality
+            .iter()
+            .map(|a| (&a.name, &a.value))
+            .chain(extra);
+        for (axis, value) in axes {
+            if !(-1.0..=1.0).contains(value) {
+                errors.push(ProfileError::AxisOutOfRange {
+                    axis: axis.clone(),
+                    value: *value,
+                });
+            }
+        }
+
+        let style = &self.response_style;
+        for (field, value) in [
+            ("verbosity", style.verbosity),
+            ("formality", style.formality),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                errors.push(ProfileError::StyleOutOfRange {
+                    field: field.to_string(),
+                    value,
+                });
+            }
+        }
+
+        for (i, phrase) in self.signature_phrases.iter().enumerate() {
+            if phrase.trim().is_empty() {
+                errors.push(ProfileError::EmptySignaturePhrase(i));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Structured diff against another profile: per-axis deltas, signature
+    /// phrase differences, and changed style/reasoning/safety fields.
+    pub fn diff(&self, other: &AiProfile) -> ProfileDiff {
+        let mut names: Vec<&str> = self
+            .personality
+            .iter()
+            .chain(other.personality.iter())
+            .map(|a| a.name.as_str())
+            .chain(self.extra_axes.keys().map(String::as_str))
+            .chain(other.extra_axes.keys().map(String::as_str))
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        let axes = names
+            .into_iter()
+            .map(|name| AxisDiff {
+                name: name.to_string(),
+                left: self.personality_value(name),
+                right: other.personality_value(name),
+            })
+            .collect();
+
+        let only_in = |a: &AiProfile, b: &AiProfile| -> Vec<String> {
+            a.signature_phrases
+                .iter()
+                .filter(|p| !b.signature_phrases.contains(p))
+                .cloned()
+                .collect()
+        };
+
+        let mut field_changes = Vec::new();
+        let mut check = |field: &str, left: String, right: String| {
+            if left != right {
+                field_changes.push(FieldChange {
+                    field: field.to_string(),
+                    left,
+                    right,
+                });
+            }
+        };
+        let (ls, rs) = (&self.response_style, &other.response_style);
+        check(
+            "reasoning_style",
+            self.reasoning_style.to_string(),
+            other.reasoning_style.to_string(),
+        );
+        check(
+            "step_depth",
+            format!("{:?}", self.step_depth),
+            format!("{:?}", other.step_depth),
+        );
+        check(
+            "response_style.verbosity",
+            format!("{:.3}", ls.verbosity),
+            format!("{:.3}", rs.verbosity),
+        );
+        check(
+            "response_style.formality",
+            format!("{:.3}", ls.formality),
+            format!("{:.3}", rs.formality),
+        );
+        check(
+            "response_style.uses_markdown",
+            ls.uses_markdown.to_string(),
+            rs.uses_markdown.to_string(),
+        );
+        check(
+            "response_style.uses_code_blocks",
+            ls.uses_code_blocks.to_string(),
+            rs.uses_code_blocks.to_string(),
+        );
+        check(
+            "response_style.uses_emojis",
+            ls.uses_emojis.to_string(),
+            rs.uses_emojis.to_string(),
+        );
+        check(
+            "response_style.preferred_list_style",
+            format!("{:?}", ls.preferred_list_style),
+            format!("{:?}", rs.preferred_list_style),
+        );
+        check(
+            "response_style.max_response_length",
+            format!("{:?}", ls.max_response_length),
+            format!("{:?}", rs.max_response_length),
+        );
+        check(
+            "response_style.paragraph_style",
+            format!("{:?}", ls.paragraph_style),
+            format!("{:?}", rs.paragraph_style),
+        );
+        check(
+            "response_style.prefers_tables",
+            ls.prefers_tables.to_string(),
+            rs.prefers_tables.to_string(),
+        );
+        check("language", self.language.clone(), other.language.clone());
+        let (lsafe, rsafe) = (&self.safety, &other.safety);
+        check(
+            "safety.refuses_harmful",
+            lsafe.refuses_harmful.to_string(),
+            rsafe.refuses_harmful.to_string(),
+        );
+        check(
+            "safety.refuses_illegal",
+            lsafe.refuses_illegal.to_string(),
+            rsafe.refuses_illegal.to_string(),
+        );
+        check(
+            "safety.hedges_uncertainty",
+            lsafe.hedges_uncertainty.to_string(),
+            rsafe.hedges_uncertainty.to_string(),
+        );
+        check(
+            "safety.adds_disclaimers",
+            lsafe.adds_disclaimers.to_string(),
+            rsafe.adds_disclaimers.to_string(),
+        );
+        check(
+            "safety.caution_level",
+            format!("{:.3}", lsafe.caution_level),
+            format!("{:.3}", rsafe.caution_level),
+        );
+        check(
+            "safety.custom_constraints",
+            lsafe.custom_constraints.join("; "),
+            rsafe.custom_constraints.join("; "),
+        );
+
+        ProfileDiff {
+            left_id: self.id.clone(),
+            right_id: other.id.clone(),
+            axes,
+            phrases_only_left: only_in(self, other),
+            phrases_only_right: only_in(other, self),
+            field_changes,
+        }
+    }
+
+    /// Put back the values captured before a recorded delta was applied
+    fn restore_priors(&mut self, priors: &DeltaPriors) {
+        for (axis_name, before) in &priors.axes {
+            if let Some(axis) = self.personality.iter_mut().find(|a| a.name == *axis_name) {
+                if let Some(value) = before {
+                    axis.value = *value;
+                }
+            } else {
+                match before {
+                    Some(value) => self.extra_axes.insert(axis_name.clone(), *value),
+                    None => self.extra_axes.remove(axis_name),
+                };
+            }
+        }
+        if let Some(verbosity) = priors.verbosity {
+            self.response_style.verbosity = verbosity;
+        }
+        if let Some(formality) = priors.formality {
+            self.response_style.formality = formality;
+        }
+    }
+}
+
+// =================================================================
+// PROFILE VALIDATION
+// =================================================================
+
+/// A single problem found by `AiProfile::validate`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ProfileError {
+    /// The profile `id` is empty or whitespace.
+    EmptyId,
+    /// The `display_name` is empty or whitespace.
+    EmptyDisplayName,
+    /// A personality or extra axis lies outside [-1.0, 1.0] (or is NaN).
+    AxisOutOfRange {
+        /// Axis name.
+        axis: String,
+        /// Offending value.
+        value: f64,
+    },
+    /// A `ResponseStyle` numeric field lies outside [0.0, 1.0] (or is NaN).
+    StyleOutOfRange {
+        /// Field name (e.g. "verbosity").
+        field: String,
+        /// Offending value.
+        value: f64,
+    },
+    /// `signature_phrases` contains an empty entry at this index.
+    EmptySignaturePhrase(usize),
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileError::EmptyId => write!(f, "id must not be empty"),
+            ProfileError::EmptyDisplayName => write!(f, "display_name must not be empty"),
+            ProfileError::AxisOutOfRange { axis, value } => {
+                write!(f, "axis '{}' = {} is outside [-1.0, 1.0]", axis, value)
+            }
+            ProfileError::StyleOutOfRange { field, value } => write!(
+                f,
+                "response_style.{} = {} is outside [0.0, 1.0]",
+                field, value
+            ),
+            ProfileError::EmptySignaturePhrase(i) => {
+                write!(f, "signature_phrases[{}] is empty", i)
+            }
+        }
+    }
+}
+
+impl ProfileError {
+    /// One-line-per-problem summary for error messages
+    pub fn summarize(errors: &[ProfileError]) -> String {
+        let lines: Vec<String> = errors.iter().map(|e| format!("  - {}", e)).collect();
+        format!(
+            "Profile failed validation ({} problem{}):\n{}",
+            errors.len(),
+            if errors.len() == 1 { "" } else { "s" },
+            lines.join("\n")
+        )
+    }
+}
+
+// =================================================================
+// BLEND VALIDATION
+// =================================================================
+
+/// Why `AiProfile::try_blend` refused a set of profiles and weights.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BlendError {
+    /// No profiles were given.
+    Empty,
+    /// The number of weights doesn't match the number of profiles.
+    LengthMismatch {
+        /// Number of profiles.
+        profiles: usize,
+        /// Number of weights.
+        weights: usize,
+    },
+    /// A weight is below zero (index into the weight slice).
+    NegativeWeight {
+        /// Position of the weight.
+        index: usize,
+        /// Offending value.
+        value: f64,
+    },
+    /// A weight is NaN or infinite.
+    NonFiniteWeight {
+        /// Position of the weight.
+        index: usize,
+        /// Offending value.
+        value: f64,
+    },
+    /// Every weight is zero, so there is nothing to normalize against.
+    AllZero,
+}
+
+impl fmt::Display for BlendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BlendError::Empty => write!(f, "Cannot blend zero profiles"),
+            BlendError::LengthMismatch { profiles, weights } => write!(
+                f,
+                "Got {} weight{} for {} profile{}",
+                weights,
+                if *weights == 1 { "" } else { "s" },
+                profiles,
+                if *profiles == 1 { "" } else { "s" }
+            ),
+            BlendError::NegativeWeight { index, value } => {
+                write!(f, "Weight {} is negative ({})", index + 1, value)
+            }
+            BlendError::NonFiniteWeight { index, value } => {
+                write!(f, "Weight {} is not a finite number ({})", index + 1, value)
+            }
+            BlendError::AllZero => write!(f, "At least one weight must be greater than zero"),
+        }
+    }
+}
+
+impl std::error::Error for BlendError {}
+
+impl BlendError {
+    /// Check a profile count and weight slice without blending anything.
+    pub fn check(count: usize, weights: &[f64]) -> Result<(), BlendError> {
+        if count == 0 {
+            return Err(BlendError::Empty);
+        }
+        if count != weights.len() {
+            return Err(BlendError::LengthMismatch {
+                profiles: count,
+                weights: weights.len(),
+            });
+        }
+        for (index, &value) in weights.iter().enumerate() {
+            if !value.is_finite() {
+                return Err(BlendError::NonFiniteWeight { index, value });
+            }
+            if value < 0.0 {
+                return Err(BlendError::NegativeWeight { index, value });
+            }
+        }
+        if weights.iter().all(|w| *w == 0.0) {
+            return Err(BlendError::AllZero);
+        }
+        Ok(())
+    }
+}
+
+// =================================================================
+// PROFILE DIFF - Structured comparison of two profiles
+// =================================================================
+
+/// One personality axis compared across two profiles. A side is `None`
+/// when that profile does not define the axis at all.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AxisDiff {
+    /// Axis name (e.g. "confidence").
+    pub name: String,
+    /// Value in the left-hand profile, if present.
+    pub left: Option<f64>,
+    /// Value in the right-hand profile, if present.
+    pub right: Option<f64>,
+}
+
+impl AxisDiff {
+    /// Right minus left, or `None` if either side lacks the axis
+    pub fn delta(&self) -> Option<f64> {
+        Some(self.right? - self.left?)
+    }
+}
+
+/// A scalar style, reasoning, or safety field that differs between profiles.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    /// Dotted field path (e.g. "response_style.verbosity").
+    pub field: String,
+    /// Rendered value in the left-hand profile.
+    pub left: String,
+    /// Rendered value in the right-hand profile.
+    pub right: String,
+}
+
+/// Structured difference between two profiles, produced by `AiProfile::diff`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileDiff {
+    /// ID of the left-hand profile.
+    pub left_id: String,
+    /// ID of the right-hand profile.
+    pub right_id: String,
+    /// Every axis (built-in or extra) defined by either profile, sorted by name.
+    pub axes: Vec<AxisDiff>,
+    /// Signature phrases only the left-hand profile uses.
+    pub phrases_only_left: Vec<String>,
+    /// Signature phrases only the right-hand profile uses.
+    pub phrases_only_right: Vec<String>,
+    /// Response style, reasoning style, and safety fields that differ.
+    pub field_changes: Vec<FieldChange>,
+}
+
+/// Values closer than this are treated as equal when diffing
+const DIFF_EPSILON: f64 = 1e-9;
+
+impl ProfileDiff {
+    /// Axes whose values differ or that only one profile defines
+    pub fn changed_axes(&self) -> impl Iterator<Item = &AxisDiff> {
+        self.axes
+            .iter()
+            .filter(|a| a.delta().map(|d| d.abs() > DIFF_EPSILON).unwrap_or(true))
+    }
+
+    /// True when the profiles differ in no compared respect
+    pub fn is_empty(&self) -> bool {
+        self.changed_axes().next().is_none()
+            && self.phrases_only_left.is_empty()
+            && self.phrases_only_right.is_empty()
+            && self.field_changes.is_empty()
+    }
+}
+
+impl fmt::Display for ProfileDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = |v: Option<f64>| v.map_or("-".to_string(), |v| format!("{:+.3}", v));
+
+        writeln!(
+            f,
+            "=== PROFILE DIFF: {} vs {} ===",
+            self.left_id, self.right_id
+        )?;
+        writeln!(
+            f,
+            "  {:<20} {:>8} {:>8} {:>8}",
+            "axis", &self.left_id, &self.right_id, "delta"
+        )?;
+        for axis in &self.axes {
+            let delta = match axis.delta() {
+                Some(d) => format!("{:+.3}", d),
+                None => "only one".to_string(),
+            };
+            writeln!(
+                f,
+                "  {:<20} {:>8} {:>8} {:>8}",
+                axis.name,
+                value(axis.left),
+                value(axis.right),
+                delta
+            )?;
+        }
+
+        if !self.field_changes.is_empty() {
+            writeln!(f, "\nChanged fields:")?;
+            for change in &self.field_changes {
+                writeln!(
+                    f,
+                    "  {:<32} {} -> {}",
+                    change.field, change.left, change.right
+                )?;
+            }
+        }
+        if !self.phrases_only_left.is_empty() {
+            writeln!(f, "\nOnly in {}:", self.left_id)?;
+            for phrase in &self.phrases_only_left {
+                writeln!(f, "  \"{}\"", phrase)?;
+            }
+        }
+        if !self.phrases_only_right.is_empty() {
+            writeln!(f, "\nOnly in {}:", self.right_id)?;
+            for phrase in &self.phrases_only_right {
+                writeln!(f, "  \"{}\"", phrase)?;
+            }
+        }
+        if self.is_empty() {
+            writeln!(f, "\nProfiles are identical in all compared fields.")?;
+        }
+        Ok(())
+    }
 }
 
 // =================================================================
@@ -722,6 +1385,40 @@ impl AiProfileStore {
         self.profiles.insert(profile.id.clone(), profile);
     }
 
+    /// Registers a user-defined profile, rejecting an empty `id` or one
+    /// already in the store. Returns the registered id.
+    pub fn register_profile(&mut self, profile: AiProfile) -> Result<String, String> {
+        let id = profile.id.trim().to_string();
+        if id.is_empty() {
+            return Err("Profile id must not be empty".to_string());
+        }
+        if self.profiles.contains_key(&id) {
+            return Err(format!(
+                "Profile '{}' is already registered; choose a different id",
+                id
+            ));
+        }
+        self.profiles.insert(
+            id.clone(),
+            AiProfile {
+                id: id.clone(),
+                ..profile
+            },
+        );
+        Ok(id)
+    }
+
+    /// Parses an `AiProfile` from JSON, validates it, and registers it via
+    /// `register_profile`. Validation errors list every problem found.
+    pub fn register_from_json(&mut self, json: &str) -> Result<String, String> {
+        let profile: AiProfile =
+            serde_json::from_str(json).map_err(|e| format!("Invalid profile JSON: {}", e))?;
+        profile
+            .validate()
+            .map_err(|errors| ProfileError::summarize(&errors))?;
+        self.register_profile(profile)
+    }
+
     /// Returns a reference to the profile with the given ID, if it exists.
     pub fn get(&self, id: &str) -> Option<&AiProfile> {
         self.profiles.get(id)
@@ -783,6 +1480,7 @@ impl AiProfileStore {
             preferred_list_style: ListStyle::Bullets,
             max_response_length: None,
             paragraph_style: ParagraphStyle::Adaptive,
+            prefers_tables: false,
         };
         profile.supported_modalities = vec![
             "text".to_string(),
@@ -836,6 +1534,7 @@ impl AiProfileStore {
             preferred_list_style: ListStyle::Numbered,
             max_response_length: None,
             paragraph_style: ParagraphStyle::Medium,
+            prefers_tables: false,
         };
         profile.supported_modalities =
             vec!["text".to_string(), "vision".to_string(), "code".to_string()];
@@ -883,6 +1582,7 @@ impl AiProfileStore {
             preferred_list_style: ListStyle::Numbered,
             max_response_length: None,
             paragraph_style: ParagraphStyle::Medium,
+            prefers_tables: false,
         };
         profile.supported_modalities = vec![
             "text".to_string(),
@@ -1078,6 +1778,187 @@ mod tests {
         assert_ne!(closest.id, "gpt4o");
     }
 
+    #[test]
+    fn test_try_blend_rejects_bad_weights() {
+        let a = AiProfile::new("a", "A");
+        let b = AiProfile::new("b", "B");
+        let both = [&a, &b];
+
+        assert_eq!(
+            AiProfile::try_blend(&[], &[]).unwrap_err(),
+            BlendError::Empty
+        );
+        assert_eq!(
+            AiProfile::try_blend(&both, &[1.0]).unwrap_err(),
+            BlendError::LengthMismatch {
+                profiles: 2,
+                weights: 1
+            }
+        );
+        assert!(matches!(
+            AiProfile::try_blend(&both, &[0.5, -0.5]).unwrap_err(),
+            BlendError::NegativeWeight { index: 1, .. }
+        ));
+        assert!(matches!(
+            AiProfile::try_blend(&both, &[f64::NAN, 0.5]).unwrap_err(),
+            BlendError::NonFiniteWeight { index: 0, .. }
+        ));
+        assert!(matches!(
+            AiProfile::try_blend(&both, &[f64::INFINITY, 0.5]).unwrap_err(),
+            BlendError::NonFiniteWeight { index: 0, .. }
+        ));
+        assert_eq!(
+            AiProfile::try_blend(&both, &[0.0, 0.0]).unwrap_err(),
+            BlendError::AllZero
+        );
+
+        // A zero weight alongside a positive one is fine
+        let blended = AiProfile::try_blend(&both, &[0.0, 1.0]).unwrap();
+        assert_eq!(blended.display_name, "A+B");
+    }
+
+    #[test]
+    fn test_profile_validate() {
+        for profile in AiProfileStore::default().list() {
+            assert_eq!(profile.validate(), Ok(()), "{} should be valid", profile.id);
+        }
+
+        let mut bad = AiProfile::new("", " ");
+        bad.response_style.verbosity = 1.5;
+        bad.response_style.formality = f64::NAN;
+        bad.set_personality("confidence", 0.5);
+        bad.personality[0].value = -2.0;
+        bad.extra_axes.insert("citation_density".to_string(), 3.0);
+        bad.signature_phrases = vec!["Sure!".to_string(), "".to_string()];
+
+        let errors = bad.validate().unwrap_err();
+        assert_eq!(errors.len(), 7);
+        assert!(errors.contains(&ProfileError::EmptyId));
+        assert!(errors.contains(&ProfileError::EmptyDisplayName));
+        assert!(errors.contains(&ProfileError::EmptySignaturePhrase(1)));
+        assert!(errors.contains(&ProfileError::StyleOutOfRange {
+            field: "verbosity".to_string(),
+            value: 1.5,
+        }));
+        let summary = ProfileError::summarize(&errors);
+        assert!(summary.contains("7 problems"));
+        assert!(summary.contains("citation_density"));
+
+        // register_from_json reports every problem, not just the first
+        // (NaN has no JSON form, so keep formality finite here)
+        let mut bad_json = bad.clone();
+        bad_json.id = "bad".to_string();
+        bad_json.response_style.formality = 0.5;
+        let json = serde_json::to_string(&bad_json).unwrap();
+        let mut store = AiProfileStore::new();
+        let err = store.register_from_json(&json).unwrap_err();
+        assert!(err.contains("display_name") && err.contains("verbosity"));
+        assert!(store.get("bad").is_none());
+    }
+
+    #[test]
+    fn test_register_from_json() {
+        let mut store = AiProfileStore::default();
+        let mut custom = AiProfile::new("my-finetune", "My Finetune");
+        custom
+            .signature_phrases
+            .push("Per my training,".to_string());
+        let json = serde_json::to_string(&custom).unwrap();
+
+        assert_eq!(store.register_from_json(&json).unwrap(), "my-finetune");
+        assert!(store.ids().contains(&"my-finetune".to_string()));
+        assert_eq!(
+            store.get("my-finetune").unwrap().signature_phrases,
+            vec!["Per my training,".to_string()]
+        );
+
+        // Collisions, empty ids, and bad JSON are rejected
+        let err = store.register_from_json(&json).unwrap_err();
+        assert!(err.contains("already registered"));
+        let err = store
+            .register_profile(AiProfile::new("  ", "Blank"))
+            .unwrap_err();
+        assert!(err.contains("must not be empty"));
+        assert!(store
+            .register_from_json("{not json")
+            .unwrap_err()
+            .contains("Invalid profile JSON"));
+    }
+
+    #[test]
+    fn test_extra_axes() {
+        let mut niche = AiProfile::new("niche", "Niche");
+        niche.extra_axes.insert("citation_density".to_string(), 0.8);
+        niche.extra_axes.insert("refusal_tendency".to_string(), 0.2);
+        let mut other = AiProfile::new("other", "Other");
+        other.extra_axes.insert("refusal_tendency".to_string(), 0.6);
+
+        // Missing keys don't drag the average toward 0.0
+        let blended = AiProfile::blend(&[&niche, &other], &[0.5, 0.5]);
+        assert_eq!(blended.extra_axes["citation_density"], 0.8);
+        assert!((blended.extra_axes["refusal_tendency"] - 0.4).abs() < 1e-9);
+
+        // Unknown axis names in a correction route into extra_axes
+        let delta = PersonalityDelta::new(DeltaSource::UserCorrection)
+            .with_adjustment("citation_density", 0.1)
+            .with_adjustment("hedging_rate", 0.3)
+            .with_confidence(1.0);
+        niche.apply_correction(&delta);
+        assert!(niche.personality.is_empty());
+        assert!((niche.extra_axes["citation_density"] - 0.9).abs() < 1e-9);
+        assert_eq!(niche.personality_value("hedging_rate"), Some(0.3));
+
+        // Older JSON without the field still loads
+        let mut json: serde_json::Value = serde_json::to_value(&other).unwrap();
+        json.as_object_mut().unwrap().remove("extra_axes");
+        let restored: AiProfile = serde_json::from_value(json).unwrap();
+        assert!(restored.extra_axes.is_empty());
+    }
+
+    #[test]
+    fn test_profile_diff() {
+        let store = AiProfileStore::default();
+        let gpt4o = store.get("gpt4o").unwrap();
+        assert!(gpt4o.diff(gpt4o).is_empty());
+
+        let mut other = gpt4o.clone();
+        other.id = "variant".to_string();
+        other.set_personality("confidence", 0.1);
+        other
+            .personality
+            .push(PersonalityAxis::new("whimsy", 0.4, "dry", "whimsical"));
+        other.signature_phrases.truncate(1);
+        other.signature_phrases.push("Indeed.".to_string());
+        other.safety.caution_level = 0.1;
+
+        let diff = gpt4o.diff(&other);
+        assert!(!diff.is_empty());
+        let confidence = diff.axes.iter().find(|a| a.name == "confidence").unwrap();
+        let expected = 0.1 - gpt4o.personality_value("confidence").unwrap();
+        assert!((confidence.delta().unwrap() - expected).abs() < 1e-9);
+        // Axis defined on one side only
+        let whimsy = diff.axes.iter().find(|a| a.name == "whimsy").unwrap();
+        assert_eq!((whimsy.left, whimsy.right), (None, Some(0.4)));
+        assert_eq!(whimsy.delta(), None);
+        // Differing phrase list lengths
+        assert_eq!(
+            diff.phrases_only_left.len(),
+            gpt4o.signature_phrases.len() - 1
+        );
+        assert_eq!(diff.phrases_only_right, vec!["Indeed.".to_string()]);
+        assert!(diff
+            .field_changes
+            .iter()
+            .any(|c| c.field == "safety.caution_level"));
+
+        let table = diff.to_string();
+        assert!(table.contains("whimsy"));
+        assert!(table.contains("only one"));
+        let json = serde_json::to_string(&diff).unwrap();
+        let restored: ProfileDiff = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, diff);
+    }
+
     #[test]
     fn test_profile_blend() {
         let store = AiProfileStore::default();
@@ -1124,6 +2005,59 @@ mod tests {
         assert!((new_confidence - (original_confidence + 0.1)).abs() < 0.001);
     }
 
+    #[test]
+    fn test_delta_invert_round_trip() {
+        let mut profile = AiProfileStore::gpt4o_profile();
+        let before = profile.clone();
+
+        let delta = PersonalityDelta::new(DeltaSource::SelfMonitoring)
+            .with_adjustment("confidence", 0.05)
+            .with_adjustment("formality", -0.1)
+            .with_confidence(0.7);
+        let inverse = delta.invert();
+        assert!(matches!(inverse.source, DeltaSource::Rollback));
+        assert_eq!(inverse.adjustments[0], ("confidence".to_string(), -0.05));
+
+        profile.apply_correction(&delta);
+        profile.apply_correction(&inverse);
+        for axis in &before.personality {
+            let value = profile.personality_value(&axis.name).unwrap();
+            assert!((value - axis.value).abs() < 1e-12, "{} drifted", axis.name);
+        }
+    }
+
+    #[test]
+    fn test_delta_invert_restores_saturated_axes() {
+        let mut profile = AiProfileStore::gpt4o_profile();
+        let before = profile.clone();
+
+        // Large enough to clamp confidence and verbosity, plus a new axis
+        let delta = PersonalityDelta::new(DeltaSource::SelfMonitoring)
+            .with_adjustment("confidence", 5.0)
+            .with_adjustment("verbosity", 5.0)
+            .with_adjustment("whimsy", 0.4)
+            .with_confidence(1.0);
+        profile.apply_correction(&delta);
+        assert_eq!(profile.personality_value("confidence"), Some(1.0));
+        assert_eq!(profile.response_style.verbosity, 1.0);
+
+        profile.apply_correction(&delta.invert());
+        for axis in &before.personality {
+            let value = profile.personality_value(&axis.name).unwrap();
+            assert!(
+                (value - axis.value).abs() < f64::EPSILON,
+                "{} drifted",
+                axis.name
+            );
+        }
+        assert_eq!(profile.personality.len(), before.personality.len());
+        assert!(profile.extra_axes.is_empty());
+        assert_eq!(
+            profile.response_style.verbosity,
+            before.response_style.verbosity
+        );
+    }
+
     #[test]
     fn test_profile_serialization() {
         let profile = AiProfileStore::gpt4o_profile();
@@ -1132,4 +2066,20 @@ mod tests {
         assert_eq!(restored.id, "gpt4o");
         assert_eq!(restored.display_name, "GPT-4o");
     }
+
+    #[test]
+    fn test_profile_language_defaults_to_english() {
+        assert_eq!(AiProfile::new("x", "X").language, "en");
+
+        // Profiles saved before the field existed load as English
+        let mut value = serde_json::to_value(AiProfileStore::claude_profile()).unwrap();
+        value.as_object_mut().unwrap().remove("language");
+        let restored: AiProfile = serde_json::from_value(value).unwrap();
+        assert_eq!(restored.language, "en");
+
+        let mut french = AiProfileStore::claude_profile();
+        french.language = "fr-CA".to_string();
+        let diff = AiProfileStore::claude_profile().diff(&french);
+        assert!(diff.field_changes.iter().any(|c| c.field == "language"));
+    }
 }
@@ -403,9 +403,11 @@ impl ReinforcementLearningOptimizer {
         self.stats.training_runs += 1;
         self.stats.trajectories_in_training += result.num_trajectories_used;
         
-        println!(
-            "[RL] Training completed: loss={:.4}, time={}ms, trajectories_used={}",
-            result.loss, result.training_time_ms, result.num_trajectories_used
+        tracing::info!(
+            loss = result.loss,
+            time_ms = result.training_time_ms,
+            trajectories_used = result.num_trajectories_used,
+            "RL training completed"
         );
         
         Ok(())